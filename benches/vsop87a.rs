@@ -76,6 +76,18 @@ fn vsop87a_neptune(c: &mut Criterion) {
     });
 }
 
+fn vsop87a_jupiter_batch(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    let start = rng.gen_range(990930.5..3912521.5);
+    let jdes: Vec<f64> = (0..8760)
+        .map(|hour| start + f64::from(hour) / 24.0)
+        .collect();
+    let mut output = vec![vsop87::RectangularCoordinates::default(); jdes.len()];
+    c.bench_function("VSOP87a Jupiter hourly batch (1 year)", move |b| {
+        b.iter(|| vsop87a::batch(vsop87::Body::Jupiter, black_box(&jdes), &mut output))
+    });
+}
+
 criterion_group!(
     vsop87a_benches,
     vsop87a_mercury,
@@ -86,6 +98,7 @@ criterion_group!(
     vsop87a_jupiter,
     vsop87a_saturn,
     vsop87a_uranus,
-    vsop87a_neptune
+    vsop87a_neptune,
+    vsop87a_jupiter_batch
 );
 criterion_main!(vsop87a_benches);
@@ -0,0 +1,468 @@
+//! Geometric, astrometric and apparent places of the bodies, seen from the Earth.
+
+use super::{
+    typed::{Barycentric, EclipticJ2000, Heliocentric, Icrs, Origin, Position, Velocity},
+    vsop87e, Body, Error, HorizontalCoordinates, JulianDay, Nutation, Observer,
+    RectangularCoordinates, Refraction, Rotation, SphericalCoordinates,
+};
+
+#[cfg(feature = "no_std")]
+use libm::sqrt;
+
+/// Speed of light, in *AU* per day.
+const SPEED_OF_LIGHT: f64 = 299_792_458_f64 * 86_400_f64 / 149_597_870_700_f64;
+
+/// Schwarzschild radius of the Sun (2*GM*/*c*²), in *AU*.
+const SCHWARZSCHILD_RADIUS: f64 = 1.974_125_743_36e-8;
+
+/// Maximum number of iterations of the light-time.
+const LIGHT_TIME_ITERATIONS: usize = 10;
+
+/// Convergence threshold of the light-time, in days (about 1 microsecond).
+const LIGHT_TIME_TOLERANCE: f64 = 1e-11;
+
+/// Position of a body in equatorial and ecliptic spherical coordinates.
+///
+/// The longitude of the equatorial coordinates is the right ascension (*α*) and their latitude is
+/// the declination (*δ*). The distance is the same in both of them, in *AU*.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Place {
+    equatorial: SphericalCoordinates,
+    ecliptic: SphericalCoordinates,
+}
+
+impl Place {
+    /// Creates the place from equatorial and ecliptic rectangular coordinates of the same
+    /// position.
+    fn new(equatorial: RectangularCoordinates, ecliptic: RectangularCoordinates) -> Self {
+        Self {
+            equatorial: equatorial.into(),
+            ecliptic: ecliptic.into(),
+        }
+    }
+
+    /// Gets the equatorial coordinates: right ascension, declination and distance.
+    #[must_use]
+    pub fn equatorial(&self) -> SphericalCoordinates {
+        self.equatorial
+    }
+
+    /// Gets the ecliptic coordinates: longitude, latitude and distance.
+    #[must_use]
+    pub fn ecliptic(&self) -> SphericalCoordinates {
+        self.ecliptic
+    }
+}
+
+/// Geometric, astrometric and apparent places of a body, seen from the center of the Earth.
+///
+/// - The geometric place is the position of the body at the time of the observation. Its
+///   equatorial coordinates are referred to the *ICRS*, and its ecliptic coordinates to the
+///   *VSOP87* ecliptic and equinox J2000.0.
+/// - The astrometric place is the position of the body when the light that reaches the Earth left
+///   it, in the same frames. It can be compared with the positions of star catalogs.
+/// - The apparent place adds the gravitational deflection of the light by the Sun and the annual
+///   aberration, and it is referred to the true equator and equinox of the date, and to the
+///   ecliptic and true equinox of the date.
+///
+/// The distance of the astrometric and apparent places is the distance travelled by the light.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeocentricPlace {
+    geometric: Place,
+    astrometric: Place,
+    apparent: Place,
+    light_time: f64,
+}
+
+impl GeocentricPlace {
+    /// Gets the geometric place, without any correction.
+    #[must_use]
+    pub fn geometric(&self) -> Place {
+        self.geometric
+    }
+
+    /// Gets the astrometric place, corrected for light-time.
+    #[must_use]
+    pub fn astrometric(&self) -> Place {
+        self.astrometric
+    }
+
+    /// Gets the apparent place, corrected for light-time, deflection, aberration, precession and
+    /// nutation.
+    #[must_use]
+    pub fn apparent(&self) -> Place {
+        self.apparent
+    }
+
+    /// Gets the time that the light takes to reach the Earth from the body, in days.
+    #[must_use]
+    pub fn light_time(&self) -> f64 {
+        self.light_time
+    }
+}
+
+/// Geometric, astrometric and apparent places of a body, seen by an observer on the surface of
+/// the Earth.
+///
+/// The places are defined in the same way as in [`GeocentricPlace`], but from the position of the
+/// observer, so they include the diurnal parallax and the diurnal aberration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TopocentricPlace {
+    geometric: Place,
+    astrometric: Place,
+    apparent: Place,
+    light_time: f64,
+}
+
+impl TopocentricPlace {
+    /// Gets the geometric place, without any correction.
+    #[must_use]
+    pub fn geometric(&self) -> Place {
+        self.geometric
+    }
+
+    /// Gets the astrometric place, corrected for light-time.
+    #[must_use]
+    pub fn astrometric(&self) -> Place {
+        self.astrometric
+    }
+
+    /// Gets the apparent place, corrected for light-time, deflection, aberration, precession and
+    /// nutation.
+    #[must_use]
+    pub fn apparent(&self) -> Place {
+        self.apparent
+    }
+
+    /// Gets the time that the light takes to reach the observer from the body, in days.
+    #[must_use]
+    pub fn light_time(&self) -> f64 {
+        self.light_time
+    }
+}
+
+/// Calculates the geometric, astrometric and apparent places of a body, seen from the center of
+/// the Earth, at the given Julian Day in *TT*.
+///
+/// The barycentric positions of the body, the Earth and the Sun, and the barycentric velocity of
+/// the Earth, are calculated with *VSOP87E*. The light-time is iterated on the series, and the
+/// apparent place is corrected with the relativistic formulas of the deflection by the Sun and of
+/// the aberration, and rotated into the true equator of the date with the given nutation model and
+/// its [precession model](Nutation::precession). The corrections are modelled to the
+/// milliarcsecond, well below the precision of *VSOP87*.
+///
+/// It returns an error if the body is not available in *VSOP87E*, such as the Earth - Moon
+/// barycenter, if the body is the Earth, or if the Julian Day is not valid for the body (see
+/// [`vsop87e::checked()`]).
+///
+/// # Example
+///
+/// Example 33.a of Meeus, Astronomical Algorithms: the apparent place of Venus in 1992 December
+/// 20.0 TD.
+///
+/// ```
+/// # #[cfg(feature = "venus")]
+/// # {
+/// use vsop87::{geocentric_place, Body, Nutation};
+///
+/// let venus = geocentric_place(Body::Venus, 2448976.5, Nutation::Iau2000B)?;
+/// let apparent = venus.apparent().equatorial();
+///
+/// // α = 21h04m41.454s, δ = −18°53′16.84″, within 0.2″.
+/// assert!((apparent.longitude().to_degrees() - 316.172_725).abs() < 0.000_06);
+/// assert!((apparent.latitude().to_degrees() + 18.888_011).abs() < 0.000_06);
+///
+/// // The light left Venus 7.6 minutes earlier, when it was 0.910_948 AU away.
+/// assert!((venus.geometric().equatorial().distance() - 0.910_846).abs() < 0.000_001);
+/// assert!((apparent.distance() - 0.910_948).abs() < 0.000_001);
+/// assert!((venus.light_time() * 1_440.0 - 7.576).abs() < 0.001);
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+pub fn geocentric_place(
+    body: Body,
+    tt: impl Into<JulianDay>,
+    nutation: Nutation,
+) -> Result<GeocentricPlace, Error> {
+    let tt = tt.into();
+    if body == Body::Earth {
+        return Err(Error::ObserverBody);
+    }
+
+    let earth = vsop87e::typed_state(Body::Earth, tt)?;
+    let ([geometric, astrometric, apparent], light_time) = places(
+        body,
+        tt,
+        nutation,
+        earth.position(),
+        earth.velocity(),
+        |position| position.geocentric(earth.position()),
+    )?;
+
+    Ok(GeocentricPlace {
+        geometric,
+        astrometric,
+        apparent,
+        light_time,
+    })
+}
+
+/// Calculates the geometric, astrometric and apparent places of a body, seen by an observer on
+/// the surface of the Earth, at the given Julian Days in *UT1* and *TT*.
+///
+/// The places are calculated in the same way as in [`geocentric_place()`], from the barycentric
+/// position and velocity of the observer: those of the Earth, plus the
+/// [position and velocity of the observer](Observer::state) relative to the center of the Earth.
+/// This adds the diurnal parallax, up to 8.8″ for the Sun, and the diurnal aberration, up to 0.3″.
+///
+/// It returns the same errors as [`geocentric_place()`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use vsop87::{geocentric_place, topocentric_place, Body, Nutation, Observer};
+///
+/// let observer = Observer::new(33.356_1_f64.to_radians(), -116.863_f64.to_radians(), 1_706.0);
+/// let (ut1, tt) = (2452879.636_806, 2452879.637_597);
+///
+/// let topocentric = topocentric_place(Body::Mars, observer, ut1, tt, Nutation::Iau2000B)?;
+/// let geocentric = geocentric_place(Body::Mars, tt, Nutation::Iau2000B)?;
+///
+/// // Example 40.a of Meeus, Astronomical Algorithms: the parallax of Mars, 0.37 AU away, moves
+/// // it by 1.29 seconds of time in right ascension and by −14.1″ in declination.
+/// let topocentric = topocentric.apparent().equatorial();
+/// let geocentric = geocentric.apparent().equatorial();
+/// let right_ascension = (topocentric.longitude() - geocentric.longitude()).to_degrees() * 240.0;
+/// let declination = (topocentric.latitude() - geocentric.latitude()).to_degrees() * 3_600.0;
+/// assert!((right_ascension - 1.29).abs() < 0.02);
+/// assert!((declination + 14.1).abs() < 0.1);
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+pub fn topocentric_place(
+    body: Body,
+    observer: Observer,
+    ut1: impl Into<JulianDay>,
+    tt: impl Into<JulianDay>,
+    nutation: Nutation,
+) -> Result<TopocentricPlace, Error> {
+    let tt = tt.into();
+    if body == Body::Earth {
+        return Err(Error::ObserverBody);
+    }
+
+    let earth = vsop87e::typed_state(Body::Earth, tt)?;
+    let offset = observer.state(ut1, tt, nutation).to_ecliptic_j2000();
+
+    let ([geometric, astrometric, apparent], light_time) = places(
+        body,
+        tt,
+        nutation,
+        earth.position() + offset.position().displacement(),
+        earth.velocity() + offset.velocity(),
+        |position| {
+            position
+                .geocentric(earth.position())
+                .topocentric(offset.position())
+        },
+    )?;
+
+    Ok(TopocentricPlace {
+        geometric,
+        astrometric,
+        apparent,
+        light_time,
+    })
+}
+
+/// Calculates the horizontal coordinates of a body, seen by an observer on the surface of the
+/// Earth, at the given Julian Days in *UT1* and *TT*.
+///
+/// They are calculated from the apparent equatorial coordinates of the
+/// [topocentric place](topocentric_place()) and the
+/// [local apparent sidereal time](Observer::local_sidereal_time), with the given refraction model.
+///
+/// It returns the same errors as [`geocentric_place()`].
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{horizontal_coordinates, Body, Nutation, Observer, Refraction};
+///
+/// let observer = Observer::new(52.0_f64.to_radians(), 5.0_f64.to_radians(), 0.0);
+/// let (ut1, tt) = (2460000.0, 2460000.0 + 69.2 / 86_400.0);
+///
+/// // At noon in winter, the Sun is low in the south.
+/// let sun = horizontal_coordinates(
+///     Body::Sun,
+///     observer,
+///     ut1,
+///     tt,
+///     Nutation::Iau2000B,
+///     Refraction::Saemundsson,
+/// )?;
+/// assert!((sun.azimuth().to_degrees() - 180.0).abs() < 10.0);
+/// assert!(sun.altitude().to_degrees() > 20.0 && sun.altitude().to_degrees() < 35.0);
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+pub fn horizontal_coordinates(
+    body: Body,
+    observer: Observer,
+    ut1: impl Into<JulianDay>,
+    tt: impl Into<JulianDay>,
+    nutation: Nutation,
+    refraction: Refraction,
+) -> Result<HorizontalCoordinates, Error> {
+    let (ut1, tt) = (ut1.into(), tt.into());
+    let place = topocentric_place(body, observer, ut1, tt, nutation)?;
+
+    Ok(HorizontalCoordinates::from_equatorial(
+        place.apparent().equatorial(),
+        observer.latitude(),
+        observer.local_sidereal_time(ut1, tt, nutation),
+        refraction,
+    ))
+}
+
+/// Calculates the geometric, astrometric and apparent places of a body, and the light-time, for
+/// an observer with the given barycentric position and velocity.
+///
+/// The barycentric positions of the body are moved to the center of the observer with `locate`.
+fn places<C: Origin>(
+    body: Body,
+    tt: JulianDay,
+    nutation: Nutation,
+    position: Position<EclipticJ2000, Barycentric>,
+    velocity: Velocity<EclipticJ2000>,
+    locate: impl Fn(Position<EclipticJ2000, Barycentric>) -> Position<EclipticJ2000, C>,
+) -> Result<([Place; 3], f64), Error> {
+    let sun = vsop87e::typed(Body::Sun, tt)?;
+    let geometric = locate(vsop87e::typed(body, tt)?);
+
+    // The light-time is iterated until the position of the body at the time of emission is
+    // consistent with the distance that the light travelled.
+    let mut light_time = geometric.distance() / SPEED_OF_LIGHT;
+    let mut emission = tt;
+    let mut astrometric = geometric;
+    for _ in 0..LIGHT_TIME_ITERATIONS {
+        emission = tt - light_time;
+        astrometric = locate(vsop87e::typed(body, emission)?);
+
+        let previous = light_time;
+        light_time = astrometric.distance() / SPEED_OF_LIGHT;
+        if (light_time - previous).abs() < LIGHT_TIME_TOLERANCE {
+            break;
+        }
+    }
+
+    let direction = unit(astrometric.to_icrs().coordinates());
+    let observer = position.heliocentric(sun).to_icrs();
+
+    // The Sun does not deflect its own light.
+    let deflected = if body == Body::Sun {
+        direction
+    } else {
+        let source = vsop87e::typed(body, emission)?
+            .heliocentric(vsop87e::typed(Body::Sun, emission)?)
+            .to_icrs();
+        deflection(direction, unit(source.coordinates()), observer)
+    };
+
+    let velocity = velocity.to_icrs().coordinates();
+    let aberrated = aberration(deflected, velocity, observer.distance());
+
+    let apparent = Position::<Icrs, C>::new(scale(aberrated, astrometric.distance()))
+        .to_mean_equator_of_date(tt, nutation.precession())
+        .to_true_equator_of_date(tt, nutation)
+        .coordinates();
+    let apparent_ecliptic =
+        Rotation::ecliptic_to_equatorial(nutation.true_obliquity(tt)).inverse() * apparent;
+
+    Ok((
+        [
+            Place::new(geometric.to_icrs().coordinates(), geometric.coordinates()),
+            Place::new(
+                astrometric.to_icrs().coordinates(),
+                astrometric.coordinates(),
+            ),
+            Place::new(apparent, apparent_ecliptic),
+        ],
+        light_time,
+    ))
+}
+
+/// Applies the gravitational deflection of the light by the Sun to a direction.
+///
+/// It receives the unit vector from the observer to the body, the unit vector from the Sun to the
+/// body and the position of the observer relative to the Sun.
+fn deflection(
+    direction: RectangularCoordinates,
+    source: RectangularCoordinates,
+    observer: Position<Icrs, Heliocentric>,
+) -> RectangularCoordinates {
+    let distance = observer.distance();
+    let observer = unit(observer.coordinates());
+
+    // The limiter avoids the singularity when the body is behind the Sun.
+    let limit = 1e-6 / (distance * distance).max(1_f64);
+    let factor = SCHWARZSCHILD_RADIUS / distance / (1_f64 + dot(source, observer)).max(limit);
+
+    // p + factor · p × (e × q) = p + factor · ((p · q) e − (p · e) q)
+    let (pq, pe) = (dot(direction, source), dot(direction, observer));
+    RectangularCoordinates {
+        x: direction.x + factor * (pq * observer.x - pe * source.x),
+        y: direction.y + factor * (pq * observer.y - pe * source.y),
+        z: direction.z + factor * (pq * observer.z - pe * source.z),
+    }
+}
+
+/// Applies the relativistic aberration to a direction, given the barycentric velocity of the
+/// observer, in *AU* per day, and its distance to the Sun, in *AU*.
+fn aberration(
+    direction: RectangularCoordinates,
+    velocity: RectangularCoordinates,
+    sun_distance: f64,
+) -> RectangularCoordinates {
+    let velocity = scale(velocity, 1_f64 / SPEED_OF_LIGHT);
+
+    #[cfg(not(feature = "no_std"))]
+    let lorentz = (1_f64 - dot(velocity, velocity)).sqrt();
+    #[cfg(feature = "no_std")]
+    let lorentz = sqrt(1_f64 - dot(velocity, velocity));
+
+    let projection = dot(direction, velocity);
+    let w1 = 1_f64 + projection / (1_f64 + lorentz);
+    let w2 = SCHWARZSCHILD_RADIUS / sun_distance;
+
+    unit(RectangularCoordinates {
+        x: direction.x * lorentz + w1 * velocity.x + w2 * (velocity.x - projection * direction.x),
+        y: direction.y * lorentz + w1 * velocity.y + w2 * (velocity.y - projection * direction.y),
+        z: direction.z * lorentz + w1 * velocity.z + w2 * (velocity.z - projection * direction.z),
+    })
+}
+
+/// Calculates the dot product of two vectors.
+fn dot(a: RectangularCoordinates, b: RectangularCoordinates) -> f64 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+/// Multiplies a vector by a factor.
+fn scale(coordinates: RectangularCoordinates, factor: f64) -> RectangularCoordinates {
+    RectangularCoordinates {
+        x: coordinates.x * factor,
+        y: coordinates.y * factor,
+        z: coordinates.z * factor,
+    }
+}
+
+/// Calculates the unit vector in the direction of a vector.
+fn unit(coordinates: RectangularCoordinates) -> RectangularCoordinates {
+    #[cfg(not(feature = "no_std"))]
+    let length = dot(coordinates, coordinates).sqrt();
+    #[cfg(feature = "no_std")]
+    let length = sqrt(dot(coordinates, coordinates));
+
+    scale(coordinates, 1_f64 / length)
+}
@@ -0,0 +1,107 @@
+//! Bodies of the solar system available in the *VSOP87* solutions.
+
+use super::Error;
+
+#[cfg(feature = "no_std")]
+use core::{fmt, str::FromStr};
+#[cfg(not(feature = "no_std"))]
+use std::{fmt, str::FromStr};
+
+/// Bodies of the solar system that can be calculated with the *VSOP87* solutions.
+///
+/// Not every body is available in every solution. The Sun is only available in *VSOP87E*, the
+/// Earth in all solutions except the base *VSOP87* and the Earth - Moon barycenter only in the
+/// base *VSOP87* solution and in *VSOP87A*. Use [`Solution::bodies()`](crate::Solution::bodies)
+/// to know which bodies are available in each solution.
+///
+/// Bodies can be parsed from their names, ignoring the case:
+///
+/// ```
+/// use vsop87::Body;
+///
+/// assert_eq!("Mars".parse::<Body>(), Ok(Body::Mars));
+/// assert_eq!("earth_moon".parse::<Body>(), Ok(Body::EarthMoon));
+/// assert_eq!(Body::EarthMoon.to_string(), "Earth-Moon");
+/// assert!("Pluto".parse::<Body>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Body {
+    /// The Sun.
+    Sun,
+    /// The planet Mercury.
+    Mercury,
+    /// The planet Venus.
+    Venus,
+    /// The planet Earth.
+    Earth,
+    /// The planet Mars.
+    Mars,
+    /// The planet Jupiter.
+    Jupiter,
+    /// The planet Saturn.
+    Saturn,
+    /// The planet Uranus.
+    Uranus,
+    /// The planet Neptune.
+    Neptune,
+    /// The Earth - Moon barycenter.
+    EarthMoon,
+}
+
+impl Body {
+    /// All the bodies, in order of distance to the Sun, with the Earth - Moon barycenter last.
+    pub const ALL: [Self; 10] = [
+        Self::Sun,
+        Self::Mercury,
+        Self::Venus,
+        Self::Earth,
+        Self::Mars,
+        Self::Jupiter,
+        Self::Saturn,
+        Self::Uranus,
+        Self::Neptune,
+        Self::EarthMoon,
+    ];
+
+    /// Gets the name of the body.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Sun => "Sun",
+            Self::Mercury => "Mercury",
+            Self::Venus => "Venus",
+            Self::Earth => "Earth",
+            Self::Mars => "Mars",
+            Self::Jupiter => "Jupiter",
+            Self::Saturn => "Saturn",
+            Self::Uranus => "Uranus",
+            Self::Neptune => "Neptune",
+            Self::EarthMoon => "Earth-Moon",
+        }
+    }
+}
+
+impl fmt::Display for Body {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Body {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        Self::ALL
+            .into_iter()
+            .find(|body| body.name().eq_ignore_ascii_case(s))
+            .or_else(|| {
+                ["earth_moon", "earth moon", "earthmoon", "emb"]
+                    .into_iter()
+                    .any(|name| name.eq_ignore_ascii_case(s))
+                    .then_some(Self::EarthMoon)
+            })
+            .ok_or(Error::UnknownBody)
+    }
+}
@@ -0,0 +1,306 @@
+//! Models of the difference between Terrestrial Time and Universal Time (*ΔT*).
+
+use super::{julian_day::SECONDS_PER_DAY, polynomial, JulianDay};
+
+/// Length of the Julian year, in days.
+const DAYS_PER_YEAR: f64 = 365.25;
+
+/// Cubic spline through the values of *ΔT* tabulated by Morrison and Stephenson (2004), from the
+/// year −500 to 1600.
+///
+/// Each entry has the first year of a century, and the coefficients of the polynomial in the
+/// fraction of the century, `t`, that gives *ΔT* in seconds: `a0 + a1·t + a2·t² + a3·t³`. The
+/// spline is clamped to the slope of the long-term parabola at −500, and to the slope of the
+/// *Espenak–Meeus* polynomial at 1600.
+const SPLINE: [(f64, [f64; 4]); 21] = [
+    (-500_f64, [17_190_f64, -1_484.8, -385.060_4, 209.860_4]),
+    (-400_f64, [15_530_f64, -1_625.339_6, 244.520_9, -69.181_3]),
+    (-300_f64, [14_080_f64, -1_343.841_8, 36.976_9, 16.864_9]),
+    (-200_f64, [12_790_f64, -1_219.293_3, 87.571_5, -18.278_2]),
+    (-100_f64, [11_640_f64, -1_098.984_9, 32.736_9, 6.247_9]),
+    (0_f64, [10_580_f64, -1_014.767_2, 51.480_7, -16.713_5]),
+    (100_f64, [9_600_f64, -961.946_2, 1.340_3, 0.606]),
+    (200_f64, [8_640_f64, -957.447_8, 3.158_2, -5.710_4]),
+    (300_f64, [7_680_f64, -968.262_6, -13.973, 2.235_6]),
+    (400_f64, [6_700_f64, -989.501_8, -7.266_2, 6.768_1]),
+    (500_f64, [5_710_f64, -983.730_1, 13.038, 0.692_1]),
+    (600_f64, [4_740_f64, -955.577_8, 15.114_4, 10.463_4]),
+    (700_f64, [3_810_f64, -893.958_9, 46.504_5, -2.545_6]),
+    (800_f64, [2_960_f64, -808.586_7, 38.867_7, 9.719_1]),
+    (900_f64, [2_200_f64, -701.694_2, 68.024_8, 3.669_4]),
+    (1_000_f64, [1_570_f64, -554.636_4, 79.033, -4.396_6]),
+    (1_100_f64, [1_090_f64, -409.760_1, 65.843_3, -6.083_2]),
+    (1_200_f64, [740_f64, -296.323, 47.593_8, -1.270_8]),
+    (1_300_f64, [490_f64, -204.947_8, 43.781_5, -8.833_7]),
+    (1_400_f64, [320_f64, -143.885_9, 17.280_4, 6.605_6]),
+    (1_500_f64, [200_f64, -89.508_5, 37.097, -27.588_5]),
+];
+
+/// Standard errors of *ΔT* estimated by Morrison and Stephenson (2004), in seconds, along with
+/// the year they refer to.
+const UNCERTAINTIES: [(f64, f64); 26] = [
+    (-500_f64, 430_f64),
+    (-400_f64, 390_f64),
+    (-300_f64, 360_f64),
+    (-200_f64, 330_f64),
+    (-100_f64, 290_f64),
+    (0_f64, 260_f64),
+    (100_f64, 240_f64),
+    (200_f64, 210_f64),
+    (300_f64, 180_f64),
+    (400_f64, 160_f64),
+    (500_f64, 140_f64),
+    (600_f64, 120_f64),
+    (700_f64, 100_f64),
+    (800_f64, 80_f64),
+    (900_f64, 70_f64),
+    (1_000_f64, 55_f64),
+    (1_100_f64, 40_f64),
+    (1_200_f64, 30_f64),
+    (1_300_f64, 20_f64),
+    (1_400_f64, 20_f64),
+    (1_500_f64, 20_f64),
+    (1_600_f64, 20_f64),
+    (1_700_f64, 5_f64),
+    (1_800_f64, 1_f64),
+    (1_900_f64, 1_f64),
+    (1_950_f64, 0.1),
+];
+
+/// Last year with observed values of *ΔT* used by the models.
+const LAST_OBSERVED_YEAR: f64 = 2_005_f64;
+
+/// Model of the difference between Terrestrial Time and Universal Time (*ΔT = TT − UT*).
+///
+/// The *VSOP87* solutions expect dates in Terrestrial Time (*TT*), while historical records give
+/// them in local solar time, that follows the rotation of the Earth (*UT*). The rotation is slowed
+/// down by the tides, so *ΔT* grows to hours in antiquity, and it can only be estimated from
+/// records of eclipses and occultations, with the uncertainty given by
+/// [`DeltaT::uncertainty()`]. Since 1972, it is better to use [`TimeScales`](crate::TimeScales)
+/// with the published leap seconds and *DUT1*.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "vsop87b", feature = "venus"))]
+/// # {
+/// use vsop87::{vsop87b, DeltaT, JulianDay};
+///
+/// // Noon of the solar eclipse of May 28th, 585 BC, in the proleptic Julian calendar.
+/// let ut = JulianDay::from_julian_calendar(-584, 5, 28)? + 0.5;
+///
+/// let delta_t = DeltaT::MorrisonStephenson.seconds(ut);
+/// assert!((delta_t / 3600.0 - 5.13).abs() < 0.01);
+/// assert!(DeltaT::uncertainty(ut) > 400.0);
+///
+/// let tt = DeltaT::MorrisonStephenson.tt(ut);
+/// let venus = vsop87b::venus(tt);
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DeltaT {
+    /// Polynomial expressions of Espenak and Meeus (2006), used in NASA's *Five Millennium Canon
+    /// of Solar Eclipses*, fitted to the values of Morrison and Stephenson (2004) and to the
+    /// observed values up to 2005, and extrapolated into the future.
+    #[default]
+    EspenakMeeus,
+    /// Cubic spline through the values tabulated by Morrison and Stephenson (2004) for the
+    /// pre-telescopic era, from the year −500 to 1600. After 1600, when telescopic observations
+    /// are available, it uses the *Espenak–Meeus* polynomials.
+    MorrisonStephenson,
+}
+
+impl DeltaT {
+    /// Calculates *ΔT* at the given Julian Day, in seconds.
+    ///
+    /// Before the year −500, both models use the long-term parabola of Morrison and Stephenson
+    /// (2004), `−20 + 32·u²`, where `u` is the number of centuries since 1820.
+    #[must_use]
+    pub fn seconds(self, jd: impl Into<JulianDay>) -> f64 {
+        let year = year(jd.into());
+
+        match self {
+            Self::MorrisonStephenson if (-500_f64..1_600_f64).contains(&year) => {
+                let i = SPLINE.partition_point(|&(start, _)| start <= year) - 1;
+                let (start, coefficients) = SPLINE[i];
+                polynomial(&coefficients, (year - start) / 100_f64)
+            }
+            Self::EspenakMeeus | Self::MorrisonStephenson => espenak_meeus(year),
+        }
+    }
+
+    /// Estimates the uncertainty (one standard error) of *ΔT* at the given Julian Day, in seconds.
+    ///
+    /// Between −500 and 1950 it is interpolated from the standard errors estimated by Morrison and
+    /// Stephenson (2004), and before −500 it follows their formula `0.8·u²`, where `u` is the
+    /// number of centuries since 1820: it is about 430 seconds at −500, and 1,000 seconds at
+    /// −1700. Up to 2005, *ΔT* was observed with an uncertainty under 0.1 seconds. After that, the
+    /// models are extrapolations, and the uncertainty is estimated to grow by 0.1 seconds per year,
+    /// and with the square of the number of centuries since 2005 like for ancient dates.
+    #[must_use]
+    pub fn uncertainty(jd: impl Into<JulianDay>) -> f64 {
+        let year = year(jd.into());
+        let (first_year, _) = UNCERTAINTIES[0];
+        let (last_year, last) = UNCERTAINTIES[UNCERTAINTIES.len() - 1];
+
+        if year < first_year {
+            let u = (year - 1_820_f64) / 100_f64;
+            0.8 * u * u
+        } else if year <= last_year {
+            let after = UNCERTAINTIES.partition_point(|&(start, _)| start <= year);
+            let Some(&(end, next)) = UNCERTAINTIES.get(after) else {
+                return last;
+            };
+            let (start, previous) = UNCERTAINTIES[after - 1];
+            previous + (next - previous) * (year - start) / (end - start)
+        } else if year <= LAST_OBSERVED_YEAR {
+            last
+        } else {
+            let years = year - LAST_OBSERVED_YEAR;
+            let u = years / 100_f64;
+            last + 0.1 * years + 0.8 * u * u
+        }
+    }
+
+    /// Converts the given Julian Day in Universal Time (*UT*) into Terrestrial Time (*TT*).
+    #[must_use]
+    pub fn tt(self, ut: impl Into<JulianDay>) -> JulianDay {
+        let ut = ut.into();
+        ut + self.seconds(ut) / SECONDS_PER_DAY
+    }
+
+    /// Converts the given Julian Day in Terrestrial Time (*TT*) into Universal Time (*UT*).
+    ///
+    /// Since *ΔT* depends on the date in *UT*, it is refined iteratively.
+    #[must_use]
+    pub fn ut(self, tt: impl Into<JulianDay>) -> JulianDay {
+        let tt = tt.into();
+
+        let mut ut = tt - self.seconds(tt) / SECONDS_PER_DAY;
+        for _ in 0..2 {
+            ut = tt - self.seconds(ut) / SECONDS_PER_DAY;
+        }
+        ut
+    }
+}
+
+/// Calculates the decimal year of the given Julian Day.
+fn year(jd: JulianDay) -> f64 {
+    2_000_f64 + jd.days_since_j2000() / DAYS_PER_YEAR
+}
+
+/// Calculates *ΔT* in seconds with the polynomial expressions of Espenak and Meeus (2006).
+fn espenak_meeus(year: f64) -> f64 {
+    let parabola = |year: f64| {
+        let u = (year - 1_820_f64) / 100_f64;
+        -20_f64 + 32_f64 * u * u
+    };
+
+    if year < -500_f64 {
+        parabola(year)
+    } else if year < 500_f64 {
+        polynomial(
+            &[
+                10_583.6,
+                -1_014.41,
+                33.783_11,
+                -5.952_053,
+                -0.179_845_2,
+                0.022_174_192,
+                0.009_031_652_1,
+            ],
+            year / 100_f64,
+        )
+    } else if year < 1_600_f64 {
+        polynomial(
+            &[
+                1_574.2,
+                -556.01,
+                71.234_72,
+                0.319_781,
+                -0.850_346_3,
+                -0.005_050_998,
+                0.008_357_207_3,
+            ],
+            (year - 1_000_f64) / 100_f64,
+        )
+    } else if year < 1_700_f64 {
+        polynomial(
+            &[120_f64, -0.980_8, -0.015_32, 1_f64 / 7_129_f64],
+            year - 1_600_f64,
+        )
+    } else if year < 1_800_f64 {
+        polynomial(
+            &[
+                8.83,
+                0.160_3,
+                -0.005_928_5,
+                0.000_133_36,
+                -1_f64 / 1_174_000_f64,
+            ],
+            year - 1_700_f64,
+        )
+    } else if year < 1_860_f64 {
+        polynomial(
+            &[
+                13.72,
+                -0.332_447,
+                0.006_861_2,
+                0.004_111_6,
+                -0.000_374_36,
+                0.000_012_127_2,
+                -0.000_000_169_9,
+                0.000_000_000_875,
+            ],
+            year - 1_800_f64,
+        )
+    } else if year < 1_900_f64 {
+        polynomial(
+            &[
+                7.62,
+                0.573_7,
+                -0.251_754,
+                0.016_806_68,
+                -0.000_447_362_4,
+                1_f64 / 233_174_f64,
+            ],
+            year - 1_860_f64,
+        )
+    } else if year < 1_920_f64 {
+        polynomial(
+            &[-2.79, 1.494_119, -0.059_893_9, 0.006_196_6, -0.000_197],
+            year - 1_900_f64,
+        )
+    } else if year < 1_941_f64 {
+        polynomial(&[21.2, 0.844_93, -0.076_1, 0.002_093_6], year - 1_920_f64)
+    } else if year < 1_961_f64 {
+        polynomial(
+            &[29.07, 0.407, -1_f64 / 233_f64, 1_f64 / 2_547_f64],
+            year - 1_950_f64,
+        )
+    } else if year < 1_986_f64 {
+        polynomial(
+            &[45.45, 1.067, -1_f64 / 260_f64, -1_f64 / 718_f64],
+            year - 1_975_f64,
+        )
+    } else if year < LAST_OBSERVED_YEAR {
+        polynomial(
+            &[
+                63.86,
+                0.334_5,
+                -0.060_374,
+                0.001_727_5,
+                0.000_651_814,
+                0.000_023_735_99,
+            ],
+            year - 2_000_f64,
+        )
+    } else if year < 2_050_f64 {
+        polynomial(&[62.92, 0.322_17, 0.005_589], year - 2_000_f64)
+    } else if year < 2_150_f64 {
+        parabola(year) - 0.562_8 * (2_150_f64 - year)
+    } else {
+        parabola(year)
+    }
+}
@@ -0,0 +1,282 @@
+//! Common interface of the *VSOP87* solutions and other ephemerides.
+
+use super::{calculate, Body, Coordinates, Error, JulianDay, Solution};
+
+/// Reference frame of the coordinates returned by an ephemeris.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Frame {
+    /// Dynamical ecliptic and equinox J2000.0.
+    EclipticJ2000,
+    /// Dynamical ecliptic and equinox of the date.
+    EclipticOfDate,
+}
+
+/// Center of the coordinates returned by an ephemeris.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Center {
+    /// The coordinates are centered in the Sun.
+    Heliocentric,
+    /// The coordinates are centered in the barycenter of the solar system.
+    Barycentric,
+}
+
+/// Time span in which an ephemeris is valid for a body, in Julian Days (*JD*).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Validity {
+    /// First Julian Day of the validity span.
+    pub start: f64,
+    /// Last Julian Day of the validity span.
+    pub end: f64,
+}
+
+impl Validity {
+    /// Creates the validity span of the given number of years before and after J2000.0.
+    #[must_use]
+    pub fn around_j2000(years: f64) -> Self {
+        let days = years * 365.25;
+
+        Self {
+            start: 2_451_545_f64 - days,
+            end: 2_451_545_f64 + days,
+        }
+    }
+
+    /// Gets the validity span of the *VSOP87* solutions for the given body.
+    ///
+    /// See [`VALIDITY`] for the validity spans of all the bodies.
+    #[must_use]
+    pub fn vsop87(body: Body) -> Self {
+        VALIDITY
+            .iter()
+            .find_map(|&(validity_body, validity)| (validity_body == body).then_some(validity))
+            .unwrap_or(Self {
+                start: f64::NEG_INFINITY,
+                end: f64::INFINITY,
+            })
+    }
+
+    /// Checks if the given Julian Day is inside the validity span.
+    #[must_use]
+    pub fn contains(&self, jde: impl Into<JulianDay>) -> bool {
+        let jde = jde.into().value();
+        jde >= self.start && jde <= self.end
+    }
+}
+
+/// Validity spans of the *VSOP87* solutions for each body, in Julian Days (*JD*).
+///
+/// The precision of the solutions is under 1" for **4,000 years** before and after J2000.0 for
+/// Mercury, Venus, the Earth, the Earth - Moon barycenter and Mars, for **2,000 years** in the
+/// case of Jupiter and Saturn and for **6,000 years** for Uranus and Neptune. The Sun, only
+/// available in *VSOP87E*, is mostly perturbed by Jupiter and Saturn, so it shares their validity.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Body, Validity, VALIDITY};
+///
+/// for (body, validity) in VALIDITY {
+///     assert_eq!(Validity::vsop87(body), validity);
+/// }
+///
+/// assert!(Validity::vsop87(Body::Neptune).contains(300_000.0));
+/// assert!(!Validity::vsop87(Body::Saturn).contains(300_000.0));
+/// ```
+pub const VALIDITY: [(Body, Validity); 10] = [
+    (Body::Sun, YEARS_2000),
+    (Body::Mercury, YEARS_4000),
+    (Body::Venus, YEARS_4000),
+    (Body::Earth, YEARS_4000),
+    (Body::Mars, YEARS_4000),
+    (Body::Jupiter, YEARS_2000),
+    (Body::Saturn, YEARS_2000),
+    (Body::Uranus, YEARS_6000),
+    (Body::Neptune, YEARS_6000),
+    (Body::EarthMoon, YEARS_4000),
+];
+
+/// Validity span of 2,000 years before and after J2000.0.
+const YEARS_2000: Validity = Validity {
+    start: 1_721_045_f64,
+    end: 3_182_045_f64,
+};
+
+/// Validity span of 4,000 years before and after J2000.0.
+const YEARS_4000: Validity = Validity {
+    start: 990_545_f64,
+    end: 3_912_545_f64,
+};
+
+/// Validity span of 6,000 years before and after J2000.0.
+const YEARS_6000: Validity = Validity {
+    start: 260_045_f64,
+    end: 4_643_045_f64,
+};
+
+/// Checks that the given Julian Day is finite and inside the validity span of the *VSOP87*
+/// solutions for the given body.
+pub(crate) fn check_validity(body: Body, jde: JulianDay) -> Result<(), Error> {
+    if !jde.is_finite() {
+        Err(Error::NonFinite)
+    } else if Validity::vsop87(body).contains(jde) {
+        Ok(())
+    } else {
+        Err(Error::OutOfRange { body })
+    }
+}
+
+/// Ephemeris that calculates the position of solar system bodies.
+///
+/// This trait is implemented by every *VSOP87* solution (see [`VSOP87`],
+/// [`VSOP87A`](crate::vsop87a::VSOP87A), [`VSOP87B`](crate::vsop87b::VSOP87B),
+/// [`VSOP87C`](crate::vsop87c::VSOP87C), [`VSOP87D`](crate::vsop87d::VSOP87D) and
+/// [`VSOP87E`](crate::vsop87e::VSOP87E)), and by the [`Solution`] enumeration, so that code can
+/// be generic over the theory being used, or choose it at runtime. It can also be implemented for
+/// other ephemerides.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "vsop87a", feature = "vsop87e", feature = "sun", feature = "earth"))]
+/// # {
+/// use vsop87::{vsop87a, vsop87e, Body, Center, Ephemeris};
+///
+/// let ephemerides: [Box<dyn Ephemeris>; 2] =
+///     [Box::new(vsop87a::VSOP87A), Box::new(vsop87e::VSOP87E)];
+///
+/// for ephemeris in &ephemerides {
+///     let coordinates = ephemeris.position(Body::Earth, 2451545.0).unwrap();
+///     assert!(coordinates.rectangular().is_some());
+/// }
+///
+/// assert_eq!(ephemerides[0].center(), Center::Heliocentric);
+/// assert_eq!(ephemerides[1].center(), Center::Barycentric);
+/// assert!(!ephemerides[0].supports(Body::Sun));
+/// assert!(ephemerides[1].supports(Body::Sun));
+/// # }
+/// ```
+pub trait Ephemeris {
+    /// Calculates the position of the given body at the given Julian Day (*JD*).
+    ///
+    /// It returns an error if the body is not available in the ephemeris.
+    fn position(&self, body: Body, jde: f64) -> Result<Coordinates, Error>;
+
+    /// Calculates the position of the given body at the given [`JulianDay`], keeping the
+    /// precision of its two parts.
+    ///
+    /// By default, both parts are added and [`Ephemeris::position()`] is used, so ephemerides that
+    /// can use the two parts should override it.
+    fn position_at(&self, body: Body, jde: JulianDay) -> Result<Coordinates, Error> {
+        self.position(body, jde.value())
+    }
+
+    /// Gets the reference frame of the returned coordinates.
+    fn frame(&self) -> Frame;
+
+    /// Gets the center of the returned coordinates.
+    fn center(&self) -> Center;
+
+    /// Gets the bodies available in the ephemeris.
+    fn bodies(&self) -> &[Body];
+
+    /// Checks if the given body is available in the ephemeris.
+    fn supports(&self, body: Body) -> bool {
+        self.bodies().contains(&body)
+    }
+
+    /// Gets the time span in which the ephemeris is valid for the given body.
+    ///
+    /// Returns `None` if the body is not available or if the validity is not known. By default,
+    /// the validity is not known.
+    fn validity(&self, _body: Body) -> Option<Validity> {
+        None
+    }
+
+    /// Calculates the position of the given body at the given Julian Day (*JD*), checking that
+    /// the Julian Day is valid.
+    ///
+    /// It returns an error if the body is not available in the ephemeris, if the Julian Day is not
+    /// finite, or if it is outside of the validity span of the ephemeris for the body.
+    fn position_checked(&self, body: Body, jde: f64) -> Result<Coordinates, Error> {
+        if !self.supports(body) {
+            // The ephemeris reports the error for the unsupported body.
+            return self.position(body, jde);
+        }
+        if !jde.is_finite() {
+            return Err(Error::NonFinite);
+        }
+        if let Some(validity) = self.validity(body) {
+            if !validity.contains(jde) {
+                return Err(Error::OutOfRange { body });
+            }
+        }
+
+        self.position(body, jde)
+    }
+}
+
+impl Ephemeris for Solution {
+    fn position(&self, body: Body, jde: f64) -> Result<Coordinates, Error> {
+        calculate(*self, body, jde)
+    }
+
+    fn position_at(&self, body: Body, jde: JulianDay) -> Result<Coordinates, Error> {
+        calculate(*self, body, jde)
+    }
+
+    fn frame(&self) -> Frame {
+        match self {
+            Self::VSOP87 | Self::VSOP87A | Self::VSOP87B | Self::VSOP87E => Frame::EclipticJ2000,
+            Self::VSOP87C | Self::VSOP87D => Frame::EclipticOfDate,
+        }
+    }
+
+    fn center(&self) -> Center {
+        match self {
+            Self::VSOP87E => Center::Barycentric,
+            _ => Center::Heliocentric,
+        }
+    }
+
+    fn bodies(&self) -> &[Body] {
+        Solution::bodies(*self)
+    }
+
+    fn validity(&self, body: Body) -> Option<Validity> {
+        Solution::supports(*self, body).then(|| Validity::vsop87(body))
+    }
+}
+
+/// Base *VSOP87* solution, as an [`Ephemeris`].
+///
+/// Its positions are the heliocentric ecliptic orbital elements for the equinox J2000.0.
+#[cfg(feature = "elements")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct VSOP87;
+
+#[cfg(feature = "elements")]
+impl Ephemeris for VSOP87 {
+    fn position(&self, body: Body, jde: f64) -> Result<Coordinates, Error> {
+        Solution::VSOP87.position(body, jde)
+    }
+
+    fn position_at(&self, body: Body, jde: JulianDay) -> Result<Coordinates, Error> {
+        Solution::VSOP87.position_at(body, jde)
+    }
+
+    fn frame(&self) -> Frame {
+        Solution::VSOP87.frame()
+    }
+
+    fn center(&self) -> Center {
+        Solution::VSOP87.center()
+    }
+
+    fn bodies(&self) -> &[Body] {
+        Solution::VSOP87.bodies()
+    }
+
+    fn validity(&self, body: Body) -> Option<Validity> {
+        Ephemeris::validity(&Solution::VSOP87, body)
+    }
+}
@@ -0,0 +1,81 @@
+//! Error type of the library.
+
+use super::{Body, Solution};
+
+#[cfg(feature = "no_std")]
+use core::fmt;
+#[cfg(not(feature = "no_std"))]
+use std::fmt;
+
+/// Errors that can happen when calculating a *VSOP87* solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The requested body is not available in the given *VSOP87* solution.
+    ///
+    /// For example, the Sun is only available in *VSOP87E*, and the Earth - Moon barycenter is
+    /// only available in the base *VSOP87* solution and in *VSOP87A*.
+    ///
+    /// It is also returned if the solution or the body have been disabled with cargo features.
+    UnsupportedBody {
+        /// Solution that was requested.
+        solution: Solution,
+        /// Body that is not available in the solution.
+        body: Body,
+    },
+    /// The Julian Day is outside of the validity span of the *VSOP87* solutions for the body.
+    ///
+    /// The validity spans can be queried with [`Validity::vsop87()`](crate::Validity::vsop87).
+    OutOfRange {
+        /// Body whose validity span does not contain the Julian Day.
+        body: Body,
+    },
+    /// The Julian Day is not a finite number.
+    NonFinite,
+    /// The given name does not correspond to any known body.
+    UnknownBody,
+    /// The given name does not correspond to any known *VSOP87* solution.
+    UnknownSolution,
+    /// The output of a batch calculation does not have the same length as the given Julian Days.
+    OutputLength,
+    /// The calendar date or the time of the day is not valid, or it cannot be represented.
+    InvalidDate,
+    /// The string is not a valid ISO 8601 date.
+    InvalidDateFormat,
+    /// The given name does not correspond to any known time scale.
+    UnknownTimeScale,
+    /// The table of leap seconds is not valid.
+    InvalidLeapSeconds,
+    /// The body is the one where the observer is, so it has no place in its sky.
+    ObserverBody,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedBody { solution, body } => {
+                write!(f, "{body} is not available in the {solution} solution")
+            }
+            Self::OutOfRange { body } => {
+                write!(
+                    f,
+                    "the Julian Day is outside of the validity span for {body}"
+                )
+            }
+            Self::NonFinite => f.write_str("the Julian Day is not finite"),
+            Self::UnknownBody => f.write_str("unknown body name"),
+            Self::UnknownSolution => f.write_str("unknown VSOP87 solution name"),
+            Self::OutputLength => {
+                f.write_str("the output length does not match the number of Julian Days")
+            }
+            Self::InvalidDate => f.write_str("invalid calendar date or time"),
+            Self::InvalidDateFormat => f.write_str("invalid ISO 8601 date format"),
+            Self::UnknownTimeScale => f.write_str("unknown time scale name"),
+            Self::InvalidLeapSeconds => f.write_str("invalid table of leap seconds"),
+            Self::ObserverBody => f.write_str("the body is where the observer is"),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for Error {}
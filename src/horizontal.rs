@@ -0,0 +1,325 @@
+//! Horizontal coordinates: azimuth and altitude, with atmospheric refraction.
+
+use super::SphericalCoordinates;
+
+#[cfg(feature = "no_std")]
+use core::f64::consts::TAU;
+#[cfg(feature = "no_std")]
+use libm::{atan2, hypot, pow, sin, sincos, tan};
+
+#[cfg(not(feature = "no_std"))]
+use std::f64::consts::TAU;
+
+/// Altitude below which the refraction is tapered to zero, in degrees.
+const MINIMUM_ALTITUDE: f64 = -1_f64;
+
+/// Range of altitudes below the minimum altitude over which the refraction decreases to zero, in
+/// degrees.
+const TAPER: f64 = 1_f64;
+
+/// Correction of the formula of Bennett, in arcminutes, so that the refraction is zero at the
+/// zenith.
+const BENNETT_ZENITH: f64 = 0.001_351_5;
+
+/// Correction of the formula of Sæmundsson, in arcminutes, so that the refraction is zero at the
+/// zenith.
+const SAEMUNDSSON_ZENITH: f64 = 0.001_927_9;
+
+/// Maximum number of iterations to invert the Bennett formula.
+const BENNETT_ITERATIONS: usize = 20;
+
+/// Model of the atmospheric refraction, that raises the bodies near the horizon.
+///
+/// All the models are accurate to a few arcseconds above 15° of altitude, and to about one
+/// arcminute at the horizon, where the refraction depends on the conditions of the atmosphere. The
+/// formulas are corrected to give no refraction at the zenith. Below 1° under the horizon, the
+/// refraction decreases linearly to zero at 2° under it, so that the apparent altitude does not
+/// jump.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::Refraction;
+///
+/// // A body on the geometric horizon is raised by about 29′.
+/// let refraction = Refraction::Bennett.refraction(0_f64);
+/// assert!((refraction.to_degrees() * 60.0 - 29.0).abs() < 0.1);
+///
+/// // It decreases with the pressure and the temperature.
+/// let atmospheric = Refraction::Atmospheric {
+///     pressure: 850.0,
+///     temperature: 30.0,
+/// };
+/// assert!(atmospheric.refraction(0_f64) < refraction);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Refraction {
+    /// No refraction: the altitude is the geometric one.
+    None,
+    /// Formula of Bennett (1982), given as a function of the apparent altitude, and inverted
+    /// iteratively. It is accurate to 0.07′ for a pressure of 1010 hPa and a temperature of 10 °C.
+    Bennett,
+    /// Formula of Sæmundsson (1986), given as a function of the geometric altitude. It is
+    /// consistent with the formula of Bennett to 0.1′, for the same conditions.
+    #[default]
+    Saemundsson,
+    /// Formula of Sæmundsson (1986), corrected for the conditions of the atmosphere.
+    Atmospheric {
+        /// Atmospheric pressure at the observer, in hectopascals.
+        pressure: f64,
+        /// Temperature at the observer, in degrees Celsius.
+        temperature: f64,
+    },
+}
+
+impl Refraction {
+    /// Calculates the refraction for the given geometric altitude, in radians.
+    ///
+    /// The apparent altitude is the geometric altitude plus the refraction. The refraction is never
+    /// negative.
+    #[must_use]
+    pub fn refraction(self, altitude: f64) -> f64 {
+        let altitude = altitude.to_degrees();
+        if altitude <= MINIMUM_ALTITUDE - TAPER {
+            return 0_f64;
+        }
+        let taper = ((altitude - MINIMUM_ALTITUDE) / TAPER + 1_f64).min(1_f64);
+        let altitude = altitude.max(MINIMUM_ALTITUDE);
+
+        let minutes = match self {
+            Self::None => 0_f64,
+            Self::Bennett => {
+                // The apparent altitude is the fixed point of h + R(h₀), that converges quickly
+                // because the refraction changes slowly with the altitude.
+                let mut apparent = altitude + saemundsson(altitude) / 60_f64;
+                for _ in 0..BENNETT_ITERATIONS {
+                    let previous = apparent;
+                    apparent = altitude + bennett(apparent) / 60_f64;
+                    if (apparent - previous).abs() < 1e-10 {
+                        break;
+                    }
+                }
+                (apparent - altitude) * 60_f64
+            }
+            Self::Saemundsson => saemundsson(altitude),
+            Self::Atmospheric {
+                pressure,
+                temperature,
+            } => saemundsson(altitude) * pressure / 1_010_f64 * 283_f64 / (273_f64 + temperature),
+        };
+
+        (minutes.max(0_f64) * taper / 60_f64).to_radians()
+    }
+}
+
+/// Position of a body in the sky of an observer: azimuth, altitude and distance.
+///
+/// The azimuth is measured from the north towards the east, and the altitude is the apparent one,
+/// raised by the refraction. The horizontal coordinates are calculated from apparent equatorial
+/// coordinates, referred to the true equator and equinox of the date, such as the ones of
+/// [`topocentric_place()`](crate::topocentric_place), with the local apparent sidereal time.
+///
+/// # Example
+///
+/// Example 13.b of Meeus, Astronomical Algorithms: Venus seen from the US Naval Observatory on
+/// 1987 April 10, at 19h21m00s UT.
+///
+/// ```
+/// use vsop87::{HorizontalCoordinates, RectangularCoordinates, Refraction, SphericalCoordinates};
+///
+/// // α = 23h09m16.641s, δ = −6°43′11.61″.
+/// let (ra, dec) = (347.319_337_5_f64.to_radians(), -6.719_891_7_f64.to_radians());
+/// let venus = SphericalCoordinates::from(RectangularCoordinates {
+///     x: dec.cos() * ra.cos(),
+///     y: dec.cos() * ra.sin(),
+///     z: dec.sin(),
+/// });
+/// let latitude = 38.921_389_f64.to_radians();
+/// let local_sidereal_time = (128.737_873_4_f64 - 77.065_556).to_radians();
+///
+/// let horizontal = HorizontalCoordinates::from_equatorial(
+///     venus,
+///     latitude,
+///     local_sidereal_time,
+///     Refraction::None,
+/// );
+/// assert!((horizontal.azimuth().to_degrees() - 248.033_7).abs() < 1e-3);
+/// assert!((horizontal.altitude().to_degrees() - 15.124_9).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HorizontalCoordinates {
+    azimuth: f64,
+    altitude: f64,
+    refraction: f64,
+    distance: f64,
+    hour_angle: f64,
+    parallactic_angle: f64,
+}
+
+impl HorizontalCoordinates {
+    /// Calculates the horizontal coordinates of a body from its apparent equatorial coordinates,
+    /// the geodetic latitude of the observer and the local apparent sidereal time, in radians.
+    #[must_use]
+    pub fn from_equatorial(
+        equatorial: SphericalCoordinates,
+        latitude: f64,
+        local_sidereal_time: f64,
+        refraction: Refraction,
+    ) -> Self {
+        let hour_angle = normalize(local_sidereal_time - equatorial.longitude());
+
+        #[cfg(not(feature = "no_std"))]
+        let ((sin_h, cos_h), (sin_dec, cos_dec), (sin_lat, cos_lat)) = (
+            hour_angle.sin_cos(),
+            equatorial.latitude().sin_cos(),
+            latitude.sin_cos(),
+        );
+        #[cfg(feature = "no_std")]
+        let ((sin_h, cos_h), (sin_dec, cos_dec), (sin_lat, cos_lat)) = (
+            sincos(hour_angle),
+            sincos(equatorial.latitude()),
+            sincos(latitude),
+        );
+
+        // Rectangular coordinates towards the east, the north and the zenith.
+        let east = -cos_dec * sin_h;
+        let north = sin_dec * cos_lat - cos_dec * cos_h * sin_lat;
+        let zenith = sin_dec * sin_lat + cos_dec * cos_h * cos_lat;
+
+        #[cfg(not(feature = "no_std"))]
+        let (azimuth, altitude) = (east.atan2(north), zenith.atan2(east.hypot(north)));
+        #[cfg(feature = "no_std")]
+        let (azimuth, altitude) = (atan2(east, north), atan2(zenith, hypot(east, north)));
+
+        #[cfg(not(feature = "no_std"))]
+        let parallactic_angle =
+            (cos_lat * sin_h).atan2(sin_lat * cos_dec - cos_lat * sin_dec * cos_h);
+        #[cfg(feature = "no_std")]
+        let parallactic_angle = atan2(
+            cos_lat * sin_h,
+            sin_lat * cos_dec - cos_lat * sin_dec * cos_h,
+        );
+
+        let refraction = refraction.refraction(altitude);
+        Self {
+            azimuth: normalize(azimuth),
+            altitude: altitude + refraction,
+            refraction,
+            distance: equatorial.distance(),
+            hour_angle,
+            parallactic_angle,
+        }
+    }
+
+    /// Gets the azimuth, measured from the north towards the east, in radians.
+    #[must_use]
+    pub fn azimuth(&self) -> f64 {
+        self.azimuth
+    }
+
+    /// Gets the apparent altitude above the horizon, including the refraction, in radians.
+    #[must_use]
+    pub fn altitude(&self) -> f64 {
+        self.altitude
+    }
+
+    /// Gets the geometric altitude above the horizon, without the refraction, in radians.
+    #[must_use]
+    pub fn geometric_altitude(&self) -> f64 {
+        self.altitude - self.refraction
+    }
+
+    /// Gets the refraction, the difference between the apparent and the geometric altitudes, in
+    /// radians.
+    #[must_use]
+    pub fn refraction(&self) -> f64 {
+        self.refraction
+    }
+
+    /// Gets the distance to the body, in *AU*.
+    #[must_use]
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Gets the local hour angle of the body, measured westwards from the meridian, in radians,
+    /// between 0 and 2π.
+    #[must_use]
+    pub fn hour_angle(&self) -> f64 {
+        self.hour_angle
+    }
+
+    /// Gets the parallactic angle, between the direction to the north celestial pole and the
+    /// direction to the zenith, measured towards the east, in radians, between −π and π.
+    ///
+    /// It is zero when the body crosses the meridian to the south of the zenith, and it gives the
+    /// orientation of the sky seen through an alt-azimuth telescope.
+    #[must_use]
+    pub fn parallactic_angle(&self) -> f64 {
+        self.parallactic_angle
+    }
+
+    /// Calculates the airmass, the relative length of the path of the light through the
+    /// atmosphere, with the formula of Pickering (2002).
+    ///
+    /// It is 1 at the zenith and about 38 at the horizon. It returns `None` if the body is below
+    /// the horizon.
+    #[must_use]
+    pub fn airmass(&self) -> Option<f64> {
+        let altitude = self.altitude.to_degrees();
+        if altitude < 0_f64 {
+            return None;
+        }
+
+        #[cfg(not(feature = "no_std"))]
+        let airmass = 1_f64
+            / (altitude + 244_f64 / (165_f64 + 47_f64 * altitude.powf(1.1)))
+                .to_radians()
+                .sin();
+        #[cfg(feature = "no_std")]
+        let airmass = 1_f64
+            / sin((altitude + 244_f64 / (165_f64 + 47_f64 * pow(altitude, 1.1))).to_radians());
+
+        Some(airmass)
+    }
+}
+
+/// Calculates the refraction of the formula of Bennett for the given apparent altitude, both in
+/// degrees, in arcminutes.
+fn bennett(altitude: f64) -> f64 {
+    let argument = (altitude + 7.31 / (altitude + 4.4)).to_radians();
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        1_f64 / argument.tan() + BENNETT_ZENITH
+    }
+    #[cfg(feature = "no_std")]
+    {
+        1_f64 / tan(argument) + BENNETT_ZENITH
+    }
+}
+
+/// Calculates the refraction of the formula of Sæmundsson for the given geometric altitude, in
+/// degrees, in arcminutes.
+fn saemundsson(altitude: f64) -> f64 {
+    let argument = (altitude + 10.3 / (altitude + 5.11)).to_radians();
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        1.02 / argument.tan() + SAEMUNDSSON_ZENITH
+    }
+    #[cfg(feature = "no_std")]
+    {
+        1.02 / tan(argument) + SAEMUNDSSON_ZENITH
+    }
+}
+
+/// Normalizes an angle between 0 and 2π.
+fn normalize(angle: f64) -> f64 {
+    let angle = angle % TAU;
+    if angle < 0_f64 {
+        angle + TAU
+    } else {
+        angle
+    }
+}
@@ -0,0 +1,756 @@
+//! Julian Day type and conversions from and to calendar dates.
+
+use super::{Error, DAYS_PER_MILLENNIUM};
+
+#[cfg(feature = "no_std")]
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+#[cfg(feature = "no_std")]
+use libm::floor;
+
+#[cfg(not(feature = "no_std"))]
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Sub},
+    str::FromStr,
+    time::SystemTime,
+};
+
+/// Julian Day of the J2000.0 epoch, January 1st, 2000 at 12:00.
+const J2000: f64 = 2_451_545_f64;
+
+/// Number of days in a Julian century.
+const DAYS_PER_CENTURY: f64 = 36_525_f64;
+
+/// Number of seconds in a day.
+pub(crate) const SECONDS_PER_DAY: f64 = 86_400_f64;
+
+/// Julian Day of the epoch of the Modified Julian Day, November 17th, 1858 at 00:00.
+const MJD_EPOCH: f64 = 2_400_000.5;
+
+/// Julian Day of the Unix epoch, January 1st, 1970 at 00:00.
+#[cfg(not(feature = "no_std"))]
+const UNIX_EPOCH: f64 = 2_440_587.5;
+
+/// Julian Day number of March 1st of the year 0 of the proleptic Gregorian calendar.
+const GREGORIAN_EPOCH: i64 = 1_721_120;
+
+/// Julian Day number of March 1st of the year 0 of the proleptic Julian calendar.
+const JULIAN_EPOCH: i64 = 1_721_118;
+
+/// Number of days in a 400 years cycle of the Gregorian calendar.
+const DAYS_PER_GREGORIAN_CYCLE: i64 = 146_097;
+
+/// Number of days in a 4 years cycle of the Julian calendar.
+const DAYS_PER_JULIAN_CYCLE: i64 = 1_461;
+
+/// Largest number of days converted into a calendar date, 2⁵³, so that the day number is exact
+/// and its arithmetic can not overflow. Years are limited to the range of `i32` well before.
+const MAXIMUM_DAYS: f64 = 9_007_199_254_740_992_f64;
+
+/// A Julian Day (*JD*): the number of days, and fraction of a day, since noon of January 1st,
+/// 4713 BC in the proleptic Julian calendar.
+///
+/// All the functions of the library that take a Julian Day accept this type, along with plain
+/// `f64` values. The *VSOP87* solutions expect the Julian Day in Barycentric Dynamical Time
+/// (*TDB*), usually called Julian Ephemeris Day (*JDE*), but this type does not enforce any time
+/// scale: it just counts days.
+///
+/// It can be created from proleptic Gregorian and Julian calendar dates, parsed from ISO 8601
+/// strings, or taken from the system clock:
+///
+/// ```
+/// # #[cfg(all(feature = "vsop87a", feature = "earth"))]
+/// # {
+/// use vsop87::{CalendarDate, JulianDay};
+///
+/// let j2000 = CalendarDate::gregorian(2000, 1, 1)?.with_time(12, 0, 0.0)?;
+/// assert_eq!(JulianDay::from(j2000), JulianDay::J2000);
+/// assert_eq!("2000-01-01T12:00:00".parse::<JulianDay>(), Ok(JulianDay::J2000));
+///
+/// // Dates before the year 1 use astronomical year numbering: the year 0 is 1 BC.
+/// assert_eq!(JulianDay::from_julian_calendar(-4712, 1, 1)?.value(), -0.5);
+///
+/// let coordinates = vsop87::vsop87a::earth(JulianDay::J2000);
+/// assert_eq!(coordinates, vsop87::vsop87a::earth(2451545.0));
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+///
+/// # Precision
+///
+/// A single `f64` only resolves about 40 microseconds around the current epoch, and the time
+/// variable of the *VSOP87* series would lose even more precision when subtracting J2000.0 from
+/// it. Julian Days are therefore stored in two parts, like the `date1` and `date2` arguments of
+/// the *SOFA* library: the time variable is calculated from the first part, usually a whole
+/// number of days, and the second part is added afterwards, so no precision is lost. Calendar
+/// dates, parsed strings and the system clock all create Julian Days this way.
+///
+/// ```
+/// use vsop87::JulianDay;
+///
+/// let jd = JulianDay::from_parts(2460000.5, 0.25);
+/// let one_millisecond_later = jd + 0.001 / 86400.0;
+///
+/// assert!(((one_millisecond_later - jd) * 86400.0 - 0.001).abs() < 1e-9);
+/// assert_eq!(jd.parts(), (2460000.5, 0.25));
+/// assert_eq!(jd, JulianDay::new(2460000.75));
+/// ```
+///
+/// Two Julian Days are equal if they represent the same instant, even if they are split in a
+/// different way.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JulianDay {
+    day: f64,
+    fraction: f64,
+}
+
+impl JulianDay {
+    /// The J2000.0 epoch, January 1st, 2000 at 12:00.
+    pub const J2000: Self = Self::new(J2000);
+
+    /// Creates a Julian Day from its value, in days.
+    #[must_use]
+    pub const fn new(jd: f64) -> Self {
+        Self::from_parts(jd, 0_f64)
+    }
+
+    /// Creates a Julian Day from two parts, in days, whose sum is the Julian Day.
+    ///
+    /// Any split works, but the precision is best when the first part is the Julian Day of a
+    /// midnight or noon close to the date, or J2000.0, and the second part is the rest.
+    #[must_use]
+    pub const fn from_parts(day: f64, fraction: f64) -> Self {
+        Self { day, fraction }
+    }
+
+    /// Creates a Julian Day from a Modified Julian Day (*MJD*), the number of days since
+    /// November 17th, 1858 at 00:00.
+    #[must_use]
+    pub const fn from_mjd(mjd: f64) -> Self {
+        Self::from_parts(MJD_EPOCH, mjd)
+    }
+
+    /// Gets the Modified Julian Day (*MJD*) of the Julian Day.
+    #[must_use]
+    pub fn mjd(self) -> f64 {
+        (self.day - MJD_EPOCH) + self.fraction
+    }
+
+    /// Gets the value of the Julian Day, in days.
+    ///
+    /// Both parts are added, so some precision may be lost: use [`JulianDay::parts()`] to keep
+    /// it.
+    #[must_use]
+    pub fn value(self) -> f64 {
+        self.day + self.fraction
+    }
+
+    /// Gets the two parts of the Julian Day, in days.
+    #[must_use]
+    pub const fn parts(self) -> (f64, f64) {
+        (self.day, self.fraction)
+    }
+
+    /// Checks if the Julian Day is a finite number.
+    #[must_use]
+    pub fn is_finite(self) -> bool {
+        self.day.is_finite() && self.fraction.is_finite()
+    }
+
+    /// Creates the Julian Day of the start of the given date of the proleptic Gregorian calendar.
+    ///
+    /// Returns [`Error::InvalidDate`] if the month or the day are not valid.
+    pub fn from_gregorian(year: i32, month: u8, day: u8) -> Result<Self, Error> {
+        CalendarDate::gregorian(year, month, day).map(Self::from)
+    }
+
+    /// Creates the Julian Day of the start of the given date of the proleptic Julian calendar.
+    ///
+    /// Returns [`Error::InvalidDate`] if the month or the day are not valid.
+    pub fn from_julian_calendar(year: i32, month: u8, day: u8) -> Result<Self, Error> {
+        CalendarDate::julian(year, month, day).map(Self::from)
+    }
+
+    /// Gets the current Julian Day from the system clock.
+    ///
+    /// The system clock follows Coordinated Universal Time (*UTC*), so the Julian Day must be
+    /// converted into Terrestrial Time (*TT*) with [`TimeScales`](crate::TimeScales) before
+    /// using it with the *VSOP87* solutions, or it will be about a minute late.
+    #[cfg(not(feature = "no_std"))]
+    #[must_use]
+    pub fn now() -> Self {
+        Self::from(SystemTime::now())
+    }
+
+    /// Converts the Julian Day into a date of the proleptic Gregorian calendar.
+    ///
+    /// Returns [`Error::NonFinite`] if the Julian Day is not finite and [`Error::InvalidDate`] if
+    /// the year does not fit in an `i32`.
+    pub fn to_gregorian(self) -> Result<CalendarDate, Error> {
+        self.to_calendar(Calendar::Gregorian)
+    }
+
+    /// Converts the Julian Day into a date of the proleptic Julian calendar.
+    ///
+    /// Returns [`Error::NonFinite`] if the Julian Day is not finite and [`Error::InvalidDate`] if
+    /// the year does not fit in an `i32`.
+    pub fn to_julian_calendar(self) -> Result<CalendarDate, Error> {
+        self.to_calendar(Calendar::Julian)
+    }
+
+    /// Converts the Julian Day into a date of the given calendar.
+    ///
+    /// Returns [`Error::NonFinite`] if the Julian Day is not finite and [`Error::InvalidDate`] if
+    /// the year does not fit in an `i32`.
+    pub fn to_calendar(self, calendar: Calendar) -> Result<CalendarDate, Error> {
+        if !self.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        // Julian Days start at noon, calendar days at midnight. The whole days of the first part
+        // are separated first, so that the fraction of the day keeps all of its precision.
+        #[cfg(feature = "no_std")]
+        let (whole_days, fraction) = {
+            let whole_days = floor(self.day);
+            let fraction = self.day - whole_days + self.fraction + 0.5;
+            let extra_days = floor(fraction);
+            (whole_days + extra_days, fraction - extra_days)
+        };
+        #[cfg(not(feature = "no_std"))]
+        let (whole_days, fraction) = {
+            let whole_days = self.day.floor();
+            let fraction = self.day - whole_days + self.fraction + 0.5;
+            let extra_days = fraction.floor();
+            (whole_days + extra_days, fraction - extra_days)
+        };
+
+        if !(-MAXIMUM_DAYS..=MAXIMUM_DAYS).contains(&whole_days) {
+            return Err(Error::InvalidDate);
+        }
+
+        let mut jdn = whole_days as i64;
+        let mut seconds = fraction * SECONDS_PER_DAY;
+        if seconds >= SECONDS_PER_DAY {
+            // The fraction of the day was rounded up to a full day.
+            jdn += 1;
+            seconds = 0_f64;
+        }
+
+        let (year, month, day) = calendar.date(jdn)?;
+        let hour = (seconds / 3_600_f64) as u8;
+        let seconds = seconds - f64::from(hour) * 3_600_f64;
+        let minute = (seconds / 60_f64) as u8;
+        let second = seconds - f64::from(minute) * 60_f64;
+
+        Ok(CalendarDate {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            calendar,
+        })
+    }
+
+    /// Gets the number of days since the J2000.0 epoch.
+    #[must_use]
+    pub fn days_since_j2000(self) -> f64 {
+        (self.day - J2000) + self.fraction
+    }
+
+    /// Gets the number of Julian centuries (of 36,525 days) since the J2000.0 epoch.
+    #[must_use]
+    pub fn julian_centuries(self) -> f64 {
+        self.days_since_j2000() / DAYS_PER_CENTURY
+    }
+
+    /// Gets the number of Julian millennia (of 365,250 days) since the J2000.0 epoch.
+    ///
+    /// This is the time variable of the *VSOP87* series.
+    #[must_use]
+    pub fn julian_millennia(self) -> f64 {
+        self.days_since_j2000() / DAYS_PER_MILLENNIUM
+    }
+}
+
+impl From<f64> for JulianDay {
+    fn from(jd: f64) -> Self {
+        Self::new(jd)
+    }
+}
+
+impl From<(f64, f64)> for JulianDay {
+    fn from((day, fraction): (f64, f64)) -> Self {
+        Self::from_parts(day, fraction)
+    }
+}
+
+impl From<JulianDay> for f64 {
+    fn from(jd: JulianDay) -> Self {
+        jd.value()
+    }
+}
+
+impl From<CalendarDate> for JulianDay {
+    fn from(date: CalendarDate) -> Self {
+        let jdn = date.calendar.day_number(date.year, date.month, date.day);
+        let seconds =
+            f64::from(date.hour) * 3_600_f64 + f64::from(date.minute) * 60_f64 + date.second;
+
+        Self::from_parts(jdn as f64 - 0.5, seconds / SECONDS_PER_DAY)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl From<SystemTime> for JulianDay {
+    fn from(time: SystemTime) -> Self {
+        let (sign, duration) = match time.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => (1_f64, duration),
+            Err(error) => (-1_f64, error.duration()),
+        };
+        let seconds_per_day = SECONDS_PER_DAY as u64;
+        let days = (duration.as_secs() / seconds_per_day) as f64;
+        let seconds = (duration.as_secs() % seconds_per_day) as f64
+            + f64::from(duration.subsec_nanos()) * 1e-9;
+
+        Self::from_parts(UNIX_EPOCH + sign * days, sign * seconds / SECONDS_PER_DAY)
+    }
+}
+
+impl Add<f64> for JulianDay {
+    type Output = Self;
+
+    fn add(self, days: f64) -> Self {
+        Self::from_parts(self.day, self.fraction + days)
+    }
+}
+
+impl Sub<f64> for JulianDay {
+    type Output = Self;
+
+    fn sub(self, days: f64) -> Self {
+        Self::from_parts(self.day, self.fraction - days)
+    }
+}
+
+impl Sub for JulianDay {
+    type Output = f64;
+
+    fn sub(self, other: Self) -> f64 {
+        (self.day - other.day) + (self.fraction - other.fraction)
+    }
+}
+
+impl PartialEq for JulianDay {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for JulianDay {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (*self - *other).partial_cmp(&0_f64)
+    }
+}
+
+/// Parses an ISO 8601 date of the proleptic Gregorian calendar, optionally with a time and a UTC
+/// offset, such as `2000-01-01`, `2000-01-01T12:00:00.5Z`, `-0500-03-01 06:30` or
+/// `2024-04-08T18:17:20+02:00`.
+///
+/// Years before 1 and after 9999 use astronomical year numbering and a sign, as in ISO 8601.
+impl FromStr for JulianDay {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, offset) = parse_date_time(s)?;
+
+        Ok(Self::from(date) - offset / SECONDS_PER_DAY)
+    }
+}
+
+/// Calendars in which a date can be represented.
+///
+/// Both calendars are proleptic: they are extended before their introduction, and years before
+/// 1 use astronomical year numbering, in which the year 0 is 1 BC and the year -1 is 2 BC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Calendar {
+    /// Gregorian calendar, introduced on October 15th, 1582.
+    Gregorian,
+    /// Julian calendar, used before the Gregorian calendar.
+    Julian,
+}
+
+impl Calendar {
+    /// Checks if the given year is a leap year in the calendar.
+    #[must_use]
+    pub fn is_leap_year(self, year: i32) -> bool {
+        match self {
+            Self::Gregorian => year % 4 == 0 && (year % 100 != 0 || year % 400 == 0),
+            Self::Julian => year % 4 == 0,
+        }
+    }
+
+    /// Gets the number of days of the given month of the given year in the calendar.
+    ///
+    /// Returns `None` if the month is not between 1 and 12.
+    #[must_use]
+    pub fn days_in_month(self, year: i32, month: u8) -> Option<u8> {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+            4 | 6 | 9 | 11 => Some(30),
+            2 if self.is_leap_year(year) => Some(29),
+            2 => Some(28),
+            _ => None,
+        }
+    }
+
+    /// Calculates the Julian Day number of the given date, that starts at its noon.
+    fn day_number(self, year: i32, month: u8, day: u8) -> i64 {
+        // Years start in March, so that the leap day is the last day of the year.
+        let year = if month <= 2 {
+            i64::from(year) - 1
+        } else {
+            i64::from(year)
+        };
+        let month = (i64::from(month) + 9) % 12;
+        let day_of_year = (153 * month + 2) / 5 + i64::from(day) - 1;
+
+        match self {
+            Self::Gregorian => {
+                let cycle = year.div_euclid(400);
+                let year_of_cycle = year.rem_euclid(400);
+                let day_of_cycle =
+                    year_of_cycle * 365 + year_of_cycle / 4 - year_of_cycle / 100 + day_of_year;
+
+                GREGORIAN_EPOCH + cycle * DAYS_PER_GREGORIAN_CYCLE + day_of_cycle
+            }
+            Self::Julian => {
+                let cycle = year.div_euclid(4);
+                let day_of_cycle = year.rem_euclid(4) * 365 + day_of_year;
+
+                JULIAN_EPOCH + cycle * DAYS_PER_JULIAN_CYCLE + day_of_cycle
+            }
+        }
+    }
+
+    /// Calculates the year, month and day of the given Julian Day number.
+    fn date(self, jdn: i64) -> Result<(i32, u8, u8), Error> {
+        let (year, day_of_year) = match self {
+            Self::Gregorian => {
+                let days = jdn - GREGORIAN_EPOCH;
+                let cycle = days.div_euclid(DAYS_PER_GREGORIAN_CYCLE);
+                let day_of_cycle = days.rem_euclid(DAYS_PER_GREGORIAN_CYCLE);
+                let year_of_cycle = (day_of_cycle - day_of_cycle / 1_460 + day_of_cycle / 36_524
+                    - day_of_cycle / 146_096)
+                    / 365;
+                let day_of_year =
+                    day_of_cycle - (year_of_cycle * 365 + year_of_cycle / 4 - year_of_cycle / 100);
+
+                (cycle * 400 + year_of_cycle, day_of_year)
+            }
+            Self::Julian => {
+                let days = jdn - JULIAN_EPOCH;
+                let cycle = days.div_euclid(DAYS_PER_JULIAN_CYCLE);
+                let day_of_cycle = days.rem_euclid(DAYS_PER_JULIAN_CYCLE);
+                let year_of_cycle = (day_of_cycle - day_of_cycle / 1_460) / 365;
+
+                (
+                    cycle * 4 + year_of_cycle,
+                    day_of_cycle - year_of_cycle * 365,
+                )
+            }
+        };
+
+        // The year started in March.
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let (year, month) = if month < 10 {
+            (year, month + 3)
+        } else {
+            (year + 1, month - 9)
+        };
+
+        let year = i32::try_from(year).map_err(|_| Error::InvalidDate)?;
+        Ok((year, month as u8, day as u8))
+    }
+}
+
+/// A date and time in a proleptic calendar.
+///
+/// It can be converted into a [`JulianDay`], and back with [`JulianDay::to_calendar()`]. The time
+/// scale of the time of the day is not specified: it is the one of the Julian Day.
+///
+/// ```
+/// use vsop87::{Calendar, CalendarDate, JulianDay};
+///
+/// // The Gregorian calendar was introduced the day after October 4th, 1582.
+/// let julian = CalendarDate::julian(1582, 10, 4)?;
+/// let gregorian = JulianDay::from(julian).to_gregorian()?;
+///
+/// assert_eq!((gregorian.year(), gregorian.month(), gregorian.day()), (1582, 10, 14));
+/// assert_eq!(gregorian.calendar(), Calendar::Gregorian);
+/// assert_eq!(gregorian.to_string(), "1582-10-14T00:00:00");
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalendarDate {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: f64,
+    calendar: Calendar,
+}
+
+impl CalendarDate {
+    /// Creates the start of the given date of the proleptic Gregorian calendar.
+    ///
+    /// Returns [`Error::InvalidDate`] if the month or the day are not valid.
+    pub fn gregorian(year: i32, month: u8, day: u8) -> Result<Self, Error> {
+        Self::new(Calendar::Gregorian, year, month, day)
+    }
+
+    /// Creates the start of the given date of the proleptic Julian calendar.
+    ///
+    /// Returns [`Error::InvalidDate`] if the month or the day are not valid.
+    pub fn julian(year: i32, month: u8, day: u8) -> Result<Self, Error> {
+        Self::new(Calendar::Julian, year, month, day)
+    }
+
+    /// Creates the start of the given date of the given calendar.
+    ///
+    /// Returns [`Error::InvalidDate`] if the month or the day are not valid.
+    pub fn new(calendar: Calendar, year: i32, month: u8, day: u8) -> Result<Self, Error> {
+        match calendar.days_in_month(year, month) {
+            Some(days) if (1..=days).contains(&day) => Ok(Self {
+                year,
+                month,
+                day,
+                hour: 0,
+                minute: 0,
+                second: 0_f64,
+                calendar,
+            }),
+            _ => Err(Error::InvalidDate),
+        }
+    }
+
+    /// Sets the time of the day of the date.
+    ///
+    /// Returns [`Error::InvalidDate`] if the hour is not below 24, the minute is not below 60, or
+    /// the second is not in the `[0, 60)` range.
+    pub fn with_time(self, hour: u8, minute: u8, second: f64) -> Result<Self, Error> {
+        if hour < 24 && minute < 60 && (0_f64..60_f64).contains(&second) {
+            Ok(Self {
+                hour,
+                minute,
+                second,
+                ..self
+            })
+        } else {
+            Err(Error::InvalidDate)
+        }
+    }
+
+    /// Gets the year of the date, in astronomical year numbering.
+    #[must_use]
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Gets the month of the date, from 1 to 12.
+    #[must_use]
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Gets the day of the month of the date, starting at 1.
+    #[must_use]
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Gets the hour of the day, from 0 to 23.
+    #[must_use]
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Gets the minute of the hour, from 0 to 59.
+    #[must_use]
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Gets the second of the minute, including its fraction.
+    #[must_use]
+    pub fn second(&self) -> f64 {
+        self.second
+    }
+
+    /// Gets the calendar of the date.
+    #[must_use]
+    pub fn calendar(&self) -> Calendar {
+        self.calendar
+    }
+}
+
+/// Formats the date in ISO 8601 format, with a sign for years before 0 or after 9999.
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if (0..=9999).contains(&self.year) {
+            write!(f, "{:04}", self.year)?;
+        } else {
+            write!(f, "{:+05}", self.year)?;
+        }
+        write!(
+            f,
+            "-{:02}-{:02}T{:02}:{:02}:",
+            self.month, self.day, self.hour, self.minute
+        )?;
+
+        // Whole seconds are written without a fraction.
+        #[allow(clippy::float_cmp)]
+        let whole_second = self.second == f64::from(self.second as u8);
+        if whole_second {
+            write!(f, "{:02}", self.second)
+        } else {
+            write!(f, "{:09.6}", self.second)
+        }
+    }
+}
+
+/// Parses an ISO 8601 date of the proleptic Gregorian calendar, optionally with a time, such as
+/// `2000-01-01` or `2000-01-01T12:00:00.5`.
+///
+/// UTC offsets are not accepted, except for `Z`, since the date would need to be shifted: parse
+/// a [`JulianDay`] instead.
+impl FromStr for CalendarDate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, offset) = parse_date_time(s)?;
+
+        if offset == 0_f64 {
+            Ok(date)
+        } else {
+            Err(Error::InvalidDateFormat)
+        }
+    }
+}
+
+/// Parses an ISO 8601 date and time, returning the date and its UTC offset in seconds.
+fn parse_date_time(s: &str) -> Result<(CalendarDate, f64), Error> {
+    let s = s.trim();
+    let (date, time) = match s.split_once(['T', 't', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+
+    let (negative, unsigned_date) = match date.strip_prefix('-') {
+        Some(date) => (true, date),
+        None => (false, date.strip_prefix('+').unwrap_or(date)),
+    };
+    let mut fields = unsigned_date.split('-');
+    let (Some(year), Some(month), Some(day), None) =
+        (fields.next(), fields.next(), fields.next(), fields.next())
+    else {
+        return Err(Error::InvalidDateFormat);
+    };
+    if year.len() < 4 {
+        return Err(Error::InvalidDateFormat);
+    }
+    let year: i32 = parse_digits(year)?;
+    let date = CalendarDate::gregorian(
+        if negative { -year } else { year },
+        parse_two_digits(month)?,
+        parse_two_digits(day)?,
+    )?;
+
+    let Some(time) = time else {
+        return Ok((date, 0_f64));
+    };
+
+    let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, 0_f64)
+    } else if let Some(position) = time.find(['+', '-']) {
+        let (time, offset) = time.split_at(position);
+        (time, parse_offset(offset)?)
+    } else {
+        (time, 0_f64)
+    };
+
+    let mut fields = time.split(':');
+    let (Some(hour), Some(minute), second, None) =
+        (fields.next(), fields.next(), fields.next(), fields.next())
+    else {
+        return Err(Error::InvalidDateFormat);
+    };
+    let second = match second {
+        Some(second) => {
+            let (whole, fraction) = second.split_once('.').unwrap_or((second, "0"));
+            if whole.len() != 2 || !is_digits(whole) || !is_digits(fraction) {
+                return Err(Error::InvalidDateFormat);
+            }
+            second.parse().map_err(|_| Error::InvalidDateFormat)?
+        }
+        None => 0_f64,
+    };
+
+    let date = date.with_time(parse_two_digits(hour)?, parse_two_digits(minute)?, second)?;
+    Ok((date, offset))
+}
+
+/// Parses a UTC offset in the `+hh:mm`, `+hhmm` or `+hh` formats, returning it in seconds.
+fn parse_offset(offset: &str) -> Result<f64, Error> {
+    let (negative, offset) = match offset.strip_prefix('-') {
+        Some(offset) => (true, offset),
+        None => (false, offset.strip_prefix('+').unwrap_or(offset)),
+    };
+    let (hours, minutes) = match (offset.len(), offset.split_once(':')) {
+        (_, Some((hours, minutes))) => (hours, minutes),
+        (4, None) => offset.split_at(2),
+        (2, None) => (offset, "00"),
+        _ => return Err(Error::InvalidDateFormat),
+    };
+
+    let hours: u8 = parse_two_digits(hours)?;
+    let minutes: u8 = parse_two_digits(minutes)?;
+    if hours >= 24 || minutes >= 60 {
+        return Err(Error::InvalidDate);
+    }
+
+    let seconds = f64::from(hours) * 3_600_f64 + f64::from(minutes) * 60_f64;
+    Ok(if negative { -seconds } else { seconds })
+}
+
+/// Parses a field of exactly two digits.
+fn parse_two_digits(field: &str) -> Result<u8, Error> {
+    if field.len() == 2 {
+        parse_digits(field)
+    } else {
+        Err(Error::InvalidDateFormat)
+    }
+}
+
+/// Parses a field made only of digits.
+fn parse_digits<T: FromStr>(field: &str) -> Result<T, Error> {
+    if is_digits(field) {
+        field.parse().map_err(|_| Error::InvalidDateFormat)
+    } else {
+        Err(Error::InvalidDateFormat)
+    }
+}
+
+/// Checks that the field is not empty and is made only of digits.
+fn is_digits(field: &str) -> bool {
+    !field.is_empty() && field.bytes().all(|byte| byte.is_ascii_digit())
+}
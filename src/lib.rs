@@ -30,6 +30,8 @@
 //! We first calculate the VSOP87 elements:
 //!
 //! ```
+//! # #[cfg(all(feature = "elements", feature = "mercury"))]
+//! # {
 //! let vsop87_elts = vsop87::mercury(2451545.0);
 //!
 //! assert!(vsop87_elts.a > 0.3870982121 && vsop87_elts.a < 0.3870982123);
@@ -38,6 +40,7 @@
 //! assert!(vsop87_elts.h > 0.2007208957 && vsop87_elts.h < 0.2007208959);
 //! assert!(vsop87_elts.q > 0.0406161540 && vsop87_elts.q < 0.0406161542);
 //! assert!(vsop87_elts.p > 0.04563512 && vsop87_elts.p < 0.04563588);
+//! # }
 //! ```
 //!
 //! Note that the `>` and `<` comparisons are there because floats should not be compared using
@@ -47,6 +50,8 @@
 //! the `into()` function in the *VSOP87* elements. This also works the other way around:
 //!
 //! ```
+//! # #[cfg(all(feature = "elements", feature = "mercury"))]
+//! # {
 //! use vsop87::{KeplerianElements, VSOP87Elements};
 //!
 //! # let vsop87_elts = vsop87::mercury(2451545.0);
@@ -67,10 +72,48 @@
 //! assert!(elements.ascending_node() > 0.843525 && elements.ascending_node() < 0.843527);
 //! assert!(elements.periapsis() > 1.35183 && elements.periapsis() < 1.35185);
 //! assert!(elements.mean_anomaly() > 4.40259 && elements.mean_anomaly() < 4.40261);
+//! # }
 //! ```
 //!
 //! As you can see, these numbers perfectly match
 //! [those from NASA](http://solarsystem.nasa.gov/planets/mercury/facts).
+//!
+//! # Dates
+//!
+//! Instead of converting dates into Julian Days by hand, the [`JulianDay`] type can be created
+//! from dates of the proleptic Gregorian and Julian calendars, from ISO 8601 strings, or from the
+//! system clock. Every function of the library accepts it, as well as plain `f64` Julian Days:
+//!
+//! ```
+//! # #[cfg(all(feature = "elements", feature = "mercury"))]
+//! # {
+//! use vsop87::JulianDay;
+//!
+//! let jd: JulianDay = "2000-01-01T12:00:00".parse()?;
+//!
+//! assert_eq!(jd.julian_millennia(), 0.0);
+//! assert_eq!(vsop87::mercury(jd), vsop87::mercury(2451545.0));
+//! # }
+//! # Ok::<(), vsop87::Error>(())
+//! ```
+//!
+//! Julian Days are stored in two parts, so that they keep a precision of microseconds or better
+//! when calculating the time variable of the series. See the
+//! [precision of Julian Days](JulianDay#precision) for more information.
+//!
+//! # Features
+//!
+//! Each solution and each body can be disabled with cargo features, to reduce build times and
+//! binary size. All of them are enabled by default:
+//!
+//! - Solutions: `elements` (the base *VSOP87* solution), `vsop87a`, `vsop87b`, `vsop87c`,
+//!   `vsop87d` and `vsop87e`, or `all_solutions` to enable all of them.
+//! - Bodies: `sun`, `mercury`, `venus`, `earth`, `earth_moon`, `mars`, `jupiter`, `saturn`,
+//!   `uranus` and `neptune`, or `all_bodies` to enable all of them.
+//!
+//! The functions of disabled solutions and bodies are not available, and the runtime
+//! [`calculate()`] function returns [`Error::UnsupportedBody`] for them. At least one solution,
+//! and one body available in it, must be enabled.
 
 #![forbid(
     missing_docs,
@@ -161,25 +204,129 @@
 // All the "allow by default" lints
 #![warn(box_pointers, unused_results)]
 
+#[cfg(not(any(
+    feature = "elements",
+    feature = "vsop87a",
+    feature = "vsop87b",
+    feature = "vsop87c",
+    feature = "vsop87d",
+    feature = "vsop87e"
+)))]
+compile_error!("at least one VSOP87 solution feature must be enabled");
+
+// Every solution has the planets from Mercury to Neptune except the Earth, so enabling any of them
+// is enough.
+#[cfg(not(any(
+    feature = "mercury",
+    feature = "venus",
+    feature = "mars",
+    feature = "jupiter",
+    feature = "saturn",
+    feature = "uranus",
+    feature = "neptune",
+    all(feature = "earth_moon", any(feature = "elements", feature = "vsop87a")),
+    all(
+        feature = "earth",
+        any(
+            feature = "vsop87a",
+            feature = "vsop87b",
+            feature = "vsop87c",
+            feature = "vsop87d",
+            feature = "vsop87e"
+        )
+    ),
+    all(feature = "sun", feature = "vsop87e")
+)))]
+compile_error!("at least one body available in the enabled VSOP87 solutions must be enabled");
+
+#[cfg(feature = "vsop87a")]
 pub mod vsop87a;
+#[cfg(feature = "vsop87b")]
 pub mod vsop87b;
+#[cfg(feature = "vsop87c")]
 pub mod vsop87c;
+#[cfg(feature = "vsop87d")]
 pub mod vsop87d;
+#[cfg(feature = "vsop87e")]
 pub mod vsop87e;
 
+pub mod typed;
+
+#[cfg(all(feature = "elements", feature = "earth_moon"))]
 mod earth_moon;
+#[cfg(all(feature = "elements", feature = "jupiter"))]
 mod jupiter;
+#[cfg(all(feature = "elements", feature = "mars"))]
 mod mars;
+#[cfg(all(feature = "elements", feature = "mercury"))]
 mod mercury;
+#[cfg(all(feature = "elements", feature = "neptune"))]
 mod neptune;
+#[cfg(all(feature = "elements", feature = "saturn"))]
 mod saturn;
+#[cfg(all(feature = "elements", feature = "uranus"))]
 mod uranus;
+#[cfg(all(feature = "elements", feature = "venus"))]
 mod venus;
 
+#[cfg(all(feature = "vsop87e", feature = "sun", feature = "earth"))]
+mod apparent;
+mod body;
+mod delta_t;
+mod ephemeris;
+mod error;
+mod horizontal;
+mod julian_day;
+mod nutation;
+mod observer;
+mod precession;
+mod precision;
+#[cfg(all(feature = "vsop87e", feature = "sun", feature = "earth"))]
+mod rise_set;
+mod rotation;
+mod sidereal;
+mod solution;
+mod tdb;
+mod time_scale;
+#[cfg(all(feature = "vsop87e", feature = "sun", feature = "earth"))]
+mod twilight;
+
+#[cfg(all(feature = "vsop87e", feature = "sun", feature = "earth"))]
+pub use apparent::{
+    geocentric_place, horizontal_coordinates, topocentric_place, GeocentricPlace, Place,
+    TopocentricPlace,
+};
+pub use body::Body;
+pub use delta_t::DeltaT;
+#[cfg(feature = "elements")]
+pub use ephemeris::VSOP87;
+pub use ephemeris::{Center, Ephemeris, Frame, Validity, VALIDITY};
+pub use error::Error;
+pub use horizontal::{HorizontalCoordinates, Refraction};
+pub use julian_day::{Calendar, CalendarDate, JulianDay};
+pub use nutation::Nutation;
+pub use observer::{Ellipsoid, Observer};
+pub use precession::Precession;
+pub use precision::{Precision, Truncated};
+#[cfg(all(feature = "vsop87e", feature = "sun", feature = "earth"))]
+pub use rise_set::{rise_transit_set, Horizon, RiseTransitSet, Visibility};
+pub use rotation::Rotation;
+pub use sidereal::{earth_rotation_angle, SiderealTime};
+pub use solution::{calculate, calculate_checked, Coordinates, Solution};
+pub use tdb::{tdb_minus_tt_abridged, tdb_minus_tt_truncated};
+pub use time_scale::{LeapSeconds, TimeScale, TimeScales};
+#[cfg(all(feature = "vsop87e", feature = "sun", feature = "earth"))]
+pub use twilight::{twilight, Twilight, TwilightTimes};
+
+#[cfg(feature = "elements")]
+use ephemeris::check_validity;
+#[cfg(feature = "elements")]
+use precision::{calculate_truncated, Variable};
+
 #[cfg(feature = "no_std")]
 use core::f64::consts::PI;
 #[cfg(feature = "no_std")]
-use libm::{acos, asin, atan, cos, sin, sincos, sqrt};
+use libm::{acos, asin, atan, atan2, cos, hypot, sin, sincos, sqrt};
 
 #[cfg(not(feature = "no_std"))]
 use std::f64::consts::PI;
@@ -290,8 +437,151 @@ impl From<VSOP87Elements> for KeplerianElements {
     }
 }
 
-/// Structure representing 3 dimensional rectangular coordinates.
+/// Structure representing the rates of change of the keplerian elements of an orbit.
+///
+/// They can be calculated from a [`VSOP87ElementsState`], and all of them are expressed per day.
+///
+/// The longitude of the periapsis is not defined for a circular orbit, and the longitude of the
+/// ascending node is not defined for an orbit in the plane of the ecliptic, so their rates are
+/// `None` when the eccentricity or the inclination is zero. The rates of the eccentricity and of
+/// the inclination are then the rates at which they grow from zero.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{KeplerianRates, VSOP87Elements, VSOP87ElementsRates, VSOP87ElementsState};
+///
+/// let circular = VSOP87ElementsState {
+///     elements: VSOP87Elements { a: 1.0, l: 0.0, k: 0.0, h: 0.0, q: 0.01, p: 0.0 },
+///     rates: VSOP87ElementsRates { a: 0.0, l: 0.017, k: 3e-8, h: 4e-8, q: 0.0, p: 1e-8 },
+/// };
+/// let rates = KeplerianRates::from(circular);
+///
+/// assert!((rates.eccentricity_rate() - 5e-8).abs() < 1e-20);
+/// assert_eq!(rates.apsidal_precession(), None);
+/// assert!((rates.nodal_precession().unwrap() - 1e-6).abs() < 1e-18);
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeplerianRates {
+    ecc: f64,
+    sma: f64,
+    incl: f64,
+    lan: Option<f64>,
+    lper: Option<f64>,
+    l0: f64,
+}
+
+impl KeplerianRates {
+    /// Gets the rate of change of the eccentricity of the orbit (*e*), per day.
+    #[must_use]
+    pub fn eccentricity_rate(&self) -> f64 {
+        self.ecc
+    }
+
+    /// Gets the rate of change of the semimajor axis of the orbit (*a*), in *AU* per day.
+    #[must_use]
+    pub fn semimajor_axis_rate(&self) -> f64 {
+        self.sma
+    }
+
+    /// Gets the rate of change of the inclination of the orbit (*i*), in radians per day.
+    #[must_use]
+    pub fn inclination_rate(&self) -> f64 {
+        self.incl
+    }
+
+    /// Gets the nodal precession rate of the orbit, in radians per day.
+    ///
+    /// This value represents the rate of change of the longitude of the ascending node (*Ω*). It
+    /// is `None` if the inclination of the orbit is zero, since the node is not defined then.
+    #[must_use]
+    pub fn nodal_precession(&self) -> Option<f64> {
+        self.lan
+    }
+
+    /// Gets the apsidal precession rate of the orbit, in radians per day.
+    ///
+    /// This value represents the rate of change of the longitude of the periapsis (*ϖ*). It is
+    /// `None` if the eccentricity of the orbit is zero, since the periapsis is not defined then.
+    #[must_use]
+    pub fn apsidal_precession(&self) -> Option<f64> {
+        self.lper
+    }
+
+    /// Gets the rate of change of the mean anomaly of the orbiting object, in radians per day.
+    ///
+    /// This value represents the mean motion of the orbiting body.
+    #[must_use]
+    pub fn mean_anomaly_rate(&self) -> f64 {
+        self.l0
+    }
+}
+
+impl From<VSOP87ElementsState> for KeplerianRates {
+    #[inline]
+    fn from(state: VSOP87ElementsState) -> Self {
+        let VSOP87ElementsState { elements, rates } = state;
+
+        let ecc_squared = elements.h * elements.h + elements.k * elements.k;
+        // `(i/2.0).sin()` squared.
+        let sin_half_incl_squared = elements.p * elements.p + elements.q * elements.q;
+
+        #[cfg(feature = "no_std")]
+        let (ecc, sin_half_incl, cos_half_incl) = (
+            sqrt(ecc_squared),
+            sqrt(sin_half_incl_squared),
+            sqrt(1_f64 - sin_half_incl_squared),
+        );
+        #[cfg(not(feature = "no_std"))]
+        let (ecc, sin_half_incl, cos_half_incl) = (
+            ecc_squared.sqrt(),
+            sin_half_incl_squared.sqrt(),
+            (1_f64 - sin_half_incl_squared).sqrt(),
+        );
+
+        // At zero eccentricity or inclination, the direction of the periapsis or of the node is
+        // not defined, and the elements grow from zero at the speed of their vector.
+        let (ecc_rate, lper) = if ecc_squared > 0_f64 {
+            (
+                (elements.h * rates.h + elements.k * rates.k) / ecc,
+                Some((elements.k * rates.h - elements.h * rates.k) / ecc_squared),
+            )
+        } else {
+            #[cfg(feature = "no_std")]
+            let ecc_rate = hypot(rates.h, rates.k);
+            #[cfg(not(feature = "no_std"))]
+            let ecc_rate = rates.h.hypot(rates.k);
+
+            (ecc_rate, None)
+        };
+        let (incl_rate, lan) = if sin_half_incl_squared > 0_f64 {
+            (
+                2_f64 * (elements.p * rates.p + elements.q * rates.q)
+                    / (sin_half_incl * cos_half_incl),
+                Some((elements.q * rates.p - elements.p * rates.q) / sin_half_incl_squared),
+            )
+        } else {
+            #[cfg(feature = "no_std")]
+            let incl_rate = 2_f64 * hypot(rates.p, rates.q);
+            #[cfg(not(feature = "no_std"))]
+            let incl_rate = 2_f64 * rates.p.hypot(rates.q);
+
+            (incl_rate, None)
+        };
+
+        Self {
+            ecc: ecc_rate,
+            sma: rates.a,
+            incl: incl_rate,
+            lan,
+            lper,
+            l0: rates.l,
+        }
+    }
+}
+
+/// Structure representing 3 dimensional rectangular coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct RectangularCoordinates {
     /// X coordinate.
     pub x: f64,
@@ -301,8 +591,22 @@ pub struct RectangularCoordinates {
     pub z: f64,
 }
 
-/// Structure representing spherical coordinates of a body.
+/// Structure representing the state vector of a body: its position and its velocity.
 #[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StateVector {
+    /// Rectangular coordinates of the position, in *AU*.
+    pub position: RectangularCoordinates,
+    /// Rectangular coordinates of the velocity, in *AU* per day.
+    pub velocity: RectangularCoordinates,
+}
+
+/// Structure representing spherical coordinates of a body.
+///
+/// They can be converted from and into [`RectangularCoordinates`] using the `From` trait. When the
+/// coordinates are rotated into an equatorial frame, for example with
+/// [`to_fk5()`](Self::to_fk5), the longitude is the right ascension (*α*) and the latitude is the
+/// declination (*δ*).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct SphericalCoordinates {
     lon: f64,
     lat: f64,
@@ -340,10 +644,150 @@ impl SphericalCoordinates {
     }
 }
 
-/// Calculates the time variable for VSOP87.
+impl From<SphericalCoordinates> for RectangularCoordinates {
+    #[inline]
+    fn from(coordinates: SphericalCoordinates) -> Self {
+        #[cfg(not(feature = "no_std"))]
+        let ((sin_lon, cos_lon), (sin_lat, cos_lat)) =
+            (coordinates.lon.sin_cos(), coordinates.lat.sin_cos());
+        #[cfg(feature = "no_std")]
+        let ((sin_lon, cos_lon), (sin_lat, cos_lat)) =
+            (sincos(coordinates.lon), sincos(coordinates.lat));
+
+        Self {
+            x: coordinates.dist * cos_lat * cos_lon,
+            y: coordinates.dist * cos_lat * sin_lon,
+            z: coordinates.dist * sin_lat,
+        }
+    }
+}
+
+impl From<RectangularCoordinates> for SphericalCoordinates {
+    #[inline]
+    fn from(RectangularCoordinates { x, y, z }: RectangularCoordinates) -> Self {
+        #[cfg(not(feature = "no_std"))]
+        let (lon, projection) = (y.atan2(x), x.hypot(y));
+        #[cfg(feature = "no_std")]
+        let (lon, projection) = (atan2(y, x), hypot(x, y));
+
+        #[cfg(not(feature = "no_std"))]
+        let (lat, dist) = (z.atan2(projection), projection.hypot(z));
+        #[cfg(feature = "no_std")]
+        let (lat, dist) = (atan2(z, projection), hypot(projection, z));
+
+        Self {
+            lon: if lon < 0_f64 { lon + 2_f64 * PI } else { lon },
+            lat,
+            dist,
+        }
+    }
+}
+
+/// Structure representing spherical coordinates of a body, along with their time derivatives.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SphericalState {
+    coordinates: SphericalCoordinates,
+    lon_rate: f64,
+    lat_rate: f64,
+    dist_rate: f64,
+}
+
+impl SphericalState {
+    /// Gets the spherical coordinates of the body.
+    #[must_use]
+    pub fn coordinates(&self) -> SphericalCoordinates {
+        self.coordinates
+    }
+
+    /// Gets the rate of change of the ecliptic longitude of the body, in radians per day.
+    ///
+    /// This value represents the angular motion of the body along the ecliptic plane.
+    #[must_use]
+    pub fn longitude_rate(&self) -> f64 {
+        self.lon_rate
+    }
+
+    /// Gets the rate of change of the ecliptic latitude of the body, in radians per day.
+    ///
+    /// This value represents the angular motion of the body towards the north ecliptic pole.
+    #[must_use]
+    pub fn latitude_rate(&self) -> f64 {
+        self.lat_rate
+    }
+
+    /// Gets the rate of change of the distance to the center of mass, in *AU* per day.
+    ///
+    /// This value represents the radial velocity of the body, positive when it moves away from
+    /// the center.
+    #[must_use]
+    pub fn distance_rate(&self) -> f64 {
+        self.dist_rate
+    }
+}
+
+/// Calculates the time variable for VSOP87: the Julian millennia since J2000.0.
 #[inline]
-fn calculate_t(jde: f64) -> f64 {
-    (jde - 2_451_545_f64) / 365_250_f64
+fn calculate_t(jde: JulianDay) -> f64 {
+    jde.julian_millennia()
+}
+
+/// Evaluates the polynomial with the given coefficients, in increasing degree, at `x`.
+fn polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0_f64, |value, &coefficient| value * x + coefficient)
+}
+
+/// Number of days in a Julian millennium, the time unit of the *VSOP87* series.
+const DAYS_PER_MILLENNIUM: f64 = 365_250_f64;
+
+/// Coefficients of a *VSOP87* series: the `A`, `B` and `C` values of each of its terms.
+type Series = [&'static [f64]; 3];
+
+/// Creates a series from its coefficient table.
+const fn series<const N: usize>(coefficients: &'static [[f64; N]; 3]) -> Series {
+    [&coefficients[0], &coefficients[1], &coefficients[2]]
+}
+
+/// Normalizes a longitude calculated from the series between 0 and 2π.
+#[cfg(any(feature = "elements", feature = "vsop87b", feature = "vsop87d"))]
+fn normalize_longitude(l: f64) -> f64 {
+    let l = l % (2_f64 * PI);
+    if l > 0_f64 {
+        l
+    } else {
+        2_f64 * PI + l
+    }
+}
+
+/// Creates the rectangular coordinates of a body from the values of its X, Y and Z variables.
+#[cfg(any(feature = "vsop87a", feature = "vsop87c", feature = "vsop87e"))]
+fn rectangular([x, y, z]: [f64; 3]) -> RectangularCoordinates {
+    RectangularCoordinates { x, y, z }
+}
+
+/// Creates the spherical coordinates of a body from the values of its L, B and R variables.
+#[cfg(any(feature = "vsop87b", feature = "vsop87d"))]
+fn spherical([l, b, r]: [f64; 3]) -> SphericalCoordinates {
+    SphericalCoordinates {
+        lon: normalize_longitude(l),
+        lat: b,
+        dist: r,
+    }
+}
+
+/// Creates the VSOP87 elements of a body from the values of its A, L, K, H, Q and P variables.
+#[cfg(feature = "elements")]
+fn elements([a, l, k, h, q, p]: [f64; 6]) -> VSOP87Elements {
+    VSOP87Elements {
+        a,
+        l: normalize_longitude(l),
+        k,
+        h,
+        q,
+        p,
+    }
 }
 
 /// Calculates the given variable.
@@ -374,6 +818,26 @@ fn calculate_var(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> f64 {
     }
 }
 
+/// Calculates the given variable, skipping the terms with an amplitude below the threshold.
+///
+/// The terms of the series are sorted by decreasing amplitude, with a few small exceptions, so
+/// the series is cut after the last term that reaches the threshold, and the terms before the cut
+/// are calculated by [`calculate_var()`], using the SIMD implementation if available. No term
+/// above the threshold is skipped. If the threshold is not positive, all the terms are
+/// calculated.
+#[inline]
+fn calculate_var_truncated(t: f64, a: &[f64], b: &[f64], c: &[f64], threshold: f64) -> f64 {
+    let terms = if threshold > 0_f64 {
+        a.iter()
+            .rposition(|a| a.abs() >= threshold)
+            .map_or(0, |last| last + 1)
+    } else {
+        a.len()
+    };
+
+    calculate_var(t, &a[..terms], &b[..terms], &c[..terms])
+}
+
 /// Fallback implementation of the variable calculation.
 ///
 /// Used in systems without SIMD support.
@@ -396,6 +860,285 @@ fn calculate_var_fallback(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> f64 {
     }
 }
 
+/// Calculates the given variable and its derivative with respect to `t`.
+///
+/// Each term `a * (b + c * t).cos()` has `-a * c * (b + c * t).sin()` as its derivative, so both
+/// are computed in the same pass.
+#[inline]
+fn calculate_var_with_rate(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> (f64, f64) {
+    a.iter()
+        .zip(b)
+        .zip(c)
+        .fold((0_f64, 0_f64), |(term, rate), ((a, b), c)| {
+            #[cfg(not(feature = "no_std"))]
+            let (sin, cos) = (b + c * t).sin_cos();
+            #[cfg(feature = "no_std")]
+            let (sin, cos) = sincos(b + c * t);
+
+            (term + a * cos, rate - a * c * sin)
+        })
+}
+
+/// Calculates the value of a coordinate and its derivative with respect to `t`, given the series
+/// multiplying each power of `t`.
+fn calculate_coordinate_with_rate(t: f64, series: &[Series]) -> (f64, f64) {
+    let mut value = 0_f64;
+    let mut rate = 0_f64;
+    // `t^n` and `n * t^(n - 1)` for the current series.
+    let mut power = 1_f64;
+    let mut power_rate = 0_f64;
+
+    for (n, [a, b, c]) in series.iter().enumerate() {
+        let (var, var_rate) = calculate_var_with_rate(t, a, b, c);
+
+        value += var * power;
+        rate += var_rate * power + var * power_rate;
+
+        power_rate = (n + 1) as f64 * power;
+        power *= t;
+    }
+
+    (value, rate)
+}
+
+/// Calculates the state vector of a body, given the series of its X, Y and Z coordinates.
+#[cfg(any(feature = "vsop87a", feature = "vsop87c", feature = "vsop87e"))]
+fn calculate_state(jde: JulianDay, [x, y, z]: &[&[Series]; 3]) -> StateVector {
+    let t = calculate_t(jde);
+
+    let (x, x_rate) = calculate_coordinate_with_rate(t, x);
+    let (y, y_rate) = calculate_coordinate_with_rate(t, y);
+    let (z, z_rate) = calculate_coordinate_with_rate(t, z);
+
+    StateVector {
+        position: RectangularCoordinates { x, y, z },
+        velocity: RectangularCoordinates {
+            x: x_rate / DAYS_PER_MILLENNIUM,
+            y: y_rate / DAYS_PER_MILLENNIUM,
+            z: z_rate / DAYS_PER_MILLENNIUM,
+        },
+    }
+}
+
+/// Calculates the spherical coordinates of a body and their rates, given the series of its L, B
+/// and R coordinates.
+#[cfg(any(feature = "vsop87b", feature = "vsop87d"))]
+fn calculate_spherical_state(jde: JulianDay, [l, b, r]: &[&[Series]; 3]) -> SphericalState {
+    let t = calculate_t(jde);
+
+    let (l, l_rate) = calculate_coordinate_with_rate(t, l);
+    let (b, b_rate) = calculate_coordinate_with_rate(t, b);
+    let (r, r_rate) = calculate_coordinate_with_rate(t, r);
+
+    SphericalState {
+        coordinates: SphericalCoordinates {
+            lon: normalize_longitude(l),
+            lat: b,
+            dist: r,
+        },
+        lon_rate: l_rate / DAYS_PER_MILLENNIUM,
+        lat_rate: b_rate / DAYS_PER_MILLENNIUM,
+        dist_rate: r_rate / DAYS_PER_MILLENNIUM,
+    }
+}
+
+/// Calculates the VSOP87 elements of a body and their rates, given the series of its A, L, K, H,
+/// Q and P elements.
+#[cfg(feature = "elements")]
+fn calculate_elements_state(
+    jde: JulianDay,
+    [a, l, k, h, q, p]: &[&[Series]; 6],
+) -> VSOP87ElementsState {
+    let t = calculate_t(jde);
+
+    let (a, a_rate) = calculate_coordinate_with_rate(t, a);
+    let (l, l_rate) = calculate_coordinate_with_rate(t, l);
+    let (k, k_rate) = calculate_coordinate_with_rate(t, k);
+    let (h, h_rate) = calculate_coordinate_with_rate(t, h);
+    let (q, q_rate) = calculate_coordinate_with_rate(t, q);
+    let (p, p_rate) = calculate_coordinate_with_rate(t, p);
+
+    VSOP87ElementsState {
+        elements: VSOP87Elements {
+            a,
+            l: normalize_longitude(l),
+            k,
+            h,
+            q,
+            p,
+        },
+        rates: VSOP87ElementsRates {
+            a: a_rate / DAYS_PER_MILLENNIUM,
+            l: l_rate / DAYS_PER_MILLENNIUM,
+            k: k_rate / DAYS_PER_MILLENNIUM,
+            h: h_rate / DAYS_PER_MILLENNIUM,
+            q: q_rate / DAYS_PER_MILLENNIUM,
+            p: p_rate / DAYS_PER_MILLENNIUM,
+        },
+    }
+}
+
+/// Calculates the powers of `t` multiplying each of the series of a variable, up to `t^5`.
+fn calculate_powers(t: f64) -> [f64; 6] {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t2 * t2;
+    let t5 = t2 * t3;
+
+    [1_f64, t, t2, t3, t4, t5]
+}
+
+/// Calculates the given variables of a body, given the powers of `t`.
+fn calculate_variables<const N: usize>(powers: &[f64; 6], variables: &[&[Series]; N]) -> [f64; N] {
+    variables.map(|series| {
+        series
+            .iter()
+            .zip(powers)
+            .map(|([a, b, c], power)| calculate_var(powers[1], a, b, c) * power)
+            .sum()
+    })
+}
+
+/// Calculates the given variables of a body at the given Julian Day.
+fn calculate_body<const N: usize>(jde: JulianDay, variables: &[&[Series]; N]) -> [f64; N] {
+    calculate_variables(&calculate_powers(calculate_t(jde)), variables)
+}
+
+/// Calculates the given variables of all the given bodies at the same Julian Day.
+///
+/// The time is calculated only once, and shared by all the bodies.
+fn calculate_all<const B: usize, const N: usize>(
+    jde: JulianDay,
+    bodies: &[&[&[Series]; N]; B],
+) -> [[f64; N]; B] {
+    let powers = calculate_powers(calculate_t(jde));
+
+    bodies.map(|variables| calculate_variables(&powers, variables))
+}
+
+/// Calculates the given variables of all the given bodies at the same Julian Day, calculating
+/// each body in parallel.
+#[cfg(feature = "rayon")]
+fn calculate_all_parallel<const B: usize, const N: usize>(
+    jde: JulianDay,
+    bodies: &[&[&[Series]; N]; B],
+) -> [[f64; N]; B] {
+    use rayon::iter::{
+        IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator,
+        ParallelIterator,
+    };
+
+    let powers = calculate_powers(calculate_t(jde));
+
+    let mut values = [[0_f64; N]; B];
+    values
+        .par_iter_mut()
+        .zip(bodies.par_iter())
+        .for_each(|(values, variables)| *values = calculate_variables(&powers, variables));
+
+    values
+}
+
+/// Number of epochs calculated at the same time in batch calculations.
+///
+/// Epochs are calculated in blocks of this size, so that the intermediate results fit in the
+/// stack and in the cache.
+const BATCH_SIZE: usize = 64;
+
+/// Calculates the given variables for a batch of Julian Days, writing the results built by
+/// `build` in the output.
+///
+/// The terms of the series are iterated in the outer loop, and the epochs in the inner loop, so
+/// that each term is loaded once for every block of epochs, and the calculation can be vectorized
+/// over time.
+fn calculate_batch<T, J: Copy + Into<JulianDay>, const N: usize>(
+    jdes: &[J],
+    variables: &[&[Series]; N],
+    output: &mut [T],
+    build: impl Fn([f64; N]) -> T,
+) -> Result<(), Error> {
+    if jdes.len() != output.len() {
+        return Err(Error::OutputLength);
+    }
+
+    let mut t = [0_f64; BATCH_SIZE];
+    let mut values = [[0_f64; BATCH_SIZE]; N];
+
+    for (jdes, output) in jdes.chunks(BATCH_SIZE).zip(output.chunks_mut(BATCH_SIZE)) {
+        let t = &mut t[..jdes.len()];
+        for (t, &jde) in t.iter_mut().zip(jdes) {
+            *t = calculate_t(jde.into());
+        }
+
+        for (series, values) in variables.iter().zip(&mut values) {
+            let values = &mut values[..jdes.len()];
+            values.fill(0_f64);
+
+            // Horner's method, starting from the series multiplying the highest power of `t`.
+            for [a, b, c] in series.iter().rev() {
+                for (value, t) in values.iter_mut().zip(&*t) {
+                    *value *= t;
+                }
+                calculate_var_batch(t, a, b, c, values);
+            }
+        }
+
+        for (i, output) in output.iter_mut().enumerate() {
+            *output = build(core::array::from_fn(|n| values[n][i]));
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculates the given variable for a batch of values of `t`, adding it to the given values.
+#[inline]
+fn calculate_var_batch(t: &[f64], a: &[f64], b: &[f64], c: &[f64], values: &mut [f64]) {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "simd",
+        not(feature = "no_std")
+    ))]
+    #[allow(unsafe_code)]
+    {
+        if is_x86_feature_detected!("avx") {
+            // Safe because we already checked that we have AVX instruction set.
+            unsafe { calculate_var_batch_avx(t, a, b, c, values) }
+        } else {
+            calculate_var_batch_fallback(t, a, b, c, values);
+        }
+    }
+
+    #[cfg(any(
+        all(not(target_arch = "x86"), not(target_arch = "x86_64")),
+        not(feature = "simd"),
+        feature = "no_std"
+    ))]
+    {
+        calculate_var_batch_fallback(t, a, b, c, values);
+    }
+}
+
+/// Fallback implementation of the batch variable calculation.
+///
+/// Used in systems without SIMD support.
+#[inline]
+fn calculate_var_batch_fallback(t: &[f64], a: &[f64], b: &[f64], c: &[f64], values: &mut [f64]) {
+    for ((a, b), c) in a.iter().zip(b).zip(c) {
+        for (value, t) in values.iter_mut().zip(t) {
+            #[cfg(not(feature = "no_std"))]
+            {
+                *value += a * (b + c * t).cos();
+            }
+
+            #[cfg(feature = "no_std")]
+            {
+                *value += a * cos(b + c * t);
+            }
+        }
+    }
+}
+
 /// Calculates the given variable using the AVX instruction set.
 #[target_feature(enable = "avx")]
 #[cfg(all(
@@ -497,22 +1240,85 @@ unsafe fn calculate_var_avx(t: f64, a: &[f64], b: &[f64], c: &[f64]) -> f64 {
     res + remainder
 }
 
-/// Elements used by the VSOP87 solution. Can be converted into keplerian elements.
+/// Calculates the given variable for a batch of values of `t` using the AVX instruction set.
 ///
-/// More information can be found [here](http://totaleclipse.eu/Astronomy/VSOP87.html).
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct VSOP87Elements {
-    /// Semimajor axis in astronomical units (*AU*).
-    pub a: f64,
-    /// Mean longitude at epoch.
-    pub l: f64,
-    /// `e * lper.cos()``, where *e* is the eccentricity and *lper* is the longitude of the
-    /// perihelion.
-    pub k: f64,
-    /// `e * lper.sin()``, where *e* is the eccentricity and *lper* is the longitude of the
-    /// perihelion (*ϖ*).
-    pub h: f64,
-    /// `(i/2.0).sin() * lan.cos()` where *i* is inclination and *lan is the longitude of the
+/// Each term is calculated for 4 values of `t` at the same time.
+#[target_feature(enable = "avx")]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "simd",
+    not(feature = "no_std")
+))]
+#[allow(unsafe_code)]
+unsafe fn calculate_var_batch_avx(t: &[f64], a: &[f64], b: &[f64], c: &[f64], values: &mut [f64]) {
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::{
+        _mm256_add_pd, _mm256_loadu_pd, _mm256_mul_pd, _mm256_set1_pd, _mm256_set_pd,
+        _mm256_storeu_pd,
+    };
+
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::{
+        _mm256_add_pd, _mm256_loadu_pd, _mm256_mul_pd, _mm256_set1_pd, _mm256_set_pd,
+        _mm256_storeu_pd,
+    };
+
+    let t_chunks = t.chunks_exact(4);
+    let t_remainder = t_chunks.remainder();
+    let (values_chunks, values_remainder) = values.split_at_mut(t.len() - t_remainder.len());
+
+    for ((&a, &b), &c) in a.iter().zip(b).zip(c) {
+        let a_vec = _mm256_set1_pd(a);
+        let b_vec = _mm256_set1_pd(b);
+        let c_vec = _mm256_set1_pd(c);
+
+        for (t, values) in t_chunks.clone().zip(values_chunks.chunks_exact_mut(4)) {
+            // Safe because the chunk is 4 f64 long.
+            let t = unsafe { _mm256_loadu_pd(t.as_ptr()) };
+            let bct = _mm256_add_pd(b_vec, _mm256_mul_pd(c_vec, t));
+
+            let mut bct_unpacked = [0_f64; 4];
+            // Safe because bct_unpacked is 4 f64 long.
+            unsafe { _mm256_storeu_pd(bct_unpacked.as_mut_ptr(), bct) };
+
+            // The arguments are in reverse order, since x86/x86_64 is little endian.
+            let cos = _mm256_set_pd(
+                bct_unpacked[3].cos(),
+                bct_unpacked[2].cos(),
+                bct_unpacked[1].cos(),
+                bct_unpacked[0].cos(),
+            );
+
+            // Safe because the chunk is 4 f64 long.
+            unsafe {
+                let sum =
+                    _mm256_add_pd(_mm256_loadu_pd(values.as_ptr()), _mm256_mul_pd(a_vec, cos));
+                _mm256_storeu_pd(values.as_mut_ptr(), sum);
+            }
+        }
+
+        for (value, t) in values_remainder.iter_mut().zip(t_remainder) {
+            *value += a * (b + c * t).cos();
+        }
+    }
+}
+
+/// Elements used by the VSOP87 solution. Can be converted into keplerian elements.
+///
+/// More information can be found [here](http://totaleclipse.eu/Astronomy/VSOP87.html).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct VSOP87Elements {
+    /// Semimajor axis in astronomical units (*AU*).
+    pub a: f64,
+    /// Mean longitude at epoch.
+    pub l: f64,
+    /// `e * lper.cos()``, where *e* is the eccentricity and *lper* is the longitude of the
+    /// perihelion.
+    pub k: f64,
+    /// `e * lper.sin()``, where *e* is the eccentricity and *lper* is the longitude of the
+    /// perihelion (*ϖ*).
+    pub h: f64,
+    /// `(i/2.0).sin() * lan.cos()` where *i* is inclination and *lan is the longitude of the
     /// ascending node (*Ω*).
     pub q: f64,
     /// `(i/2.0).sin() * lan.sin()` where *i* is inclination and *lan is the longitude of the
@@ -520,6 +1326,34 @@ pub struct VSOP87Elements {
     pub p: f64,
 }
 
+/// Rates of change of the elements used by the VSOP87 solution, per day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VSOP87ElementsRates {
+    /// Rate of change of the semimajor axis, in *AU* per day.
+    pub a: f64,
+    /// Rate of change of the mean longitude, in radians per day.
+    pub l: f64,
+    /// Rate of change of `k`, per day.
+    pub k: f64,
+    /// Rate of change of `h`, per day.
+    pub h: f64,
+    /// Rate of change of `q`, per day.
+    pub q: f64,
+    /// Rate of change of `p`, per day.
+    pub p: f64,
+}
+
+/// Elements used by the VSOP87 solution, along with their rates of change.
+///
+/// Can be converted into [`KeplerianRates`], to get the apsidal and nodal precession rates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VSOP87ElementsState {
+    /// Elements of the VSOP87 solution.
+    pub elements: VSOP87Elements,
+    /// Rates of change of the elements, per day.
+    pub rates: VSOP87ElementsRates,
+}
+
 impl From<KeplerianElements> for VSOP87Elements {
     #[inline]
     fn from(elts: KeplerianElements) -> Self {
@@ -555,6 +1389,423 @@ impl From<KeplerianElements> for VSOP87Elements {
     }
 }
 
+/// Series of the A, L, K, H, Q and P elements of Mercury.
+#[cfg(all(feature = "elements", feature = "mercury"))]
+static MERCURY: [&[Series]; 6] = [
+    &[
+        series(&mercury::A0),
+        series(&mercury::A1),
+        series(&mercury::A2),
+    ],
+    &[
+        series(&mercury::L0),
+        series(&mercury::L1),
+        series(&mercury::L2),
+        series(&mercury::L3),
+    ],
+    &[
+        series(&mercury::K0),
+        series(&mercury::K1),
+        series(&mercury::K2),
+        series(&mercury::K3),
+        series(&mercury::K4),
+        series(&mercury::K5),
+    ],
+    &[
+        series(&mercury::H0),
+        series(&mercury::H1),
+        series(&mercury::H2),
+        series(&mercury::H3),
+        series(&mercury::H4),
+        series(&mercury::H5),
+    ],
+    &[
+        series(&mercury::Q0),
+        series(&mercury::Q1),
+        series(&mercury::Q2),
+        series(&mercury::Q3),
+        series(&mercury::Q4),
+        series(&mercury::Q5),
+    ],
+    &[
+        series(&mercury::P0),
+        series(&mercury::P1),
+        series(&mercury::P2),
+        series(&mercury::P3),
+        series(&mercury::P4),
+    ],
+];
+
+/// Series of the A, L, K, H, Q and P elements of Venus.
+#[cfg(all(feature = "elements", feature = "venus"))]
+static VENUS: [&[Series]; 6] = [
+    &[series(&venus::A0), series(&venus::A1), series(&venus::A2)],
+    &[
+        series(&venus::L0),
+        series(&venus::L1),
+        series(&venus::L2),
+        series(&venus::L3),
+    ],
+    &[
+        series(&venus::K0),
+        series(&venus::K1),
+        series(&venus::K2),
+        series(&venus::K3),
+        series(&venus::K4),
+        series(&venus::K5),
+    ],
+    &[
+        series(&venus::H0),
+        series(&venus::H1),
+        series(&venus::H2),
+        series(&venus::H3),
+        series(&venus::H4),
+        series(&venus::H5),
+    ],
+    &[
+        series(&venus::Q0),
+        series(&venus::Q1),
+        series(&venus::Q2),
+        series(&venus::Q3),
+        series(&venus::Q4),
+        series(&venus::Q5),
+    ],
+    &[
+        series(&venus::P0),
+        series(&venus::P1),
+        series(&venus::P2),
+        series(&venus::P3),
+        series(&venus::P4),
+    ],
+];
+
+/// Series of the A, L, K, H, Q and P elements of the Earth - Moon barycenter.
+#[cfg(all(feature = "elements", feature = "earth_moon"))]
+static EARTH_MOON: [&[Series]; 6] = [
+    &[
+        series(&earth_moon::A0),
+        series(&earth_moon::A1),
+        series(&earth_moon::A2),
+    ],
+    &[
+        series(&earth_moon::L0),
+        series(&earth_moon::L1),
+        series(&earth_moon::L2),
+        series(&earth_moon::L3),
+        series(&earth_moon::L4),
+        series(&earth_moon::L5),
+    ],
+    &[
+        series(&earth_moon::K0),
+        series(&earth_moon::K1),
+        series(&earth_moon::K2),
+        series(&earth_moon::K3),
+        series(&earth_moon::K4),
+        series(&earth_moon::K5),
+    ],
+    &[
+        series(&earth_moon::H0),
+        series(&earth_moon::H1),
+        series(&earth_moon::H2),
+        series(&earth_moon::H3),
+        series(&earth_moon::H4),
+        series(&earth_moon::H5),
+    ],
+    &[
+        series(&earth_moon::Q0),
+        series(&earth_moon::Q1),
+        series(&earth_moon::Q2),
+        series(&earth_moon::Q3),
+        series(&earth_moon::Q4),
+        series(&earth_moon::Q5),
+    ],
+    &[
+        series(&earth_moon::P0),
+        series(&earth_moon::P1),
+        series(&earth_moon::P2),
+        series(&earth_moon::P3),
+        series(&earth_moon::P4),
+    ],
+];
+
+/// Series of the A, L, K, H, Q and P elements of Mars.
+#[cfg(all(feature = "elements", feature = "mars"))]
+static MARS: [&[Series]; 6] = [
+    &[series(&mars::A0), series(&mars::A1), series(&mars::A2)],
+    &[
+        series(&mars::L0),
+        series(&mars::L1),
+        series(&mars::L2),
+        series(&mars::L3),
+        series(&mars::L4),
+        series(&mars::L5),
+    ],
+    &[
+        series(&mars::K0),
+        series(&mars::K1),
+        series(&mars::K2),
+        series(&mars::K3),
+        series(&mars::K4),
+        series(&mars::K5),
+    ],
+    &[
+        series(&mars::H0),
+        series(&mars::H1),
+        series(&mars::H2),
+        series(&mars::H3),
+        series(&mars::H4),
+        series(&mars::H5),
+    ],
+    &[
+        series(&mars::Q0),
+        series(&mars::Q1),
+        series(&mars::Q2),
+        series(&mars::Q3),
+        series(&mars::Q4),
+        series(&mars::Q5),
+    ],
+    &[
+        series(&mars::P0),
+        series(&mars::P1),
+        series(&mars::P2),
+        series(&mars::P3),
+    ],
+];
+
+/// Series of the A, L, K, H, Q and P elements of Jupiter.
+#[cfg(all(feature = "elements", feature = "jupiter"))]
+static JUPITER: [&[Series]; 6] = [
+    &[
+        series(&jupiter::A0),
+        series(&jupiter::A1),
+        series(&jupiter::A2),
+        series(&jupiter::A3),
+        series(&jupiter::A4),
+        series(&jupiter::A5),
+    ],
+    &[
+        series(&jupiter::L0),
+        series(&jupiter::L1),
+        series(&jupiter::L2),
+        series(&jupiter::L3),
+        series(&jupiter::L4),
+        series(&jupiter::L5),
+    ],
+    &[
+        series(&jupiter::K0),
+        series(&jupiter::K1),
+        series(&jupiter::K2),
+        series(&jupiter::K3),
+        series(&jupiter::K4),
+    ],
+    &[
+        series(&jupiter::H0),
+        series(&jupiter::H1),
+        series(&jupiter::H2),
+        series(&jupiter::H3),
+        series(&jupiter::H4),
+    ],
+    &[
+        series(&jupiter::Q0),
+        series(&jupiter::Q1),
+        series(&jupiter::Q2),
+        series(&jupiter::Q3),
+    ],
+    &[
+        series(&jupiter::P0),
+        series(&jupiter::P1),
+        series(&jupiter::P2),
+    ],
+];
+
+/// Series of the A, L, K, H, Q and P elements of Saturn.
+#[cfg(all(feature = "elements", feature = "saturn"))]
+static SATURN: [&[Series]; 6] = [
+    &[
+        series(&saturn::A0),
+        series(&saturn::A1),
+        series(&saturn::A2),
+        series(&saturn::A3),
+        series(&saturn::A4),
+        series(&saturn::A5),
+    ],
+    &[
+        series(&saturn::L0),
+        series(&saturn::L1),
+        series(&saturn::L2),
+        series(&saturn::L3),
+        series(&saturn::L4),
+        series(&saturn::L5),
+    ],
+    &[
+        series(&saturn::K0),
+        series(&saturn::K1),
+        series(&saturn::K2),
+        series(&saturn::K3),
+        series(&saturn::K4),
+        series(&saturn::K5),
+    ],
+    &[
+        series(&saturn::H0),
+        series(&saturn::H1),
+        series(&saturn::H2),
+        series(&saturn::H3),
+        series(&saturn::H4),
+        series(&saturn::H5),
+    ],
+    &[
+        series(&saturn::Q0),
+        series(&saturn::Q1),
+        series(&saturn::Q2),
+        series(&saturn::Q3),
+        series(&saturn::Q4),
+    ],
+    &[
+        series(&saturn::P0),
+        series(&saturn::P1),
+        series(&saturn::P2),
+        series(&saturn::P3),
+    ],
+];
+
+/// Series of the A, L, K, H, Q and P elements of Uranus.
+#[cfg(all(feature = "elements", feature = "uranus"))]
+static URANUS: [&[Series]; 6] = [
+    &[
+        series(&uranus::A0),
+        series(&uranus::A1),
+        series(&uranus::A2),
+        series(&uranus::A3),
+        series(&uranus::A4),
+        series(&uranus::A5),
+    ],
+    &[
+        series(&uranus::L0),
+        series(&uranus::L1),
+        series(&uranus::L2),
+        series(&uranus::L3),
+        series(&uranus::L4),
+        series(&uranus::L5),
+    ],
+    &[
+        series(&uranus::K0),
+        series(&uranus::K1),
+        series(&uranus::K2),
+        series(&uranus::K3),
+        series(&uranus::K4),
+    ],
+    &[
+        series(&uranus::H0),
+        series(&uranus::H1),
+        series(&uranus::H2),
+        series(&uranus::H3),
+        series(&uranus::H4),
+    ],
+    &[
+        series(&uranus::Q0),
+        series(&uranus::Q1),
+        series(&uranus::Q2),
+        series(&uranus::Q3),
+    ],
+    &[
+        series(&uranus::P0),
+        series(&uranus::P1),
+        series(&uranus::P2),
+    ],
+];
+
+/// Series of the A, L, K, H, Q and P elements of Neptune.
+#[cfg(all(feature = "elements", feature = "neptune"))]
+static NEPTUNE: [&[Series]; 6] = [
+    &[
+        series(&neptune::A0),
+        series(&neptune::A1),
+        series(&neptune::A2),
+        series(&neptune::A3),
+        series(&neptune::A4),
+        series(&neptune::A5),
+    ],
+    &[
+        series(&neptune::L0),
+        series(&neptune::L1),
+        series(&neptune::L2),
+        series(&neptune::L3),
+        series(&neptune::L4),
+        series(&neptune::L5),
+    ],
+    &[
+        series(&neptune::K0),
+        series(&neptune::K1),
+        series(&neptune::K2),
+        series(&neptune::K3),
+        series(&neptune::K4),
+        series(&neptune::K5),
+    ],
+    &[
+        series(&neptune::H0),
+        series(&neptune::H1),
+        series(&neptune::H2),
+        series(&neptune::H3),
+        series(&neptune::H4),
+        series(&neptune::H5),
+    ],
+    &[
+        series(&neptune::Q0),
+        series(&neptune::Q1),
+        series(&neptune::Q2),
+        series(&neptune::Q3),
+    ],
+    &[
+        series(&neptune::P0),
+        series(&neptune::P1),
+        series(&neptune::P2),
+    ],
+];
+
+/// Bodies available in VSOP87, in the order used by [`all_bodies()`].
+///
+/// Only the bodies enabled with their cargo features are included.
+#[cfg(feature = "elements")]
+pub const BODIES: &[Body] = &[
+    #[cfg(all(feature = "elements", feature = "mercury"))]
+    Body::Mercury,
+    #[cfg(all(feature = "elements", feature = "venus"))]
+    Body::Venus,
+    #[cfg(all(feature = "elements", feature = "earth_moon"))]
+    Body::EarthMoon,
+    #[cfg(all(feature = "elements", feature = "mars"))]
+    Body::Mars,
+    #[cfg(all(feature = "elements", feature = "jupiter"))]
+    Body::Jupiter,
+    #[cfg(all(feature = "elements", feature = "saturn"))]
+    Body::Saturn,
+    #[cfg(all(feature = "elements", feature = "uranus"))]
+    Body::Uranus,
+    #[cfg(all(feature = "elements", feature = "neptune"))]
+    Body::Neptune,
+];
+
+/// Series of all the bodies available in VSOP87, in the same order as [`BODIES`].
+#[cfg(feature = "elements")]
+static ALL_BODIES: [&[&[Series]; 6]; BODIES.len()] = [
+    #[cfg(all(feature = "elements", feature = "mercury"))]
+    &MERCURY,
+    #[cfg(all(feature = "elements", feature = "venus"))]
+    &VENUS,
+    #[cfg(all(feature = "elements", feature = "earth_moon"))]
+    &EARTH_MOON,
+    #[cfg(all(feature = "elements", feature = "mars"))]
+    &MARS,
+    #[cfg(all(feature = "elements", feature = "jupiter"))]
+    &JUPITER,
+    #[cfg(all(feature = "elements", feature = "saturn"))]
+    &SATURN,
+    #[cfg(all(feature = "elements", feature = "uranus"))]
+    &URANUS,
+    #[cfg(all(feature = "elements", feature = "neptune"))]
+    &NEPTUNE,
+];
+
 /// Calculates VSOP87 solution for Mercury.
 ///
 /// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
@@ -563,9 +1814,8 @@ impl From<KeplerianElements> for VSOP87Elements {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// orbit of the planet Mercury. In this case, we calculate the orbit of Mercury in December 31st,
-/// 1899.
+/// Given a date in [*JD*](crate::JulianDay), we can get the orbit of the planet Mercury. In this
+/// case, we calculate the orbit of Mercury in December 31st, 1899.
 ///
 /// ```
 /// let vsop87_elts = vsop87::mercury(2415020.0);
@@ -588,67 +1838,92 @@ impl From<KeplerianElements> for VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(all(feature = "elements", feature = "mercury"))]
 #[must_use]
-pub fn mercury(jde: f64) -> VSOP87Elements {
-    let t = calculate_t(jde);
+pub fn mercury(jde: impl Into<JulianDay>) -> VSOP87Elements {
+    elements(calculate_body(jde.into(), &MERCURY))
+}
 
-    let a0 = calculate_var(t, &mercury::A0[0], &mercury::A0[1], &mercury::A0[2]);
-    let a1 = calculate_var(t, &mercury::A1[0], &mercury::A1[1], &mercury::A1[2]);
-    let a2 = calculate_var(t, &mercury::A2[0], &mercury::A2[1], &mercury::A2[2]);
-
-    let l0 = calculate_var(t, &mercury::L0[0], &mercury::L0[1], &mercury::L0[2]);
-    let l1 = calculate_var(t, &mercury::L1[0], &mercury::L1[1], &mercury::L1[2]);
-    let l2 = calculate_var(t, &mercury::L2[0], &mercury::L2[1], &mercury::L2[2]);
-    let l3 = calculate_var(t, &mercury::L3[0], &mercury::L3[1], &mercury::L3[2]);
-
-    let k0 = calculate_var(t, &mercury::K0[0], &mercury::K0[1], &mercury::K0[2]);
-    let k1 = calculate_var(t, &mercury::K1[0], &mercury::K1[1], &mercury::K1[2]);
-    let k2 = calculate_var(t, &mercury::K2[0], &mercury::K2[1], &mercury::K2[2]);
-    let k3 = calculate_var(t, &mercury::K3[0], &mercury::K3[1], &mercury::K3[2]);
-    let k4 = calculate_var(t, &mercury::K4[0], &mercury::K4[1], &mercury::K4[2]);
-    let k5 = calculate_var(t, &mercury::K5[0], &mercury::K5[1], &mercury::K5[2]);
-
-    let h0 = calculate_var(t, &mercury::H0[0], &mercury::H0[1], &mercury::H0[2]);
-    let h1 = calculate_var(t, &mercury::H1[0], &mercury::H1[1], &mercury::H1[2]);
-    let h2 = calculate_var(t, &mercury::H2[0], &mercury::H2[1], &mercury::H2[2]);
-    let h3 = calculate_var(t, &mercury::H3[0], &mercury::H3[1], &mercury::H3[2]);
-    let h4 = calculate_var(t, &mercury::H4[0], &mercury::H4[1], &mercury::H4[2]);
-    let h5 = calculate_var(t, &mercury::H5[0], &mercury::H5[1], &mercury::H5[2]);
-
-    let q0 = calculate_var(t, &mercury::Q0[0], &mercury::Q0[1], &mercury::Q0[2]);
-    let q1 = calculate_var(t, &mercury::Q1[0], &mercury::Q1[1], &mercury::Q1[2]);
-    let q2 = calculate_var(t, &mercury::Q2[0], &mercury::Q2[1], &mercury::Q2[2]);
-    let q3 = calculate_var(t, &mercury::Q3[0], &mercury::Q3[1], &mercury::Q3[2]);
-    let q4 = calculate_var(t, &mercury::Q4[0], &mercury::Q4[1], &mercury::Q4[2]);
-    let q5 = calculate_var(t, &mercury::Q5[0], &mercury::Q5[1], &mercury::Q5[2]);
-
-    let p0 = calculate_var(t, &mercury::P0[0], &mercury::P0[1], &mercury::P0[2]);
-    let p1 = calculate_var(t, &mercury::P1[0], &mercury::P1[1], &mercury::P1[2]);
-    let p2 = calculate_var(t, &mercury::P2[0], &mercury::P2[1], &mercury::P2[2]);
-    let p3 = calculate_var(t, &mercury::P3[0], &mercury::P3[1], &mercury::P3[2]);
-    let p4 = calculate_var(t, &mercury::P4[0], &mercury::P4[1], &mercury::P4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+/// Calculates VSOP87 solution for Mercury, along with the rates of change of the elements.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for the planet Mercury, and the rates of change of the elements, by
+/// differentiating each term of the series. The parameter needed is the Julian Day (*JD*) for the
+/// given date. It returns a `VSOP87ElementsState` structure, with the `VSOP87Elements`, as returned
+/// by [`mercury()`], and their rates per day.
+///
+/// # Example
+///
+/// ```
+/// let state = vsop87::mercury_state(2415020.0);
+/// let vsop87_elts = state.elements;
+///
+/// assert!(vsop87_elts.a > 0.3870977205 && vsop87_elts.a < 0.3870977207);
+/// assert!(vsop87_elts.l > 3.1341564064 && vsop87_elts.l < 3.1341564066);
+/// assert!(vsop87_elts.k > 0.0452159417 && vsop87_elts.k < 0.0452159419);
+/// assert!(vsop87_elts.h > 0.2005915793 && vsop87_elts.h < 0.2005915795);
+/// assert!(vsop87_elts.q > 0.0405500077 && vsop87_elts.q < 0.0405500079);
+/// assert!(vsop87_elts.p > 0.04576328 && vsop87_elts.p < 0.04576404);
+/// ```
+///
+/// The rates can then be converted into keplerian rates, to get the precession of the orbit:
+///
+/// ```
+/// use vsop87::KeplerianRates;
+///
+/// # let state = vsop87::mercury_state(2415020.0);
+/// #
+/// let rates = KeplerianRates::from(state);
+/// let apsidal_precession = rates.apsidal_precession().unwrap();
+/// let nodal_precession = rates.nodal_precession().unwrap();
+/// ```
+#[cfg(all(feature = "elements", feature = "mercury"))]
+#[must_use]
+pub fn mercury_state(jde: impl Into<JulianDay>) -> VSOP87ElementsState {
+    calculate_elements_state(jde.into(), &MERCURY)
+}
 
-    let a = a0 + a1 * t + a2 * t2;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4 + k5 * t5;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4 + h5 * t5;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3 + q4 * t4 + q5 * t5;
-    let p = p0 + p1 * t + p2 * t2 + p3 * t3 + p4 * t4;
+/// Calculates VSOP87 solution for Mercury, checking the Julian Day.
+///
+/// This function is equivalent to [`mercury()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Mercury (see [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Body, Error};
+///
+/// assert_eq!(vsop87::mercury_checked(2451545.0)?, vsop87::mercury(2451545.0));
+/// assert_eq!(
+///     vsop87::mercury_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Mercury })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "mercury"))]
+pub fn mercury_checked(jde: impl Into<JulianDay>) -> Result<VSOP87Elements, Error> {
+    checked(Body::Mercury, jde)
+}
 
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+/// Calculates VSOP87 solution for Mercury, along with the rates of change of the elements, checking
+/// the Julian Day.
+///
+/// This function is equivalent to [`mercury_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Mercury (see
+/// [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Error};
+///
+/// assert_eq!(vsop87::mercury_state_checked(2451545.0)?, vsop87::mercury_state(2451545.0));
+/// assert_eq!(vsop87::mercury_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "mercury"))]
+pub fn mercury_state_checked(jde: impl Into<JulianDay>) -> Result<VSOP87ElementsState, Error> {
+    checked_state(Body::Mercury, jde)
 }
 
 /// Calculates VSOP87 solution for Venus.
@@ -659,8 +1934,8 @@ pub fn mercury(jde: f64) -> VSOP87Elements {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// orbit of the planet Venus. In this case, we calculate the orbit of Venus in January 1st, 2000.
+/// Given a date in [*JD*](crate::JulianDay), we can get the orbit of the planet Venus. In this
+/// case, we calculate the orbit of Venus in January 1st, 2000.
 ///
 /// ```
 /// let vsop87_elts = vsop87::venus(2451545.0);
@@ -683,67 +1958,92 @@ pub fn mercury(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(all(feature = "elements", feature = "venus"))]
 #[must_use]
-pub fn venus(jde: f64) -> VSOP87Elements {
-    let t = calculate_t(jde);
+pub fn venus(jde: impl Into<JulianDay>) -> VSOP87Elements {
+    elements(calculate_body(jde.into(), &VENUS))
+}
 
-    let a0 = calculate_var(t, &venus::A0[0], &venus::A0[1], &venus::A0[2]);
-    let a1 = calculate_var(t, &venus::A1[0], &venus::A1[1], &venus::A1[2]);
-    let a2 = calculate_var(t, &venus::A2[0], &venus::A2[1], &venus::A2[2]);
-
-    let l0 = calculate_var(t, &venus::L0[0], &venus::L0[1], &venus::L0[2]);
-    let l1 = calculate_var(t, &venus::L1[0], &venus::L1[1], &venus::L1[2]);
-    let l2 = calculate_var(t, &venus::L2[0], &venus::L2[1], &venus::L2[2]);
-    let l3 = calculate_var(t, &venus::L3[0], &venus::L3[1], &venus::L3[2]);
-
-    let k0 = calculate_var(t, &venus::K0[0], &venus::K0[1], &venus::K0[2]);
-    let k1 = calculate_var(t, &venus::K1[0], &venus::K1[1], &venus::K1[2]);
-    let k2 = calculate_var(t, &venus::K2[0], &venus::K2[1], &venus::K2[2]);
-    let k3 = calculate_var(t, &venus::K3[0], &venus::K3[1], &venus::K3[2]);
-    let k4 = calculate_var(t, &venus::K4[0], &venus::K4[1], &venus::K4[2]);
-    let k5 = calculate_var(t, &venus::K5[0], &venus::K5[1], &venus::K5[2]);
-
-    let h0 = calculate_var(t, &venus::H0[0], &venus::H0[1], &venus::H0[2]);
-    let h1 = calculate_var(t, &venus::H1[0], &venus::H1[1], &venus::H1[2]);
-    let h2 = calculate_var(t, &venus::H2[0], &venus::H2[1], &venus::H2[2]);
-    let h3 = calculate_var(t, &venus::H3[0], &venus::H3[1], &venus::H3[2]);
-    let h4 = calculate_var(t, &venus::H4[0], &venus::H4[1], &venus::H4[2]);
-    let h5 = calculate_var(t, &venus::H5[0], &venus::H5[1], &venus::H5[2]);
-
-    let q0 = calculate_var(t, &venus::Q0[0], &venus::Q0[1], &venus::Q0[2]);
-    let q1 = calculate_var(t, &venus::Q1[0], &venus::Q1[1], &venus::Q1[2]);
-    let q2 = calculate_var(t, &venus::Q2[0], &venus::Q2[1], &venus::Q2[2]);
-    let q3 = calculate_var(t, &venus::Q3[0], &venus::Q3[1], &venus::Q3[2]);
-    let q4 = calculate_var(t, &venus::Q4[0], &venus::Q4[1], &venus::Q4[2]);
-    let q5 = calculate_var(t, &venus::Q5[0], &venus::Q5[1], &venus::Q5[2]);
-
-    let p0 = calculate_var(t, &venus::P0[0], &venus::P0[1], &venus::P0[2]);
-    let p1 = calculate_var(t, &venus::P1[0], &venus::P1[1], &venus::P1[2]);
-    let p2 = calculate_var(t, &venus::P2[0], &venus::P2[1], &venus::P2[2]);
-    let p3 = calculate_var(t, &venus::P3[0], &venus::P3[1], &venus::P3[2]);
-    let p4 = calculate_var(t, &venus::P4[0], &venus::P4[1], &venus::P4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+/// Calculates VSOP87 solution for Venus, along with the rates of change of the elements.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for the planet Venus, and the rates of change of the elements, by
+/// differentiating each term of the series. The parameter needed is the Julian Day (*JD*) for the
+/// given date. It returns a `VSOP87ElementsState` structure, with the `VSOP87Elements`, as returned
+/// by [`venus()`], and their rates per day.
+///
+/// # Example
+///
+/// ```
+/// let state = vsop87::venus_state(2451545.0);
+/// let vsop87_elts = state.elements;
+///
+/// assert!(vsop87_elts.a > 0.7233269303 && vsop87_elts.a < 0.7233269305);
+/// assert!(vsop87_elts.l > 3.1761350909 && vsop87_elts.l < 3.1761350911);
+/// assert!(vsop87_elts.k > -0.0045086078 && vsop87_elts.k < -0.0045086076);
+/// assert!(vsop87_elts.h > 0.0050312181 && vsop87_elts.h < 0.0050312183);
+/// assert!(vsop87_elts.q > 0.0068248057 && vsop87_elts.q < 0.0068248059);
+/// assert!(vsop87_elts.p > 0.02882177 && vsop87_elts.p < 0.02882253);
+/// ```
+///
+/// The rates can then be converted into keplerian rates, to get the precession of the orbit:
+///
+/// ```
+/// use vsop87::KeplerianRates;
+///
+/// # let state = vsop87::venus_state(2451545.0);
+/// #
+/// let rates = KeplerianRates::from(state);
+/// let apsidal_precession = rates.apsidal_precession().unwrap();
+/// let nodal_precession = rates.nodal_precession().unwrap();
+/// ```
+#[cfg(all(feature = "elements", feature = "venus"))]
+#[must_use]
+pub fn venus_state(jde: impl Into<JulianDay>) -> VSOP87ElementsState {
+    calculate_elements_state(jde.into(), &VENUS)
+}
 
-    let a = a0 + a1 * t + a2 * t2;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4 + k5 * t5;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4 + h5 * t5;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3 + q4 * t4 + q5 * t5;
-    let p = p0 + p1 * t + p2 * t2 + p3 * t3 + p4 * t4;
+/// Calculates VSOP87 solution for Venus, checking the Julian Day.
+///
+/// This function is equivalent to [`venus()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Venus (see [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Body, Error};
+///
+/// assert_eq!(vsop87::venus_checked(2451545.0)?, vsop87::venus(2451545.0));
+/// assert_eq!(
+///     vsop87::venus_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Venus })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "venus"))]
+pub fn venus_checked(jde: impl Into<JulianDay>) -> Result<VSOP87Elements, Error> {
+    checked(Body::Venus, jde)
+}
 
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+/// Calculates VSOP87 solution for Venus, along with the rates of change of the elements, checking
+/// the Julian Day.
+///
+/// This function is equivalent to [`venus_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Venus (see
+/// [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Error};
+///
+/// assert_eq!(vsop87::venus_state_checked(2451545.0)?, vsop87::venus_state(2451545.0));
+/// assert_eq!(vsop87::venus_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "venus"))]
+pub fn venus_state_checked(jde: impl Into<JulianDay>) -> Result<VSOP87ElementsState, Error> {
+    checked_state(Body::Venus, jde)
 }
 
 /// Calculates VSOP87 solution for Earth - Moon barycenter.
@@ -754,10 +2054,9 @@ pub fn venus(jde: f64) -> VSOP87Elements {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// orbit of the Earth - Moon barycenter (the center of masses between the Earth and the Moon, not
-/// exactly the center of the Earth). In this case, we calculate the orbit of the Earth - Moon
-/// barycenter in December 19th, 1099.
+/// Given a date in [*JD*](crate::JulianDay), we can get the orbit of the Earth - Moon barycenter
+/// (the center of masses between the Earth and the Moon, not exactly the center of the Earth). In
+/// this case, we calculate the orbit of the Earth - Moon barycenter in December 19th, 1099.
 /// ```
 /// let vsop87_elts = vsop87::earth_moon(2122820.0);
 ///
@@ -779,230 +2078,94 @@ pub fn venus(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
-#[allow(clippy::too_many_lines)]
+#[cfg(all(feature = "elements", feature = "earth_moon"))]
 #[must_use]
-pub fn earth_moon(jde: f64) -> VSOP87Elements {
-    let t = calculate_t(jde);
-
-    let a0 = calculate_var(
-        t,
-        &earth_moon::A0[0],
-        &earth_moon::A0[1],
-        &earth_moon::A0[2],
-    );
-    let a1 = calculate_var(
-        t,
-        &earth_moon::A1[0],
-        &earth_moon::A1[1],
-        &earth_moon::A1[2],
-    );
-    let a2 = calculate_var(
-        t,
-        &earth_moon::A2[0],
-        &earth_moon::A2[1],
-        &earth_moon::A2[2],
-    );
-
-    let l0 = calculate_var(
-        t,
-        &earth_moon::L0[0],
-        &earth_moon::L0[1],
-        &earth_moon::L0[2],
-    );
-    let l1 = calculate_var(
-        t,
-        &earth_moon::L1[0],
-        &earth_moon::L1[1],
-        &earth_moon::L1[2],
-    );
-    let l2 = calculate_var(
-        t,
-        &earth_moon::L2[0],
-        &earth_moon::L2[1],
-        &earth_moon::L2[2],
-    );
-    let l3 = calculate_var(
-        t,
-        &earth_moon::L3[0],
-        &earth_moon::L3[1],
-        &earth_moon::L3[2],
-    );
-    let l4 = calculate_var(
-        t,
-        &earth_moon::L4[0],
-        &earth_moon::L4[1],
-        &earth_moon::L4[2],
-    );
-    let l5 = calculate_var(
-        t,
-        &earth_moon::L5[0],
-        &earth_moon::L5[1],
-        &earth_moon::L5[2],
-    );
-
-    let k0 = calculate_var(
-        t,
-        &earth_moon::K0[0],
-        &earth_moon::K0[1],
-        &earth_moon::K0[2],
-    );
-    let k1 = calculate_var(
-        t,
-        &earth_moon::K1[0],
-        &earth_moon::K1[1],
-        &earth_moon::K1[2],
-    );
-    let k2 = calculate_var(
-        t,
-        &earth_moon::K2[0],
-        &earth_moon::K2[1],
-        &earth_moon::K2[2],
-    );
-    let k3 = calculate_var(
-        t,
-        &earth_moon::K3[0],
-        &earth_moon::K3[1],
-        &earth_moon::K3[2],
-    );
-    let k4 = calculate_var(
-        t,
-        &earth_moon::K4[0],
-        &earth_moon::K4[1],
-        &earth_moon::K4[2],
-    );
-    let k5 = calculate_var(
-        t,
-        &earth_moon::K5[0],
-        &earth_moon::K5[1],
-        &earth_moon::K5[2],
-    );
-
-    let h0 = calculate_var(
-        t,
-        &earth_moon::H0[0],
-        &earth_moon::H0[1],
-        &earth_moon::H0[2],
-    );
-    let h1 = calculate_var(
-        t,
-        &earth_moon::H1[0],
-        &earth_moon::H1[1],
-        &earth_moon::H1[2],
-    );
-    let h2 = calculate_var(
-        t,
-        &earth_moon::H2[0],
-        &earth_moon::H2[1],
-        &earth_moon::H2[2],
-    );
-    let h3 = calculate_var(
-        t,
-        &earth_moon::H3[0],
-        &earth_moon::H3[1],
-        &earth_moon::H3[2],
-    );
-    let h4 = calculate_var(
-        t,
-        &earth_moon::H4[0],
-        &earth_moon::H4[1],
-        &earth_moon::H4[2],
-    );
-    let h5 = calculate_var(
-        t,
-        &earth_moon::H5[0],
-        &earth_moon::H5[1],
-        &earth_moon::H5[2],
-    );
-
-    let q0 = calculate_var(
-        t,
-        &earth_moon::Q0[0],
-        &earth_moon::Q0[1],
-        &earth_moon::Q0[2],
-    );
-    let q1 = calculate_var(
-        t,
-        &earth_moon::Q1[0],
-        &earth_moon::Q1[1],
-        &earth_moon::Q1[2],
-    );
-    let q2 = calculate_var(
-        t,
-        &earth_moon::Q2[0],
-        &earth_moon::Q2[1],
-        &earth_moon::Q2[2],
-    );
-    let q3 = calculate_var(
-        t,
-        &earth_moon::Q3[0],
-        &earth_moon::Q3[1],
-        &earth_moon::Q3[2],
-    );
-    let q4 = calculate_var(
-        t,
-        &earth_moon::Q4[0],
-        &earth_moon::Q4[1],
-        &earth_moon::Q4[2],
-    );
-    let q5 = calculate_var(
-        t,
-        &earth_moon::Q5[0],
-        &earth_moon::Q5[1],
-        &earth_moon::Q5[2],
-    );
-
-    let p0 = calculate_var(
-        t,
-        &earth_moon::P0[0],
-        &earth_moon::P0[1],
-        &earth_moon::P0[2],
-    );
-    let p1 = calculate_var(
-        t,
-        &earth_moon::P1[0],
-        &earth_moon::P1[1],
-        &earth_moon::P1[2],
-    );
-    let p2 = calculate_var(
-        t,
-        &earth_moon::P2[0],
-        &earth_moon::P2[1],
-        &earth_moon::P2[2],
-    );
-    let p3 = calculate_var(
-        t,
-        &earth_moon::P3[0],
-        &earth_moon::P3[1],
-        &earth_moon::P3[2],
-    );
-    let p4 = calculate_var(
-        t,
-        &earth_moon::P4[0],
-        &earth_moon::P4[1],
-        &earth_moon::P4[2],
-    );
+pub fn earth_moon(jde: impl Into<JulianDay>) -> VSOP87Elements {
+    elements(calculate_body(jde.into(), &EARTH_MOON))
+}
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+/// Calculates VSOP87 solution for Earth - Moon barycenter, along with the rates of change of the
+/// elements.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for the Earth - Moon barycenter, and the rates of change of the elements, by
+/// differentiating each term of the series. The parameter needed is the Julian Day (*JD*) for the
+/// given date. It returns a `VSOP87ElementsState` structure, with the `VSOP87Elements`, as returned
+/// by [`earth_moon()`], and their rates per day.
+///
+/// # Example
+///
+/// ```
+/// let state = vsop87::earth_moon_state(2122820.0);
+/// let vsop87_elts = state.elements;
+///
+/// assert!(vsop87_elts.a > 1.0000134925 && vsop87_elts.a < 1.0000134927);
+/// assert!(vsop87_elts.l > 1.8519621672 && vsop87_elts.l < 1.8519621674);
+/// assert!(vsop87_elts.k > -0.0029638176 && vsop87_elts.k < -0.0029638174);
+/// assert!(vsop87_elts.h > 0.0168402193 && vsop87_elts.h < 0.0168402195);
+/// assert!(vsop87_elts.q > 0.0010301900 && vsop87_elts.q < 0.0010301902);
+/// assert!(vsop87_elts.p > -0.00005346 && vsop87_elts.p < -0.00005270);
+/// ```
+///
+/// The rates can then be converted into keplerian rates, to get the precession of the orbit:
+///
+/// ```
+/// use vsop87::KeplerianRates;
+///
+/// # let state = vsop87::earth_moon_state(2122820.0);
+/// #
+/// let rates = KeplerianRates::from(state);
+/// let apsidal_precession = rates.apsidal_precession().unwrap();
+/// let nodal_precession = rates.nodal_precession().unwrap();
+/// ```
+#[cfg(all(feature = "elements", feature = "earth_moon"))]
+#[must_use]
+pub fn earth_moon_state(jde: impl Into<JulianDay>) -> VSOP87ElementsState {
+    calculate_elements_state(jde.into(), &EARTH_MOON)
+}
 
-    let a = a0 + a1 * t + a2 * t2;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4 + k5 * t5;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4 + h5 * t5;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3 + q4 * t4 + q5 * t5;
-    let p = p0 + p1 * t + p2 * t2 + p3 * t3 + p4 * t4;
+/// Calculates VSOP87 solution for Earth - Moon barycenter, checking the Julian Day.
+///
+/// This function is equivalent to [`earth_moon()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Earth - Moon barycenter
+/// (see [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Body, Error};
+///
+/// assert_eq!(vsop87::earth_moon_checked(2451545.0)?, vsop87::earth_moon(2451545.0));
+/// assert_eq!(
+///     vsop87::earth_moon_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::EarthMoon })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "earth_moon"))]
+pub fn earth_moon_checked(jde: impl Into<JulianDay>) -> Result<VSOP87Elements, Error> {
+    checked(Body::EarthMoon, jde)
+}
 
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+/// Calculates VSOP87 solution for Earth - Moon barycenter, along with the rates of change of the
+/// elements, checking the Julian Day.
+///
+/// This function is equivalent to [`earth_moon_state()`], but it returns an error if the Julian Day
+/// is not finite, or if it is outside of the validity span of the solution for Earth - Moon
+/// barycenter (see [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Error};
+///
+/// assert_eq!(vsop87::earth_moon_state_checked(2451545.0)?, vsop87::earth_moon_state(2451545.0));
+/// assert_eq!(vsop87::earth_moon_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "earth_moon"))]
+pub fn earth_moon_state_checked(jde: impl Into<JulianDay>) -> Result<VSOP87ElementsState, Error> {
+    checked_state(Body::EarthMoon, jde)
 }
 
 /// Calculates VSOP87 solution for Mars.
@@ -1013,8 +2176,8 @@ pub fn earth_moon(jde: f64) -> VSOP87Elements {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// orbit of the planet Mars. In this case, we calculate the orbit of Mars in December 19th, 1199.
+/// Given a date in [*JD*](crate::JulianDay), we can get the orbit of the planet Mars. In this case,
+/// we calculate the orbit of Mars in December 19th, 1199.
 ///
 /// ```
 /// let vsop87_elts = vsop87::mars(2159345.0);
@@ -1037,87 +2200,110 @@ pub fn earth_moon(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(all(feature = "elements", feature = "mars"))]
 #[must_use]
-pub fn mars(jde: f64) -> VSOP87Elements {
-    let t = calculate_t(jde);
-
-    let a0 = calculate_var(t, &mars::A0[0], &mars::A0[1], &mars::A0[2]);
-    let a1 = calculate_var(t, &mars::A1[0], &mars::A1[1], &mars::A1[2]);
-    let a2 = calculate_var(t, &mars::A2[0], &mars::A2[1], &mars::A2[2]);
-
-    let l0 = calculate_var(t, &mars::L0[0], &mars::L0[1], &mars::L0[2]);
-    let l1 = calculate_var(t, &mars::L1[0], &mars::L1[1], &mars::L1[2]);
-    let l2 = calculate_var(t, &mars::L2[0], &mars::L2[1], &mars::L2[2]);
-    let l3 = calculate_var(t, &mars::L3[0], &mars::L3[1], &mars::L3[2]);
-    let l4 = calculate_var(t, &mars::L4[0], &mars::L4[1], &mars::L4[2]);
-    let l5 = calculate_var(t, &mars::L5[0], &mars::L5[1], &mars::L5[2]);
-
-    let k0 = calculate_var(t, &mars::K0[0], &mars::K0[1], &mars::K0[2]);
-    let k1 = calculate_var(t, &mars::K1[0], &mars::K1[1], &mars::K1[2]);
-    let k2 = calculate_var(t, &mars::K2[0], &mars::K2[1], &mars::K2[2]);
-    let k3 = calculate_var(t, &mars::K3[0], &mars::K3[1], &mars::K3[2]);
-    let k4 = calculate_var(t, &mars::K4[0], &mars::K4[1], &mars::K4[2]);
-    let k5 = calculate_var(t, &mars::K5[0], &mars::K5[1], &mars::K5[2]);
-
-    let h0 = calculate_var(t, &mars::H0[0], &mars::H0[1], &mars::H0[2]);
-    let h1 = calculate_var(t, &mars::H1[0], &mars::H1[1], &mars::H1[2]);
-    let h2 = calculate_var(t, &mars::H2[0], &mars::H2[1], &mars::H2[2]);
-    let h3 = calculate_var(t, &mars::H3[0], &mars::H3[1], &mars::H3[2]);
-    let h4 = calculate_var(t, &mars::H4[0], &mars::H4[1], &mars::H4[2]);
-    let h5 = calculate_var(t, &mars::H5[0], &mars::H5[1], &mars::H5[2]);
-
-    let q0 = calculate_var(t, &mars::Q0[0], &mars::Q0[1], &mars::Q0[2]);
-    let q1 = calculate_var(t, &mars::Q1[0], &mars::Q1[1], &mars::Q1[2]);
-    let q2 = calculate_var(t, &mars::Q2[0], &mars::Q2[1], &mars::Q2[2]);
-    let q3 = calculate_var(t, &mars::Q3[0], &mars::Q3[1], &mars::Q3[2]);
-    let q4 = calculate_var(t, &mars::Q4[0], &mars::Q4[1], &mars::Q4[2]);
-    let q5 = calculate_var(t, &mars::Q5[0], &mars::Q5[1], &mars::Q5[2]);
-
-    let p0 = calculate_var(t, &mars::P0[0], &mars::P0[1], &mars::P0[2]);
-    let p1 = calculate_var(t, &mars::P1[0], &mars::P1[1], &mars::P1[2]);
-    let p2 = calculate_var(t, &mars::P2[0], &mars::P2[1], &mars::P2[2]);
-    let p3 = calculate_var(t, &mars::P3[0], &mars::P3[1], &mars::P3[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let a = a0 + a1 * t + a2 * t2;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4 + k5 * t5;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4 + h5 * t5;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3 + q4 * t4 + q5 * t5;
-    let p = p0 + p1 * t + p2 * t2 + p3 * t3;
-
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+pub fn mars(jde: impl Into<JulianDay>) -> VSOP87Elements {
+    elements(calculate_body(jde.into(), &MARS))
 }
 
-/// Calculates VSOP87 solution for Jupiter.
+/// Calculates VSOP87 solution for Mars, along with the rates of change of the elements.
 ///
 /// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
-/// equinox J2000.0) for the planet Jupiter. The parameter needed is the Julian Day (*JD*) for the
-/// given date. It returns the `VSOP87Elements` of the VSOP87 solution.
+/// equinox J2000.0) for the planet Mars, and the rates of change of the elements, by
+/// differentiating each term of the series. The parameter needed is the Julian Day (*JD*) for the
+/// given date. It returns a `VSOP87ElementsState` structure, with the `VSOP87Elements`, as returned
+/// by [`mars()`], and their rates per day.
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// orbit of the planet Jupiter. In this case, we calculate the orbit of Jupiter in December 30th,
-/// 1799.
-///
 /// ```
-/// let vsop87_elts = vsop87::jupiter(2378495.0);
+/// let state = vsop87::mars_state(2159345.0);
+/// let vsop87_elts = state.elements;
 ///
-/// assert!(vsop87_elts.a > 5.2027276672 && vsop87_elts.a < 5.2027276674);
-/// assert!(vsop87_elts.l > 1.4820596291 && vsop87_elts.l < 1.4820596293);
+/// assert!(vsop87_elts.a > 1.5237578877 && vsop87_elts.a < 1.5237578879);
+/// assert!(vsop87_elts.l > 4.0669853278 && vsop87_elts.l < 4.0669853280);
+/// assert!(vsop87_elts.k > 0.0821906316 && vsop87_elts.k < 0.0821906318);
+/// assert!(vsop87_elts.h > -0.0427917583 && vsop87_elts.h < -0.0427917581);
+/// assert!(vsop87_elts.q > 0.0103081045 && vsop87_elts.q < 0.0103081047);
+/// assert!(vsop87_elts.p > 0.01313608 && vsop87_elts.p < 0.01313684);
+/// ```
+///
+/// The rates can then be converted into keplerian rates, to get the precession of the orbit:
+///
+/// ```
+/// use vsop87::KeplerianRates;
+///
+/// # let state = vsop87::mars_state(2159345.0);
+/// #
+/// let rates = KeplerianRates::from(state);
+/// let apsidal_precession = rates.apsidal_precession().unwrap();
+/// let nodal_precession = rates.nodal_precession().unwrap();
+/// ```
+#[cfg(all(feature = "elements", feature = "mars"))]
+#[must_use]
+pub fn mars_state(jde: impl Into<JulianDay>) -> VSOP87ElementsState {
+    calculate_elements_state(jde.into(), &MARS)
+}
+
+/// Calculates VSOP87 solution for Mars, checking the Julian Day.
+///
+/// This function is equivalent to [`mars()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Mars (see [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Body, Error};
+///
+/// assert_eq!(vsop87::mars_checked(2451545.0)?, vsop87::mars(2451545.0));
+/// assert_eq!(
+///     vsop87::mars_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Mars })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "mars"))]
+pub fn mars_checked(jde: impl Into<JulianDay>) -> Result<VSOP87Elements, Error> {
+    checked(Body::Mars, jde)
+}
+
+/// Calculates VSOP87 solution for Mars, along with the rates of change of the elements, checking
+/// the Julian Day.
+///
+/// This function is equivalent to [`mars_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Mars (see
+/// [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Error};
+///
+/// assert_eq!(vsop87::mars_state_checked(2451545.0)?, vsop87::mars_state(2451545.0));
+/// assert_eq!(vsop87::mars_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "mars"))]
+pub fn mars_state_checked(jde: impl Into<JulianDay>) -> Result<VSOP87ElementsState, Error> {
+    checked_state(Body::Mars, jde)
+}
+
+/// Calculates VSOP87 solution for Jupiter.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for the planet Jupiter. The parameter needed is the Julian Day (*JD*) for the
+/// given date. It returns the `VSOP87Elements` of the VSOP87 solution.
+///
+/// # Example
+///
+/// Given a date in [*JD*](crate::JulianDay), we can get the orbit of the planet Jupiter. In this
+/// case, we calculate the orbit of Jupiter in December 30th, 1799.
+///
+/// ```
+/// let vsop87_elts = vsop87::jupiter(2378495.0);
+///
+/// assert!(vsop87_elts.a > 5.2027276672 && vsop87_elts.a < 5.2027276674);
+/// assert!(vsop87_elts.l > 1.4820596291 && vsop87_elts.l < 1.4820596293);
 /// assert!(vsop87_elts.k > 0.0464780412 && vsop87_elts.k < 0.0464780414);
 /// assert!(vsop87_elts.h > 0.0116460263 && vsop87_elts.h < 0.0116460265);
 /// assert!(vsop87_elts.q > -0.0019921307 && vsop87_elts.q < -0.0019921305);
@@ -1134,66 +2320,92 @@ pub fn mars(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(all(feature = "elements", feature = "jupiter"))]
 #[must_use]
-pub fn jupiter(jde: f64) -> VSOP87Elements {
-    let t = calculate_t(jde);
+pub fn jupiter(jde: impl Into<JulianDay>) -> VSOP87Elements {
+    elements(calculate_body(jde.into(), &JUPITER))
+}
 
-    let a0 = calculate_var(t, &jupiter::A0[0], &jupiter::A0[1], &jupiter::A0[2]);
-    let a1 = calculate_var(t, &jupiter::A1[0], &jupiter::A1[1], &jupiter::A1[2]);
-    let a2 = calculate_var(t, &jupiter::A2[0], &jupiter::A2[1], &jupiter::A2[2]);
-    let a3 = calculate_var(t, &jupiter::A3[0], &jupiter::A3[1], &jupiter::A3[2]);
-    let a4 = calculate_var(t, &jupiter::A4[0], &jupiter::A4[1], &jupiter::A4[2]);
-    let a5 = calculate_var(t, &jupiter::A5[0], &jupiter::A5[1], &jupiter::A5[2]);
-
-    let l0 = calculate_var(t, &jupiter::L0[0], &jupiter::L0[1], &jupiter::L0[2]);
-    let l1 = calculate_var(t, &jupiter::L1[0], &jupiter::L1[1], &jupiter::L1[2]);
-    let l2 = calculate_var(t, &jupiter::L2[0], &jupiter::L2[1], &jupiter::L2[2]);
-    let l3 = calculate_var(t, &jupiter::L3[0], &jupiter::L3[1], &jupiter::L3[2]);
-    let l4 = calculate_var(t, &jupiter::L4[0], &jupiter::L4[1], &jupiter::L4[2]);
-    let l5 = calculate_var(t, &jupiter::L5[0], &jupiter::L5[1], &jupiter::L5[2]);
-
-    let k0 = calculate_var(t, &jupiter::K0[0], &jupiter::K0[1], &jupiter::K0[2]);
-    let k1 = calculate_var(t, &jupiter::K1[0], &jupiter::K1[1], &jupiter::K1[2]);
-    let k2 = calculate_var(t, &jupiter::K2[0], &jupiter::K2[1], &jupiter::K2[2]);
-    let k3 = calculate_var(t, &jupiter::K3[0], &jupiter::K3[1], &jupiter::K3[2]);
-    let k4 = calculate_var(t, &jupiter::K4[0], &jupiter::K4[1], &jupiter::K4[2]);
-
-    let h0 = calculate_var(t, &jupiter::H0[0], &jupiter::H0[1], &jupiter::H0[2]);
-    let h1 = calculate_var(t, &jupiter::H1[0], &jupiter::H1[1], &jupiter::H1[2]);
-    let h2 = calculate_var(t, &jupiter::H2[0], &jupiter::H2[1], &jupiter::H2[2]);
-    let h3 = calculate_var(t, &jupiter::H3[0], &jupiter::H3[1], &jupiter::H3[2]);
-    let h4 = calculate_var(t, &jupiter::H4[0], &jupiter::H4[1], &jupiter::H4[2]);
-
-    let q0 = calculate_var(t, &jupiter::Q0[0], &jupiter::Q0[1], &jupiter::Q0[2]);
-    let q1 = calculate_var(t, &jupiter::Q1[0], &jupiter::Q1[1], &jupiter::Q1[2]);
-    let q2 = calculate_var(t, &jupiter::Q2[0], &jupiter::Q2[1], &jupiter::Q2[2]);
-    let q3 = calculate_var(t, &jupiter::Q3[0], &jupiter::Q3[1], &jupiter::Q3[2]);
-
-    let p0 = calculate_var(t, &jupiter::P0[0], &jupiter::P0[1], &jupiter::P0[2]);
-    let p1 = calculate_var(t, &jupiter::P1[0], &jupiter::P1[1], &jupiter::P1[2]);
-    let p2 = calculate_var(t, &jupiter::P2[0], &jupiter::P2[1], &jupiter::P2[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+/// Calculates VSOP87 solution for Jupiter, along with the rates of change of the elements.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for the planet Jupiter, and the rates of change of the elements, by
+/// differentiating each term of the series. The parameter needed is the Julian Day (*JD*) for the
+/// given date. It returns a `VSOP87ElementsState` structure, with the `VSOP87Elements`, as returned
+/// by [`jupiter()`], and their rates per day.
+///
+/// # Example
+///
+/// ```
+/// let state = vsop87::jupiter_state(2378495.0);
+/// let vsop87_elts = state.elements;
+///
+/// assert!(vsop87_elts.a > 5.2027276672 && vsop87_elts.a < 5.2027276674);
+/// assert!(vsop87_elts.l > 1.4820596291 && vsop87_elts.l < 1.4820596293);
+/// assert!(vsop87_elts.k > 0.0464780412 && vsop87_elts.k < 0.0464780414);
+/// assert!(vsop87_elts.h > 0.0116460263 && vsop87_elts.h < 0.0116460265);
+/// assert!(vsop87_elts.q > -0.0019921307 && vsop87_elts.q < -0.0019921305);
+/// assert!(vsop87_elts.p > 0.01123447 && vsop87_elts.p < 0.01123523);
+/// ```
+///
+/// The rates can then be converted into keplerian rates, to get the precession of the orbit:
+///
+/// ```
+/// use vsop87::KeplerianRates;
+///
+/// # let state = vsop87::jupiter_state(2378495.0);
+/// #
+/// let rates = KeplerianRates::from(state);
+/// let apsidal_precession = rates.apsidal_precession().unwrap();
+/// let nodal_precession = rates.nodal_precession().unwrap();
+/// ```
+#[cfg(all(feature = "elements", feature = "jupiter"))]
+#[must_use]
+pub fn jupiter_state(jde: impl Into<JulianDay>) -> VSOP87ElementsState {
+    calculate_elements_state(jde.into(), &JUPITER)
+}
 
-    let a = a0 + a1 * t + a2 * t2 + a3 * t3 + a4 * t4 + a5 * t5;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3;
-    let p = p0 + p1 * t + p2 * t2;
+/// Calculates VSOP87 solution for Jupiter, checking the Julian Day.
+///
+/// This function is equivalent to [`jupiter()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Jupiter (see [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Body, Error};
+///
+/// assert_eq!(vsop87::jupiter_checked(2451545.0)?, vsop87::jupiter(2451545.0));
+/// assert_eq!(
+///     vsop87::jupiter_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "jupiter"))]
+pub fn jupiter_checked(jde: impl Into<JulianDay>) -> Result<VSOP87Elements, Error> {
+    checked(Body::Jupiter, jde)
+}
 
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+/// Calculates VSOP87 solution for Jupiter, along with the rates of change of the elements, checking
+/// the Julian Day.
+///
+/// This function is equivalent to [`jupiter_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Jupiter (see
+/// [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Error};
+///
+/// assert_eq!(vsop87::jupiter_state_checked(2451545.0)?, vsop87::jupiter_state(2451545.0));
+/// assert_eq!(vsop87::jupiter_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "jupiter"))]
+pub fn jupiter_state_checked(jde: impl Into<JulianDay>) -> Result<VSOP87ElementsState, Error> {
+    checked_state(Body::Jupiter, jde)
 }
 
 /// Calculates VSOP87 solution for Saturn.
@@ -1204,9 +2416,8 @@ pub fn jupiter(jde: f64) -> VSOP87Elements {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// orbit of the planet Saturn. In this case, we calculate the orbit of Saturn in December 29th,
-/// 1599.
+/// Given a date in [*JD*](crate::JulianDay), we can get the orbit of the planet Saturn. In this
+/// case, we calculate the orbit of Saturn in December 29th, 1599.
 ///
 /// ```
 /// let vsop87_elts = vsop87::saturn(2305445.0);
@@ -1229,70 +2440,92 @@ pub fn jupiter(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(all(feature = "elements", feature = "saturn"))]
 #[must_use]
-pub fn saturn(jde: f64) -> VSOP87Elements {
-    let t = calculate_t(jde);
+pub fn saturn(jde: impl Into<JulianDay>) -> VSOP87Elements {
+    elements(calculate_body(jde.into(), &SATURN))
+}
 
-    let a0 = calculate_var(t, &saturn::A0[0], &saturn::A0[1], &saturn::A0[2]);
-    let a1 = calculate_var(t, &saturn::A1[0], &saturn::A1[1], &saturn::A1[2]);
-    let a2 = calculate_var(t, &saturn::A2[0], &saturn::A2[1], &saturn::A2[2]);
-    let a3 = calculate_var(t, &saturn::A3[0], &saturn::A3[1], &saturn::A3[2]);
-    let a4 = calculate_var(t, &saturn::A4[0], &saturn::A4[1], &saturn::A4[2]);
-    let a5 = calculate_var(t, &saturn::A5[0], &saturn::A5[1], &saturn::A5[2]);
-
-    let l0 = calculate_var(t, &saturn::L0[0], &saturn::L0[1], &saturn::L0[2]);
-    let l1 = calculate_var(t, &saturn::L1[0], &saturn::L1[1], &saturn::L1[2]);
-    let l2 = calculate_var(t, &saturn::L2[0], &saturn::L2[1], &saturn::L2[2]);
-    let l3 = calculate_var(t, &saturn::L3[0], &saturn::L3[1], &saturn::L3[2]);
-    let l4 = calculate_var(t, &saturn::L4[0], &saturn::L4[1], &saturn::L4[2]);
-    let l5 = calculate_var(t, &saturn::L5[0], &saturn::L5[1], &saturn::L5[2]);
-
-    let k0 = calculate_var(t, &saturn::K0[0], &saturn::K0[1], &saturn::K0[2]);
-    let k1 = calculate_var(t, &saturn::K1[0], &saturn::K1[1], &saturn::K1[2]);
-    let k2 = calculate_var(t, &saturn::K2[0], &saturn::K2[1], &saturn::K2[2]);
-    let k3 = calculate_var(t, &saturn::K3[0], &saturn::K3[1], &saturn::K3[2]);
-    let k4 = calculate_var(t, &saturn::K4[0], &saturn::K4[1], &saturn::K4[2]);
-    let k5 = calculate_var(t, &saturn::K5[0], &saturn::K5[1], &saturn::K5[2]);
-
-    let h0 = calculate_var(t, &saturn::H0[0], &saturn::H0[1], &saturn::H0[2]);
-    let h1 = calculate_var(t, &saturn::H1[0], &saturn::H1[1], &saturn::H1[2]);
-    let h2 = calculate_var(t, &saturn::H2[0], &saturn::H2[1], &saturn::H2[2]);
-    let h3 = calculate_var(t, &saturn::H3[0], &saturn::H3[1], &saturn::H3[2]);
-    let h4 = calculate_var(t, &saturn::H4[0], &saturn::H4[1], &saturn::H4[2]);
-    let h5 = calculate_var(t, &saturn::H5[0], &saturn::H5[1], &saturn::H5[2]);
-
-    let q0 = calculate_var(t, &saturn::Q0[0], &saturn::Q0[1], &saturn::Q0[2]);
-    let q1 = calculate_var(t, &saturn::Q1[0], &saturn::Q1[1], &saturn::Q1[2]);
-    let q2 = calculate_var(t, &saturn::Q2[0], &saturn::Q2[1], &saturn::Q2[2]);
-    let q3 = calculate_var(t, &saturn::Q3[0], &saturn::Q3[1], &saturn::Q3[2]);
-    let q4 = calculate_var(t, &saturn::Q4[0], &saturn::Q4[1], &saturn::Q4[2]);
-
-    let p0 = calculate_var(t, &saturn::P0[0], &saturn::P0[1], &saturn::P0[2]);
-    let p1 = calculate_var(t, &saturn::P1[0], &saturn::P1[1], &saturn::P1[2]);
-    let p2 = calculate_var(t, &saturn::P2[0], &saturn::P2[1], &saturn::P2[2]);
-    let p3 = calculate_var(t, &saturn::P3[0], &saturn::P3[1], &saturn::P3[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+/// Calculates VSOP87 solution for Saturn, along with the rates of change of the elements.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for the planet Saturn, and the rates of change of the elements, by
+/// differentiating each term of the series. The parameter needed is the Julian Day (*JD*) for the
+/// given date. It returns a `VSOP87ElementsState` structure, with the `VSOP87Elements`, as returned
+/// by [`saturn()`], and their rates per day.
+///
+/// # Example
+///
+/// ```
+/// let state = vsop87::saturn_state(2305445.0);
+/// let vsop87_elts = state.elements;
+///
+/// assert!(vsop87_elts.a > 9.5727100002 && vsop87_elts.a < 9.5727100004);
+/// assert!(vsop87_elts.l > 3.5107821038 && vsop87_elts.l < 3.5107821040);
+/// assert!(vsop87_elts.k > -0.0048218813 && vsop87_elts.k < -0.0048218811);
+/// assert!(vsop87_elts.h > 0.0575514202 && vsop87_elts.h < 0.0575514204);
+/// assert!(vsop87_elts.q > -0.0090348990 && vsop87_elts.q < -0.0090348988);
+/// assert!(vsop87_elts.p > 0.01965756 && vsop87_elts.p < 0.01965832);
+/// ```
+///
+/// The rates can then be converted into keplerian rates, to get the precession of the orbit:
+///
+/// ```
+/// use vsop87::KeplerianRates;
+///
+/// # let state = vsop87::saturn_state(2305445.0);
+/// #
+/// let rates = KeplerianRates::from(state);
+/// let apsidal_precession = rates.apsidal_precession().unwrap();
+/// let nodal_precession = rates.nodal_precession().unwrap();
+/// ```
+#[cfg(all(feature = "elements", feature = "saturn"))]
+#[must_use]
+pub fn saturn_state(jde: impl Into<JulianDay>) -> VSOP87ElementsState {
+    calculate_elements_state(jde.into(), &SATURN)
+}
 
-    let a = a0 + a1 * t + a2 * t2 + a3 * t3 + a4 * t4 + a5 * t5;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4 + k5 * t5;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4 + h5 * t5;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3 + q4 * t4;
-    let p = p0 + p1 * t + p2 * t2 + p3 * t3;
+/// Calculates VSOP87 solution for Saturn, checking the Julian Day.
+///
+/// This function is equivalent to [`saturn()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Saturn (see [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Body, Error};
+///
+/// assert_eq!(vsop87::saturn_checked(2451545.0)?, vsop87::saturn(2451545.0));
+/// assert_eq!(
+///     vsop87::saturn_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Saturn })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "saturn"))]
+pub fn saturn_checked(jde: impl Into<JulianDay>) -> Result<VSOP87Elements, Error> {
+    checked(Body::Saturn, jde)
+}
 
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+/// Calculates VSOP87 solution for Saturn, along with the rates of change of the elements, checking
+/// the Julian Day.
+///
+/// This function is equivalent to [`saturn_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Saturn (see
+/// [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Error};
+///
+/// assert_eq!(vsop87::saturn_state_checked(2451545.0)?, vsop87::saturn_state(2451545.0));
+/// assert_eq!(vsop87::saturn_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "saturn"))]
+pub fn saturn_state_checked(jde: impl Into<JulianDay>) -> Result<VSOP87ElementsState, Error> {
+    checked_state(Body::Saturn, jde)
 }
 
 /// Calculates VSOP87 solution for Uranus.
@@ -1303,9 +2536,8 @@ pub fn saturn(jde: f64) -> VSOP87Elements {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// orbit of the planet Uranus. In this case, we calculate the orbit of Uranus in December 19th,
-/// 1399.
+/// Given a date in [*JD*](crate::JulianDay), we can get the orbit of the planet Uranus. In this
+/// case, we calculate the orbit of Uranus in December 19th, 1399.
 ///
 /// ```
 /// let vsop87_elts = vsop87::uranus(2232395.0);
@@ -1328,66 +2560,92 @@ pub fn saturn(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(all(feature = "elements", feature = "uranus"))]
 #[must_use]
-pub fn uranus(jde: f64) -> VSOP87Elements {
-    let t = calculate_t(jde);
+pub fn uranus(jde: impl Into<JulianDay>) -> VSOP87Elements {
+    elements(calculate_body(jde.into(), &URANUS))
+}
 
-    let a0 = calculate_var(t, &uranus::A0[0], &uranus::A0[1], &uranus::A0[2]);
-    let a1 = calculate_var(t, &uranus::A1[0], &uranus::A1[1], &uranus::A1[2]);
-    let a2 = calculate_var(t, &uranus::A2[0], &uranus::A2[1], &uranus::A2[2]);
-    let a3 = calculate_var(t, &uranus::A3[0], &uranus::A3[1], &uranus::A3[2]);
-    let a4 = calculate_var(t, &uranus::A4[0], &uranus::A4[1], &uranus::A4[2]);
-    let a5 = calculate_var(t, &uranus::A5[0], &uranus::A5[1], &uranus::A5[2]);
-
-    let l0 = calculate_var(t, &uranus::L0[0], &uranus::L0[1], &uranus::L0[2]);
-    let l1 = calculate_var(t, &uranus::L1[0], &uranus::L1[1], &uranus::L1[2]);
-    let l2 = calculate_var(t, &uranus::L2[0], &uranus::L2[1], &uranus::L2[2]);
-    let l3 = calculate_var(t, &uranus::L3[0], &uranus::L3[1], &uranus::L3[2]);
-    let l4 = calculate_var(t, &uranus::L4[0], &uranus::L4[1], &uranus::L4[2]);
-    let l5 = calculate_var(t, &uranus::L5[0], &uranus::L5[1], &uranus::L5[2]);
-
-    let k0 = calculate_var(t, &uranus::K0[0], &uranus::K0[1], &uranus::K0[2]);
-    let k1 = calculate_var(t, &uranus::K1[0], &uranus::K1[1], &uranus::K1[2]);
-    let k2 = calculate_var(t, &uranus::K2[0], &uranus::K2[1], &uranus::K2[2]);
-    let k3 = calculate_var(t, &uranus::K3[0], &uranus::K3[1], &uranus::K3[2]);
-    let k4 = calculate_var(t, &uranus::K4[0], &uranus::K4[1], &uranus::K4[2]);
-
-    let h0 = calculate_var(t, &uranus::H0[0], &uranus::H0[1], &uranus::H0[2]);
-    let h1 = calculate_var(t, &uranus::H1[0], &uranus::H1[1], &uranus::H1[2]);
-    let h2 = calculate_var(t, &uranus::H2[0], &uranus::H2[1], &uranus::H2[2]);
-    let h3 = calculate_var(t, &uranus::H3[0], &uranus::H3[1], &uranus::H3[2]);
-    let h4 = calculate_var(t, &uranus::H4[0], &uranus::H4[1], &uranus::H4[2]);
-
-    let q0 = calculate_var(t, &uranus::Q0[0], &uranus::Q0[1], &uranus::Q0[2]);
-    let q1 = calculate_var(t, &uranus::Q1[0], &uranus::Q1[1], &uranus::Q1[2]);
-    let q2 = calculate_var(t, &uranus::Q2[0], &uranus::Q2[1], &uranus::Q2[2]);
-    let q3 = calculate_var(t, &uranus::Q3[0], &uranus::Q3[1], &uranus::Q3[2]);
-
-    let p0 = calculate_var(t, &uranus::P0[0], &uranus::P0[1], &uranus::P0[2]);
-    let p1 = calculate_var(t, &uranus::P1[0], &uranus::P1[1], &uranus::P1[2]);
-    let p2 = calculate_var(t, &uranus::P2[0], &uranus::P2[1], &uranus::P2[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+/// Calculates VSOP87 solution for Uranus, along with the rates of change of the elements.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for the planet Uranus, and the rates of change of the elements, by
+/// differentiating each term of the series. The parameter needed is the Julian Day (*JD*) for the
+/// given date. It returns a `VSOP87ElementsState` structure, with the `VSOP87Elements`, as returned
+/// by [`uranus()`], and their rates per day.
+///
+/// # Example
+///
+/// ```
+/// let state = vsop87::uranus_state(2232395.0);
+/// let vsop87_elts = state.elements;
+///
+/// assert!(vsop87_elts.a > 19.2497356422 && vsop87_elts.a < 19.2497356424);
+/// assert!(vsop87_elts.l > 4.5777275752 && vsop87_elts.l < 4.5777275754);
+/// assert!(vsop87_elts.k > -0.0466529112 && vsop87_elts.k < -0.0466529110);
+/// assert!(vsop87_elts.h > 0.0051308956 && vsop87_elts.h < 0.0051308958);
+/// assert!(vsop87_elts.q > 0.0019206656 && vsop87_elts.q < 0.0019206658);
+/// assert!(vsop87_elts.p > 0.00655819 && vsop87_elts.p < 0.00655895);
+/// ```
+///
+/// The rates can then be converted into keplerian rates, to get the precession of the orbit:
+///
+/// ```
+/// use vsop87::KeplerianRates;
+///
+/// # let state = vsop87::uranus_state(2232395.0);
+/// #
+/// let rates = KeplerianRates::from(state);
+/// let apsidal_precession = rates.apsidal_precession().unwrap();
+/// let nodal_precession = rates.nodal_precession().unwrap();
+/// ```
+#[cfg(all(feature = "elements", feature = "uranus"))]
+#[must_use]
+pub fn uranus_state(jde: impl Into<JulianDay>) -> VSOP87ElementsState {
+    calculate_elements_state(jde.into(), &URANUS)
+}
 
-    let a = a0 + a1 * t + a2 * t2 + a3 * t3 + a4 * t4 + a5 * t5;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3;
-    let p = p0 + p1 * t + p2 * t2;
+/// Calculates VSOP87 solution for Uranus, checking the Julian Day.
+///
+/// This function is equivalent to [`uranus()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Uranus (see [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Body, Error};
+///
+/// assert_eq!(vsop87::uranus_checked(2451545.0)?, vsop87::uranus(2451545.0));
+/// assert_eq!(
+///     vsop87::uranus_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Uranus })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "uranus"))]
+pub fn uranus_checked(jde: impl Into<JulianDay>) -> Result<VSOP87Elements, Error> {
+    checked(Body::Uranus, jde)
+}
 
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
-    }
+/// Calculates VSOP87 solution for Uranus, along with the rates of change of the elements, checking
+/// the Julian Day.
+///
+/// This function is equivalent to [`uranus_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Uranus (see
+/// [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Error};
+///
+/// assert_eq!(vsop87::uranus_state_checked(2451545.0)?, vsop87::uranus_state(2451545.0));
+/// assert_eq!(vsop87::uranus_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "uranus"))]
+pub fn uranus_state_checked(jde: impl Into<JulianDay>) -> Result<VSOP87ElementsState, Error> {
+    checked_state(Body::Uranus, jde)
 }
 
 /// Calculates VSOP87 solution for Neptune.
@@ -1398,9 +2656,8 @@ pub fn uranus(jde: f64) -> VSOP87Elements {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// orbit of the planet Neptune. In this case, we calculate the orbit of Neptune in December 19th,
-/// 1499.
+/// Given a date in [*JD*](crate::JulianDay), we can get the orbit of the planet Neptune. In this
+/// case, we calculate the orbit of Neptune in December 19th, 1499.
 ///
 /// ```
 /// let vsop87_elts = vsop87::neptune(2268920.0);
@@ -1423,66 +2680,548 @@ pub fn uranus(jde: f64) -> VSOP87Elements {
 /// let k_elements: KeplerianElements = vsop87_elts.into();
 /// let convert_back = VSOP87Elements::from(k_elements);
 /// ```
+#[cfg(all(feature = "elements", feature = "neptune"))]
 #[must_use]
-pub fn neptune(jde: f64) -> VSOP87Elements {
-    let t = calculate_t(jde);
+pub fn neptune(jde: impl Into<JulianDay>) -> VSOP87Elements {
+    elements(calculate_body(jde.into(), &NEPTUNE))
+}
 
-    let a0 = calculate_var(t, &neptune::A0[0], &neptune::A0[1], &neptune::A0[2]);
-    let a1 = calculate_var(t, &neptune::A1[0], &neptune::A1[1], &neptune::A1[2]);
-    let a2 = calculate_var(t, &neptune::A2[0], &neptune::A2[1], &neptune::A2[2]);
-    let a3 = calculate_var(t, &neptune::A3[0], &neptune::A3[1], &neptune::A3[2]);
-    let a4 = calculate_var(t, &neptune::A4[0], &neptune::A4[1], &neptune::A4[2]);
-    let a5 = calculate_var(t, &neptune::A5[0], &neptune::A5[1], &neptune::A5[2]);
-
-    let l0 = calculate_var(t, &neptune::L0[0], &neptune::L0[1], &neptune::L0[2]);
-    let l1 = calculate_var(t, &neptune::L1[0], &neptune::L1[1], &neptune::L1[2]);
-    let l2 = calculate_var(t, &neptune::L2[0], &neptune::L2[1], &neptune::L2[2]);
-    let l3 = calculate_var(t, &neptune::L3[0], &neptune::L3[1], &neptune::L3[2]);
-    let l4 = calculate_var(t, &neptune::L4[0], &neptune::L4[1], &neptune::L4[2]);
-    let l5 = calculate_var(t, &neptune::L5[0], &neptune::L5[1], &neptune::L5[2]);
-
-    let k0 = calculate_var(t, &neptune::K0[0], &neptune::K0[1], &neptune::K0[2]);
-    let k1 = calculate_var(t, &neptune::K1[0], &neptune::K1[1], &neptune::K1[2]);
-    let k2 = calculate_var(t, &neptune::K2[0], &neptune::K2[1], &neptune::K2[2]);
-    let k3 = calculate_var(t, &neptune::K3[0], &neptune::K3[1], &neptune::K3[2]);
-    let k4 = calculate_var(t, &neptune::K4[0], &neptune::K4[1], &neptune::K4[2]);
-    let k5 = calculate_var(t, &neptune::K5[0], &neptune::K5[1], &neptune::K5[2]);
-
-    let h0 = calculate_var(t, &neptune::H0[0], &neptune::H0[1], &neptune::H0[2]);
-    let h1 = calculate_var(t, &neptune::H1[0], &neptune::H1[1], &neptune::H1[2]);
-    let h2 = calculate_var(t, &neptune::H2[0], &neptune::H2[1], &neptune::H2[2]);
-    let h3 = calculate_var(t, &neptune::H3[0], &neptune::H3[1], &neptune::H3[2]);
-    let h4 = calculate_var(t, &neptune::H4[0], &neptune::H4[1], &neptune::H4[2]);
-    let h5 = calculate_var(t, &neptune::H5[0], &neptune::H5[1], &neptune::H5[2]);
-
-    let q0 = calculate_var(t, &neptune::Q0[0], &neptune::Q0[1], &neptune::Q0[2]);
-    let q1 = calculate_var(t, &neptune::Q1[0], &neptune::Q1[1], &neptune::Q1[2]);
-    let q2 = calculate_var(t, &neptune::Q2[0], &neptune::Q2[1], &neptune::Q2[2]);
-    let q3 = calculate_var(t, &neptune::Q3[0], &neptune::Q3[1], &neptune::Q3[2]);
-
-    let p0 = calculate_var(t, &neptune::P0[0], &neptune::P0[1], &neptune::P0[2]);
-    let p1 = calculate_var(t, &neptune::P1[0], &neptune::P1[1], &neptune::P1[2]);
-    let p2 = calculate_var(t, &neptune::P2[0], &neptune::P2[1], &neptune::P2[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
+/// Calculates VSOP87 solution for Neptune, along with the rates of change of the elements.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for the planet Neptune, and the rates of change of the elements, by
+/// differentiating each term of the series. The parameter needed is the Julian Day (*JD*) for the
+/// given date. It returns a `VSOP87ElementsState` structure, with the `VSOP87Elements`, as returned
+/// by [`neptune()`], and their rates per day.
+///
+/// # Example
+///
+/// ```
+/// let state = vsop87::neptune_state(2268920.0);
+/// let vsop87_elts = state.elements;
+///
+/// assert!(vsop87_elts.a > 30.1963044187 && vsop87_elts.a < 30.1963044189);
+/// assert!(vsop87_elts.l > 5.1088676118 && vsop87_elts.l < 5.1088676120);
+/// assert!(vsop87_elts.k > 0.0091964091 && vsop87_elts.k < 0.0091964093);
+/// assert!(vsop87_elts.h > 0.0031103619 && vsop87_elts.h < 0.0031103621);
+/// assert!(vsop87_elts.q > -0.0102800265 && vsop87_elts.q < -0.0102800263);
+/// assert!(vsop87_elts.p > 0.01148076 && vsop87_elts.p < 0.01148152);
+/// ```
+///
+/// The rates can then be converted into keplerian rates, to get the precession of the orbit:
+///
+/// ```
+/// use vsop87::KeplerianRates;
+///
+/// # let state = vsop87::neptune_state(2268920.0);
+/// #
+/// let rates = KeplerianRates::from(state);
+/// let apsidal_precession = rates.apsidal_precession().unwrap();
+/// let nodal_precession = rates.nodal_precession().unwrap();
+/// ```
+#[cfg(all(feature = "elements", feature = "neptune"))]
+#[must_use]
+pub fn neptune_state(jde: impl Into<JulianDay>) -> VSOP87ElementsState {
+    calculate_elements_state(jde.into(), &NEPTUNE)
+}
 
-    let a = a0 + a1 * t + a2 * t2 + a3 * t3 + a4 * t4 + a5 * t5;
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let k = k0 + k1 * t + k2 * t2 + k3 * t3 + k4 * t4 + k5 * t5;
-    let h = h0 + h1 * t + h2 * t2 + h3 * t3 + h4 * t4 + h5 * t5;
-    let q = q0 + q1 * t + q2 * t2 + q3 * t3;
-    let p = p0 + p1 * t + p2 * t2;
+/// Calculates VSOP87 solution for Neptune, checking the Julian Day.
+///
+/// This function is equivalent to [`neptune()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Neptune (see [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Body, Error};
+///
+/// assert_eq!(vsop87::neptune_checked(2451545.0)?, vsop87::neptune(2451545.0));
+/// assert_eq!(
+///     vsop87::neptune_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Neptune })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "neptune"))]
+pub fn neptune_checked(jde: impl Into<JulianDay>) -> Result<VSOP87Elements, Error> {
+    checked(Body::Neptune, jde)
+}
 
-    VSOP87Elements {
-        a,
-        l: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        k,
-        h,
-        q,
-        p,
+/// Calculates VSOP87 solution for Neptune, along with the rates of change of the elements, checking
+/// the Julian Day.
+///
+/// This function is equivalent to [`neptune_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Neptune (see
+/// [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Error};
+///
+/// assert_eq!(vsop87::neptune_state_checked(2451545.0)?, vsop87::neptune_state(2451545.0));
+/// assert_eq!(vsop87::neptune_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "neptune"))]
+pub fn neptune_state_checked(jde: impl Into<JulianDay>) -> Result<VSOP87ElementsState, Error> {
+    checked_state(Body::Neptune, jde)
+}
+
+/// Calculates VSOP87 solution for all its bodies at the same Julian Day.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for all the bodies available in VSOP87, at the given Julian Day (*JD*). It
+/// returns the `VSOP87Elements` of each body, in the order of [`BODIES`]. It is equivalent to
+/// calling the function of each body, but the time is only calculated once.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::Body;
+///
+/// let bodies = vsop87::all_bodies(2451545.0);
+///
+/// assert_eq!(vsop87::BODIES[4], Body::Jupiter);
+/// let jupiter = vsop87::jupiter(2451545.0);
+/// assert_eq!(bodies[4], jupiter);
+/// # }
+/// ```
+#[cfg(feature = "elements")]
+#[must_use]
+pub fn all_bodies(jde: impl Into<JulianDay>) -> [VSOP87Elements; BODIES.len()] {
+    calculate_all(jde.into(), &ALL_BODIES).map(elements)
+}
+
+/// Calculates VSOP87 solution for all its bodies at the same Julian Day, in parallel.
+///
+/// This function is equivalent to [`all_bodies()`], but each body is calculated in parallel, using
+/// the global [`rayon`] thread pool. It is only available with the `rayon` feature.
+#[cfg(all(feature = "elements", feature = "rayon"))]
+#[must_use]
+pub fn all_bodies_parallel(jde: impl Into<JulianDay>) -> [VSOP87Elements; BODIES.len()] {
+    calculate_all_parallel(jde.into(), &ALL_BODIES).map(elements)
+}
+
+/// Calculates VSOP87 solution for the given body, with the given precision.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for the given body, skipping the terms of the series that are not needed for
+/// the given `Precision`. The parameters needed are the body, the Julian Day (*JD*) for the given
+/// date and the precision. It returns the `VSOP87Elements`, along with the amplitude thresholds
+/// used for each of them, or an error if the body is not available in VSOP87.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "mercury")]
+/// # {
+/// use vsop87::{Body, Precision};
+///
+/// let vsop87_elts = vsop87::mercury(2415020.0);
+/// let truncated =
+///     vsop87::truncated(Body::Mercury, 2415020.0, Precision::Amplitude(0.000001)).unwrap();
+///
+/// assert!((truncated.value.a - vsop87_elts.a).abs() < 0.00001);
+/// assert!((truncated.value.l - vsop87_elts.l).abs() < 0.00001);
+/// assert!(truncated.thresholds.a > 0.0);
+/// # }
+/// ```
+#[cfg(feature = "elements")]
+pub fn truncated(
+    body: Body,
+    jde: impl Into<JulianDay>,
+    precision: Precision,
+) -> Result<Truncated<VSOP87Elements>, Error> {
+    let ([a, l, k, h, q, p], thresholds) = calculate_truncated(
+        jde.into(),
+        body,
+        body_series(body)?,
+        [
+            Variable::Distance,
+            Variable::Angle,
+            Variable::Angle,
+            Variable::Angle,
+            Variable::Angle,
+            Variable::Angle,
+        ],
+        precision,
+    );
+
+    Ok(Truncated {
+        value: elements([a, l, k, h, q, p]),
+        thresholds: VSOP87Elements {
+            a: thresholds[0],
+            l: thresholds[1],
+            k: thresholds[2],
+            h: thresholds[3],
+            q: thresholds[4],
+            p: thresholds[5],
+        },
+    })
+}
+
+/// Calculates VSOP87 solution for the given body at many Julian Days.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for the given body at each of the given Julian Days (*JD*), writing the
+/// `VSOP87Elements` in the output slice, in the same order. It is equivalent to calling the
+/// function of the body for each Julian Day, but much faster for big batches, since each term of
+/// the series is calculated for many Julian Days at the same time.
+///
+/// It returns an error if the body is not available in VSOP87, or if the output does not have the
+/// same length as the Julian Days.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{Body, VSOP87Elements};
+///
+/// // Calculate the position of Jupiter every 30 days.
+/// let jdes = [2451545.0, 2451575.0, 2451605.0];
+/// let mut output = [VSOP87Elements::default(); 3];
+/// vsop87::batch(Body::Jupiter, &jdes, &mut output).unwrap();
+///
+/// for (&jde, elements) in jdes.iter().zip(&output) {
+///     let expected = vsop87::jupiter(jde);
+///     assert!((elements.a - expected.a).abs() < 0.000000001);
+///     assert!((elements.l - expected.l).abs() < 0.000000001);
+///     assert!((elements.k - expected.k).abs() < 0.000000001);
+/// }
+/// # }
+/// ```
+#[cfg(feature = "elements")]
+pub fn batch(body: Body, jdes: &[f64], output: &mut [VSOP87Elements]) -> Result<(), Error> {
+    calculate_batch(jdes, body_series(body)?, output, elements)
+}
+
+/// Calculates VSOP87 solution for the given body at many two-part Julian Days.
+///
+/// This function is equivalent to [`batch()`], but it takes [`JulianDay`]s instead of `f64` values,
+/// so that the time variable of each of them is calculated without losing precision. See the
+/// [precision of Julian Days](JulianDay#precision).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{Body, JulianDay, VSOP87Elements};
+///
+/// // Calculate the orbital elements of Jupiter every 6 hours.
+/// let jdes = [0.0, 0.25, 0.5, 0.75].map(|fraction| JulianDay::from_parts(2451545.0, fraction));
+/// let mut output = [VSOP87Elements::default(); 4];
+/// vsop87::batch_julian_days(Body::Jupiter, &jdes, &mut output)?;
+///
+/// let mut expected = [VSOP87Elements::default(); 4];
+/// vsop87::batch(Body::Jupiter, &[2451545.0, 2451545.25, 2451545.5, 2451545.75], &mut expected)?;
+/// assert_eq!(output, expected);
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+#[cfg(feature = "elements")]
+pub fn batch_julian_days(
+    body: Body,
+    jdes: &[JulianDay],
+    output: &mut [VSOP87Elements],
+) -> Result<(), Error> {
+    calculate_batch(jdes, body_series(body)?, output, elements)
+}
+
+/// Calculates VSOP87 solution for the given body, checking the Julian Day.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for the given body at the given Julian Day (*JD*), in the same way as the
+/// function of the body.
+///
+/// The functions of the bodies also have checked variants of their own, such as `saturn_checked()`,
+/// which call this function with the body as a parameter.
+///
+/// It returns an error if the body is not available in VSOP87, if the Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{Body, Error};
+///
+/// let checked = vsop87::checked(Body::Jupiter, 2451545.0).unwrap();
+/// assert_eq!(checked, vsop87::jupiter(2451545.0));
+///
+/// assert_eq!(
+///     vsop87::checked(Body::Jupiter, 0.0),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// assert_eq!(vsop87::checked(Body::Jupiter, f64::NAN), Err(Error::NonFinite));
+/// # }
+/// ```
+#[cfg(feature = "elements")]
+pub fn checked(body: Body, jde: impl Into<JulianDay>) -> Result<VSOP87Elements, Error> {
+    let jde = jde.into();
+
+    let series = body_series(body)?;
+    check_validity(body, jde)?;
+
+    Ok(elements(calculate_body(jde, series)))
+}
+
+/// Calculates VSOP87 solution and its rates for the given body, checking the Julian Day.
+///
+/// This function calculates the VSOP87 solution (heliocentric ecliptic orbital elements for the
+/// equinox J2000.0) for the given body at the given Julian Day (*JD*), along with its rates, in the
+/// same way as the state function of the body.
+///
+/// It returns an error if the body is not available in VSOP87, if the Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{Body, Error};
+///
+/// let checked = vsop87::checked_state(Body::Jupiter, 2451545.0).unwrap();
+/// assert_eq!(checked, vsop87::jupiter_state(2451545.0));
+///
+/// assert!(vsop87::checked_state(Body::Jupiter, f64::INFINITY).is_err());
+/// # }
+/// ```
+#[cfg(feature = "elements")]
+pub fn checked_state(body: Body, jde: impl Into<JulianDay>) -> Result<VSOP87ElementsState, Error> {
+    let jde = jde.into();
+
+    let series = body_series(body)?;
+    check_validity(body, jde)?;
+
+    Ok(calculate_elements_state(jde, series))
+}
+
+/// Calculates VSOP87 solution for all its bodies, checking the Julian Day.
+///
+/// This function is equivalent to [`all_bodies()`], but it checks that the Julian Day is valid for
+/// all the bodies.
+///
+/// It returns an error if the Julian Day is not finite, or if it is outside of the validity span of
+/// the solution for any of the bodies (see [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{Body, Error};
+///
+/// let bodies = vsop87::all_bodies_checked(2451545.0).unwrap();
+/// assert_eq!(bodies, vsop87::all_bodies(2451545.0));
+///
+/// // Valid for the inner planets, but not for Jupiter.
+/// assert_eq!(
+///     vsop87::all_bodies_checked(1500000.0),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// # }
+/// ```
+#[cfg(feature = "elements")]
+pub fn all_bodies_checked(
+    jde: impl Into<JulianDay>,
+) -> Result<[VSOP87Elements; BODIES.len()], Error> {
+    let jde = jde.into();
+
+    for &body in BODIES {
+        check_validity(body, jde)?;
+    }
+
+    Ok(all_bodies(jde))
+}
+
+/// Calculates VSOP87 solution for all its bodies in parallel, checking the Julian Day.
+///
+/// This function is equivalent to [`all_bodies_parallel()`], but it checks that the Julian Day is
+/// valid for all the bodies.
+///
+/// It returns an error if the Julian Day is not finite, or if it is outside of the validity span of
+/// the solution for any of the bodies (see [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::Error;
+///
+/// let bodies = vsop87::all_bodies_parallel_checked(2451545.0)?;
+/// assert_eq!(bodies, vsop87::all_bodies_parallel(2451545.0));
+///
+/// assert_eq!(vsop87::all_bodies_parallel_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+#[cfg(all(feature = "elements", feature = "rayon"))]
+pub fn all_bodies_parallel_checked(
+    jde: impl Into<JulianDay>,
+) -> Result<[VSOP87Elements; BODIES.len()], Error> {
+    let jde = jde.into();
+
+    for &body in BODIES {
+        check_validity(body, jde)?;
+    }
+
+    Ok(all_bodies_parallel(jde))
+}
+
+/// Calculates VSOP87 solution with the given precision, checking the Julian Day.
+///
+/// This function is equivalent to [`truncated()`], but it checks that the Julian Day is valid for
+/// the body.
+///
+/// It returns an error if the body is not available in VSOP87, if the Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{Body, Error};
+///
+/// # use vsop87::Precision;
+/// let checked = vsop87::truncated_checked(Body::Jupiter, 2451545.0, Precision::Arcseconds(1.0));
+/// assert_eq!(checked, vsop87::truncated(Body::Jupiter, 2451545.0, Precision::Arcseconds(1.0)));
+///
+/// assert_eq!(
+///     vsop87::truncated_checked(Body::Jupiter, 0.0, Precision::Full),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// # }
+/// ```
+#[cfg(feature = "elements")]
+pub fn truncated_checked(
+    body: Body,
+    jde: impl Into<JulianDay>,
+    precision: Precision,
+) -> Result<Truncated<VSOP87Elements>, Error> {
+    let jde = jde.into();
+
+    ensure_supported(body)?;
+    check_validity(body, jde)?;
+
+    truncated(body, jde, precision)
+}
+
+/// Calculates VSOP87 solution at many Julian Days, checking the Julian Days.
+///
+/// This function is equivalent to [`batch()`], but it checks that all the Julian Days are valid for
+/// the body before calculating any of them.
+///
+/// It returns an error if the body is not available in VSOP87, if the Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{Body, Error};
+///
+/// # use vsop87::VSOP87Elements;
+/// let mut output = [VSOP87Elements::default(); 2];
+/// vsop87::batch_checked(Body::Jupiter, &[2451545.0, 2451546.0], &mut output).unwrap();
+///
+/// assert_eq!(
+///     vsop87::batch_checked(Body::Jupiter, &[2451545.0, f64::NAN], &mut output),
+///     Err(Error::NonFinite)
+/// );
+/// # }
+/// ```
+#[cfg(feature = "elements")]
+pub fn batch_checked(body: Body, jdes: &[f64], output: &mut [VSOP87Elements]) -> Result<(), Error> {
+    ensure_supported(body)?;
+    for &jde in jdes {
+        check_validity(body, jde.into())?;
+    }
+
+    batch(body, jdes, output)
+}
+
+/// Calculates VSOP87 solution at many two-part Julian Days, checking the Julian Days.
+///
+/// This function is equivalent to [`batch_julian_days()`], but it checks that all the Julian Days
+/// are valid for the body before calculating any of them.
+///
+/// It returns an error if the body is not available in VSOP87, if a Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`]).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{Body, Error, JulianDay, VSOP87Elements};
+///
+/// let jdes = [JulianDay::from_parts(2451545.0, 0.25), JulianDay::new(2451546.0)];
+/// let mut output = [VSOP87Elements::default(); 2];
+/// vsop87::batch_julian_days_checked(Body::Jupiter, &jdes, &mut output)?;
+///
+/// let jdes = [JulianDay::new(2451545.0), JulianDay::new(0.0)];
+/// assert_eq!(
+///     vsop87::batch_julian_days_checked(Body::Jupiter, &jdes, &mut output),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+#[cfg(feature = "elements")]
+pub fn batch_julian_days_checked(
+    body: Body,
+    jdes: &[JulianDay],
+    output: &mut [VSOP87Elements],
+) -> Result<(), Error> {
+    ensure_supported(body)?;
+    for &jde in jdes {
+        check_validity(body, jde)?;
+    }
+
+    batch_julian_days(body, jdes, output)
+}
+
+/// Checks that the given body is available in VSOP87.
+#[cfg(feature = "elements")]
+fn ensure_supported(body: Body) -> Result<(), Error> {
+    body_series(body).map(|_| ())
+}
+
+/// Gets the series of the given body.
+#[cfg(feature = "elements")]
+fn body_series(body: Body) -> Result<&'static [&'static [Series]; 6], Error> {
+    match body {
+        #[cfg(all(feature = "elements", feature = "mercury"))]
+        Body::Mercury => Ok(&MERCURY),
+        #[cfg(all(feature = "elements", feature = "venus"))]
+        Body::Venus => Ok(&VENUS),
+        #[cfg(all(feature = "elements", feature = "earth_moon"))]
+        Body::EarthMoon => Ok(&EARTH_MOON),
+        #[cfg(all(feature = "elements", feature = "mars"))]
+        Body::Mars => Ok(&MARS),
+        #[cfg(all(feature = "elements", feature = "jupiter"))]
+        Body::Jupiter => Ok(&JUPITER),
+        #[cfg(all(feature = "elements", feature = "saturn"))]
+        Body::Saturn => Ok(&SATURN),
+        #[cfg(all(feature = "elements", feature = "uranus"))]
+        Body::Uranus => Ok(&URANUS),
+        #[cfg(all(feature = "elements", feature = "neptune"))]
+        Body::Neptune => Ok(&NEPTUNE),
+        _ => Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87,
+            body,
+        }),
     }
 }
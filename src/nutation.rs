@@ -0,0 +1,714 @@
+//! Nutation of the Earth's axis and obliquity of the ecliptic.
+
+use super::{JulianDay, Precession, RectangularCoordinates, Rotation, SphericalCoordinates};
+
+#[cfg(feature = "no_std")]
+use core::f64::consts::PI;
+#[cfg(feature = "no_std")]
+use libm::sincos;
+
+#[cfg(not(feature = "no_std"))]
+use std::f64::consts::PI;
+
+/// Radians in an arcsecond.
+pub(crate) const ARCSECOND: f64 = PI / 648_000_f64;
+
+/// Arcseconds in a full circle.
+const TURN: f64 = 1_296_000_f64;
+
+/// Nutation in longitude and in obliquity added by the IAU 2000B model to account for the
+/// planetary terms, in arcseconds.
+const IAU_2000B_OFFSETS: (f64, f64) = (-0.000_135, 0.000_388);
+
+/// Terms of the IAU 1980 nutation with amplitudes of 0.0003" or more.
+///
+/// Each term has the multipliers of the fundamental arguments *D*, *M*, *M'*, *F* and *Ω*, and
+/// the coefficients of the sine of the argument in longitude and of its cosine in obliquity, and
+/// of their rates per Julian century, in units of 0.0001".
+const IAU_1980: [([i8; 5], [f64; 4]); 63] = [
+    ([0, 0, 0, 0, 1], [-171_996_f64, -174.2, 92_025_f64, 8.9]),
+    ([-2, 0, 0, 2, 2], [-13_187_f64, -1.6, 5_736_f64, -3.1]),
+    ([0, 0, 0, 2, 2], [-2_274_f64, -0.2, 977_f64, -0.5]),
+    ([0, 0, 0, 0, 2], [2_062_f64, 0.2, -895_f64, 0.5]),
+    ([0, 1, 0, 0, 0], [1_426_f64, -3.4, 54_f64, -0.1]),
+    ([0, 0, 1, 0, 0], [712_f64, 0.1, -7_f64, 0_f64]),
+    ([-2, 1, 0, 2, 2], [-517_f64, 1.2, 224_f64, -0.6]),
+    ([0, 0, 0, 2, 1], [-386_f64, -0.4, 200_f64, 0_f64]),
+    ([0, 0, 1, 2, 2], [-301_f64, 0_f64, 129_f64, -0.1]),
+    ([-2, -1, 0, 2, 2], [217_f64, -0.5, -95_f64, 0.3]),
+    ([-2, 0, 1, 0, 0], [-158_f64, 0_f64, 0_f64, 0_f64]),
+    ([-2, 0, 0, 2, 1], [129_f64, 0.1, -70_f64, 0_f64]),
+    ([0, 0, -1, 2, 2], [123_f64, 0_f64, -53_f64, 0_f64]),
+    ([2, 0, 0, 0, 0], [63_f64, 0_f64, 0_f64, 0_f64]),
+    ([0, 0, 1, 0, 1], [63_f64, 0_f64, -33_f64, 0_f64]),
+    ([2, 0, -1, 2, 2], [-59_f64, 0_f64, 26_f64, 0_f64]),
+    ([0, 0, -1, 0, 1], [-58_f64, 0_f64, 32_f64, 0_f64]),
+    ([0, 0, 1, 2, 1], [-51_f64, 0_f64, 27_f64, 0_f64]),
+    ([-2, 0, 2, 0, 0], [48_f64, 0_f64, 0_f64, 0_f64]),
+    ([0, 0, -2, 2, 1], [46_f64, 0_f64, -24_f64, 0_f64]),
+    ([2, 0, 0, 2, 2], [-38_f64, 0_f64, 16_f64, 0_f64]),
+    ([0, 0, 2, 2, 2], [-31_f64, 0_f64, 13_f64, 0_f64]),
+    ([0, 0, 2, 0, 0], [29_f64, 0_f64, 0_f64, 0_f64]),
+    ([-2, 0, 1, 2, 2], [29_f64, 0_f64, -12_f64, 0_f64]),
+    ([0, 0, 0, 2, 0], [26_f64, 0_f64, 0_f64, 0_f64]),
+    ([-2, 0, 0, 2, 0], [-22_f64, 0_f64, 0_f64, 0_f64]),
+    ([0, 0, -1, 2, 1], [21_f64, 0_f64, -10_f64, 0_f64]),
+    ([0, 2, 0, 0, 0], [17_f64, -0.1, 0_f64, 0_f64]),
+    ([2, 0, -1, 0, 1], [16_f64, 0_f64, -8_f64, 0_f64]),
+    ([-2, 2, 0, 2, 2], [-16_f64, 0.1, 7_f64, 0_f64]),
+    ([0, 1, 0, 0, 1], [-15_f64, 0_f64, 9_f64, 0_f64]),
+    ([-2, 0, 1, 0, 1], [-13_f64, 0_f64, 7_f64, 0_f64]),
+    ([0, -1, 0, 0, 1], [-12_f64, 0_f64, 6_f64, 0_f64]),
+    ([0, 0, 2, -2, 0], [11_f64, 0_f64, 0_f64, 0_f64]),
+    ([2, 0, -1, 2, 1], [-10_f64, 0_f64, 5_f64, 0_f64]),
+    ([2, 0, 1, 2, 2], [-8_f64, 0_f64, 3_f64, 0_f64]),
+    ([0, 1, 0, 2, 2], [7_f64, 0_f64, -3_f64, 0_f64]),
+    ([-2, 1, 1, 0, 0], [-7_f64, 0_f64, 0_f64, 0_f64]),
+    ([0, -1, 0, 2, 2], [-7_f64, 0_f64, 3_f64, 0_f64]),
+    ([2, 0, 0, 2, 1], [-7_f64, 0_f64, 3_f64, 0_f64]),
+    ([2, 0, 1, 0, 0], [6_f64, 0_f64, 0_f64, 0_f64]),
+    ([-2, 0, 2, 2, 2], [6_f64, 0_f64, -3_f64, 0_f64]),
+    ([-2, 0, 1, 2, 1], [6_f64, 0_f64, -3_f64, 0_f64]),
+    ([2, 0, -2, 0, 1], [-6_f64, 0_f64, 3_f64, 0_f64]),
+    ([2, 0, 0, 0, 1], [-6_f64, 0_f64, 3_f64, 0_f64]),
+    ([0, -1, 1, 0, 0], [5_f64, 0_f64, 0_f64, 0_f64]),
+    ([-2, -1, 0, 2, 1], [-5_f64, 0_f64, 3_f64, 0_f64]),
+    ([-2, 0, 0, 0, 1], [-5_f64, 0_f64, 3_f64, 0_f64]),
+    ([0, 0, 2, 2, 1], [-5_f64, 0_f64, 3_f64, 0_f64]),
+    ([-2, 0, 2, 0, 1], [4_f64, 0_f64, 0_f64, 0_f64]),
+    ([-2, 1, 0, 2, 1], [4_f64, 0_f64, 0_f64, 0_f64]),
+    ([0, 0, 1, -2, 0], [4_f64, 0_f64, 0_f64, 0_f64]),
+    ([-1, 0, 1, 0, 0], [-4_f64, 0_f64, 0_f64, 0_f64]),
+    ([-2, 1, 0, 0, 0], [-4_f64, 0_f64, 0_f64, 0_f64]),
+    ([1, 0, 0, 0, 0], [-4_f64, 0_f64, 0_f64, 0_f64]),
+    ([0, 0, 1, 2, 0], [3_f64, 0_f64, 0_f64, 0_f64]),
+    ([0, 0, -2, 2, 2], [-3_f64, 0_f64, 0_f64, 0_f64]),
+    ([-1, -1, 1, 0, 0], [-3_f64, 0_f64, 0_f64, 0_f64]),
+    ([0, 1, 1, 0, 0], [-3_f64, 0_f64, 0_f64, 0_f64]),
+    ([0, -1, 1, 2, 2], [-3_f64, 0_f64, 0_f64, 0_f64]),
+    ([2, -1, -1, 2, 2], [-3_f64, 0_f64, 0_f64, 0_f64]),
+    ([0, 0, 3, 2, 2], [-3_f64, 0_f64, 0_f64, 0_f64]),
+    ([2, -1, 0, 2, 2], [-3_f64, 0_f64, 0_f64, 0_f64]),
+];
+
+/// Luni-solar terms of the IAU 2000B nutation.
+///
+/// Each term has the multipliers of the fundamental arguments *l*, *l'*, *F*, *D* and *Ω*, and the
+/// coefficients in longitude (sine, sine rate per Julian century and cosine) and in obliquity
+/// (cosine, cosine rate per Julian century and sine), in units of 0.1 microarcseconds.
+const IAU_2000B: [([i8; 5], [f64; 6]); 77] = [
+    (
+        [0, 0, 0, 0, 1],
+        [
+            -172_064_161_f64,
+            -174_666_f64,
+            33_386_f64,
+            92_052_331_f64,
+            9_086_f64,
+            15_377_f64,
+        ],
+    ),
+    (
+        [0, 0, 2, -2, 2],
+        [
+            -13_170_906_f64,
+            -1_675_f64,
+            -13_696_f64,
+            5_730_336_f64,
+            -3_015_f64,
+            -4_587_f64,
+        ],
+    ),
+    (
+        [0, 0, 2, 0, 2],
+        [
+            -2_276_413_f64,
+            -234_f64,
+            2_796_f64,
+            978_459_f64,
+            -485_f64,
+            1_374_f64,
+        ],
+    ),
+    (
+        [0, 0, 0, 0, 2],
+        [
+            2_074_554_f64,
+            207_f64,
+            -698_f64,
+            -897_492_f64,
+            470_f64,
+            -291_f64,
+        ],
+    ),
+    (
+        [0, 1, 0, 0, 0],
+        [
+            1_475_877_f64,
+            -3_633_f64,
+            11_817_f64,
+            73_871_f64,
+            -184_f64,
+            -1_924_f64,
+        ],
+    ),
+    (
+        [0, 1, 2, -2, 2],
+        [
+            -516_821_f64,
+            1_226_f64,
+            -524_f64,
+            224_386_f64,
+            -677_f64,
+            -174_f64,
+        ],
+    ),
+    (
+        [1, 0, 0, 0, 0],
+        [711_159_f64, 73_f64, -872_f64, -6_750_f64, 0_f64, 358_f64],
+    ),
+    (
+        [0, 0, 2, 0, 1],
+        [
+            -387_298_f64,
+            -367_f64,
+            380_f64,
+            200_728_f64,
+            18_f64,
+            318_f64,
+        ],
+    ),
+    (
+        [1, 0, 2, 0, 2],
+        [
+            -301_461_f64,
+            -36_f64,
+            816_f64,
+            129_025_f64,
+            -63_f64,
+            367_f64,
+        ],
+    ),
+    (
+        [0, -1, 2, -2, 2],
+        [
+            215_829_f64,
+            -494_f64,
+            111_f64,
+            -95_929_f64,
+            299_f64,
+            132_f64,
+        ],
+    ),
+    (
+        [0, 0, 2, -2, 1],
+        [128_227_f64, 137_f64, 181_f64, -68_982_f64, -9_f64, 39_f64],
+    ),
+    (
+        [-1, 0, 2, 0, 2],
+        [123_457_f64, 11_f64, 19_f64, -53_311_f64, 32_f64, -4_f64],
+    ),
+    (
+        [-1, 0, 0, 2, 0],
+        [156_994_f64, 10_f64, -168_f64, -1_235_f64, 0_f64, 82_f64],
+    ),
+    (
+        [1, 0, 0, 0, 1],
+        [63_110_f64, 63_f64, 27_f64, -33_228_f64, 0_f64, -9_f64],
+    ),
+    (
+        [-1, 0, 0, 0, 1],
+        [-57_976_f64, -63_f64, -189_f64, 31_429_f64, 0_f64, -75_f64],
+    ),
+    (
+        [-1, 0, 2, 2, 2],
+        [-59_641_f64, -11_f64, 149_f64, 25_543_f64, -11_f64, 66_f64],
+    ),
+    (
+        [1, 0, 2, 0, 1],
+        [-51_613_f64, -42_f64, 129_f64, 26_366_f64, 0_f64, 78_f64],
+    ),
+    (
+        [-2, 0, 2, 0, 1],
+        [45_893_f64, 50_f64, 31_f64, -24_236_f64, -10_f64, 20_f64],
+    ),
+    (
+        [0, 0, 0, 2, 0],
+        [63_384_f64, 11_f64, -150_f64, -1_220_f64, 0_f64, 29_f64],
+    ),
+    (
+        [0, 0, 2, 2, 2],
+        [-38_571_f64, -1_f64, 158_f64, 16_452_f64, -11_f64, 68_f64],
+    ),
+    (
+        [0, -2, 2, -2, 2],
+        [32_481_f64, 0_f64, 0_f64, -13_870_f64, 0_f64, 0_f64],
+    ),
+    (
+        [-2, 0, 0, 2, 0],
+        [-47_722_f64, 0_f64, -18_f64, 477_f64, 0_f64, -25_f64],
+    ),
+    (
+        [2, 0, 2, 0, 2],
+        [-31_046_f64, -1_f64, 131_f64, 13_238_f64, -11_f64, 59_f64],
+    ),
+    (
+        [1, 0, 2, -2, 2],
+        [28_593_f64, 0_f64, -1_f64, -12_338_f64, 10_f64, -3_f64],
+    ),
+    (
+        [-1, 0, 2, 0, 1],
+        [20_441_f64, 21_f64, 10_f64, -10_758_f64, 0_f64, -3_f64],
+    ),
+    (
+        [2, 0, 0, 0, 0],
+        [29_243_f64, 0_f64, -74_f64, -609_f64, 0_f64, 13_f64],
+    ),
+    (
+        [0, 0, 2, 0, 0],
+        [25_887_f64, 0_f64, -66_f64, -550_f64, 0_f64, 11_f64],
+    ),
+    (
+        [0, 1, 0, 0, 1],
+        [-14_053_f64, -25_f64, 79_f64, 8_551_f64, -2_f64, -45_f64],
+    ),
+    (
+        [-1, 0, 0, 2, 1],
+        [15_164_f64, 10_f64, 11_f64, -8_001_f64, 0_f64, -1_f64],
+    ),
+    (
+        [0, 2, 2, -2, 2],
+        [-15_794_f64, 72_f64, -16_f64, 6_850_f64, -42_f64, -5_f64],
+    ),
+    (
+        [0, 0, -2, 2, 0],
+        [21_783_f64, 0_f64, 13_f64, -167_f64, 0_f64, 13_f64],
+    ),
+    (
+        [1, 0, 0, -2, 1],
+        [-12_873_f64, -10_f64, -37_f64, 6_953_f64, 0_f64, -14_f64],
+    ),
+    (
+        [0, -1, 0, 0, 1],
+        [-12_654_f64, 11_f64, 63_f64, 6_415_f64, 0_f64, 26_f64],
+    ),
+    (
+        [-1, 0, 2, 2, 1],
+        [-10_204_f64, 0_f64, 25_f64, 5_222_f64, 0_f64, 15_f64],
+    ),
+    (
+        [0, 2, 0, 0, 0],
+        [16_707_f64, -85_f64, -10_f64, 168_f64, -1_f64, 10_f64],
+    ),
+    (
+        [1, 0, 2, 2, 2],
+        [-7_691_f64, 0_f64, 44_f64, 3_268_f64, 0_f64, 19_f64],
+    ),
+    (
+        [-2, 0, 2, 0, 0],
+        [-11_024_f64, 0_f64, -14_f64, 104_f64, 0_f64, 2_f64],
+    ),
+    (
+        [0, 1, 2, 0, 2],
+        [7_566_f64, -21_f64, -11_f64, -3_250_f64, 0_f64, -5_f64],
+    ),
+    (
+        [0, 0, 2, 2, 1],
+        [-6_637_f64, -11_f64, 25_f64, 3_353_f64, 0_f64, 14_f64],
+    ),
+    (
+        [0, -1, 2, 0, 2],
+        [-7_141_f64, 21_f64, 8_f64, 3_070_f64, 0_f64, 4_f64],
+    ),
+    (
+        [0, 0, 0, 2, 1],
+        [-6_302_f64, -11_f64, 2_f64, 3_272_f64, 0_f64, 4_f64],
+    ),
+    (
+        [1, 0, 2, -2, 1],
+        [5_800_f64, 10_f64, 2_f64, -3_045_f64, 0_f64, -1_f64],
+    ),
+    (
+        [2, 0, 2, -2, 2],
+        [6_443_f64, 0_f64, -7_f64, -2_768_f64, 0_f64, -4_f64],
+    ),
+    (
+        [-2, 0, 0, 2, 1],
+        [-5_774_f64, -11_f64, -15_f64, 3_041_f64, 0_f64, -5_f64],
+    ),
+    (
+        [2, 0, 2, 0, 1],
+        [-5_350_f64, 0_f64, 21_f64, 2_695_f64, 0_f64, 12_f64],
+    ),
+    (
+        [0, -1, 2, -2, 1],
+        [-4_752_f64, -11_f64, -3_f64, 2_719_f64, 0_f64, -3_f64],
+    ),
+    (
+        [0, 0, 0, -2, 1],
+        [-4_940_f64, -11_f64, -21_f64, 2_720_f64, 0_f64, -9_f64],
+    ),
+    (
+        [-1, -1, 0, 2, 0],
+        [7_350_f64, 0_f64, -8_f64, -51_f64, 0_f64, 4_f64],
+    ),
+    (
+        [2, 0, 0, -2, 1],
+        [4_065_f64, 0_f64, 6_f64, -2_206_f64, 0_f64, 1_f64],
+    ),
+    (
+        [1, 0, 0, 2, 0],
+        [6_579_f64, 0_f64, -24_f64, -199_f64, 0_f64, 2_f64],
+    ),
+    (
+        [0, 1, 2, -2, 1],
+        [3_579_f64, 0_f64, 5_f64, -1_900_f64, 0_f64, 1_f64],
+    ),
+    (
+        [1, -1, 0, 0, 0],
+        [4_725_f64, 0_f64, -6_f64, -41_f64, 0_f64, 3_f64],
+    ),
+    (
+        [-2, 0, 2, 0, 2],
+        [-3_075_f64, 0_f64, -2_f64, 1_313_f64, 0_f64, -1_f64],
+    ),
+    (
+        [3, 0, 2, 0, 2],
+        [-2_904_f64, 0_f64, 15_f64, 1_233_f64, 0_f64, 7_f64],
+    ),
+    (
+        [0, -1, 0, 2, 0],
+        [4_348_f64, 0_f64, -10_f64, -81_f64, 0_f64, 2_f64],
+    ),
+    (
+        [1, -1, 2, 0, 2],
+        [-2_878_f64, 0_f64, 8_f64, 1_232_f64, 0_f64, 4_f64],
+    ),
+    (
+        [0, 0, 0, 1, 0],
+        [-4_230_f64, 0_f64, 5_f64, -20_f64, 0_f64, -2_f64],
+    ),
+    (
+        [-1, -1, 2, 2, 2],
+        [-2_819_f64, 0_f64, 7_f64, 1_207_f64, 0_f64, 3_f64],
+    ),
+    (
+        [-1, 0, 2, 0, 0],
+        [-4_056_f64, 0_f64, 5_f64, 40_f64, 0_f64, -2_f64],
+    ),
+    (
+        [0, -1, 2, 2, 2],
+        [-2_647_f64, 0_f64, 11_f64, 1_129_f64, 0_f64, 5_f64],
+    ),
+    (
+        [-2, 0, 0, 0, 1],
+        [-2_294_f64, 0_f64, -10_f64, 1_266_f64, 0_f64, -4_f64],
+    ),
+    (
+        [1, 1, 2, 0, 2],
+        [2_481_f64, 0_f64, -7_f64, -1_062_f64, 0_f64, -3_f64],
+    ),
+    (
+        [2, 0, 0, 0, 1],
+        [2_179_f64, 0_f64, -2_f64, -1_129_f64, 0_f64, -2_f64],
+    ),
+    (
+        [-1, 1, 0, 1, 0],
+        [3_276_f64, 0_f64, 1_f64, -9_f64, 0_f64, 0_f64],
+    ),
+    (
+        [1, 1, 0, 0, 0],
+        [-3_389_f64, 0_f64, 5_f64, 35_f64, 0_f64, -2_f64],
+    ),
+    (
+        [1, 0, 2, 0, 0],
+        [3_339_f64, 0_f64, -13_f64, -107_f64, 0_f64, 1_f64],
+    ),
+    (
+        [-1, 0, 2, -2, 1],
+        [-1_987_f64, 0_f64, -6_f64, 1_073_f64, 0_f64, -2_f64],
+    ),
+    (
+        [1, 0, 0, 0, 2],
+        [-1_981_f64, 0_f64, 0_f64, 854_f64, 0_f64, 0_f64],
+    ),
+    (
+        [-1, 0, 0, 1, 0],
+        [4_026_f64, 0_f64, -353_f64, -553_f64, 0_f64, -139_f64],
+    ),
+    (
+        [0, 0, 2, 1, 2],
+        [1_660_f64, 0_f64, -5_f64, -710_f64, 0_f64, -2_f64],
+    ),
+    (
+        [-1, 0, 2, 4, 2],
+        [-1_521_f64, 0_f64, 9_f64, 647_f64, 0_f64, 4_f64],
+    ),
+    (
+        [-1, 1, 0, 1, 1],
+        [1_314_f64, 0_f64, 0_f64, -700_f64, 0_f64, 0_f64],
+    ),
+    (
+        [0, -2, 2, -2, 1],
+        [-1_283_f64, 0_f64, 0_f64, 672_f64, 0_f64, 0_f64],
+    ),
+    (
+        [1, 0, 2, 2, 1],
+        [-1_331_f64, 0_f64, 8_f64, 663_f64, 0_f64, 4_f64],
+    ),
+    (
+        [-2, 0, 2, 2, 2],
+        [1_383_f64, 0_f64, -2_f64, -594_f64, 0_f64, -2_f64],
+    ),
+    (
+        [-1, 0, 0, 0, 2],
+        [1_405_f64, 0_f64, 4_f64, -610_f64, 0_f64, 2_f64],
+    ),
+    (
+        [1, 1, 2, -2, 2],
+        [1_290_f64, 0_f64, 0_f64, -556_f64, 0_f64, 0_f64],
+    ),
+];
+
+/// Model of the nutation, the periodic motion of the Earth's axis caused by the Moon and the Sun.
+///
+/// The nutation is given by its components in longitude (*Δψ*), that moves the equinox along the
+/// ecliptic, and in obliquity (*Δε*), that changes the angle between the equator and the
+/// ecliptic. They take coordinates referred to the mean equator and equinox of the date, as given
+/// by the [`Precession`], to the true equator and equinox of the date. The angles are calculated
+/// from the Julian Day in Terrestrial Time (*TT*), and they are also used for the
+/// [apparent sidereal time](crate::SiderealTime::greenwich_apparent).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::Nutation;
+///
+/// // Example 22.a of Meeus, Astronomical Algorithms: 1987 April 10, 0h TD.
+/// let (longitude, obliquity) = Nutation::Iau1980.angles(2446895.5);
+/// assert!((longitude.to_degrees() * 3600.0 + 3.788).abs() < 0.001);
+/// assert!((obliquity.to_degrees() * 3600.0 - 9.443).abs() < 0.001);
+///
+/// let obliquity = Nutation::Iau1980.true_obliquity(2446895.5).to_degrees();
+/// assert!((obliquity - (23.0 + 26.0 / 60.0 + 36.850 / 3600.0)).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Nutation {
+    /// IAU 1980 nutation of Wahr (1981), used with the IAU 1976 precession. The bundled series has
+    /// the 63 terms with amplitudes of 0.0003″ or more, as given by Meeus, so it is accurate to
+    /// about 0.001″.
+    Iau1980,
+    /// IAU 2000B nutation, used with the IAU 2006 precession. It has the 77 largest luni-solar
+    /// terms of the IAU 2000A model and fixed offsets for the planetary terms, and it is accurate
+    /// to 1 milliarcsecond between 1995 and 2050.
+    #[default]
+    Iau2000B,
+}
+
+impl Nutation {
+    /// Calculates the nutation in longitude and in obliquity (*Δψ* and *Δε*) at the given Julian
+    /// Day in *TT*, in radians.
+    #[must_use]
+    pub fn angles(self, tt: impl Into<JulianDay>) -> (f64, f64) {
+        let tt = tt.into();
+
+        match self {
+            Self::Iau1980 => iau_1980(tt),
+            Self::Iau2000B => iau_2000b(tt),
+        }
+    }
+
+    /// Gets the precession model that the nutation model is used with.
+    #[must_use]
+    pub fn precession(self) -> Precession {
+        match self {
+            Self::Iau1980 => Precession::Iau1976,
+            Self::Iau2000B => Precession::Iau2006,
+        }
+    }
+
+    /// Calculates the mean obliquity of the ecliptic at the given Julian Day in *TT*, in radians,
+    /// with the precession model that the nutation model is used with.
+    ///
+    /// It is the angle between the mean equator and the ecliptic of the date.
+    #[must_use]
+    pub fn mean_obliquity(self, tt: impl Into<JulianDay>) -> f64 {
+        self.precession().mean_obliquity(tt)
+    }
+
+    /// Calculates the true obliquity of the ecliptic at the given Julian Day in *TT*, in radians.
+    ///
+    /// It is the angle between the true equator and the ecliptic of the date: the mean obliquity
+    /// plus the nutation in obliquity.
+    #[must_use]
+    pub fn true_obliquity(self, tt: impl Into<JulianDay>) -> f64 {
+        let tt = tt.into();
+        let (_, obliquity) = self.angles(tt);
+
+        self.mean_obliquity(tt) + obliquity
+    }
+
+    /// Calculates the rotation from the mean equator and equinox of the given date to its true
+    /// equator and equinox.
+    #[must_use]
+    pub fn equatorial(self, tt: impl Into<JulianDay>) -> Rotation {
+        let tt = tt.into();
+        let (longitude, obliquity) = self.angles(tt);
+        let mean_obliquity = self.mean_obliquity(tt);
+
+        Rotation::about_x(-(mean_obliquity + obliquity))
+            * Rotation::about_z(-longitude)
+            * Rotation::about_x(mean_obliquity)
+    }
+
+    /// Calculates the rotation from the ecliptic and mean equinox of the given date to its
+    /// ecliptic and true equinox.
+    ///
+    /// It only changes the longitude, by adding the nutation in longitude.
+    #[must_use]
+    pub fn ecliptic(self, tt: impl Into<JulianDay>) -> Rotation {
+        let (longitude, _) = self.angles(tt);
+
+        Rotation::about_z(-longitude)
+    }
+
+    /// Calculates the rotation from the ecliptic and mean equinox of the given date to its true
+    /// equator and equinox.
+    #[must_use]
+    pub fn ecliptic_to_true_equatorial(self, tt: impl Into<JulianDay>) -> Rotation {
+        let tt = tt.into();
+
+        Rotation::ecliptic_to_equatorial(self.true_obliquity(tt)) * self.ecliptic(tt)
+    }
+}
+
+impl RectangularCoordinates {
+    /// Rotates coordinates of the *VSOP87* ecliptic and equinox of the given date, as given by
+    /// *VSOP87C*, into the true equator and equinox of the date.
+    ///
+    /// The coordinates are first corrected to the *FK5* frame with
+    /// [`Rotation::vsop87_of_date_to_fk5()`], and then rotated with
+    /// [`Nutation::ecliptic_to_true_equatorial()`].
+    #[must_use]
+    pub fn to_true_equatorial(self, tt: impl Into<JulianDay>, nutation: Nutation) -> Self {
+        let tt = tt.into();
+
+        nutation.ecliptic_to_true_equatorial(tt) * Rotation::vsop87_of_date_to_fk5(tt) * self
+    }
+}
+
+impl SphericalCoordinates {
+    /// Rotates coordinates of the *VSOP87* ecliptic and equinox of the given date, as given by
+    /// *VSOP87D*, into the true equator and equinox of the date.
+    ///
+    /// The longitude and latitude of the result are the right ascension and the declination. The
+    /// coordinates are first corrected to the *FK5* frame with
+    /// [`Rotation::vsop87_of_date_to_fk5()`], and then rotated with
+    /// [`Nutation::ecliptic_to_true_equatorial()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "vsop87d", feature = "mars"))]
+    /// # {
+    /// use vsop87::{vsop87d, Nutation};
+    ///
+    /// let tt = 2460000.5;
+    /// let mars = vsop87d::mars(tt).to_true_equatorial(tt, Nutation::Iau2000B);
+    /// assert!((mars.distance() - vsop87d::mars(tt).distance()).abs() < 1e-12);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_true_equatorial(self, tt: impl Into<JulianDay>, nutation: Nutation) -> Self {
+        let tt = tt.into();
+
+        nutation.ecliptic_to_true_equatorial(tt) * Rotation::vsop87_of_date_to_fk5(tt) * self
+    }
+}
+
+/// Calculates the Delaunay arguments *l*, *l'*, *F*, *D* and *Ω* of the IAU 2000B nutation, in
+/// radians, given the Julian centuries since J2000.0 in *TT*.
+pub(crate) fn delaunay_arguments(t: f64) -> [f64; 5] {
+    [
+        485_868.249_036 + 1_717_915_923.217_8 * t,
+        1_287_104.793_05 + 129_596_581.048_1 * t,
+        335_779.526_232 + 1_739_527_262.847_8 * t,
+        1_072_260.703_69 + 1_602_961_601.209 * t,
+        450_160.398_036 - 6_962_890.543_1 * t,
+    ]
+    .map(|argument| argument % TURN * ARCSECOND)
+}
+
+/// Calculates the nutation in longitude and in obliquity (*Δψ* and *Δε*) of the IAU 1980 model at
+/// the given Julian Day in *TT*, in radians.
+fn iau_1980(tt: JulianDay) -> (f64, f64) {
+    let t = tt.julian_centuries();
+    let arguments = [
+        297.850_36 + 445_267.111_48 * t - 0.001_914_2 * t * t + t * t * t / 189_474_f64,
+        357.527_72 + 35_999.050_34 * t - 0.000_160_3 * t * t - t * t * t / 300_000_f64,
+        134.962_98 + 477_198.867_398 * t + 0.008_697_2 * t * t + t * t * t / 56_250_f64,
+        93.271_91 + 483_202.017_538 * t - 0.003_682_5 * t * t + t * t * t / 327_270_f64,
+        125.044_52 - 1_934.136_261 * t + 0.002_070_8 * t * t + t * t * t / 450_000_f64,
+    ]
+    .map(f64::to_radians);
+
+    let (longitude, obliquity) = IAU_1980.iter().fold(
+        (0_f64, 0_f64),
+        |(longitude, obliquity), (multipliers, [ps, pst, ec, ect])| {
+            let argument = angle(*multipliers, &arguments);
+            #[cfg(not(feature = "no_std"))]
+            let (sin, cos) = argument.sin_cos();
+            #[cfg(feature = "no_std")]
+            let (sin, cos) = sincos(argument);
+
+            (
+                longitude + (ps + pst * t) * sin,
+                obliquity + (ec + ect * t) * cos,
+            )
+        },
+    );
+
+    (
+        longitude * 0.000_1 * ARCSECOND,
+        obliquity * 0.000_1 * ARCSECOND,
+    )
+}
+
+/// Calculates the nutation in longitude and in obliquity (*Δψ* and *Δε*) of the IAU 2000B model at
+/// the given Julian Day in *TT*, in radians.
+fn iau_2000b(tt: JulianDay) -> (f64, f64) {
+    let t = tt.julian_centuries();
+    let arguments = delaunay_arguments(t);
+
+    // The smallest terms are added first, to reduce the rounding errors.
+    let (longitude, obliquity) = IAU_2000B.iter().rev().fold(
+        (0_f64, 0_f64),
+        |(longitude, obliquity), (multipliers, [ps, pst, pc, ec, ect, es])| {
+            let argument = angle(*multipliers, &arguments);
+            #[cfg(not(feature = "no_std"))]
+            let (sin, cos) = argument.sin_cos();
+            #[cfg(feature = "no_std")]
+            let (sin, cos) = sincos(argument);
+
+            (
+                longitude + (ps + pst * t) * sin + pc * cos,
+                obliquity + (ec + ect * t) * cos + es * sin,
+            )
+        },
+    );
+
+    let (longitude_offset, obliquity_offset) = IAU_2000B_OFFSETS;
+    (
+        (longitude * 1e-7 + longitude_offset) * ARCSECOND,
+        (obliquity * 1e-7 + obliquity_offset) * ARCSECOND,
+    )
+}
+
+/// Calculates the argument of a term, given its multipliers and the fundamental arguments.
+pub(crate) fn angle(multipliers: [i8; 5], arguments: &[f64; 5]) -> f64 {
+    multipliers
+        .iter()
+        .zip(arguments)
+        .map(|(&multiplier, argument)| f64::from(multiplier) * argument)
+        .sum()
+}
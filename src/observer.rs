@@ -0,0 +1,207 @@
+//! Observers on the surface of the Earth.
+
+use super::{
+    typed::{Geocentric, Icrs, State},
+    JulianDay, Nutation, RectangularCoordinates, Rotation, SiderealTime, StateVector,
+};
+
+#[cfg(feature = "no_std")]
+use core::f64::consts::TAU;
+#[cfg(feature = "no_std")]
+use libm::{sincos, sqrt};
+
+#[cfg(not(feature = "no_std"))]
+use std::f64::consts::TAU;
+
+/// Astronomical unit, in meters.
+const ASTRONOMICAL_UNIT: f64 = 149_597_870_700_f64;
+
+/// Rotation rate of the Earth, in radians per day of *UT1*.
+const ROTATION_RATE: f64 = TAU * 1.002_737_811_911_354_6;
+
+/// Reference ellipsoid of the Earth, that geodetic coordinates are referred to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Ellipsoid {
+    /// World Geodetic System 1984, used by GPS.
+    #[default]
+    Wgs84,
+    /// Geodetic Reference System 1980, used by the ITRF and most national datums. It differs from
+    /// WGS84 by less than a millimeter.
+    Grs80,
+}
+
+impl Ellipsoid {
+    /// Gets the equatorial radius of the ellipsoid, in meters.
+    #[must_use]
+    pub fn equatorial_radius(self) -> f64 {
+        match self {
+            Self::Wgs84 | Self::Grs80 => 6_378_137_f64,
+        }
+    }
+
+    /// Gets the flattening of the ellipsoid.
+    #[must_use]
+    pub fn flattening(self) -> f64 {
+        match self {
+            Self::Wgs84 => 1_f64 / 298.257_223_563,
+            Self::Grs80 => 1_f64 / 298.257_222_101,
+        }
+    }
+}
+
+/// Observer on the surface of the Earth, given by its geodetic coordinates.
+///
+/// The latitude and the longitude are given in radians, the longitude positive towards the east,
+/// and the height above the ellipsoid in meters. The polar motion, that moves the terrestrial
+/// frame by less than 15 meters, is neglected.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{Ellipsoid, Observer};
+///
+/// let observer = Observer::new(-0.5, 3.1, 2_500.0).with_ellipsoid(Ellipsoid::Grs80);
+/// let coordinates = observer.terrestrial_coordinates();
+///
+/// assert!((coordinates.x + 5_599_000.557_726).abs() < 1e-6);
+/// assert!((coordinates.y - 233_011.672_236).abs() < 1e-6);
+/// assert!((coordinates.z + 3_040_909.470_610).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Observer {
+    latitude: f64,
+    longitude: f64,
+    height: f64,
+    ellipsoid: Ellipsoid,
+}
+
+impl Observer {
+    /// Creates an observer from its geodetic latitude and longitude, in radians, and its height
+    /// above the WGS84 ellipsoid, in meters.
+    #[must_use]
+    pub const fn new(latitude: f64, longitude: f64, height: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            height,
+            ellipsoid: Ellipsoid::Wgs84,
+        }
+    }
+
+    /// Sets the ellipsoid that the coordinates are referred to.
+    #[must_use]
+    pub fn with_ellipsoid(self, ellipsoid: Ellipsoid) -> Self {
+        Self { ellipsoid, ..self }
+    }
+
+    /// Gets the geodetic latitude, in radians.
+    #[must_use]
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Gets the longitude, in radians, positive towards the east.
+    #[must_use]
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Gets the height above the ellipsoid, in meters.
+    #[must_use]
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Gets the ellipsoid that the coordinates are referred to.
+    #[must_use]
+    pub fn ellipsoid(&self) -> Ellipsoid {
+        self.ellipsoid
+    }
+
+    /// Calculates the geocentric rectangular coordinates of the observer in the terrestrial frame,
+    /// in meters.
+    ///
+    /// The X axis points to the intersection of the equator and the Greenwich meridian, and the Z
+    /// axis to the north pole.
+    #[must_use]
+    pub fn terrestrial_coordinates(&self) -> RectangularCoordinates {
+        let radius = self.ellipsoid.equatorial_radius();
+        let flattening = self.ellipsoid.flattening();
+        let eccentricity_squared = flattening * (2_f64 - flattening);
+
+        #[cfg(not(feature = "no_std"))]
+        let ((sin_lat, cos_lat), (sin_lon, cos_lon)) =
+            (self.latitude.sin_cos(), self.longitude.sin_cos());
+        #[cfg(feature = "no_std")]
+        let ((sin_lat, cos_lat), (sin_lon, cos_lon)) =
+            (sincos(self.latitude), sincos(self.longitude));
+
+        // Radius of curvature in the prime vertical.
+        #[cfg(not(feature = "no_std"))]
+        let normal = radius / (1_f64 - eccentricity_squared * sin_lat * sin_lat).sqrt();
+        #[cfg(feature = "no_std")]
+        let normal = radius / sqrt(1_f64 - eccentricity_squared * sin_lat * sin_lat);
+
+        let projection = (normal + self.height) * cos_lat;
+        RectangularCoordinates {
+            x: projection * cos_lon,
+            y: projection * sin_lon,
+            z: (normal * (1_f64 - eccentricity_squared) + self.height) * sin_lat,
+        }
+    }
+
+    /// Calculates the position and the velocity of the observer relative to the center of the
+    /// Earth, in the *ICRS*, in *AU* and *AU* per day.
+    ///
+    /// The terrestrial coordinates are rotated with the Greenwich apparent sidereal time, the
+    /// nutation and the precession of the given model, at the given Julian Days in *UT1* and *TT*.
+    /// The velocity is the one due to the rotation of the Earth.
+    #[must_use]
+    pub fn state(
+        &self,
+        ut1: impl Into<JulianDay>,
+        tt: impl Into<JulianDay>,
+        nutation: Nutation,
+    ) -> State<Icrs, Geocentric> {
+        let tt = tt.into();
+        let RectangularCoordinates { x, y, z } = self.terrestrial_coordinates();
+        let terrestrial = StateVector {
+            position: RectangularCoordinates {
+                x: x / ASTRONOMICAL_UNIT,
+                y: y / ASTRONOMICAL_UNIT,
+                z: z / ASTRONOMICAL_UNIT,
+            },
+            velocity: RectangularCoordinates {
+                x: -ROTATION_RATE * y / ASTRONOMICAL_UNIT,
+                y: ROTATION_RATE * x / ASTRONOMICAL_UNIT,
+                z: 0_f64,
+            },
+        };
+
+        let rotation = Rotation::FRAME_BIAS
+            * nutation.precession().equatorial(tt, JulianDay::J2000)
+            * nutation.equatorial(tt).inverse()
+            * Rotation::about_z(-sidereal_time(nutation).greenwich_apparent(ut1, tt));
+        State::new(rotation * terrestrial)
+    }
+
+    /// Calculates the local apparent sidereal time of the observer, in radians, at the given Julian
+    /// Days in *UT1* and *TT*, with the sidereal time model consistent with the nutation model.
+    #[must_use]
+    pub fn local_sidereal_time(
+        &self,
+        ut1: impl Into<JulianDay>,
+        tt: impl Into<JulianDay>,
+        nutation: Nutation,
+    ) -> f64 {
+        sidereal_time(nutation).local_apparent(ut1, tt, self.longitude)
+    }
+}
+
+/// Gets the sidereal time model consistent with the nutation model.
+fn sidereal_time(nutation: Nutation) -> SiderealTime {
+    match nutation {
+        Nutation::Iau1980 => SiderealTime::Iau1982,
+        Nutation::Iau2000B => SiderealTime::Iau2006,
+    }
+}
@@ -0,0 +1,218 @@
+//! Precession of the equator and the ecliptic.
+
+use super::{nutation::ARCSECOND, polynomial, JulianDay, Rotation};
+
+/// Coefficients of the precession angles *ζ*, *z* and *θ* of the equator of Lieske et al. (1977),
+/// in arcseconds, in increasing powers of the Julian centuries since J2000.0.
+const IAU_1976_EQUATOR: [&[f64]; 3] = [
+    &[0_f64, 2_306.218_1, 0.301_88, 0.017_998],
+    &[0_f64, 2_306.218_1, 1.094_68, 0.018_203],
+    &[0_f64, 2_004.310_9, -0.426_65, -0.041_833],
+];
+
+/// Coefficients of the precession angles *π*, *Π* and *p* of the ecliptic of Lieske et al.
+/// (1977), in arcseconds, in increasing powers of the Julian centuries since J2000.0.
+const IAU_1976_ECLIPTIC: [&[f64]; 3] = [
+    &[0_f64, 47.002_9, -0.033_02, 0.000_06],
+    &[629_554.982_4, -869.808_9, 0.035_36],
+    &[0_f64, 5_029.096_6, 1.111_13, -0.000_006],
+];
+
+/// Coefficients of the precession angles *ζ*, *z* and *θ* of the equator of Capitaine et al.
+/// (2003), in arcseconds, in increasing powers of the Julian centuries since J2000.0.
+const IAU_2006_EQUATOR: [&[f64]; 3] = [
+    &[
+        2.650_545,
+        2_306.083_227,
+        0.298_849_9,
+        0.018_018_28,
+        -0.000_005_971,
+        -0.000_000_317_3,
+    ],
+    &[
+        -2.650_545,
+        2_306.077_181,
+        1.092_734_8,
+        0.018_268_37,
+        -0.000_028_596,
+        -0.000_000_290_4,
+    ],
+    &[
+        0_f64,
+        2_004.191_903,
+        -0.429_493_4,
+        -0.041_822_64,
+        -0.000_007_089,
+        -0.000_000_127_4,
+    ],
+];
+
+/// Coefficients of the precession angles *π*, *Π* and *p* of the ecliptic of Capitaine et al.
+/// (2003), in arcseconds, in increasing powers of the Julian centuries since J2000.0.
+const IAU_2006_ECLIPTIC: [&[f64]; 3] = [
+    &[
+        0_f64,
+        46.998_973,
+        -0.033_492_6,
+        -0.000_125_59,
+        0.000_000_113,
+        -0.000_000_002_2,
+    ],
+    &[
+        629_546.793_6,
+        -867.957_58,
+        0.157_992,
+        -0.000_537_1,
+        -0.000_047_97,
+        0.000_000_072,
+    ],
+    &[
+        0_f64,
+        5_028.796_195,
+        1.105_434_8,
+        0.000_079_64,
+        -0.000_023_857,
+        -0.000_000_038_3,
+    ],
+];
+
+/// Model of the precession, the slow motion of the equator and the ecliptic, and of the equinox
+/// at their intersection.
+///
+/// The precession rotates coordinates between the mean equators, or the ecliptics, of two dates,
+/// that are given as Julian Days in Terrestrial Time (*TT*). Unlike the precession built into
+/// *VSOP87C* and *VSOP87D*, it can be applied to the coordinates of any solution and body, or to
+/// the coordinates of a star catalog. The equatorial precession refers to the mean equator and
+/// equinox J2000.0, so coordinates in the *ICRS* need the inverse of the
+/// [frame bias](Rotation::FRAME_BIAS) first.
+///
+/// The precession of *VSOP87C* and *VSOP87D* follows the rate of the IAU 1976 model, so the IAU
+/// 1976 precession of *VSOP87A* coordinates reproduces them to 0.02″ between 1800 and 2200,
+/// while the IAU 2006 precession, with a rate corrected by −0.3″ per century, departs from them
+/// by about 0.3″ per century.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "vsop87a", feature = "vsop87c", feature = "mars"))]
+/// # {
+/// use vsop87::{vsop87a, vsop87c, JulianDay, Precession};
+///
+/// let tt: JulianDay = "2100-01-01T00:00:00".parse()?;
+/// let precession = Precession::Iau1976.ecliptic(JulianDay::J2000, tt);
+///
+/// // The position of Mars, referred to the ecliptic and equinox of the date.
+/// let mars = vsop87a::mars(tt).rotate(precession);
+/// let expected = vsop87c::mars(tt);
+/// assert!((mars.x - expected.x).abs() < 1e-7);
+/// assert!((mars.y - expected.y).abs() < 1e-7);
+/// assert!((mars.z - expected.z).abs() < 1e-7);
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Precession {
+    /// IAU 1976 precession of Lieske et al. (1977), used with the *FK5* catalog and the IAU 1980
+    /// nutation.
+    Iau1976,
+    /// IAU 2006 precession of Capitaine et al. (2003), known as *P03*, used with the IAU 2000
+    /// nutation. It is accurate to a few milliarcseconds over several centuries.
+    #[default]
+    Iau2006,
+}
+
+impl Precession {
+    /// Calculates the rotation from the mean equator and equinox of one date to the mean equator
+    /// and equinox of another date.
+    #[must_use]
+    pub fn equatorial(self, from: impl Into<JulianDay>, to: impl Into<JulianDay>) -> Rotation {
+        self.equatorial_from_j2000(to.into()) * self.equatorial_from_j2000(from.into()).inverse()
+    }
+
+    /// Calculates the rotation from the ecliptic and mean equinox of one date to the ecliptic and
+    /// mean equinox of another date.
+    #[must_use]
+    pub fn ecliptic(self, from: impl Into<JulianDay>, to: impl Into<JulianDay>) -> Rotation {
+        self.ecliptic_from_j2000(to.into()) * self.ecliptic_from_j2000(from.into()).inverse()
+    }
+
+    /// Calculates the mean obliquity of the ecliptic at the given Julian Day in *TT*, in radians.
+    ///
+    /// It is the angle between the mean equator and the ecliptic of the date.
+    #[must_use]
+    pub fn mean_obliquity(self, tt: impl Into<JulianDay>) -> f64 {
+        let tt = tt.into();
+
+        match self {
+            Self::Iau1976 => mean_obliquity_iau_1980(tt),
+            Self::Iau2006 => mean_obliquity_iau_2006(tt),
+        }
+    }
+
+    /// Calculates the rotation from the ecliptic and mean equinox of the given date to its mean
+    /// equator and equinox.
+    #[must_use]
+    pub fn ecliptic_to_equatorial(self, tt: impl Into<JulianDay>) -> Rotation {
+        Rotation::ecliptic_to_equatorial(self.mean_obliquity(tt))
+    }
+
+    /// Calculates the rotation from the mean equator and equinox J2000.0 to those of the date.
+    fn equatorial_from_j2000(self, tt: JulianDay) -> Rotation {
+        let [zeta, z, theta] = angles(tt, self.equator_coefficients());
+
+        Rotation::about_z(-z) * Rotation::about_y(theta) * Rotation::about_z(-zeta)
+    }
+
+    /// Calculates the rotation from the ecliptic and equinox J2000.0 to those of the date.
+    fn ecliptic_from_j2000(self, tt: JulianDay) -> Rotation {
+        // Inclination of the ecliptic of the date, longitude of its ascending node on the
+        // ecliptic J2000.0, and general precession in longitude.
+        let [inclination, node, general] = angles(tt, self.ecliptic_coefficients());
+
+        Rotation::about_z(-(node + general))
+            * Rotation::about_x(inclination)
+            * Rotation::about_z(node)
+    }
+
+    /// Gets the coefficients of the precession angles of the equator.
+    fn equator_coefficients(self) -> [&'static [f64]; 3] {
+        match self {
+            Self::Iau1976 => IAU_1976_EQUATOR,
+            Self::Iau2006 => IAU_2006_EQUATOR,
+        }
+    }
+
+    /// Gets the coefficients of the precession angles of the ecliptic.
+    fn ecliptic_coefficients(self) -> [&'static [f64]; 3] {
+        match self {
+            Self::Iau1976 => IAU_1976_ECLIPTIC,
+            Self::Iau2006 => IAU_2006_ECLIPTIC,
+        }
+    }
+}
+
+/// Calculates the mean obliquity of the ecliptic of the IAU 1980 model at the given Julian Day in
+/// *TT*, in radians.
+fn mean_obliquity_iau_1980(tt: JulianDay) -> f64 {
+    let t = tt.julian_centuries();
+
+    (84_381.448 + (-46.815 + (-0.000_59 + 0.001_813 * t) * t) * t) * ARCSECOND
+}
+
+/// Calculates the mean obliquity of the ecliptic of the IAU 2006 model at the given Julian Day in
+/// *TT*, in radians.
+fn mean_obliquity_iau_2006(tt: JulianDay) -> f64 {
+    let t = tt.julian_centuries();
+
+    (84_381.406
+        + (-46.836_769
+            + (-0.000_183_1 + (0.002_003_40 + (-0.000_000_576 - 0.000_000_043_4 * t) * t) * t) * t)
+            * t)
+        * ARCSECOND
+}
+
+/// Calculates three precession angles at the given Julian Day, in radians.
+fn angles(tt: JulianDay, coefficients: [&[f64]; 3]) -> [f64; 3] {
+    let t = tt.julian_centuries();
+    coefficients.map(|coefficients| polynomial(coefficients, t) * ARCSECOND)
+}
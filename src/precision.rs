@@ -0,0 +1,146 @@
+//! Truncation of the *VSOP87* series.
+
+use super::{calculate_t, calculate_var_truncated, Body, JulianDay, Series};
+
+#[cfg(feature = "no_std")]
+use libm::sqrt;
+
+/// Precision used when calculating a *VSOP87* solution.
+///
+/// The full solution evaluates thousands of terms for each body, which gives a precision far
+/// beyond what many applications need. Terms with small amplitudes can be skipped to speed up the
+/// calculation, either by giving the amplitude threshold directly or by giving the target accuracy
+/// of the result.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Precision {
+    /// Full solution: all terms are calculated.
+    #[default]
+    Full,
+    /// Skip the terms whose amplitude is below the given threshold.
+    ///
+    /// The threshold is given in the units of each variable: *AU* for distances and rectangular
+    /// coordinates, radians for angles. For the terms multiplied by a power of the time, the
+    /// amplitude is multiplied by that power too.
+    Amplitude(f64),
+    /// Skip terms so that the result has approximately the given accuracy, in arcseconds.
+    ///
+    /// The angle is seen at the mean distance of the body from the Sun, the semimajor axis of its
+    /// orbit, and at 1 *AU* for the Sun, as seen from the Earth. For the barycentric position of
+    /// the Sun in *VSOP87E*, which is within 0.01 *AU* of the barycenter, the accuracy is thus
+    /// much coarser as seen from the barycenter.
+    Arcseconds(f64),
+    /// Skip terms so that the result has approximately the given accuracy, in *AU*.
+    AstronomicalUnits(f64),
+}
+
+/// Result of a truncated calculation, along with the amplitude thresholds actually used.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Truncated<T> {
+    /// Result of the calculation.
+    pub value: T,
+    /// Amplitude threshold used for each of the variables of the result, in its units.
+    ///
+    /// The threshold applies to the terms multiplied by their power of the time `t`, in Julian
+    /// millennia from J2000.0, so the series multiplying `t^n` is cut at `threshold / |t|^n`. Each
+    /// series is cut after its last term reaching that amplitude, so the terms skipped are all
+    /// below it. A threshold of `0.0` means that no term has been skipped.
+    pub thresholds: T,
+}
+
+/// Kind of a variable of a *VSOP87* solution, that defines its units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Variable {
+    /// The variable is an angle, in radians.
+    #[cfg(any(feature = "elements", feature = "vsop87b", feature = "vsop87d"))]
+    Angle,
+    /// The variable is a distance, in *AU*.
+    Distance,
+}
+
+impl Precision {
+    /// Calculates the amplitude threshold for the given variable.
+    ///
+    /// The error of a series truncated at an amplitude `ε` is approximately `2ε√n`, being `n` the
+    /// number of terms of the series. The number of terms of the full variable is used, so the
+    /// resulting accuracy is conservative.
+    fn threshold(self, variable: Variable, distance: f64, series: &[Series]) -> f64 {
+        let accuracy = match (self, variable) {
+            (Self::Full, _) => return 0_f64,
+            (Self::Amplitude(threshold), _) => return threshold,
+            #[cfg(any(feature = "elements", feature = "vsop87b", feature = "vsop87d"))]
+            (Self::Arcseconds(arcseconds), Variable::Angle) => {
+                (arcseconds / 3_600_f64).to_radians()
+            }
+            (Self::Arcseconds(arcseconds), Variable::Distance) => {
+                (arcseconds / 3_600_f64).to_radians() * distance
+            }
+            #[cfg(any(feature = "elements", feature = "vsop87b", feature = "vsop87d"))]
+            (Self::AstronomicalUnits(au), Variable::Angle) => au / distance,
+            (Self::AstronomicalUnits(au), Variable::Distance) => au,
+        };
+
+        let terms = series.iter().map(|[a, _, _]| a.len()).sum::<usize>() as f64;
+
+        #[cfg(feature = "no_std")]
+        {
+            accuracy / (2_f64 * sqrt(terms))
+        }
+
+        #[cfg(not(feature = "no_std"))]
+        {
+            accuracy / (2_f64 * terms.sqrt())
+        }
+    }
+}
+
+/// Gets the distance used to convert between angular and linear accuracies for a body, in *AU*.
+///
+/// This is the semimajor axis of the orbit of planets. For the Sun, the distance to the Earth is
+/// used, since its position is usually observed from there.
+pub(crate) fn reference_distance(body: Body) -> f64 {
+    match body {
+        Body::Sun | Body::Earth | Body::EarthMoon => 1_f64,
+        Body::Mercury => 0.387_098,
+        Body::Venus => 0.723_332,
+        Body::Mars => 1.523_679,
+        Body::Jupiter => 5.2044,
+        Body::Saturn => 9.5826,
+        Body::Uranus => 19.2184,
+        Body::Neptune => 30.110_387,
+    }
+}
+
+/// Calculates the given variables, skipping the terms below the threshold of each of them.
+///
+/// Returns the values of the variables and the thresholds used for each of them.
+pub(crate) fn calculate_truncated<const N: usize>(
+    jde: JulianDay,
+    body: Body,
+    variables: &[&[Series]; N],
+    kinds: [Variable; N],
+    precision: Precision,
+) -> ([f64; N], [f64; N]) {
+    let t = calculate_t(jde);
+    let distance = reference_distance(body);
+
+    let mut values = [0_f64; N];
+    let mut thresholds = [0_f64; N];
+
+    for (((series, kind), value), threshold) in variables
+        .iter()
+        .zip(kinds)
+        .zip(&mut values)
+        .zip(&mut thresholds)
+    {
+        *threshold = precision.threshold(kind, distance, series);
+
+        let mut power = 1_f64;
+        for [a, b, c] in *series {
+            // The amplitude of the terms is multiplied by the power of `t`.
+            *value += calculate_var_truncated(t, a, b, c, *threshold / power.abs()) * power;
+            power *= t;
+        }
+    }
+
+    (values, thresholds)
+}
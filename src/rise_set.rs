@@ -0,0 +1,382 @@
+//! Rising, setting and transit times of the bodies.
+
+use super::{
+    horizontal_coordinates, Body, DeltaT, Error, HorizontalCoordinates, JulianDay, Nutation,
+    Observer, Refraction,
+};
+
+#[cfg(feature = "no_std")]
+use core::f64::consts::{PI, TAU};
+
+#[cfg(not(feature = "no_std"))]
+use std::f64::consts::{PI, TAU};
+
+/// Number of positions calculated per day to find the events, one per hour.
+const SAMPLES_PER_DAY: usize = 24;
+
+/// Maximum number of iterations to refine the time of an event.
+const ITERATIONS: usize = 20;
+
+/// Convergence threshold of the time of an event, in days (about 10 milliseconds).
+const TOLERANCE: f64 = 1e-7;
+
+/// Maximum number of extremes of the altitude during a day: the start and the end of the day, and
+/// up to two upper and two lower transits, since a sidereal day is shorter than a day.
+const EXTREMES: usize = 6;
+
+/// Altitude of the horizon that the rising and setting times are referred to.
+///
+/// The altitude is the geometric altitude of the center of the body, so it includes the
+/// refraction at the horizon, of about 34′, and the semidiameter of the body.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Horizon {
+    /// Standard altitude of the stars and the planets, −0.5667° (−34′), the refraction at the
+    /// horizon.
+    #[default]
+    Standard,
+    /// Standard altitude of the Sun, −0.8333° (−50′), the refraction at the horizon and the
+    /// semidiameter of the Sun, so that the rising and setting times are those of its upper limb.
+    Sun,
+    /// Custom altitude, in radians.
+    Custom(f64),
+}
+
+impl Horizon {
+    /// Gets the geometric altitude of the horizon, in radians.
+    #[must_use]
+    pub fn altitude(self) -> f64 {
+        match self {
+            Self::Standard => -(34_f64 / 60_f64).to_radians(),
+            Self::Sun => -(50_f64 / 60_f64).to_radians(),
+            Self::Custom(altitude) => altitude,
+        }
+    }
+}
+
+/// Whether a body crosses the horizon of an observer during a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    /// The body rises or sets during the day.
+    CrossesHorizon,
+    /// The body stays above the horizon during the whole day, like the Sun in the polar summer.
+    Circumpolar,
+    /// The body stays below the horizon during the whole day, like the Sun in the polar winter.
+    NeverRises,
+}
+
+/// Rising, setting and transit times of a body during a day, as Julian Days in *UT1*.
+///
+/// Each time is the first event of its kind during the day, and it is `None` if there is no such
+/// event: a body that rises and sets has no rising or no setting time on some days, since the
+/// events happen about 4 minutes earlier each day for the stars, and the transits of a planet can
+/// also slip out of the day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RiseTransitSet {
+    rise: Option<JulianDay>,
+    upper_transit: Option<JulianDay>,
+    set: Option<JulianDay>,
+    lower_transit: Option<JulianDay>,
+    visibility: Visibility,
+}
+
+impl RiseTransitSet {
+    /// Gets the time when the body rises above the horizon, if any.
+    #[must_use]
+    pub fn rise(&self) -> Option<JulianDay> {
+        self.rise
+    }
+
+    /// Gets the time of the upper transit, when the body crosses the meridian at its highest
+    /// altitude, if any.
+    #[must_use]
+    pub fn upper_transit(&self) -> Option<JulianDay> {
+        self.upper_transit
+    }
+
+    /// Gets the time when the body sets below the horizon, if any.
+    #[must_use]
+    pub fn set(&self) -> Option<JulianDay> {
+        self.set
+    }
+
+    /// Gets the time of the lower transit, when the body crosses the meridian at its lowest
+    /// altitude, if any.
+    #[must_use]
+    pub fn lower_transit(&self) -> Option<JulianDay> {
+        self.lower_transit
+    }
+
+    /// Gets whether the body crosses the horizon during the day, or stays above or below it.
+    #[must_use]
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+}
+
+/// Calculates the rising, setting and transit times of a body for an observer on the surface of
+/// the Earth, during the day that starts at the given Julian Day in *UT1*.
+///
+/// The day starts at 0h *UT1* for a Julian Day ending in .5, and it can be shifted by the time
+/// zone of the observer to get the events of a local day. The positions of the body are the
+/// [horizontal coordinates](horizontal_coordinates()) of its apparent topocentric place,
+/// calculated with the IAU 2000B nutation every hour, and with *TT* estimated with the given
+/// *ΔT* model. The transits are found between those positions, and the rising and setting times
+/// between the transits, where the altitude is highest and lowest, so that a body that stays
+/// above or below the horizon for less than an hour is not missed. All the events are refined to
+/// 10 milliseconds, well below the uncertainty of the refraction at the horizon, of a few minutes
+/// of time at most.
+///
+/// It returns the same errors as [`geocentric_place()`](crate::geocentric_place).
+///
+/// # Example
+///
+/// Example 15.a of Meeus, Astronomical Algorithms: Venus seen from Boston on 1988 March 20.
+///
+/// ```
+/// # #[cfg(feature = "venus")]
+/// # {
+/// use vsop87::{rise_transit_set, Body, DeltaT, Horizon, JulianDay, Observer, Visibility};
+///
+/// let boston = Observer::new(42.333_3_f64.to_radians(), -71.083_3_f64.to_radians(), 0.0);
+/// let date = JulianDay::from_gregorian(1988, 3, 20)?;
+///
+/// let venus = rise_transit_set(
+///     Body::Venus,
+///     boston,
+///     date,
+///     DeltaT::EspenakMeeus,
+///     Horizon::Standard,
+/// )?;
+/// assert_eq!(venus.visibility(), Visibility::CrossesHorizon);
+///
+/// // Rising at 12h25m, transit at 19h41m and setting at 2h55m UT.
+/// let hours = |event: Option<JulianDay>| event.map(|jd| (jd - date) * 24.0).unwrap();
+/// assert!((hours(venus.rise()) - 12.424).abs() < 0.01);
+/// assert!((hours(venus.upper_transit()) - 19.675).abs() < 0.01);
+/// assert!((hours(venus.set()) - 2.911).abs() < 0.01);
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+pub fn rise_transit_set(
+    body: Body,
+    observer: Observer,
+    date: impl Into<JulianDay>,
+    delta_t: DeltaT,
+    horizon: Horizon,
+) -> Result<RiseTransitSet, Error> {
+    let position = |ut1: JulianDay| {
+        horizontal_coordinates(
+            body,
+            observer,
+            ut1,
+            delta_t.tt(ut1),
+            Nutation::Iau2000B,
+            Refraction::None,
+        )
+    };
+    let samples = samples(&position, date.into())?;
+    let extremes = extremes(&samples, &position)?;
+
+    let altitude = horizon.altitude();
+    let above = |position: &HorizontalCoordinates| position.geometric_altitude() - altitude;
+    let rise = search(extremes.points(), &position, above, true)?;
+    let set = search(extremes.points(), &position, above, false)?;
+
+    Ok(RiseTransitSet {
+        rise,
+        upper_transit: extremes.upper_transit,
+        set,
+        lower_transit: extremes.lower_transit,
+        visibility: visibility(extremes.points(), rise, set, above),
+    })
+}
+
+/// Calculates the positions of a body every hour, from the start to the end of the day.
+pub(crate) fn samples<F>(
+    position: &F,
+    start: JulianDay,
+) -> Result<[(JulianDay, HorizontalCoordinates); SAMPLES_PER_DAY + 1], Error>
+where
+    F: Fn(JulianDay) -> Result<HorizontalCoordinates, Error>,
+{
+    let first = position(start)?;
+    let mut samples = [(start, first); SAMPLES_PER_DAY + 1];
+    for (i, sample) in samples.iter_mut().enumerate().skip(1) {
+        let time = start + i as f64 / SAMPLES_PER_DAY as f64;
+        *sample = (time, position(time)?);
+    }
+    Ok(samples)
+}
+
+/// Positions of a body at the extremes of its altitude during a day: the start and the end of the
+/// day, and its upper and lower transits, in chronological order.
+///
+/// The altitude of the body is monotonic between two consecutive extremes, so a crossing of a
+/// given altitude, however short, is always bracketed by them.
+pub(crate) struct Extremes {
+    points: [(JulianDay, HorizontalCoordinates); EXTREMES],
+    len: usize,
+    upper_transit: Option<JulianDay>,
+    lower_transit: Option<JulianDay>,
+}
+
+impl Extremes {
+    /// Gets the positions at the extremes, in chronological order.
+    pub(crate) fn points(&self) -> &[(JulianDay, HorizontalCoordinates)] {
+        &self.points[..self.len]
+    }
+}
+
+/// Finds the upper and lower transits of a body between the positions calculated every hour, to
+/// get the extremes of its altitude during the day.
+pub(crate) fn extremes<F>(
+    samples: &[(JulianDay, HorizontalCoordinates)],
+    position: &F,
+) -> Result<Extremes, Error>
+where
+    F: Fn(JulianDay) -> Result<HorizontalCoordinates, Error>,
+{
+    let upper = |position: &HorizontalCoordinates| wrap(position.hour_angle());
+    let lower = |position: &HorizontalCoordinates| wrap(position.hour_angle() - PI);
+
+    let mut extremes = Extremes {
+        points: [samples[0]; EXTREMES],
+        len: 1,
+        upper_transit: None,
+        lower_transit: None,
+    };
+    for pair in samples.windows(2) {
+        // An hour is much shorter than the time between an upper and a lower transit, so there
+        // is at most one transit between two samples.
+        let transit = if let Some(transit) = crossing(pair, position, upper, true)? {
+            extremes.upper_transit = extremes.upper_transit.or(Some(transit.0));
+            Some(transit)
+        } else if let Some(transit) = crossing(pair, position, lower, true)? {
+            extremes.lower_transit = extremes.lower_transit.or(Some(transit.0));
+            Some(transit)
+        } else {
+            None
+        };
+
+        if let Some(transit) = transit {
+            if extremes.len < EXTREMES - 1 {
+                extremes.points[extremes.len] = transit;
+                extremes.len += 1;
+            }
+        }
+    }
+    extremes.points[extremes.len] = samples[samples.len() - 1];
+    extremes.len += 1;
+
+    Ok(extremes)
+}
+
+/// Finds the first time when the given value crosses zero, increasing or decreasing, between the
+/// positions, and refines it with the regula falsi.
+///
+/// The value must change by less than π between two positions, so that the jumps of an angle
+/// from −π to π are not taken for crossings.
+pub(crate) fn search<F, V>(
+    points: &[(JulianDay, HorizontalCoordinates)],
+    position: &F,
+    value: V,
+    increasing: bool,
+) -> Result<Option<JulianDay>, Error>
+where
+    F: Fn(JulianDay) -> Result<HorizontalCoordinates, Error>,
+    V: Fn(&HorizontalCoordinates) -> f64,
+{
+    for pair in points.windows(2) {
+        if let Some((time, _)) = crossing(pair, position, &value, increasing)? {
+            return Ok(Some(time));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Finds the time when the given value crosses zero, increasing or decreasing, between two
+/// positions, if it does, and refines it with the regula falsi.
+fn crossing<F, V>(
+    pair: &[(JulianDay, HorizontalCoordinates)],
+    position: &F,
+    value: V,
+    increasing: bool,
+) -> Result<Option<(JulianDay, HorizontalCoordinates)>, Error>
+where
+    F: Fn(JulianDay) -> Result<HorizontalCoordinates, Error>,
+    V: Fn(&HorizontalCoordinates) -> f64,
+{
+    let sign = if increasing { 1_f64 } else { -1_f64 };
+
+    let (mut a, first) = pair[0];
+    let (mut b, second) = pair[1];
+    let (mut value_a, mut value_b) = (sign * value(&first), sign * value(&second));
+    if value_a >= 0_f64 || value_b < 0_f64 || value_b - value_a >= PI {
+        return Ok(None);
+    }
+
+    // Illinois variant of the regula falsi: the value at an end kept twice in a row is halved, so
+    // that the bracket shrinks from both sides.
+    let mut current = (a, first);
+    let mut kept = 0_i8;
+    for _ in 0..ITERATIONS {
+        let time = a + (b - a) * value_a / (value_a - value_b);
+        current = (time, position(time)?);
+        let value = sign * value(&current.1);
+
+        if value < 0_f64 {
+            a = time;
+            value_a = value;
+            if kept == -1 {
+                value_b /= 2_f64;
+            }
+            kept = -1;
+        } else {
+            b = time;
+            value_b = value;
+            if kept == 1 {
+                value_a /= 2_f64;
+            }
+            kept = 1;
+        }
+
+        if b - a < TOLERANCE {
+            break;
+        }
+    }
+
+    Ok(Some(current))
+}
+
+/// Gets whether a body crosses the horizon, or stays above or below it during the day.
+///
+/// If there are no crossings, the altitude is on the same side of the horizon at all the
+/// extremes, so it is decided from the altitude at the first one.
+pub(crate) fn visibility<V>(
+    extremes: &[(JulianDay, HorizontalCoordinates)],
+    rise: Option<JulianDay>,
+    set: Option<JulianDay>,
+    above: V,
+) -> Visibility
+where
+    V: Fn(&HorizontalCoordinates) -> f64,
+{
+    if rise.is_some() || set.is_some() {
+        Visibility::CrossesHorizon
+    } else if above(&extremes[0].1) >= 0_f64 {
+        Visibility::Circumpolar
+    } else {
+        Visibility::NeverRises
+    }
+}
+
+/// Wraps an angle between −π and π.
+fn wrap(angle: f64) -> f64 {
+    let angle = (angle + PI) % TAU;
+    if angle < 0_f64 {
+        angle + PI
+    } else {
+        angle - PI
+    }
+}
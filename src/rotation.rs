@@ -0,0 +1,378 @@
+//! Rotations between reference frames.
+//!
+//! The *VSOP87* solutions, apart from *VSOP87C* and *VSOP87D*, give coordinates referred to the
+//! dynamical ecliptic and equinox J2000.0. This module rotates them into the mean equator and
+//! equinox J2000.0, the *FK5* frame, with the correction published with the theory, or the
+//! *ICRS*, through the frame bias.
+
+use core::ops::Mul;
+
+#[cfg(feature = "no_std")]
+use libm::{atan2, hypot, sincos};
+
+use super::{
+    nutation::ARCSECOND, JulianDay, RectangularCoordinates, SphericalCoordinates, StateVector,
+};
+
+/// Rotation between two reference frames, as a 3×3 orthogonal matrix.
+///
+/// The rotations are applied to coordinates with the `*` operator, or with the `rotate()` method
+/// of [`RectangularCoordinates`], [`SphericalCoordinates`] and [`StateVector`], and they are
+/// composed with the `*` operator too, where `b * a` applies `a` first.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "vsop87a", feature = "earth"))]
+/// # {
+/// use vsop87::{vsop87a, Rotation};
+///
+/// let earth = vsop87a::earth(2451545.0);
+/// let equatorial = Rotation::VSOP87_TO_FK5 * earth;
+///
+/// assert!((equatorial.x - earth.x).abs() < 1e-6);
+/// assert!(earth.z.abs() < 1e-5 && (equatorial.z - 0.3847).abs() < 1e-4);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotation {
+    matrix: [[f64; 3]; 3],
+}
+
+impl Rotation {
+    /// Rotation that does not change the coordinates.
+    pub const IDENTITY: Self = Self::from_matrix([
+        [1_f64, 0_f64, 0_f64],
+        [0_f64, 1_f64, 0_f64],
+        [0_f64, 0_f64, 1_f64],
+    ]);
+
+    /// Mean obliquity of the ecliptic at J2000.0 of the *IAU 2006* precession, in radians.
+    pub const OBLIQUITY_J2000: f64 = 84_381.406 * ARCSECOND;
+
+    /// Rotation from the *VSOP87* ecliptic frame to the *FK5* frame, the mean equator and equinox
+    /// J2000.0.
+    ///
+    /// This is the matrix published with the theory by Bretagnon and Francou (1988). It rotates
+    /// about the equinox by an obliquity of 23°26′21.41″, and also includes the small rotation
+    /// of 0.094″ about the pole between the *VSOP87* equinox and the *FK5* equinox.
+    pub const VSOP87_TO_FK5: Self = Self::from_matrix([
+        [1_f64, 0.000_000_440_360, -0.000_000_190_919],
+        [-0.000_000_479_966, 0.917_482_137_087, -0.397_776_982_902],
+        [0_f64, 0.397_776_982_902, 0.917_482_137_087],
+    ]);
+
+    /// Rotation from the mean equator and equinox J2000.0 to the *ICRS*, the frame bias.
+    ///
+    /// The bias is given by the *IERS* Conventions (2003): an offset of −14.6 mas of the equinox
+    /// in right ascension, and offsets of −16.617 mas and −6.819 mas of the pole. It is the
+    /// transpose of the bias matrix of *SOFA*, that rotates in the other direction.
+    pub const FRAME_BIAS: Self = Self::from_matrix([
+        [
+            0.999_999_999_999_994_2,
+            0.000_000_070_782_794_778_573_38,
+            -0.000_000_080_562_173_809_869_7,
+        ],
+        [
+            -0.000_000_070_782_797_441_991_98,
+            0.999_999_999_999_996_9,
+            -0.000_000_033_060_408_839_805_52,
+        ],
+        [
+            0.000_000_080_562_171_469_761_32,
+            0.000_000_033_060_414_542_221_36,
+            0.999_999_999_999_996_2,
+        ],
+    ]);
+
+    /// Rotation from the *VSOP87* ecliptic frame to the *ICRS*.
+    ///
+    /// It rotates about the equinox by the [obliquity at J2000.0](Self::OBLIQUITY_J2000) into
+    /// the mean equator and equinox J2000.0, and then applies the [frame bias](Self::FRAME_BIAS).
+    /// The result differs from [`VSOP87_TO_FK5`](Self::VSOP87_TO_FK5) by less than 0.1″.
+    pub const VSOP87_TO_ICRS: Self = Self::from_matrix([
+        [
+            0.999_999_999_999_994_2,
+            0.000_000_032_896_172_922_379_77,
+            -0.000_000_102_070_121_449_414_3,
+        ],
+        [
+            -0.000_000_070_782_797_441_991_98,
+            0.917_482_129_914_569_7,
+            -0.397_776_999_444_939_5,
+        ],
+        [
+            0.000_000_080_562_171_469_761_32,
+            0.397_776_999_444_944_5,
+            0.917_482_129_914_566_9,
+        ],
+    ]);
+
+    /// Creates the rotation from the *VSOP87* ecliptic and equinox of the given date, used by
+    /// *VSOP87C* and *VSOP87D*, to the *FK5* ecliptic and equinox of the date.
+    ///
+    /// This is the correction given by Meeus (*Astronomical Algorithms*, chapter 32), of
+    /// −0.09033″ in longitude, and of 0.03916″ for the inclination of the ecliptic, about an axis
+    /// that follows the precession of the equinox. The date is given as a Julian Day in *TT*.
+    #[must_use]
+    pub fn vsop87_of_date_to_fk5(tt: impl Into<JulianDay>) -> Self {
+        let t = tt.into().julian_centuries();
+        let precession = (1.397 + 0.000_31 * t) * t;
+        let precession = precession.to_radians();
+        let inclination = 0.039_16 * ARCSECOND;
+
+        Self::about_z(0.090_33 * ARCSECOND)
+            * Self::about_z(-precession)
+            * Self::about_x(inclination)
+            * Self::about_y(inclination)
+            * Self::about_z(precession)
+    }
+
+    /// Creates a rotation from its matrix, where each row gives one of the new coordinates.
+    ///
+    /// The matrix is expected to be orthogonal, with a determinant of `1`.
+    #[must_use]
+    pub const fn from_matrix(matrix: [[f64; 3]; 3]) -> Self {
+        Self { matrix }
+    }
+
+    /// Gets the matrix of the rotation, where each row gives one of the new coordinates.
+    #[must_use]
+    pub fn matrix(&self) -> [[f64; 3]; 3] {
+        self.matrix
+    }
+
+    /// Creates a rotation of the reference frame about its X axis, by the given angle in radians.
+    ///
+    /// Positive angles rotate the frame counterclockwise, as seen from the positive end of the
+    /// axis, so the coordinates rotate clockwise.
+    #[must_use]
+    pub fn about_x(angle: f64) -> Self {
+        #[cfg(not(feature = "no_std"))]
+        let (sin, cos) = angle.sin_cos();
+        #[cfg(feature = "no_std")]
+        let (sin, cos) = sincos(angle);
+
+        Self::from_matrix([[1_f64, 0_f64, 0_f64], [0_f64, cos, sin], [0_f64, -sin, cos]])
+    }
+
+    /// Creates a rotation of the reference frame about its Y axis, by the given angle in radians.
+    ///
+    /// Positive angles rotate the frame counterclockwise, as seen from the positive end of the
+    /// axis, so the coordinates rotate clockwise.
+    #[must_use]
+    pub fn about_y(angle: f64) -> Self {
+        #[cfg(not(feature = "no_std"))]
+        let (sin, cos) = angle.sin_cos();
+        #[cfg(feature = "no_std")]
+        let (sin, cos) = sincos(angle);
+
+        Self::from_matrix([[cos, 0_f64, -sin], [0_f64, 1_f64, 0_f64], [sin, 0_f64, cos]])
+    }
+
+    /// Creates a rotation of the reference frame about its Z axis, by the given angle in radians.
+    ///
+    /// Positive angles rotate the frame counterclockwise, as seen from the positive end of the
+    /// axis, so the coordinates rotate clockwise.
+    #[must_use]
+    pub fn about_z(angle: f64) -> Self {
+        #[cfg(not(feature = "no_std"))]
+        let (sin, cos) = angle.sin_cos();
+        #[cfg(feature = "no_std")]
+        let (sin, cos) = sincos(angle);
+
+        Self::from_matrix([[cos, sin, 0_f64], [-sin, cos, 0_f64], [0_f64, 0_f64, 1_f64]])
+    }
+
+    /// Creates the rotation from an ecliptic frame to the equatorial frame of the same equinox,
+    /// given the obliquity of the ecliptic, in radians.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vsop87::Rotation;
+    ///
+    /// let rotation = Rotation::ecliptic_to_equatorial(Rotation::OBLIQUITY_J2000);
+    /// let difference = rotation.inverse() * Rotation::VSOP87_TO_FK5;
+    ///
+    /// // The obliquities differ by 0.005″, and the equinoxes by 0.094″.
+    /// assert!(difference.angle() < 0.1 / 206_265.0);
+    /// ```
+    #[must_use]
+    pub fn ecliptic_to_equatorial(obliquity: f64) -> Self {
+        Self::about_x(-obliquity)
+    }
+
+    /// Gets the inverse rotation, the transpose of the matrix.
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.matrix;
+        Self::from_matrix([[a, d, g], [b, e, h], [c, f, i]])
+    }
+
+    /// Gets the angle of the rotation, about its axis, in radians.
+    #[must_use]
+    pub fn angle(&self) -> f64 {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.matrix;
+        let trace = a + e + i;
+        // The sine of the angle, from the antisymmetric part, is more precise for small angles.
+        let (x, y, z) = (h - f, c - g, d - b);
+
+        #[cfg(not(feature = "no_std"))]
+        {
+            (0.5 * x.hypot(y).hypot(z)).atan2(0.5 * (trace - 1_f64))
+        }
+        #[cfg(feature = "no_std")]
+        {
+            atan2(0.5 * hypot(hypot(x, y), z), 0.5 * (trace - 1_f64))
+        }
+    }
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Rotation {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let mut matrix = [[0_f64; 3]; 3];
+        for (row, lhs_row) in matrix.iter_mut().zip(self.matrix) {
+            for (j, element) in row.iter_mut().enumerate() {
+                *element = lhs_row[0] * rhs.matrix[0][j]
+                    + lhs_row[1] * rhs.matrix[1][j]
+                    + lhs_row[2] * rhs.matrix[2][j];
+            }
+        }
+
+        Self::from_matrix(matrix)
+    }
+}
+
+impl Mul<RectangularCoordinates> for Rotation {
+    type Output = RectangularCoordinates;
+
+    fn mul(self, rhs: RectangularCoordinates) -> RectangularCoordinates {
+        let RectangularCoordinates { x, y, z } = rhs;
+        let [x, y, z] = self.matrix.map(|[a, b, c]| a * x + b * y + c * z);
+
+        RectangularCoordinates { x, y, z }
+    }
+}
+
+impl Mul<SphericalCoordinates> for Rotation {
+    type Output = SphericalCoordinates;
+
+    fn mul(self, rhs: SphericalCoordinates) -> SphericalCoordinates {
+        (self * RectangularCoordinates::from(rhs)).into()
+    }
+}
+
+impl Mul<StateVector> for Rotation {
+    type Output = StateVector;
+
+    fn mul(self, rhs: StateVector) -> StateVector {
+        StateVector {
+            position: self * rhs.position,
+            velocity: self * rhs.velocity,
+        }
+    }
+}
+
+impl RectangularCoordinates {
+    /// Rotates the coordinates into a new reference frame.
+    #[must_use]
+    pub fn rotate(self, rotation: Rotation) -> Self {
+        rotation * self
+    }
+
+    /// Rotates coordinates of the *VSOP87* ecliptic frame into the *FK5* frame, the mean equator
+    /// and equinox J2000.0.
+    ///
+    /// See [`Rotation::VSOP87_TO_FK5`] for more information.
+    #[must_use]
+    pub fn to_fk5(self) -> Self {
+        Rotation::VSOP87_TO_FK5 * self
+    }
+
+    /// Rotates coordinates of the *VSOP87* ecliptic frame into the *ICRS*.
+    ///
+    /// See [`Rotation::VSOP87_TO_ICRS`] for more information.
+    #[must_use]
+    pub fn to_icrs(self) -> Self {
+        Rotation::VSOP87_TO_ICRS * self
+    }
+}
+
+impl SphericalCoordinates {
+    /// Rotates the coordinates into a new reference frame.
+    ///
+    /// The distance does not change, and the longitude is kept between `0` and `2π`.
+    #[must_use]
+    pub fn rotate(self, rotation: Rotation) -> Self {
+        rotation * self
+    }
+
+    /// Rotates coordinates of the *VSOP87* ecliptic frame into the *FK5* frame, the mean equator
+    /// and equinox J2000.0.
+    ///
+    /// The longitude and latitude of the result are the right ascension and the declination. See
+    /// [`Rotation::VSOP87_TO_FK5`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "vsop87b", feature = "earth"))]
+    /// # {
+    /// use vsop87::vsop87b;
+    ///
+    /// let earth = vsop87b::earth(2451545.0);
+    /// let equatorial = earth.to_fk5();
+    ///
+    /// assert!((equatorial.distance() - earth.distance()).abs() < 1e-12);
+    /// assert!((equatorial.longitude().to_degrees() - 101.29).abs() < 0.01);
+    /// assert!((equatorial.latitude().to_degrees() - 23.03).abs() < 0.01);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_fk5(self) -> Self {
+        Rotation::VSOP87_TO_FK5 * self
+    }
+
+    /// Rotates coordinates of the *VSOP87* ecliptic frame into the *ICRS*.
+    ///
+    /// The longitude and latitude of the result are the right ascension and the declination. See
+    /// [`Rotation::VSOP87_TO_ICRS`] for more information.
+    #[must_use]
+    pub fn to_icrs(self) -> Self {
+        Rotation::VSOP87_TO_ICRS * self
+    }
+}
+
+impl StateVector {
+    /// Rotates the position and the velocity into a new reference frame.
+    #[must_use]
+    pub fn rotate(self, rotation: Rotation) -> Self {
+        rotation * self
+    }
+
+    /// Rotates a state vector of the *VSOP87* ecliptic frame into the *FK5* frame, the mean
+    /// equator and equinox J2000.0.
+    ///
+    /// See [`Rotation::VSOP87_TO_FK5`] for more information.
+    #[must_use]
+    pub fn to_fk5(self) -> Self {
+        Rotation::VSOP87_TO_FK5 * self
+    }
+
+    /// Rotates a state vector of the *VSOP87* ecliptic frame into the *ICRS*.
+    ///
+    /// See [`Rotation::VSOP87_TO_ICRS`] for more information.
+    #[must_use]
+    pub fn to_icrs(self) -> Self {
+        Rotation::VSOP87_TO_ICRS * self
+    }
+}
@@ -0,0 +1,237 @@
+//! Rotation of the Earth: Earth Rotation Angle and sidereal time.
+
+use super::{
+    nutation::{self, ARCSECOND},
+    JulianDay, Nutation,
+};
+
+#[cfg(feature = "no_std")]
+use core::f64::consts::TAU;
+#[cfg(feature = "no_std")]
+use libm::{cos, sin};
+
+#[cfg(not(feature = "no_std"))]
+use std::f64::consts::TAU;
+
+/// Terms of the complementary terms of the equation of the equinoxes, with amplitudes of 1
+/// microarcsecond or more.
+///
+/// Each term has the multipliers of the Delaunay arguments *l*, *l'*, *F*, *D* and *Ω*, and the
+/// coefficient of the sine of the argument, in arcseconds.
+const COMPLEMENTARY_TERMS: [([i8; 5], f64); 12] = [
+    ([0, 0, 0, 0, 1], 0.002_640_96),
+    ([0, 0, 0, 0, 2], 0.000_063_52),
+    ([0, 0, 2, -2, 3], 0.000_011_75),
+    ([0, 0, 2, -2, 1], 0.000_011_21),
+    ([0, 0, 2, -2, 2], -0.000_004_55),
+    ([0, 0, 2, 0, 3], 0.000_002_02),
+    ([0, 0, 2, 0, 1], 0.000_001_98),
+    ([0, 0, 0, 0, 3], -0.000_001_72),
+    ([0, 1, 0, 0, 1], -0.000_001_41),
+    ([0, 1, 0, 0, -1], -0.000_001_26),
+    ([1, 0, 0, 0, -1], -0.000_000_63),
+    ([1, 0, 0, 0, 1], -0.000_000_63),
+];
+
+/// Calculates the Earth Rotation Angle (*ERA*) at the given Julian Day in *UT1*, in radians.
+///
+/// The Earth Rotation Angle is the angle between the Celestial Intermediate Origin and the
+/// Terrestrial Intermediate Origin, and it is proportional to *UT1*. It is the basis of the
+/// IAU 2006 Greenwich mean sidereal time.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{earth_rotation_angle, JulianDay};
+///
+/// let era = earth_rotation_angle(JulianDay::from_mjd(54_388.0));
+/// assert!((era - 0.402_283_724_002_815_8).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn earth_rotation_angle(ut1: impl Into<JulianDay>) -> f64 {
+    let ut1 = ut1.into();
+    let (day, fraction) = ut1.parts();
+
+    // The fraction of the day is calculated separately to keep the precision.
+    let turns = day % 1_f64 + fraction % 1_f64;
+    normalize(TAU * (turns + 0.779_057_273_264 + 0.002_737_811_911_354_48 * ut1.days_since_j2000()))
+}
+
+/// Model of the sidereal time, the hour angle of the equinox.
+///
+/// The mean sidereal time refers to the mean equinox of the date, and the apparent sidereal time
+/// to the true equinox, affected by nutation. Both of them are calculated from the Julian Day in
+/// Universal Time (*UT1*), that follows the rotation of the Earth, and the Julian Day in
+/// Terrestrial Time (*TT*), used for the precession and nutation. Both Julian Days can be
+/// obtained with [`TimeScales`](crate::TimeScales).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{JulianDay, SiderealTime};
+///
+/// let ut1: JulianDay = "1987-04-10T19:21:00".parse()?;
+/// let tt = ut1 + 55.5 / 86_400.0;
+///
+/// let gmst = SiderealTime::Iau1982.greenwich_mean(ut1, tt);
+/// assert!((gmst.to_degrees() - 128.737_873_4).abs() < 1e-6);
+///
+/// // Local apparent sidereal time at the US Naval Observatory.
+/// let last = SiderealTime::Iau2006.local_apparent(ut1, tt, (-77.065_6_f64).to_radians());
+/// assert!((last.to_degrees() - 51.671_3).abs() < 1e-4);
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SiderealTime {
+    /// IAU 1982 mean sidereal time, with the equation of the equinoxes of the IAU 1980 nutation
+    /// and the IAU 1994 complementary terms.
+    Iau1982,
+    /// IAU 2006 mean sidereal time, based on the Earth Rotation Angle, with the equation of the
+    /// equinoxes of the IAU 2000B nutation. It is accurate to about 1 milliarcsecond.
+    #[default]
+    Iau2006,
+}
+
+impl SiderealTime {
+    /// Calculates the Greenwich mean sidereal time (*GMST*), in radians.
+    #[must_use]
+    pub fn greenwich_mean(self, ut1: impl Into<JulianDay>, tt: impl Into<JulianDay>) -> f64 {
+        let ut1 = ut1.into();
+
+        match self {
+            Self::Iau1982 => {
+                let t = ut1.julian_centuries();
+                let (day, fraction) = ut1.parts();
+
+                // The seconds of the day are added separately to keep the precision, and the
+                // sidereal time at 0h UT1 is calculated from the previous noon.
+                let seconds = 86_400_f64 * (day % 1_f64 + fraction % 1_f64);
+                let gmst = 24_110.548_41 - 43_200_f64
+                    + (8_640_184.812_866 + (0.093_104 - 0.000_006_2 * t) * t) * t
+                    + seconds;
+                normalize(gmst * TAU / 86_400_f64)
+            }
+            Self::Iau2006 => {
+                let t = tt.into().julian_centuries();
+                let precession = 0.014_506
+                    + (4_612.156_534
+                        + (1.391_581_7
+                            + (-0.000_000_44 + (-0.000_029_956 - 0.000_000_036_8 * t) * t) * t)
+                            * t)
+                        * t;
+                normalize(earth_rotation_angle(ut1) + precession * ARCSECOND)
+            }
+        }
+    }
+
+    /// Calculates the Greenwich apparent sidereal time (*GAST*), in radians.
+    #[must_use]
+    pub fn greenwich_apparent(self, ut1: impl Into<JulianDay>, tt: impl Into<JulianDay>) -> f64 {
+        let tt = tt.into();
+
+        normalize(self.greenwich_mean(ut1, tt) + self.equation_of_the_equinoxes(tt))
+    }
+
+    /// Calculates the local mean sidereal time at the given longitude, in radians.
+    ///
+    /// The longitude is given in radians, positive towards the east.
+    #[must_use]
+    pub fn local_mean(
+        self,
+        ut1: impl Into<JulianDay>,
+        tt: impl Into<JulianDay>,
+        longitude: f64,
+    ) -> f64 {
+        normalize(self.greenwich_mean(ut1, tt) + longitude)
+    }
+
+    /// Calculates the local apparent sidereal time at the given longitude, in radians.
+    ///
+    /// The longitude is given in radians, positive towards the east.
+    #[must_use]
+    pub fn local_apparent(
+        self,
+        ut1: impl Into<JulianDay>,
+        tt: impl Into<JulianDay>,
+        longitude: f64,
+    ) -> f64 {
+        normalize(self.greenwich_apparent(ut1, tt) + longitude)
+    }
+
+    /// Calculates the equation of the equinoxes, the difference between the apparent and the mean
+    /// sidereal time, at the given Julian Day in *TT*, in radians.
+    ///
+    /// It is the nutation in longitude projected on the equator, plus some complementary terms.
+    #[must_use]
+    pub fn equation_of_the_equinoxes(self, tt: impl Into<JulianDay>) -> f64 {
+        let tt = tt.into();
+        let t = tt.julian_centuries();
+        let arguments = nutation::delaunay_arguments(t);
+
+        match self {
+            Self::Iau1982 => {
+                let (longitude, _) = Nutation::Iau1980.angles(tt);
+                let node = arguments[4];
+
+                #[cfg(not(feature = "no_std"))]
+                let complementary = 0.002_64 * node.sin() + 0.000_063 * (2_f64 * node).sin();
+                #[cfg(feature = "no_std")]
+                let complementary = 0.002_64 * sin(node) + 0.000_063 * sin(2_f64 * node);
+
+                equinox_projection(longitude, Nutation::Iau1980.mean_obliquity(tt))
+                    + complementary * ARCSECOND
+            }
+            Self::Iau2006 => {
+                let (longitude, _) = Nutation::Iau2000B.angles(tt);
+
+                let complementary = COMPLEMENTARY_TERMS.iter().fold(
+                    -0.000_000_87 * t * argument_sin([0, 0, 0, 0, 1], &arguments),
+                    |value, (multipliers, amplitude)| {
+                        value + amplitude * argument_sin(*multipliers, &arguments)
+                    },
+                );
+
+                equinox_projection(longitude, Nutation::Iau2000B.mean_obliquity(tt))
+                    + complementary * ARCSECOND
+            }
+        }
+    }
+}
+
+/// Projects the nutation in longitude on the equator, given the mean obliquity.
+fn equinox_projection(longitude: f64, obliquity: f64) -> f64 {
+    #[cfg(not(feature = "no_std"))]
+    {
+        longitude * obliquity.cos()
+    }
+
+    #[cfg(feature = "no_std")]
+    {
+        longitude * cos(obliquity)
+    }
+}
+
+/// Calculates the sine of the argument with the given multipliers of the Delaunay arguments.
+fn argument_sin(multipliers: [i8; 5], arguments: &[f64; 5]) -> f64 {
+    let argument = nutation::angle(multipliers, arguments);
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        argument.sin()
+    }
+
+    #[cfg(feature = "no_std")]
+    {
+        sin(argument)
+    }
+}
+
+/// Normalizes an angle to the `[0, 2π)` range.
+fn normalize(angle: f64) -> f64 {
+    let angle = angle % TAU;
+    if angle < 0_f64 {
+        angle + TAU
+    } else {
+        angle
+    }
+}
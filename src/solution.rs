@@ -0,0 +1,395 @@
+//! Runtime selection of *VSOP87* solutions and bodies.
+
+use super::{
+    ephemeris::check_validity, Body, Error, JulianDay, RectangularCoordinates,
+    SphericalCoordinates, VSOP87Elements,
+};
+
+#[cfg(feature = "vsop87a")]
+use super::vsop87a;
+#[cfg(feature = "vsop87b")]
+use super::vsop87b;
+#[cfg(feature = "vsop87c")]
+use super::vsop87c;
+#[cfg(feature = "vsop87d")]
+use super::vsop87d;
+#[cfg(feature = "vsop87e")]
+use super::vsop87e;
+#[cfg(feature = "elements")]
+use super::BODIES;
+
+#[cfg(feature = "no_std")]
+use core::{fmt, str::FromStr};
+#[cfg(not(feature = "no_std"))]
+use std::{fmt, str::FromStr};
+
+/// The different *VSOP87* solutions.
+///
+/// Each solution has its own module in the library, with one function per body. This enumeration
+/// allows choosing the solution at runtime, and calculating any body with [`calculate()`]:
+///
+/// ```
+/// # #[cfg(all(feature = "vsop87d", feature = "jupiter"))]
+/// # {
+/// use vsop87::{Body, Solution};
+///
+/// let solution: Solution = "VSOP87D".parse().unwrap();
+/// let coordinates = vsop87::calculate(solution, Body::Jupiter, 2232395.0)
+///     .unwrap()
+///     .spherical()
+///     .unwrap();
+///
+/// assert_eq!(coordinates, vsop87::vsop87d::jupiter(2232395.0));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Solution {
+    /// Base *VSOP87* solution: heliocentric ecliptic orbital elements for the equinox J2000.0.
+    VSOP87,
+    /// *VSOP87A* solution: heliocentric ecliptic rectangular coordinates for the equinox J2000.0.
+    VSOP87A,
+    /// *VSOP87B* solution: heliocentric ecliptic spherical coordinates for the equinox J2000.0.
+    VSOP87B,
+    /// *VSOP87C* solution: heliocentric ecliptic rectangular coordinates for the equinox of the
+    /// day.
+    VSOP87C,
+    /// *VSOP87D* solution: heliocentric ecliptic spherical coordinates for the equinox of the day.
+    VSOP87D,
+    /// *VSOP87E* solution: barycentric ecliptic rectangular coordinates for the equinox J2000.0.
+    VSOP87E,
+}
+
+impl Solution {
+    /// All the *VSOP87* solutions.
+    pub const ALL: [Self; 6] = [
+        Self::VSOP87,
+        Self::VSOP87A,
+        Self::VSOP87B,
+        Self::VSOP87C,
+        Self::VSOP87D,
+        Self::VSOP87E,
+    ];
+
+    /// Gets the name of the solution.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::VSOP87 => "VSOP87",
+            Self::VSOP87A => "VSOP87A",
+            Self::VSOP87B => "VSOP87B",
+            Self::VSOP87C => "VSOP87C",
+            Self::VSOP87D => "VSOP87D",
+            Self::VSOP87E => "VSOP87E",
+        }
+    }
+
+    /// Gets the bodies available in the solution.
+    ///
+    /// Only the bodies enabled with their cargo features are included, and none if the solution
+    /// has been disabled.
+    #[must_use]
+    pub fn bodies(self) -> &'static [Body] {
+        match self {
+            #[cfg(feature = "elements")]
+            Self::VSOP87 => BODIES,
+            #[cfg(not(feature = "elements"))]
+            Self::VSOP87 => &[],
+            #[cfg(feature = "vsop87a")]
+            Self::VSOP87A => vsop87a::BODIES,
+            #[cfg(not(feature = "vsop87a"))]
+            Self::VSOP87A => &[],
+            #[cfg(feature = "vsop87b")]
+            Self::VSOP87B => vsop87b::BODIES,
+            #[cfg(not(feature = "vsop87b"))]
+            Self::VSOP87B => &[],
+            #[cfg(feature = "vsop87c")]
+            Self::VSOP87C => vsop87c::BODIES,
+            #[cfg(not(feature = "vsop87c"))]
+            Self::VSOP87C => &[],
+            #[cfg(feature = "vsop87d")]
+            Self::VSOP87D => vsop87d::BODIES,
+            #[cfg(not(feature = "vsop87d"))]
+            Self::VSOP87D => &[],
+            #[cfg(feature = "vsop87e")]
+            Self::VSOP87E => vsop87e::BODIES,
+            #[cfg(not(feature = "vsop87e"))]
+            Self::VSOP87E => &[],
+        }
+    }
+
+    /// Checks if the given body is available in the solution.
+    #[must_use]
+    pub fn supports(self, body: Body) -> bool {
+        self.bodies().contains(&body)
+    }
+
+    /// Calculates the solution for the given body, at the given Julian Day (*JD*).
+    ///
+    /// This is equivalent to [`calculate()`].
+    pub fn calculate(self, body: Body, jde: impl Into<JulianDay>) -> Result<Coordinates, Error> {
+        calculate(self, body, jde)
+    }
+
+    /// Calculates the solution for the given body, at the given Julian Day (*JD*), checking that
+    /// the Julian Day is valid.
+    ///
+    /// This is equivalent to [`calculate_checked()`].
+    pub fn calculate_checked(
+        self,
+        body: Body,
+        jde: impl Into<JulianDay>,
+    ) -> Result<Coordinates, Error> {
+        calculate_checked(self, body, jde)
+    }
+}
+
+impl fmt::Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Solution {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        Self::ALL
+            .into_iter()
+            .find(|solution| solution.name().eq_ignore_ascii_case(s))
+            .ok_or(Error::UnknownSolution)
+    }
+}
+
+/// Result of a *VSOP87* calculation, that depends on the solution used.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Coordinates {
+    /// Orbital elements, returned by the base *VSOP87* solution.
+    Elements(VSOP87Elements),
+    /// Rectangular coordinates, returned by *VSOP87A*, *VSOP87C* and *VSOP87E*.
+    Rectangular(RectangularCoordinates),
+    /// Spherical coordinates, returned by *VSOP87B* and *VSOP87D*.
+    Spherical(SphericalCoordinates),
+}
+
+impl Coordinates {
+    /// Gets the orbital elements, if the result comes from the base *VSOP87* solution.
+    #[must_use]
+    pub fn elements(self) -> Option<VSOP87Elements> {
+        match self {
+            Self::Elements(elements) => Some(elements),
+            _ => None,
+        }
+    }
+
+    /// Gets the rectangular coordinates, if the result comes from *VSOP87A*, *VSOP87C* or
+    /// *VSOP87E*.
+    #[must_use]
+    pub fn rectangular(self) -> Option<RectangularCoordinates> {
+        match self {
+            Self::Rectangular(coordinates) => Some(coordinates),
+            _ => None,
+        }
+    }
+
+    /// Gets the spherical coordinates, if the result comes from *VSOP87B* or *VSOP87D*.
+    #[must_use]
+    pub fn spherical(self) -> Option<SphericalCoordinates> {
+        match self {
+            Self::Spherical(coordinates) => Some(coordinates),
+            _ => None,
+        }
+    }
+}
+
+/// Calculates the given *VSOP87* solution for the given body.
+///
+/// This function calls the function of the module of the solution for the body (for example,
+/// [`vsop87a::mars()`] for `Solution::VSOP87A` and `Body::Mars`), which allows selecting both of
+/// them at runtime. The parameter needed is the Julian Day (*JD*) for the given date. It returns
+/// an error if the body is not available in the solution.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "vsop87a", feature = "vsop87b", feature = "earth_moon"))]
+/// # {
+/// use vsop87::{Body, Error, Solution};
+///
+/// for body in Solution::VSOP87A.bodies() {
+///     let coordinates = vsop87::calculate(Solution::VSOP87A, *body, 2451545.0).unwrap();
+///     assert!(coordinates.rectangular().is_some());
+/// }
+///
+/// assert_eq!(
+///     vsop87::calculate(Solution::VSOP87B, Body::EarthMoon, 2451545.0),
+///     Err(Error::UnsupportedBody {
+///         solution: Solution::VSOP87B,
+///         body: Body::EarthMoon
+///     })
+/// );
+/// # }
+/// ```
+pub fn calculate(
+    solution: Solution,
+    body: Body,
+    jde: impl Into<JulianDay>,
+) -> Result<Coordinates, Error> {
+    let jde = jde.into();
+
+    match (solution, body) {
+        #[cfg(all(feature = "elements", feature = "mercury"))]
+        (Solution::VSOP87, Body::Mercury) => Ok(Coordinates::Elements(super::mercury(jde))),
+        #[cfg(all(feature = "elements", feature = "venus"))]
+        (Solution::VSOP87, Body::Venus) => Ok(Coordinates::Elements(super::venus(jde))),
+        #[cfg(all(feature = "elements", feature = "earth_moon"))]
+        (Solution::VSOP87, Body::EarthMoon) => Ok(Coordinates::Elements(super::earth_moon(jde))),
+        #[cfg(all(feature = "elements", feature = "mars"))]
+        (Solution::VSOP87, Body::Mars) => Ok(Coordinates::Elements(super::mars(jde))),
+        #[cfg(all(feature = "elements", feature = "jupiter"))]
+        (Solution::VSOP87, Body::Jupiter) => Ok(Coordinates::Elements(super::jupiter(jde))),
+        #[cfg(all(feature = "elements", feature = "saturn"))]
+        (Solution::VSOP87, Body::Saturn) => Ok(Coordinates::Elements(super::saturn(jde))),
+        #[cfg(all(feature = "elements", feature = "uranus"))]
+        (Solution::VSOP87, Body::Uranus) => Ok(Coordinates::Elements(super::uranus(jde))),
+        #[cfg(all(feature = "elements", feature = "neptune"))]
+        (Solution::VSOP87, Body::Neptune) => Ok(Coordinates::Elements(super::neptune(jde))),
+
+        #[cfg(all(feature = "vsop87a", feature = "mercury"))]
+        (Solution::VSOP87A, Body::Mercury) => Ok(Coordinates::Rectangular(vsop87a::mercury(jde))),
+        #[cfg(all(feature = "vsop87a", feature = "venus"))]
+        (Solution::VSOP87A, Body::Venus) => Ok(Coordinates::Rectangular(vsop87a::venus(jde))),
+        #[cfg(all(feature = "vsop87a", feature = "earth"))]
+        (Solution::VSOP87A, Body::Earth) => Ok(Coordinates::Rectangular(vsop87a::earth(jde))),
+        #[cfg(all(feature = "vsop87a", feature = "earth_moon"))]
+        (Solution::VSOP87A, Body::EarthMoon) => {
+            Ok(Coordinates::Rectangular(vsop87a::earth_moon(jde)))
+        }
+        #[cfg(all(feature = "vsop87a", feature = "mars"))]
+        (Solution::VSOP87A, Body::Mars) => Ok(Coordinates::Rectangular(vsop87a::mars(jde))),
+        #[cfg(all(feature = "vsop87a", feature = "jupiter"))]
+        (Solution::VSOP87A, Body::Jupiter) => Ok(Coordinates::Rectangular(vsop87a::jupiter(jde))),
+        #[cfg(all(feature = "vsop87a", feature = "saturn"))]
+        (Solution::VSOP87A, Body::Saturn) => Ok(Coordinates::Rectangular(vsop87a::saturn(jde))),
+        #[cfg(all(feature = "vsop87a", feature = "uranus"))]
+        (Solution::VSOP87A, Body::Uranus) => Ok(Coordinates::Rectangular(vsop87a::uranus(jde))),
+        #[cfg(all(feature = "vsop87a", feature = "neptune"))]
+        (Solution::VSOP87A, Body::Neptune) => Ok(Coordinates::Rectangular(vsop87a::neptune(jde))),
+
+        #[cfg(all(feature = "vsop87b", feature = "mercury"))]
+        (Solution::VSOP87B, Body::Mercury) => Ok(Coordinates::Spherical(vsop87b::mercury(jde))),
+        #[cfg(all(feature = "vsop87b", feature = "venus"))]
+        (Solution::VSOP87B, Body::Venus) => Ok(Coordinates::Spherical(vsop87b::venus(jde))),
+        #[cfg(all(feature = "vsop87b", feature = "earth"))]
+        (Solution::VSOP87B, Body::Earth) => Ok(Coordinates::Spherical(vsop87b::earth(jde))),
+        #[cfg(all(feature = "vsop87b", feature = "mars"))]
+        (Solution::VSOP87B, Body::Mars) => Ok(Coordinates::Spherical(vsop87b::mars(jde))),
+        #[cfg(all(feature = "vsop87b", feature = "jupiter"))]
+        (Solution::VSOP87B, Body::Jupiter) => Ok(Coordinates::Spherical(vsop87b::jupiter(jde))),
+        #[cfg(all(feature = "vsop87b", feature = "saturn"))]
+        (Solution::VSOP87B, Body::Saturn) => Ok(Coordinates::Spherical(vsop87b::saturn(jde))),
+        #[cfg(all(feature = "vsop87b", feature = "uranus"))]
+        (Solution::VSOP87B, Body::Uranus) => Ok(Coordinates::Spherical(vsop87b::uranus(jde))),
+        #[cfg(all(feature = "vsop87b", feature = "neptune"))]
+        (Solution::VSOP87B, Body::Neptune) => Ok(Coordinates::Spherical(vsop87b::neptune(jde))),
+
+        #[cfg(all(feature = "vsop87c", feature = "mercury"))]
+        (Solution::VSOP87C, Body::Mercury) => Ok(Coordinates::Rectangular(vsop87c::mercury(jde))),
+        #[cfg(all(feature = "vsop87c", feature = "venus"))]
+        (Solution::VSOP87C, Body::Venus) => Ok(Coordinates::Rectangular(vsop87c::venus(jde))),
+        #[cfg(all(feature = "vsop87c", feature = "earth"))]
+        (Solution::VSOP87C, Body::Earth) => Ok(Coordinates::Rectangular(vsop87c::earth(jde))),
+        #[cfg(all(feature = "vsop87c", feature = "mars"))]
+        (Solution::VSOP87C, Body::Mars) => Ok(Coordinates::Rectangular(vsop87c::mars(jde))),
+        #[cfg(all(feature = "vsop87c", feature = "jupiter"))]
+        (Solution::VSOP87C, Body::Jupiter) => Ok(Coordinates::Rectangular(vsop87c::jupiter(jde))),
+        #[cfg(all(feature = "vsop87c", feature = "saturn"))]
+        (Solution::VSOP87C, Body::Saturn) => Ok(Coordinates::Rectangular(vsop87c::saturn(jde))),
+        #[cfg(all(feature = "vsop87c", feature = "uranus"))]
+        (Solution::VSOP87C, Body::Uranus) => Ok(Coordinates::Rectangular(vsop87c::uranus(jde))),
+        #[cfg(all(feature = "vsop87c", feature = "neptune"))]
+        (Solution::VSOP87C, Body::Neptune) => Ok(Coordinates::Rectangular(vsop87c::neptune(jde))),
+
+        #[cfg(all(feature = "vsop87d", feature = "mercury"))]
+        (Solution::VSOP87D, Body::Mercury) => Ok(Coordinates::Spherical(vsop87d::mercury(jde))),
+        #[cfg(all(feature = "vsop87d", feature = "venus"))]
+        (Solution::VSOP87D, Body::Venus) => Ok(Coordinates::Spherical(vsop87d::venus(jde))),
+        #[cfg(all(feature = "vsop87d", feature = "earth"))]
+        (Solution::VSOP87D, Body::Earth) => Ok(Coordinates::Spherical(vsop87d::earth(jde))),
+        #[cfg(all(feature = "vsop87d", feature = "mars"))]
+        (Solution::VSOP87D, Body::Mars) => Ok(Coordinates::Spherical(vsop87d::mars(jde))),
+        #[cfg(all(feature = "vsop87d", feature = "jupiter"))]
+        (Solution::VSOP87D, Body::Jupiter) => Ok(Coordinates::Spherical(vsop87d::jupiter(jde))),
+        #[cfg(all(feature = "vsop87d", feature = "saturn"))]
+        (Solution::VSOP87D, Body::Saturn) => Ok(Coordinates::Spherical(vsop87d::saturn(jde))),
+        #[cfg(all(feature = "vsop87d", feature = "uranus"))]
+        (Solution::VSOP87D, Body::Uranus) => Ok(Coordinates::Spherical(vsop87d::uranus(jde))),
+        #[cfg(all(feature = "vsop87d", feature = "neptune"))]
+        (Solution::VSOP87D, Body::Neptune) => Ok(Coordinates::Spherical(vsop87d::neptune(jde))),
+
+        #[cfg(all(feature = "vsop87e", feature = "sun"))]
+        (Solution::VSOP87E, Body::Sun) => Ok(Coordinates::Rectangular(vsop87e::sun(jde))),
+        #[cfg(all(feature = "vsop87e", feature = "mercury"))]
+        (Solution::VSOP87E, Body::Mercury) => Ok(Coordinates::Rectangular(vsop87e::mercury(jde))),
+        #[cfg(all(feature = "vsop87e", feature = "venus"))]
+        (Solution::VSOP87E, Body::Venus) => Ok(Coordinates::Rectangular(vsop87e::venus(jde))),
+        #[cfg(all(feature = "vsop87e", feature = "earth"))]
+        (Solution::VSOP87E, Body::Earth) => Ok(Coordinates::Rectangular(vsop87e::earth(jde))),
+        #[cfg(all(feature = "vsop87e", feature = "mars"))]
+        (Solution::VSOP87E, Body::Mars) => Ok(Coordinates::Rectangular(vsop87e::mars(jde))),
+        #[cfg(all(feature = "vsop87e", feature = "jupiter"))]
+        (Solution::VSOP87E, Body::Jupiter) => Ok(Coordinates::Rectangular(vsop87e::jupiter(jde))),
+        #[cfg(all(feature = "vsop87e", feature = "saturn"))]
+        (Solution::VSOP87E, Body::Saturn) => Ok(Coordinates::Rectangular(vsop87e::saturn(jde))),
+        #[cfg(all(feature = "vsop87e", feature = "uranus"))]
+        (Solution::VSOP87E, Body::Uranus) => Ok(Coordinates::Rectangular(vsop87e::uranus(jde))),
+        #[cfg(all(feature = "vsop87e", feature = "neptune"))]
+        (Solution::VSOP87E, Body::Neptune) => Ok(Coordinates::Rectangular(vsop87e::neptune(jde))),
+
+        (solution, body) => Err(Error::UnsupportedBody { solution, body }),
+    }
+}
+
+/// Calculates the given *VSOP87* solution for the given body, checking that the Julian Day is
+/// valid.
+///
+/// This function is equivalent to [`calculate()`], but it returns an error if the Julian Day
+/// (*JD*) is not finite, or if it is outside of the validity span of the solution for the body
+/// (see [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "vsop87b", feature = "saturn"))]
+/// # {
+/// use vsop87::{Body, Error, Solution};
+///
+/// assert_eq!(
+///     vsop87::calculate_checked(Solution::VSOP87B, Body::Saturn, 2451545.0),
+///     vsop87::calculate(Solution::VSOP87B, Body::Saturn, 2451545.0)
+/// );
+/// assert_eq!(
+///     vsop87::calculate_checked(Solution::VSOP87B, Body::Saturn, 0.0),
+///     Err(Error::OutOfRange { body: Body::Saturn })
+/// );
+/// assert_eq!(
+///     vsop87::calculate_checked(Solution::VSOP87B, Body::Saturn, f64::NAN),
+///     Err(Error::NonFinite)
+/// );
+/// # }
+/// ```
+pub fn calculate_checked(
+    solution: Solution,
+    body: Body,
+    jde: impl Into<JulianDay>,
+) -> Result<Coordinates, Error> {
+    let jde = jde.into();
+
+    if !solution.supports(body) {
+        return Err(Error::UnsupportedBody { solution, body });
+    }
+    check_validity(body, jde)?;
+
+    calculate(solution, body, jde)
+}
@@ -0,0 +1,233 @@
+//! Difference between Barycentric Dynamical Time and Terrestrial Time (*TDB − TT*).
+//!
+//! The difference is periodic, with an amplitude of 1.7 milliseconds, and it is given by the
+//! series of Fairhead and Bretagnon (1990), in the same form as the *VSOP87* series: the sum of
+//! terms `A·cos(B + C·t)`, multiplied by powers of the Julian millennia since J2000.0, `t`. Only
+//! an abridged series is bundled, with the leading 45 of its 787 terms, as given in the *SOFA*
+//! implementation, with amplitudes down to 0.1 microseconds, so it is accurate to about a
+//! microsecond for the geocenter. The terms that depend on the position of the observer, of up to
+//! 2 microseconds, are not included.
+
+use super::{calculate_body, calculate_t, calculate_var_truncated, series, JulianDay, Series};
+
+/// Terms of *TDB − TT* that are not multiplied by the time.
+const T0: [[f64; 30]; 3] = [
+    [
+        0.001_656_674_564,
+        0.000_022_417_471,
+        0.000_013_839_792,
+        0.000_004_770_086,
+        0.000_004_676_74,
+        0.000_002_256_707,
+        0.000_001_694_205,
+        0.000_001_554_905,
+        0.000_001_276_839,
+        0.000_001_193_379,
+        0.000_001_115_322,
+        0.000_000_794_185,
+        0.000_000_447_061,
+        0.000_000_435_206,
+        0.000_000_600_309,
+        0.000_000_496_817,
+        0.000_000_486_306,
+        0.000_000_432_392,
+        0.000_000_468_597,
+        0.000_000_375_51,
+        0.000_000_243_085,
+        0.000_000_173_435,
+        0.000_000_230_685,
+        0.000_000_203_747,
+        0.000_000_143_935,
+        0.000_000_159_08,
+        0.000_000_119_979,
+        0.000_000_118_971,
+        0.000_000_116_12,
+        0.000_000_137_927,
+    ],
+    [
+        4.669_257_868,
+        2.726_181_115,
+        4.626_108_083,
+        5.156_790_583,
+        2.450_398_766,
+        3.972_316_935,
+        3.454_336_421,
+        3.627_670_763,
+        4.418_026_014,
+        2.079_027_403,
+        6.135_134_049,
+        0.751_516_75,
+        2.045_000_171,
+        2.778_542_02,
+        1.107_475_582,
+        4.125_905_497,
+        5.232_396_159,
+        0.865_101_982,
+        4.295_602_432,
+        2.532_680_477,
+        2.081_041_598,
+        4.582_947_158,
+        3.203_056_255,
+        2.763_191_491,
+        4.386_721_468,
+        0.319_278_899,
+        2.980_789_441,
+        0.343_750_899,
+        5.585_893_103,
+        5.848_323_649,
+    ],
+    [
+        6_283.075_849_991,
+        5_753.384_884_897,
+        12_566.151_699_983,
+        529.690_965_095,
+        6_069.776_754_553,
+        213.299_095_438,
+        -3.523_118_349,
+        77_713.771_467_92,
+        7_860.419_392_439,
+        5_223.693_919_802,
+        3_930.209_696_22,
+        11_506.769_769_794,
+        26.298_319_8,
+        -398.149_003_408,
+        1_577.343_542_448,
+        6_208.294_251_424,
+        5_884.926_846_583,
+        74.781_598_567,
+        6_244.942_814_354,
+        5_507.553_238_667,
+        -775.522_611_324,
+        18_849.227_549_974,
+        5_856.477_659_115,
+        12_036.460_734_888,
+        -796.298_006_816,
+        10_977.078_804_699,
+        38.133_035_638,
+        5_486.777_843_175,
+        1_059.381_930_189,
+        11_790.629_088_659,
+    ],
+];
+
+/// Terms of *TDB − TT* multiplied by `t`.
+const T1: [[f64; 6]; 3] = [
+    [
+        0.000_102_156_724,
+        0.000_001_706_807,
+        0.000_000_269_668,
+        0.000_000_265_919,
+        0.000_000_210_568,
+        0.000_000_077_996,
+    ],
+    [
+        2.678_235_678,
+        2.635_107_921,
+        1.829_494_152,
+        4.265_251_04,
+        4.691_942_021,
+        3.099_547_877,
+    ],
+    [
+        6_283.075_849_991,
+        12_566.151_699_983,
+        213.299_095_438,
+        529.690_965_095,
+        -3.523_118_349,
+        5_223.693_919_802,
+    ],
+];
+
+/// Terms of *TDB − TT* multiplied by `t^2`.
+const T2: [[f64; 6]; 3] = [
+    [
+        0.000_004_322_99,
+        -0.000_000_406_495,
+        0.000_000_122_605,
+        0.000_000_019_476,
+        0.000_000_016_916,
+        0.000_000_013_374,
+    ],
+    [
+        1.072_097_421,
+        0_f64,
+        0.867_344_307,
+        0.071_390_654,
+        2.940_163_017,
+        6.214_599_294,
+    ],
+    [
+        6_283.075_849_991,
+        0_f64,
+        12_566.151_699_983,
+        213.299_095_438,
+        529.690_965_095,
+        -3.523_118_349,
+    ],
+];
+
+/// Terms of *TDB − TT* multiplied by `t^3`.
+const T3: [[f64; 2]; 3] = [
+    [0.000_000_143_388, 0.000_000_006_671],
+    [5.843_842_561, 5.487_537_867],
+    [6_283.075_849_991, 12_566.151_699_983],
+];
+
+/// Terms of *TDB − TT* multiplied by `t^4`.
+const T4: [[f64; 1]; 3] = [[0.000_000_003_826], [4.134_460_948], [6_283.075_849_991]];
+
+/// Abridged series of *TDB − TT*, in seconds, for each power of the time.
+const TDB_MINUS_TT: &[Series] = &[
+    series(&T0),
+    series(&T1),
+    series(&T2),
+    series(&T3),
+    series(&T4),
+];
+
+/// Calculates the difference between Barycentric Dynamical Time and Terrestrial Time
+/// (*TDB − TT*) at the given Julian Day in *TT*, in seconds, with the abridged series.
+///
+/// The abridged series has the leading 45 of the 787 terms of Fairhead and Bretagnon, with
+/// amplitudes down to 0.1 microseconds, so it is accurate to about a microsecond for the
+/// geocenter. All of them are calculated, using the SIMD implementation if available. Since the
+/// difference is under 2 milliseconds, the Julian Day can also be given in *TDB* with no loss of
+/// precision.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{tdb_minus_tt_abridged, JulianDay};
+///
+/// let tdb_minus_tt = tdb_minus_tt_abridged(JulianDay::J2000);
+/// assert!((tdb_minus_tt + 0.000_099_3).abs() < 1e-7);
+/// ```
+#[must_use]
+pub fn tdb_minus_tt_abridged(tt: impl Into<JulianDay>) -> f64 {
+    let [value] = calculate_body(tt.into(), &[TDB_MINUS_TT]);
+    value
+}
+
+/// Calculates the difference between Barycentric Dynamical Time and Terrestrial Time
+/// (*TDB − TT*) at the given Julian Day in *TT*, in seconds, skipping the terms of the abridged
+/// series with an amplitude below the given threshold, in seconds.
+///
+/// For the terms multiplied by a power of the time, the amplitude is multiplied by that power
+/// too. A threshold of 10 microseconds leaves 3 or 4 terms, accurate to 25 microseconds between
+/// 1600 and 2200, and a threshold of `0.0` calculates the whole abridged series, like
+/// [`tdb_minus_tt_abridged()`], so thresholds below 0.1 microseconds do not improve the accuracy
+/// further.
+#[must_use]
+pub fn tdb_minus_tt_truncated(tt: impl Into<JulianDay>, threshold: f64) -> f64 {
+    let t = calculate_t(tt.into());
+
+    let mut value = 0_f64;
+    let mut power = 1_f64;
+    for [a, b, c] in TDB_MINUS_TT {
+        // The amplitude of the terms is multiplied by the power of `t`.
+        value += calculate_var_truncated(t, a, b, c, threshold / power.abs()) * power;
+        power *= t;
+    }
+
+    value
+}
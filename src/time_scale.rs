@@ -0,0 +1,469 @@
+//! Time scales and conversions between them.
+
+use super::{julian_day::SECONDS_PER_DAY, tdb_minus_tt_abridged, Error, JulianDay};
+
+#[cfg(feature = "no_std")]
+use core::{fmt, str::FromStr};
+
+#[cfg(not(feature = "no_std"))]
+use std::{borrow::Cow, fmt, fs, io, path::Path, str::FromStr};
+
+/// Difference between Terrestrial Time and International Atomic Time (*TT − TAI*), in seconds.
+const TT_MINUS_TAI: f64 = 32.184;
+
+/// Rate of Geocentric Coordinate Time with respect to Terrestrial Time (*L<sub>G</sub>*).
+const L_G: f64 = 6.969_290_134e-10;
+
+/// Rate of Barycentric Coordinate Time with respect to Barycentric Dynamical Time
+/// (*L<sub>B</sub>*).
+const L_B: f64 = 1.550_519_768e-8;
+
+/// Difference between Barycentric Dynamical Time and Barycentric Coordinate Time at
+/// [`COORDINATE_EPOCH`] (*TDB<sub>0</sub>*), in seconds.
+const TDB_0: f64 = -6.55e-5;
+
+/// Julian Day in *TT* of January 1st, 1977 at 00:00:00 *TAI*, when the coordinate time scales
+/// were synchronized with Terrestrial Time.
+const COORDINATE_EPOCH: JulianDay = JulianDay::from_parts(2_443_144.5, 0.000_372_5);
+
+/// Differences between International Atomic Time and Coordinated Universal Time (*TAI − UTC*)
+/// since 1972, in seconds, along with the Modified Julian Day in which each of them started.
+const LEAP_SECONDS: [(f64, f64); 28] = [
+    (41_317_f64, 10_f64),
+    (41_499_f64, 11_f64),
+    (41_683_f64, 12_f64),
+    (42_048_f64, 13_f64),
+    (42_413_f64, 14_f64),
+    (42_778_f64, 15_f64),
+    (43_144_f64, 16_f64),
+    (43_509_f64, 17_f64),
+    (43_874_f64, 18_f64),
+    (44_239_f64, 19_f64),
+    (44_786_f64, 20_f64),
+    (45_151_f64, 21_f64),
+    (45_516_f64, 22_f64),
+    (46_247_f64, 23_f64),
+    (47_161_f64, 24_f64),
+    (47_892_f64, 25_f64),
+    (48_257_f64, 26_f64),
+    (48_804_f64, 27_f64),
+    (49_169_f64, 28_f64),
+    (49_534_f64, 29_f64),
+    (50_083_f64, 30_f64),
+    (50_630_f64, 31_f64),
+    (51_179_f64, 32_f64),
+    (53_736_f64, 33_f64),
+    (54_832_f64, 34_f64),
+    (56_109_f64, 35_f64),
+    (57_204_f64, 36_f64),
+    (57_754_f64, 37_f64),
+];
+
+/// Differences between International Atomic Time and Coordinated Universal Time (*TAI − UTC*)
+/// between 1960 and 1972, when the seconds of UTC were not SI seconds.
+///
+/// Each entry has the Modified Julian Day in which it started, and the `a`, `b` and `c` terms of
+/// the difference, in seconds: `a + (MJD - b) × c`.
+const DRIFTS: [(f64, f64, f64, f64); 14] = [
+    (36_934_f64, 1.417_818, 37_300_f64, 0.001_296),
+    (37_300_f64, 1.422_818, 37_300_f64, 0.001_296),
+    (37_512_f64, 1.372_818, 37_300_f64, 0.001_296),
+    (37_665_f64, 1.845_858, 37_665_f64, 0.001_123_2),
+    (38_334_f64, 1.945_858, 37_665_f64, 0.001_123_2),
+    (38_395_f64, 3.240_13, 38_761_f64, 0.001_296),
+    (38_486_f64, 3.340_13, 38_761_f64, 0.001_296),
+    (38_639_f64, 3.440_13, 38_761_f64, 0.001_296),
+    (38_761_f64, 3.540_13, 38_761_f64, 0.001_296),
+    (38_820_f64, 3.640_13, 38_761_f64, 0.001_296),
+    (38_942_f64, 3.740_13, 38_761_f64, 0.001_296),
+    (39_004_f64, 3.840_13, 38_761_f64, 0.001_296),
+    (39_126_f64, 4.313_17, 39_126_f64, 0.002_592),
+    (39_887_f64, 4.213_17, 39_126_f64, 0.002_592),
+];
+
+/// Time scales in which a [`JulianDay`] can be given.
+///
+/// The *VSOP87* solutions expect Julian Days in Barycentric Dynamical Time (*TDB*), that differs
+/// from Terrestrial Time (*TT*) by less than 2 milliseconds, so Julian Days in any other time
+/// scale must be converted into *TT*, or into *TDB* for the most precise barycentric positions,
+/// with [`TimeScales`] first.
+///
+/// Time scales can be parsed from their abbreviations, ignoring the case:
+///
+/// ```
+/// use vsop87::TimeScale;
+///
+/// assert_eq!("utc".parse::<TimeScale>(), Ok(TimeScale::Utc));
+/// assert_eq!(TimeScale::Ut1.to_string(), "UT1");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeScale {
+    /// Coordinated Universal Time (*UTC*), the basis of civil time. It follows International
+    /// Atomic Time, with leap seconds to keep it within 0.9 seconds of *UT1*.
+    Utc,
+    /// International Atomic Time (*TAI*).
+    Tai,
+    /// Terrestrial Time (*TT*), 32.184 seconds ahead of International Atomic Time.
+    Tt,
+    /// Universal Time (*UT1*), that follows the rotation of the Earth.
+    Ut1,
+    /// Barycentric Dynamical Time (*TDB*), the time scale of the *VSOP87* solutions. It differs
+    /// periodically from Terrestrial Time by up to 1.7 milliseconds, as given by
+    /// [`tdb_minus_tt_abridged()`](crate::tdb_minus_tt_abridged).
+    Tdb,
+    /// Geocentric Coordinate Time (*TCG*), the proper time of the geocenter. It runs faster than
+    /// Terrestrial Time by 0.7 parts per billion.
+    Tcg,
+    /// Barycentric Coordinate Time (*TCB*), the proper time of the barycenter of the solar system.
+    /// It runs faster than Barycentric Dynamical Time by 15.5 parts per billion.
+    Tcb,
+}
+
+impl TimeScale {
+    /// All the time scales.
+    pub const ALL: [Self; 7] = [
+        Self::Utc,
+        Self::Tai,
+        Self::Tt,
+        Self::Ut1,
+        Self::Tdb,
+        Self::Tcg,
+        Self::Tcb,
+    ];
+
+    /// Gets the abbreviation of the time scale.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Utc => "UTC",
+            Self::Tai => "TAI",
+            Self::Tt => "TT",
+            Self::Ut1 => "UT1",
+            Self::Tdb => "TDB",
+            Self::Tcg => "TCG",
+            Self::Tcb => "TCB",
+        }
+    }
+}
+
+impl fmt::Display for TimeScale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for TimeScale {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        Self::ALL
+            .into_iter()
+            .find(|scale| scale.name().eq_ignore_ascii_case(s))
+            .ok_or(Error::UnknownTimeScale)
+    }
+}
+
+/// Table of leap seconds: the differences between International Atomic Time and Coordinated
+/// Universal Time (*TAI − UTC*).
+///
+/// The bundled table has all the leap seconds introduced up to January 1st, 2017. When new leap
+/// seconds are announced, an updated table can be loaded at runtime from the `Leap_Second.dat`
+/// file published by the *IERS*, without updating the library, except with the `no_std` feature:
+///
+/// ```
+/// # #[cfg(not(feature = "no_std"))]
+/// # {
+/// use vsop87::{JulianDay, LeapSeconds};
+///
+/// let table: LeapSeconds = "
+/// ##    MJD        Date        TAI-UTC (s)
+/// ##           day month year
+///     41317.0    1  1 1972       10
+///     41499.0    1  7 1972       11
+/// "
+/// .parse()?;
+///
+/// assert_eq!(table.tai_minus_utc(JulianDay::from_gregorian(1972, 3, 1)?), 10.0);
+/// assert_eq!(table.tai_minus_utc(JulianDay::from_gregorian(2000, 1, 1)?), 11.0);
+///
+/// let bundled = LeapSeconds::bundled();
+/// assert_eq!(bundled.tai_minus_utc(JulianDay::from_gregorian(2000, 1, 1)?), 32.0);
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+///
+/// Between 1960 and 1972, the seconds of UTC were not SI seconds, and the difference drifted
+/// continuously: it is calculated with the formulas of the *IERS* for any table. Before 1960,
+/// UTC was not defined, and the difference is taken as zero.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "no_std", derive(Copy))]
+pub struct LeapSeconds {
+    #[cfg(not(feature = "no_std"))]
+    entries: Cow<'static, [(f64, f64)]>,
+    #[cfg(feature = "no_std")]
+    entries: &'static [(f64, f64)],
+}
+
+impl LeapSeconds {
+    /// Gets the bundled table of leap seconds.
+    #[must_use]
+    pub const fn bundled() -> Self {
+        Self {
+            #[cfg(not(feature = "no_std"))]
+            entries: Cow::Borrowed(&LEAP_SECONDS),
+            #[cfg(feature = "no_std")]
+            entries: &LEAP_SECONDS,
+        }
+    }
+
+    /// Loads a table of leap seconds from a file in the `Leap_Second.dat` format of the *IERS*.
+    ///
+    /// The file can be downloaded from <https://hpiers.obspm.fr/iers/bul/bulc/Leap_Second.dat>.
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if the file is not valid.
+    #[cfg(not(feature = "no_std"))]
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        fs::read_to_string(path)?
+            .parse()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Gets the difference between International Atomic Time and Coordinated Universal Time
+    /// (*TAI − UTC*) at the given Julian Day in *UTC*, in seconds.
+    #[must_use]
+    pub fn tai_minus_utc(&self, utc: impl Into<JulianDay>) -> f64 {
+        let mjd = utc.into().mjd();
+
+        let after = self.entries.partition_point(|&(start, _)| start <= mjd);
+        if let Some(&(_, offset)) = after.checked_sub(1).and_then(|i| self.entries.get(i)) {
+            return offset;
+        }
+
+        let after = DRIFTS.partition_point(|&(start, ..)| start <= mjd);
+        after.checked_sub(1).map_or(0_f64, |i| {
+            let (_, a, b, c) = DRIFTS[i];
+            a + (mjd - b) * c
+        })
+    }
+
+    /// Gets the Julian Day in *UTC* of the last leap second of the table, the start of the last
+    /// change of the difference between International Atomic Time and Coordinated Universal Time.
+    #[must_use]
+    pub fn last_leap_second(&self) -> JulianDay {
+        JulianDay::from_mjd(
+            self.entries
+                .last()
+                .map_or(LEAP_SECONDS[0].0, |&(start, _)| start),
+        )
+    }
+}
+
+impl Default for LeapSeconds {
+    fn default() -> Self {
+        Self::bundled()
+    }
+}
+
+/// Parses a table of leap seconds in the `Leap_Second.dat` format of the *IERS*.
+///
+/// Each line that is not empty or a comment, starting with `#`, has the Modified Julian Day, the
+/// day, the month and the year in which the difference started, and the difference, in seconds.
+/// Returns [`Error::InvalidLeapSeconds`] if any line is not valid, if the dates are not in
+/// increasing order, or if there are no entries.
+#[cfg(not(feature = "no_std"))]
+impl FromStr for LeapSeconds {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut entries: Vec<(f64, f64)> = Vec::new();
+
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split_whitespace();
+            let (Some(mjd), Some(day), Some(month), Some(year), Some(offset), None) = (
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+            ) else {
+                return Err(Error::InvalidLeapSeconds);
+            };
+
+            let mjd: f64 = mjd.parse().map_err(|_| Error::InvalidLeapSeconds)?;
+            let offset: f64 = offset.parse().map_err(|_| Error::InvalidLeapSeconds)?;
+            let date = match (day.parse(), month.parse(), year.parse()) {
+                (Ok(day), Ok(month), Ok(year)) => JulianDay::from_gregorian(year, month, day)
+                    .map_err(|_| Error::InvalidLeapSeconds)?,
+                _ => return Err(Error::InvalidLeapSeconds),
+            };
+
+            let consistent = (date.mjd() - mjd).abs() < 1e-6;
+            let increasing = entries.last().map_or(true, |&(last, _)| mjd > last);
+            if !consistent || !increasing || !offset.is_finite() {
+                return Err(Error::InvalidLeapSeconds);
+            }
+
+            entries.push((mjd, offset));
+        }
+
+        if entries.is_empty() {
+            return Err(Error::InvalidLeapSeconds);
+        }
+
+        Ok(Self {
+            entries: Cow::Owned(entries),
+        })
+    }
+}
+
+/// Converter of Julian Days between time scales.
+///
+/// It uses a table of leap seconds, the bundled one by default, and the difference between *UT1*
+/// and *UTC* (*DUT1*), that must be supplied by the user, since it changes unpredictably with the
+/// rotation of the Earth. It is published weekly by the *IERS* in its Bulletin A, and it is always
+/// below 0.9 seconds. If it is not given, it is taken as zero, so *UT1* is approximated by *UTC*.
+/// Before 1960, when *UTC* was not defined, Julian Days in Universal Time can be converted with a
+/// [`DeltaT`](crate::DeltaT) model instead.
+///
+/// Julian Days in *UTC*, such as those parsed from timestamps or taken from the system clock, must
+/// be converted into Terrestrial Time (*TT*) before using them with the *VSOP87* solutions:
+///
+/// ```
+/// # #[cfg(all(feature = "vsop87a", feature = "earth"))]
+/// # {
+/// use vsop87::{vsop87a, JulianDay, TimeScale, TimeScales};
+///
+/// let utc: JulianDay = "2024-04-08T18:17:20Z".parse()?;
+/// let time_scales = TimeScales::new().with_dut1(-0.0096);
+///
+/// let tt = time_scales.convert(utc, TimeScale::Utc, TimeScale::Tt);
+/// assert!(((tt - utc) * 86400.0 - 69.184).abs() < 1e-6);
+///
+/// let ut1 = time_scales.convert(tt, TimeScale::Tt, TimeScale::Ut1);
+/// assert!(((ut1 - utc) * 86400.0 + 0.0096).abs() < 1e-6);
+///
+/// let earth = vsop87a::earth(tt);
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+///
+/// Julian Days in *UTC* cannot represent the leap seconds themselves, such as
+/// December 31st, 2016 at 23:59:60, so they are converted as the first second of the next day.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "no_std", derive(Copy))]
+pub struct TimeScales {
+    leap_seconds: LeapSeconds,
+    dut1: f64,
+}
+
+impl TimeScales {
+    /// Creates a converter with the bundled table of leap seconds and a *DUT1* of zero.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the table of leap seconds.
+    #[must_use]
+    pub fn with_leap_seconds(self, leap_seconds: LeapSeconds) -> Self {
+        Self {
+            leap_seconds,
+            ..self
+        }
+    }
+
+    /// Sets the difference between *UT1* and *UTC* (*DUT1*), in seconds.
+    #[must_use]
+    pub fn with_dut1(self, dut1: f64) -> Self {
+        Self { dut1, ..self }
+    }
+
+    /// Gets the table of leap seconds.
+    #[must_use]
+    pub fn leap_seconds(&self) -> &LeapSeconds {
+        &self.leap_seconds
+    }
+
+    /// Gets the difference between *UT1* and *UTC* (*DUT1*), in seconds.
+    #[must_use]
+    pub fn dut1(&self) -> f64 {
+        self.dut1
+    }
+
+    /// Converts the given Julian Day from one time scale to another.
+    #[must_use]
+    pub fn convert(&self, jd: impl Into<JulianDay>, from: TimeScale, to: TimeScale) -> JulianDay {
+        let jd = jd.into();
+        if from == to {
+            return jd;
+        }
+
+        self.tai_to_scale(self.scale_to_tai(jd, from), to)
+    }
+
+    /// Converts the given Julian Day into Terrestrial Time (*TT*), the time scale expected by the
+    /// *VSOP87* solutions.
+    #[must_use]
+    pub fn tt(&self, jd: impl Into<JulianDay>, scale: TimeScale) -> JulianDay {
+        self.convert(jd, scale, TimeScale::Tt)
+    }
+
+    /// Converts the given Julian Day into Barycentric Dynamical Time (*TDB*), the time scale of the
+    /// *VSOP87* solutions.
+    #[must_use]
+    pub fn tdb(&self, jd: impl Into<JulianDay>, scale: TimeScale) -> JulianDay {
+        self.convert(jd, scale, TimeScale::Tdb)
+    }
+
+    /// Converts the given Julian Day into International Atomic Time.
+    fn scale_to_tai(&self, jd: JulianDay, scale: TimeScale) -> JulianDay {
+        match scale {
+            TimeScale::Utc => jd + self.leap_seconds.tai_minus_utc(jd) / SECONDS_PER_DAY,
+            TimeScale::Tai => jd,
+            TimeScale::Tt => jd - TT_MINUS_TAI / SECONDS_PER_DAY,
+            TimeScale::Ut1 => self.scale_to_tai(jd - self.dut1 / SECONDS_PER_DAY, TimeScale::Utc),
+            TimeScale::Tdb => self.scale_to_tai(
+                jd - tdb_minus_tt_abridged(jd) / SECONDS_PER_DAY,
+                TimeScale::Tt,
+            ),
+            TimeScale::Tcg => self.scale_to_tai(jd - L_G * (jd - COORDINATE_EPOCH), TimeScale::Tt),
+            TimeScale::Tcb => {
+                let tdb = jd - L_B * (jd - COORDINATE_EPOCH) + TDB_0 / SECONDS_PER_DAY;
+                self.scale_to_tai(tdb, TimeScale::Tdb)
+            }
+        }
+    }
+
+    /// Converts the given Julian Day from International Atomic Time.
+    fn tai_to_scale(&self, tai: JulianDay, scale: TimeScale) -> JulianDay {
+        match scale {
+            TimeScale::Utc => {
+                // The difference depends on the UTC date, so it is refined once.
+                let utc = tai - self.leap_seconds.tai_minus_utc(tai) / SECONDS_PER_DAY;
+                tai - self.leap_seconds.tai_minus_utc(utc) / SECONDS_PER_DAY
+            }
+            TimeScale::Tai => tai,
+            TimeScale::Tt => tai + TT_MINUS_TAI / SECONDS_PER_DAY,
+            TimeScale::Ut1 => self.tai_to_scale(tai, TimeScale::Utc) + self.dut1 / SECONDS_PER_DAY,
+            TimeScale::Tdb => {
+                let tt = self.tai_to_scale(tai, TimeScale::Tt);
+                tt + tdb_minus_tt_abridged(tt) / SECONDS_PER_DAY
+            }
+            TimeScale::Tcg => {
+                let tt = self.tai_to_scale(tai, TimeScale::Tt);
+                tt + L_G / (1_f64 - L_G) * (tt - COORDINATE_EPOCH)
+            }
+            TimeScale::Tcb => {
+                let tdb = self.tai_to_scale(tai, TimeScale::Tdb);
+                let offset = L_B * (tdb - COORDINATE_EPOCH) - TDB_0 / SECONDS_PER_DAY;
+                tdb + offset / (1_f64 - L_B)
+            }
+        }
+    }
+}
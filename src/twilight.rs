@@ -0,0 +1,141 @@
+//! Beginning and end of the twilights.
+
+use super::{
+    horizontal_coordinates,
+    rise_set::{extremes, samples, search, visibility},
+    Body, DeltaT, Error, HorizontalCoordinates, JulianDay, Nutation, Observer, Refraction,
+    Visibility,
+};
+
+/// Twilight, defined by the depression of the center of the Sun below the horizon.
+///
+/// The depression is the opposite of the geometric altitude of the Sun, without refraction.
+/// Custom depressions can be used for other periods of the day, such as the golden hour, when the
+/// Sun is between 6° above and 4° below the horizon, and the blue hour, between 4° and 6° below
+/// it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Twilight {
+    /// Civil twilight, with the Sun 6° below the horizon: the brightest stars and planets appear,
+    /// and the outdoor activities need artificial light.
+    Civil,
+    /// Nautical twilight, with the Sun 12° below the horizon: the horizon at sea can no longer be
+    /// seen to measure the altitude of the stars.
+    Nautical,
+    /// Astronomical twilight, with the Sun 18° below the horizon: the sky is fully dark.
+    Astronomical,
+    /// Custom depression below the horizon, in radians, negative above the horizon.
+    Custom(f64),
+}
+
+impl Twilight {
+    /// Gets the depression of the Sun below the horizon, in radians.
+    #[must_use]
+    pub fn depression(self) -> f64 {
+        match self {
+            Self::Civil => 6_f64.to_radians(),
+            Self::Nautical => 12_f64.to_radians(),
+            Self::Astronomical => 18_f64.to_radians(),
+            Self::Custom(depression) => depression,
+        }
+    }
+}
+
+/// Beginning and end of a twilight during a day, as Julian Days in *UT1*.
+///
+/// The twilight begins in the morning, when the Sun rises above its depression, and it ends in
+/// the evening, when the Sun sets below it. Each time is the first event of its kind during the
+/// day, and it is `None` if there is no such event: near the poles, the Sun can stay above the
+/// depression during the whole day, like in the polar day or the white nights, or below it, like
+/// in the polar night.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwilightTimes {
+    begin: Option<JulianDay>,
+    end: Option<JulianDay>,
+    visibility: Visibility,
+}
+
+impl TwilightTimes {
+    /// Gets the time when the twilight begins, in the morning, if any.
+    #[must_use]
+    pub fn begin(&self) -> Option<JulianDay> {
+        self.begin
+    }
+
+    /// Gets the time when the twilight ends, in the evening, if any.
+    #[must_use]
+    pub fn end(&self) -> Option<JulianDay> {
+        self.end
+    }
+
+    /// Gets whether the Sun crosses the depression of the twilight during the day, or stays above
+    /// ([`Visibility::Circumpolar`]) or below it ([`Visibility::NeverRises`]).
+    #[must_use]
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+}
+
+/// Calculates the beginning and the end of a twilight for an observer on the surface of the
+/// Earth, during the day that starts at the given Julian Day in *UT1*.
+///
+/// The positions of the Sun are calculated and the events are found in the same way as in
+/// [`rise_transit_set()`](crate::rise_transit_set), from the apparent topocentric place of the
+/// Sun, given by *VSOP87E*, so that a twilight that begins and ends within an hour is not
+/// missed.
+///
+/// It returns the same errors as [`geocentric_place()`](crate::geocentric_place).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{twilight, DeltaT, JulianDay, Observer, Twilight, Visibility};
+///
+/// let paris = Observer::new(48.857_f64.to_radians(), 2.352_f64.to_radians(), 35.0);
+/// let date = JulianDay::from_gregorian(2024, 6, 21)?;
+///
+/// // The civil twilight begins before 3h30m and ends after 20h20m UT.
+/// let civil = twilight(paris, date, DeltaT::EspenakMeeus, Twilight::Civil)?;
+/// assert!(civil.begin().unwrap() < date + 3.5 / 24.0);
+/// assert!(civil.end().unwrap() > date + 20.33 / 24.0);
+///
+/// // At the summer solstice, the Sun never goes 18° below the horizon in Paris.
+/// let astronomical = twilight(paris, date, DeltaT::EspenakMeeus, Twilight::Astronomical)?;
+/// assert_eq!(astronomical.visibility(), Visibility::Circumpolar);
+/// assert_eq!(astronomical.begin(), None);
+///
+/// // The evening blue hour, with the Sun between 4° and 6° below the horizon.
+/// let blue = Twilight::Custom(4_f64.to_radians());
+/// let start = twilight(paris, date, DeltaT::EspenakMeeus, blue)?;
+/// assert!(start.end().unwrap() < civil.end().unwrap());
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+pub fn twilight(
+    observer: Observer,
+    date: impl Into<JulianDay>,
+    delta_t: DeltaT,
+    twilight: Twilight,
+) -> Result<TwilightTimes, Error> {
+    let position = |ut1: JulianDay| {
+        horizontal_coordinates(
+            Body::Sun,
+            observer,
+            ut1,
+            delta_t.tt(ut1),
+            Nutation::Iau2000B,
+            Refraction::None,
+        )
+    };
+    let samples = samples(&position, date.into())?;
+    let extremes = extremes(&samples, &position)?;
+
+    let depression = twilight.depression();
+    let above = |position: &HorizontalCoordinates| position.geometric_altitude() + depression;
+    let begin = search(extremes.points(), &position, above, true)?;
+    let end = search(extremes.points(), &position, above, false)?;
+
+    Ok(TwilightTimes {
+        begin,
+        end,
+        visibility: visibility(extremes.points(), begin, end, above),
+    })
+}
@@ -0,0 +1,831 @@
+//! Coordinates tagged with their reference frame and their center.
+//!
+//! The functions of the *VSOP87* solutions return plain [`RectangularCoordinates`] and
+//! [`SphericalCoordinates`], whatever their frame and center are, so nothing prevents subtracting
+//! a barycentric position of *VSOP87E* from a heliocentric position of *VSOP87A*, or a position of
+//! *VSOP87C*, referred to the equinox of the date, from one referred to J2000.0. The types of this
+//! module carry the frame and the center as type parameters, so that those mistakes do not
+//! compile:
+//!
+//! - [`Position`] and [`Spherical`] are the position of a body relative to a center.
+//! - [`Displacement`] is the difference between two positions, that has no center.
+//! - [`Velocity`] and [`State`] add the velocity of the body relative to the center.
+//!
+//! Each solution module has a `typed()` function that returns them, and the conversions between
+//! frames and centers are explicit methods. The new center of a conversion follows from the
+//! method, such as [`Position::geocentric()`], so that a position can not be relabelled with the
+//! wrong center. In the same way, the rotations between frames are only available as named
+//! conversions, such as [`Position::to_fk5()`], that apply the right rotation for the frames.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(all(feature = "vsop87a", feature = "vsop87e", feature = "sun", feature = "earth", feature = "mars"))]
+//! # {
+//! use vsop87::{vsop87a, vsop87e, Body};
+//!
+//! let jde = 2451545.0;
+//! let mars = vsop87a::typed(Body::Mars, jde)?;
+//! let earth = vsop87a::typed(Body::Earth, jde)?;
+//!
+//! // The position of Mars seen from the Earth, in the FK5 frame.
+//! let geocentric = mars.geocentric(earth).to_fk5();
+//! assert!((geocentric.distance() - 1.849).abs() < 0.001);
+//!
+//! // Barycentric positions must be made heliocentric before mixing them with VSOP87A.
+//! let sun = vsop87e::typed(Body::Sun, jde)?;
+//! let earth_e = vsop87e::typed(Body::Earth, jde)?.heliocentric(sun);
+//! assert!((earth_e - earth).length() < 1e-6);
+//! # }
+//! # Ok::<(), vsop87::Error>(())
+//! ```
+//!
+//! Mixing frames or centers does not compile:
+//!
+//! ```compile_fail
+//! use vsop87::{vsop87a, vsop87e, Body};
+//!
+//! let earth = vsop87e::typed(Body::Earth, 2451545.0)?;
+//! let difference = earth - vsop87a::typed(Body::Mars, 2451545.0)?;
+//! # Ok::<(), vsop87::Error>(())
+//! ```
+//!
+//! ```compile_fail
+//! use vsop87::{vsop87a, vsop87e, Body};
+//!
+//! let mars = vsop87e::typed(Body::Mars, 2451545.0)?;
+//! let geocentric = mars.geocentric(vsop87a::typed(Body::Earth, 2451545.0)?);
+//! # Ok::<(), vsop87::Error>(())
+//! ```
+//!
+//! ```compile_fail
+//! use vsop87::{vsop87a, vsop87c, Body};
+//!
+//! let earth = vsop87c::typed(Body::Earth, 2451545.0)?;
+//! let difference = earth - vsop87a::typed(Body::Mars, 2451545.0)?;
+//! # Ok::<(), vsop87::Error>(())
+//! ```
+
+use core::{
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+#[cfg(feature = "no_std")]
+use libm::sqrt;
+
+use super::{
+    JulianDay, Nutation, Precession, RectangularCoordinates, Rotation, SphericalCoordinates,
+    StateVector,
+};
+
+/// Reference frame of typed coordinates.
+///
+/// It is implemented by the frames of this module, and it can be implemented by other frames,
+/// that are only used as type parameters.
+pub trait ReferenceFrame: Debug + Clone + Copy + PartialEq {}
+
+/// Center of typed coordinates.
+///
+/// It is implemented by the centers of this module, and it can be implemented by other centers,
+/// that are only used as type parameters.
+pub trait Origin: Debug + Clone + Copy + PartialEq {}
+
+/// *VSOP87* dynamical ecliptic and equinox J2000.0, used by *VSOP87*, *VSOP87A*, *VSOP87B* and
+/// *VSOP87E*.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EclipticJ2000 {}
+
+/// *VSOP87* dynamical ecliptic and equinox of the date, used by *VSOP87C* and *VSOP87D*.
+///
+/// The date is not part of the type, so it must be the same for all the coordinates that are
+/// combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EclipticOfDate {}
+
+/// Mean equator and equinox J2000.0 of the *FK5* catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fk5 {}
+
+/// International Celestial Reference System (*ICRS*).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Icrs {}
+
+/// Mean equator and equinox of the date.
+///
+/// The date is not part of the type, so it must be the same for all the coordinates that are
+/// combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MeanEquatorOfDate {}
+
+/// True equator and equinox of the date, affected by nutation.
+///
+/// The date is not part of the type, so it must be the same for all the coordinates that are
+/// combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrueEquatorOfDate {}
+
+impl ReferenceFrame for EclipticJ2000 {}
+impl ReferenceFrame for EclipticOfDate {}
+impl ReferenceFrame for Fk5 {}
+impl ReferenceFrame for Icrs {}
+impl ReferenceFrame for MeanEquatorOfDate {}
+impl ReferenceFrame for TrueEquatorOfDate {}
+
+/// Center of the Sun, used by all the solutions except *VSOP87E*.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heliocentric {}
+
+/// Barycenter of the solar system, used by *VSOP87E*.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Barycentric {}
+
+/// Center of the Earth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Geocentric {}
+
+/// Observer on the surface of the Earth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Topocentric {}
+
+impl Origin for Heliocentric {}
+impl Origin for Barycentric {}
+impl Origin for Geocentric {}
+impl Origin for Topocentric {}
+
+/// Position of a body relative to a center, in rectangular coordinates, in *AU*.
+///
+/// Two positions with the same frame and center can be subtracted, giving the [`Displacement`]
+/// between them, and a displacement can be added to a position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position<F, C> {
+    coordinates: RectangularCoordinates,
+    tags: PhantomData<(F, C)>,
+}
+
+/// Position of a body relative to a center, in spherical coordinates.
+///
+/// It can be converted from and into a [`Position`] with the `From` trait.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spherical<F, C> {
+    coordinates: SphericalCoordinates,
+    tags: PhantomData<(F, C)>,
+}
+
+/// Difference between two positions in the same frame, in rectangular coordinates, in *AU*.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Displacement<F> {
+    coordinates: RectangularCoordinates,
+    frame: PhantomData<F>,
+}
+
+/// Velocity of a body, in rectangular coordinates, in *AU* per day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Velocity<F> {
+    coordinates: RectangularCoordinates,
+    frame: PhantomData<F>,
+}
+
+/// Position and velocity of a body relative to a center.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct State<F, C> {
+    position: Position<F, C>,
+    velocity: Velocity<F>,
+}
+
+impl<F: ReferenceFrame, C: Origin> Position<F, C> {
+    /// Tags rectangular coordinates with their frame and their center.
+    #[must_use]
+    pub const fn new(coordinates: RectangularCoordinates) -> Self {
+        Self {
+            coordinates,
+            tags: PhantomData,
+        }
+    }
+
+    /// Gets the untagged rectangular coordinates.
+    #[must_use]
+    pub fn coordinates(&self) -> RectangularCoordinates {
+        self.coordinates
+    }
+
+    /// Gets the distance to the center, in *AU*.
+    #[must_use]
+    pub fn distance(&self) -> f64 {
+        length(self.coordinates)
+    }
+
+    /// Gets the position relative to a new center, given the position of the new center relative
+    /// to the current one. The public conversions fix the new center, so that it can not be
+    /// mislabelled.
+    fn relative_to<D: Origin>(self, center: Self) -> Position<F, D> {
+        Position::new((self - center).coordinates)
+    }
+
+    /// Gets the displacement from the center to the position.
+    ///
+    /// It can be added to a position relative to another center: for example, the position of an
+    /// observer relative to the center of the Earth can be added to the barycentric position of
+    /// the Earth.
+    #[must_use]
+    pub fn displacement(self) -> Displacement<F> {
+        Displacement::new(self.coordinates)
+    }
+
+    /// Rotates the position into a new frame, with the given rotation.
+    ///
+    /// The rotation is not checked: it must rotate from the frame of the position to the new one.
+    #[must_use]
+    pub(crate) fn into_frame<G: ReferenceFrame>(self, rotation: Rotation) -> Position<G, C> {
+        Position::new(rotation * self.coordinates)
+    }
+}
+
+impl<F: ReferenceFrame> Position<F, Barycentric> {
+    /// Gets the position relative to the Sun, given the barycentric position of the Sun.
+    #[must_use]
+    pub fn heliocentric(self, sun: Self) -> Position<F, Heliocentric> {
+        self.relative_to(sun)
+    }
+
+    /// Gets the position relative to the Earth, given the barycentric position of the Earth.
+    #[must_use]
+    pub fn geocentric(self, earth: Self) -> Position<F, Geocentric> {
+        self.relative_to(earth)
+    }
+}
+
+impl<F: ReferenceFrame> Position<F, Heliocentric> {
+    /// Gets the position relative to the Earth, given the heliocentric position of the Earth.
+    #[must_use]
+    pub fn geocentric(self, earth: Self) -> Position<F, Geocentric> {
+        self.relative_to(earth)
+    }
+}
+
+impl<F: ReferenceFrame> Position<F, Geocentric> {
+    /// Gets the position relative to an observer on the surface of the Earth, given the position
+    /// of the observer relative to the center of the Earth.
+    #[must_use]
+    pub fn topocentric(self, observer: Self) -> Position<F, Topocentric> {
+        self.relative_to(observer)
+    }
+}
+
+impl<C: Origin> Position<EclipticJ2000, C> {
+    /// Rotates the position into the *FK5* frame.
+    ///
+    /// See [`Rotation::VSOP87_TO_FK5`] for more information.
+    #[must_use]
+    pub fn to_fk5(self) -> Position<Fk5, C> {
+        self.into_frame(Rotation::VSOP87_TO_FK5)
+    }
+
+    /// Rotates the position into the *ICRS*.
+    ///
+    /// See [`Rotation::VSOP87_TO_ICRS`] for more information.
+    #[must_use]
+    pub fn to_icrs(self) -> Position<Icrs, C> {
+        self.into_frame(Rotation::VSOP87_TO_ICRS)
+    }
+
+    /// Precesses the position into the ecliptic and equinox of the given date, as a Julian Day in
+    /// *TT*.
+    ///
+    /// The IAU 1976 precession reproduces the frame of *VSOP87C* and *VSOP87D* to 0.02″. See
+    /// [`Precession`] for more information.
+    #[must_use]
+    pub fn to_ecliptic_of_date(
+        self,
+        tt: impl Into<JulianDay>,
+        precession: Precession,
+    ) -> Position<EclipticOfDate, C> {
+        self.into_frame(precession.ecliptic(JulianDay::J2000, tt))
+    }
+}
+
+impl<C: Origin> Position<EclipticOfDate, C> {
+    /// Precesses the position from the ecliptic and equinox of the given date, as a Julian Day in
+    /// *TT*, into the ecliptic and equinox J2000.0.
+    #[must_use]
+    pub fn to_ecliptic_j2000(
+        self,
+        tt: impl Into<JulianDay>,
+        precession: Precession,
+    ) -> Position<EclipticJ2000, C> {
+        self.into_frame(precession.ecliptic(tt, JulianDay::J2000))
+    }
+
+    /// Rotates the position into the mean equator and equinox of the date, given as a Julian Day
+    /// in *TT*.
+    ///
+    /// The position is first corrected to the *FK5* frame with
+    /// [`Rotation::vsop87_of_date_to_fk5()`].
+    #[must_use]
+    pub fn to_mean_equator_of_date(
+        self,
+        tt: impl Into<JulianDay>,
+        precession: Precession,
+    ) -> Position<MeanEquatorOfDate, C> {
+        let tt = tt.into();
+        self.into_frame(precession.ecliptic_to_equatorial(tt) * Rotation::vsop87_of_date_to_fk5(tt))
+    }
+
+    /// Rotates the position into the true equator and equinox of the date, given as a Julian Day
+    /// in *TT*.
+    ///
+    /// See [`RectangularCoordinates::to_true_equatorial()`] for more information.
+    #[must_use]
+    pub fn to_true_equator_of_date(
+        self,
+        tt: impl Into<JulianDay>,
+        nutation: Nutation,
+    ) -> Position<TrueEquatorOfDate, C> {
+        Position::new(self.coordinates.to_true_equatorial(tt, nutation))
+    }
+}
+
+impl<C: Origin> Position<Fk5, C> {
+    /// Precesses the position into the mean equator and equinox of the given date, as a Julian
+    /// Day in *TT*.
+    #[must_use]
+    pub fn to_mean_equator_of_date(
+        self,
+        tt: impl Into<JulianDay>,
+        precession: Precession,
+    ) -> Position<MeanEquatorOfDate, C> {
+        self.into_frame(precession.equatorial(JulianDay::J2000, tt))
+    }
+
+    /// Rotates the position from the *FK5* frame into the ecliptic and equinox of J2000.0 of
+    /// *VSOP87*.
+    #[must_use]
+    pub fn to_ecliptic_j2000(self) -> Position<EclipticJ2000, C> {
+        self.into_frame(Rotation::VSOP87_TO_FK5.inverse())
+    }
+}
+
+impl<C: Origin> Position<Icrs, C> {
+    /// Precesses the position into the mean equator and equinox of the given date, as a Julian
+    /// Day in *TT*, after removing the [frame bias](Rotation::FRAME_BIAS).
+    #[must_use]
+    pub fn to_mean_equator_of_date(
+        self,
+        tt: impl Into<JulianDay>,
+        precession: Precession,
+    ) -> Position<MeanEquatorOfDate, C> {
+        self.into_frame(
+            precession.equatorial(JulianDay::J2000, tt) * Rotation::FRAME_BIAS.inverse(),
+        )
+    }
+
+    /// Rotates the position from the *ICRS* into the ecliptic and equinox of J2000.0 of *VSOP87*.
+    #[must_use]
+    pub fn to_ecliptic_j2000(self) -> Position<EclipticJ2000, C> {
+        self.into_frame(Rotation::VSOP87_TO_ICRS.inverse())
+    }
+}
+
+impl<C: Origin> Position<MeanEquatorOfDate, C> {
+    /// Rotates the position into the true equator and equinox of the date, given as a Julian Day
+    /// in *TT*.
+    #[must_use]
+    pub fn to_true_equator_of_date(
+        self,
+        tt: impl Into<JulianDay>,
+        nutation: Nutation,
+    ) -> Position<TrueEquatorOfDate, C> {
+        self.into_frame(nutation.equatorial(tt))
+    }
+
+    /// Precesses the position from the mean equator and equinox of the given date, as a Julian Day
+    /// in *TT*, into those of the *FK5* frame.
+    #[must_use]
+    pub fn to_fk5(self, tt: impl Into<JulianDay>, precession: Precession) -> Position<Fk5, C> {
+        self.into_frame(precession.equatorial(tt, JulianDay::J2000))
+    }
+
+    /// Precesses the position from the mean equator and equinox of the given date, as a Julian Day
+    /// in *TT*, into the *ICRS*, adding the [frame bias](Rotation::FRAME_BIAS).
+    #[must_use]
+    pub fn to_icrs(self, tt: impl Into<JulianDay>, precession: Precession) -> Position<Icrs, C> {
+        self.into_frame(Rotation::FRAME_BIAS * precession.equatorial(tt, JulianDay::J2000))
+    }
+}
+
+impl<C: Origin> Position<TrueEquatorOfDate, C> {
+    /// Rotates the position into the mean equator and equinox of the date, given as a Julian Day
+    /// in *TT*.
+    #[must_use]
+    pub fn to_mean_equator_of_date(
+        self,
+        tt: impl Into<JulianDay>,
+        nutation: Nutation,
+    ) -> Position<MeanEquatorOfDate, C> {
+        self.into_frame(nutation.equatorial(tt).inverse())
+    }
+}
+
+impl<F: ReferenceFrame, C: Origin> Sub for Position<F, C> {
+    type Output = Displacement<F>;
+
+    fn sub(self, rhs: Self) -> Displacement<F> {
+        Displacement::new(RectangularCoordinates {
+            x: self.coordinates.x - rhs.coordinates.x,
+            y: self.coordinates.y - rhs.coordinates.y,
+            z: self.coordinates.z - rhs.coordinates.z,
+        })
+    }
+}
+
+impl<F: ReferenceFrame, C: Origin> Add<Displacement<F>> for Position<F, C> {
+    type Output = Self;
+
+    fn add(self, rhs: Displacement<F>) -> Self {
+        Self::new(sum(self.coordinates, rhs.coordinates, 1_f64))
+    }
+}
+
+impl<F: ReferenceFrame, C: Origin> Sub<Displacement<F>> for Position<F, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Displacement<F>) -> Self {
+        Self::new(sum(self.coordinates, rhs.coordinates, -1_f64))
+    }
+}
+
+impl<F: ReferenceFrame, C: Origin> From<Spherical<F, C>> for Position<F, C> {
+    fn from(spherical: Spherical<F, C>) -> Self {
+        Self::new(spherical.coordinates.into())
+    }
+}
+
+impl<F: ReferenceFrame, C: Origin> Spherical<F, C> {
+    /// Tags spherical coordinates with their frame and their center.
+    ///
+    /// In equatorial frames, the longitude is the right ascension and the latitude is the
+    /// declination.
+    #[must_use]
+    pub const fn new(coordinates: SphericalCoordinates) -> Self {
+        Self {
+            coordinates,
+            tags: PhantomData,
+        }
+    }
+
+    /// Gets the untagged spherical coordinates.
+    #[must_use]
+    pub fn coordinates(&self) -> SphericalCoordinates {
+        self.coordinates
+    }
+}
+
+impl<F: ReferenceFrame, C: Origin> From<Position<F, C>> for Spherical<F, C> {
+    fn from(position: Position<F, C>) -> Self {
+        Self::new(position.coordinates.into())
+    }
+}
+
+impl<F: ReferenceFrame> Displacement<F> {
+    /// Tags rectangular coordinates of a displacement with their frame.
+    #[must_use]
+    pub const fn new(coordinates: RectangularCoordinates) -> Self {
+        Self {
+            coordinates,
+            frame: PhantomData,
+        }
+    }
+
+    /// Gets the untagged rectangular coordinates.
+    #[must_use]
+    pub fn coordinates(&self) -> RectangularCoordinates {
+        self.coordinates
+    }
+
+    /// Gets the length of the displacement, in *AU*.
+    #[must_use]
+    pub fn length(&self) -> f64 {
+        length(self.coordinates)
+    }
+
+    /// Rotates the displacement into a new frame, with the given rotation.
+    ///
+    /// The rotation is not checked: it must rotate from the frame of the displacement to the new
+    /// one.
+    #[must_use]
+    pub(crate) fn into_frame<G: ReferenceFrame>(self, rotation: Rotation) -> Displacement<G> {
+        Displacement::new(rotation * self.coordinates)
+    }
+}
+
+impl Displacement<EclipticJ2000> {
+    /// Rotates the displacement into the *FK5* frame.
+    #[must_use]
+    pub fn to_fk5(self) -> Displacement<Fk5> {
+        self.into_frame(Rotation::VSOP87_TO_FK5)
+    }
+
+    /// Rotates the displacement into the *ICRS*.
+    #[must_use]
+    pub fn to_icrs(self) -> Displacement<Icrs> {
+        self.into_frame(Rotation::VSOP87_TO_ICRS)
+    }
+}
+
+impl Displacement<Fk5> {
+    /// Rotates the displacement from the *FK5* frame into the ecliptic and equinox of J2000.0 of
+    /// *VSOP87*.
+    #[must_use]
+    pub fn to_ecliptic_j2000(self) -> Displacement<EclipticJ2000> {
+        self.into_frame(Rotation::VSOP87_TO_FK5.inverse())
+    }
+}
+
+impl Displacement<Icrs> {
+    /// Rotates the displacement from the *ICRS* into the ecliptic and equinox of J2000.0 of *VSOP87*.
+    #[must_use]
+    pub fn to_ecliptic_j2000(self) -> Displacement<EclipticJ2000> {
+        self.into_frame(Rotation::VSOP87_TO_ICRS.inverse())
+    }
+}
+
+impl<F: ReferenceFrame> Add for Displacement<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(sum(self.coordinates, rhs.coordinates, 1_f64))
+    }
+}
+
+impl<F: ReferenceFrame> Sub for Displacement<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(sum(self.coordinates, rhs.coordinates, -1_f64))
+    }
+}
+
+impl<F: ReferenceFrame> Neg for Displacement<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(scale(self.coordinates, -1_f64))
+    }
+}
+
+impl<F: ReferenceFrame> Mul<f64> for Displacement<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(scale(self.coordinates, rhs))
+    }
+}
+
+impl<F: ReferenceFrame> Velocity<F> {
+    /// Tags rectangular coordinates of a velocity with their frame.
+    #[must_use]
+    pub const fn new(coordinates: RectangularCoordinates) -> Self {
+        Self {
+            coordinates,
+            frame: PhantomData,
+        }
+    }
+
+    /// Gets the untagged rectangular coordinates.
+    #[must_use]
+    pub fn coordinates(&self) -> RectangularCoordinates {
+        self.coordinates
+    }
+
+    /// Gets the speed, in *AU* per day.
+    #[must_use]
+    pub fn speed(&self) -> f64 {
+        length(self.coordinates)
+    }
+
+    /// Rotates the velocity into a new frame, with the given rotation.
+    ///
+    /// The rotation is not checked: it must rotate from the frame of the velocity to the new one.
+    #[must_use]
+    pub(crate) fn into_frame<G: ReferenceFrame>(self, rotation: Rotation) -> Velocity<G> {
+        Velocity::new(rotation * self.coordinates)
+    }
+}
+
+impl Velocity<EclipticJ2000> {
+    /// Rotates the velocity into the *FK5* frame.
+    #[must_use]
+    pub fn to_fk5(self) -> Velocity<Fk5> {
+        self.into_frame(Rotation::VSOP87_TO_FK5)
+    }
+
+    /// Rotates the velocity into the *ICRS*.
+    #[must_use]
+    pub fn to_icrs(self) -> Velocity<Icrs> {
+        self.into_frame(Rotation::VSOP87_TO_ICRS)
+    }
+}
+
+impl Velocity<Fk5> {
+    /// Rotates the velocity from the *FK5* frame into the ecliptic and equinox of J2000.0 of
+    /// *VSOP87*.
+    #[must_use]
+    pub fn to_ecliptic_j2000(self) -> Velocity<EclipticJ2000> {
+        self.into_frame(Rotation::VSOP87_TO_FK5.inverse())
+    }
+}
+
+impl Velocity<Icrs> {
+    /// Rotates the velocity from the *ICRS* into the ecliptic and equinox of J2000.0 of *VSOP87*.
+    #[must_use]
+    pub fn to_ecliptic_j2000(self) -> Velocity<EclipticJ2000> {
+        self.into_frame(Rotation::VSOP87_TO_ICRS.inverse())
+    }
+}
+
+impl<F: ReferenceFrame> Add for Velocity<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(sum(self.coordinates, rhs.coordinates, 1_f64))
+    }
+}
+
+impl<F: ReferenceFrame> Sub for Velocity<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(sum(self.coordinates, rhs.coordinates, -1_f64))
+    }
+}
+
+impl<F: ReferenceFrame> Neg for Velocity<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(scale(self.coordinates, -1_f64))
+    }
+}
+
+impl<F: ReferenceFrame> Mul<f64> for Velocity<F> {
+    type Output = Displacement<F>;
+
+    /// Calculates the displacement after the given number of days.
+    fn mul(self, rhs: f64) -> Displacement<F> {
+        Displacement::new(scale(self.coordinates, rhs))
+    }
+}
+
+impl<F: ReferenceFrame, C: Origin> State<F, C> {
+    /// Tags a state vector with its frame and its center.
+    #[must_use]
+    pub const fn new(state: StateVector) -> Self {
+        Self {
+            position: Position::new(state.position),
+            velocity: Velocity::new(state.velocity),
+        }
+    }
+
+    /// Gets the position of the body.
+    #[must_use]
+    pub fn position(&self) -> Position<F, C> {
+        self.position
+    }
+
+    /// Gets the velocity of the body, relative to the center.
+    #[must_use]
+    pub fn velocity(&self) -> Velocity<F> {
+        self.velocity
+    }
+
+    /// Gets the untagged state vector.
+    #[must_use]
+    pub fn state_vector(&self) -> StateVector {
+        StateVector {
+            position: self.position.coordinates,
+            velocity: self.velocity.coordinates,
+        }
+    }
+
+    /// Gets the state relative to a new center, given the state of the new center relative to the
+    /// current one.
+    fn relative_to<D: Origin>(self, center: Self) -> State<F, D> {
+        State {
+            position: self.position.relative_to(center.position),
+            velocity: self.velocity - center.velocity,
+        }
+    }
+
+    /// Rotates the position and the velocity into a new frame, with the given rotation.
+    ///
+    /// The rotation is not checked: it must rotate from the frame of the state to the new one.
+    /// The rotation is assumed to be constant, which is accurate for the slow precession and
+    /// nutation.
+    #[must_use]
+    pub(crate) fn into_frame<G: ReferenceFrame>(self, rotation: Rotation) -> State<G, C> {
+        State {
+            position: self.position.into_frame(rotation),
+            velocity: self.velocity.into_frame(rotation),
+        }
+    }
+}
+
+impl<F: ReferenceFrame> State<F, Barycentric> {
+    /// Gets the state relative to the Sun, given the barycentric state of the Sun.
+    #[must_use]
+    pub fn heliocentric(self, sun: Self) -> State<F, Heliocentric> {
+        self.relative_to(sun)
+    }
+
+    /// Gets the state relative to the Earth, given the barycentric state of the Earth.
+    #[must_use]
+    pub fn geocentric(self, earth: Self) -> State<F, Geocentric> {
+        self.relative_to(earth)
+    }
+}
+
+impl<F: ReferenceFrame> State<F, Heliocentric> {
+    /// Gets the state relative to the Earth, given the heliocentric state of the Earth.
+    #[must_use]
+    pub fn geocentric(self, earth: Self) -> State<F, Geocentric> {
+        self.relative_to(earth)
+    }
+}
+
+impl<F: ReferenceFrame> State<F, Geocentric> {
+    /// Gets the state relative to an observer on the surface of the Earth, given the state of the
+    /// observer relative to the center of the Earth.
+    #[must_use]
+    pub fn topocentric(self, observer: Self) -> State<F, Topocentric> {
+        self.relative_to(observer)
+    }
+}
+
+impl<C: Origin> State<EclipticJ2000, C> {
+    /// Rotates the position and the velocity into the *FK5* frame.
+    #[must_use]
+    pub fn to_fk5(self) -> State<Fk5, C> {
+        self.into_frame(Rotation::VSOP87_TO_FK5)
+    }
+
+    /// Rotates the position and the velocity into the *ICRS*.
+    #[must_use]
+    pub fn to_icrs(self) -> State<Icrs, C> {
+        self.into_frame(Rotation::VSOP87_TO_ICRS)
+    }
+}
+
+impl<C: Origin> State<Fk5, C> {
+    /// Rotates the position and the velocity from the *FK5* frame into the ecliptic and equinox
+    /// of J2000.0 of *VSOP87*.
+    #[must_use]
+    pub fn to_ecliptic_j2000(self) -> State<EclipticJ2000, C> {
+        self.into_frame(Rotation::VSOP87_TO_FK5.inverse())
+    }
+}
+
+impl<C: Origin> State<Icrs, C> {
+    /// Rotates the position and the velocity from the *ICRS* into the ecliptic and equinox of
+    /// J2000.0 of *VSOP87*.
+    #[must_use]
+    pub fn to_ecliptic_j2000(self) -> State<EclipticJ2000, C> {
+        self.into_frame(Rotation::VSOP87_TO_ICRS.inverse())
+    }
+}
+
+/// Calculates the length of a vector.
+fn length(coordinates: RectangularCoordinates) -> f64 {
+    let RectangularCoordinates { x, y, z } = coordinates;
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        (x * x + y * y + z * z).sqrt()
+    }
+    #[cfg(feature = "no_std")]
+    {
+        sqrt(x * x + y * y + z * z)
+    }
+}
+
+/// Adds the second vector, multiplied by the given factor, to the first one.
+fn sum(
+    a: RectangularCoordinates,
+    b: RectangularCoordinates,
+    factor: f64,
+) -> RectangularCoordinates {
+    RectangularCoordinates {
+        x: a.x + factor * b.x,
+        y: a.y + factor * b.y,
+        z: a.z + factor * b.z,
+    }
+}
+
+/// Multiplies a vector by a factor.
+fn scale(coordinates: RectangularCoordinates, factor: f64) -> RectangularCoordinates {
+    RectangularCoordinates {
+        x: coordinates.x * factor,
+        y: coordinates.y * factor,
+        z: coordinates.z * factor,
+    }
+}
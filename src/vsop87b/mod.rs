@@ -5,11 +5,13 @@
 //!
 //! # Example
 //!
-//! Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-//! position of the planet Earth in the solar system using spherical coordinates. In this case, we
-//! calculate where the Earth was in December 30th, 1799.
+//! Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Earth in the
+//! solar system using spherical coordinates. In this case, we calculate where the Earth was in
+//! December 30th, 1799.
 //!
 //! ```
+//! # #[cfg(feature = "earth")]
+//! # {
 //! use vsop87::vsop87b;
 //!
 //! let coordinates = vsop87b::earth(2378495.0);
@@ -17,21 +19,304 @@
 //! assert!(coordinates.longitude() > 1.7750058557 && coordinates.longitude() < 1.7750058559);
 //! assert!(coordinates.latitude() > 0.0004381094 && coordinates.latitude() < 0.0004381096);
 //! assert!(coordinates.distance() > 0.9832270 && coordinates.distance() < 0.9832278);
+//! # }
+//! ```
 
+#[cfg(feature = "earth")]
 mod earth;
+#[cfg(feature = "jupiter")]
 mod jupiter;
+#[cfg(feature = "mars")]
 mod mars;
+#[cfg(feature = "mercury")]
 mod mercury;
+#[cfg(feature = "neptune")]
 mod neptune;
+#[cfg(feature = "saturn")]
 mod saturn;
+#[cfg(feature = "uranus")]
 mod uranus;
+#[cfg(feature = "venus")]
 mod venus;
 
-use super::{calculate_t, calculate_var, SphericalCoordinates};
-#[cfg(feature = "no_std")]
-use core::f64::consts::PI;
-#[cfg(not(feature = "no_std"))]
-use std::f64::consts::PI;
+#[cfg(feature = "rayon")]
+use super::calculate_all_parallel;
+#[cfg(any(
+    feature = "mercury",
+    feature = "venus",
+    feature = "earth",
+    feature = "mars",
+    feature = "jupiter",
+    feature = "saturn",
+    feature = "uranus",
+    feature = "neptune"
+))]
+use super::series;
+use super::{
+    calculate_all, calculate_batch, calculate_body, calculate_spherical_state,
+    ephemeris::check_validity,
+    precision::{calculate_truncated, Variable},
+    spherical,
+    typed::{EclipticJ2000, Heliocentric, Spherical},
+    Body, Center, Coordinates, Ephemeris, Error, Frame, JulianDay, Precision, Series, Solution,
+    SphericalCoordinates, SphericalState, Truncated, Validity,
+};
+/// Series of the L, B and R coordinates of Mercury.
+#[cfg(feature = "mercury")]
+static MERCURY: [&[Series]; 3] = [
+    &[
+        series(&mercury::L0),
+        series(&mercury::L1),
+        series(&mercury::L2),
+        series(&mercury::L3),
+        series(&mercury::L4),
+        series(&mercury::L5),
+    ],
+    &[
+        series(&mercury::B0),
+        series(&mercury::B1),
+        series(&mercury::B2),
+        series(&mercury::B3),
+        series(&mercury::B4),
+        series(&mercury::B5),
+    ],
+    &[
+        series(&mercury::R0),
+        series(&mercury::R1),
+        series(&mercury::R2),
+        series(&mercury::R3),
+        series(&mercury::R4),
+    ],
+];
+
+/// Series of the L, B and R coordinates of Venus.
+#[cfg(feature = "venus")]
+static VENUS: [&[Series]; 3] = [
+    &[
+        series(&venus::L0),
+        series(&venus::L1),
+        series(&venus::L2),
+        series(&venus::L3),
+        series(&venus::L4),
+        series(&venus::L5),
+    ],
+    &[
+        series(&venus::B0),
+        series(&venus::B1),
+        series(&venus::B2),
+        series(&venus::B3),
+        series(&venus::B4),
+        series(&venus::B5),
+    ],
+    &[
+        series(&venus::R0),
+        series(&venus::R1),
+        series(&venus::R2),
+        series(&venus::R3),
+        series(&venus::R4),
+    ],
+];
+
+/// Series of the L, B and R coordinates of Earth.
+#[cfg(feature = "earth")]
+static EARTH: [&[Series]; 3] = [
+    &[
+        series(&earth::L0),
+        series(&earth::L1),
+        series(&earth::L2),
+        series(&earth::L3),
+        series(&earth::L4),
+        series(&earth::L5),
+    ],
+    &[
+        series(&earth::B0),
+        series(&earth::B1),
+        series(&earth::B2),
+        series(&earth::B3),
+        series(&earth::B4),
+        series(&earth::B5),
+    ],
+    &[
+        series(&earth::R0),
+        series(&earth::R1),
+        series(&earth::R2),
+        series(&earth::R3),
+        series(&earth::R4),
+    ],
+];
+
+/// Series of the L, B and R coordinates of Mars.
+#[cfg(feature = "mars")]
+static MARS: [&[Series]; 3] = [
+    &[
+        series(&mars::L0),
+        series(&mars::L1),
+        series(&mars::L2),
+        series(&mars::L3),
+        series(&mars::L4),
+        series(&mars::L5),
+    ],
+    &[
+        series(&mars::B0),
+        series(&mars::B1),
+        series(&mars::B2),
+        series(&mars::B3),
+        series(&mars::B4),
+        series(&mars::B5),
+    ],
+    &[
+        series(&mars::R0),
+        series(&mars::R1),
+        series(&mars::R2),
+        series(&mars::R3),
+        series(&mars::R4),
+    ],
+];
+
+/// Series of the L, B and R coordinates of Jupiter.
+#[cfg(feature = "jupiter")]
+static JUPITER: [&[Series]; 3] = [
+    &[
+        series(&jupiter::L0),
+        series(&jupiter::L1),
+        series(&jupiter::L2),
+        series(&jupiter::L3),
+        series(&jupiter::L4),
+        series(&jupiter::L5),
+    ],
+    &[
+        series(&jupiter::B0),
+        series(&jupiter::B1),
+        series(&jupiter::B2),
+        series(&jupiter::B3),
+        series(&jupiter::B4),
+        series(&jupiter::B5),
+    ],
+    &[
+        series(&jupiter::R0),
+        series(&jupiter::R1),
+        series(&jupiter::R2),
+        series(&jupiter::R3),
+        series(&jupiter::R4),
+    ],
+];
+
+/// Series of the L, B and R coordinates of Saturn.
+#[cfg(feature = "saturn")]
+static SATURN: [&[Series]; 3] = [
+    &[
+        series(&saturn::L0),
+        series(&saturn::L1),
+        series(&saturn::L2),
+        series(&saturn::L3),
+        series(&saturn::L4),
+        series(&saturn::L5),
+    ],
+    &[
+        series(&saturn::B0),
+        series(&saturn::B1),
+        series(&saturn::B2),
+        series(&saturn::B3),
+        series(&saturn::B4),
+        series(&saturn::B5),
+    ],
+    &[
+        series(&saturn::R0),
+        series(&saturn::R1),
+        series(&saturn::R2),
+        series(&saturn::R3),
+        series(&saturn::R4),
+    ],
+];
+
+/// Series of the L, B and R coordinates of Uranus.
+#[cfg(feature = "uranus")]
+static URANUS: [&[Series]; 3] = [
+    &[
+        series(&uranus::L0),
+        series(&uranus::L1),
+        series(&uranus::L2),
+        series(&uranus::L3),
+        series(&uranus::L4),
+    ],
+    &[
+        series(&uranus::B0),
+        series(&uranus::B1),
+        series(&uranus::B2),
+        series(&uranus::B3),
+    ],
+    &[
+        series(&uranus::R0),
+        series(&uranus::R1),
+        series(&uranus::R2),
+        series(&uranus::R3),
+    ],
+];
+
+/// Series of the L, B and R coordinates of Neptune.
+#[cfg(feature = "neptune")]
+static NEPTUNE: [&[Series]; 3] = [
+    &[
+        series(&neptune::L0),
+        series(&neptune::L1),
+        series(&neptune::L2),
+        series(&neptune::L3),
+    ],
+    &[
+        series(&neptune::B0),
+        series(&neptune::B1),
+        series(&neptune::B2),
+        series(&neptune::B3),
+    ],
+    &[
+        series(&neptune::R0),
+        series(&neptune::R1),
+        series(&neptune::R2),
+        series(&neptune::R3),
+    ],
+];
+
+/// Bodies available in VSOP87B, in the order used by [`all_bodies()`].
+///
+/// Only the bodies enabled with their cargo features are included.
+pub const BODIES: &[Body] = &[
+    #[cfg(feature = "mercury")]
+    Body::Mercury,
+    #[cfg(feature = "venus")]
+    Body::Venus,
+    #[cfg(feature = "earth")]
+    Body::Earth,
+    #[cfg(feature = "mars")]
+    Body::Mars,
+    #[cfg(feature = "jupiter")]
+    Body::Jupiter,
+    #[cfg(feature = "saturn")]
+    Body::Saturn,
+    #[cfg(feature = "uranus")]
+    Body::Uranus,
+    #[cfg(feature = "neptune")]
+    Body::Neptune,
+];
+
+/// Series of all the bodies available in VSOP87B, in the same order as [`BODIES`].
+static ALL_BODIES: [&[&[Series]; 3]; BODIES.len()] = [
+    #[cfg(feature = "mercury")]
+    &MERCURY,
+    #[cfg(feature = "venus")]
+    &VENUS,
+    #[cfg(feature = "earth")]
+    &EARTH,
+    #[cfg(feature = "mars")]
+    &MARS,
+    #[cfg(feature = "jupiter")]
+    &JUPITER,
+    #[cfg(feature = "saturn")]
+    &SATURN,
+    #[cfg(feature = "uranus")]
+    &URANUS,
+    #[cfg(feature = "neptune")]
+    &NEPTUNE,
+];
 
 /// Calculates VSOP87B solution for Mercury.
 ///
@@ -43,9 +328,9 @@ use std::f64::consts::PI;
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Mercury in the solar system using spherical coordinates. In this case,
-/// we calculate where Mercury was in January 1st, 2000.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Mercury in the
+/// solar system using spherical coordinates. In this case, we calculate where Mercury was in
+/// January 1st, 2000.
 ///
 /// ```
 /// use vsop87::vsop87b;
@@ -56,45 +341,82 @@ use std::f64::consts::PI;
 /// assert!(coordinates.latitude() > -0.0527573412 && coordinates.latitude() < -0.0527573410);
 /// assert!(coordinates.distance() > 0.4664711 && coordinates.distance() < 0.4664719);
 /// ```
+#[cfg(feature = "mercury")]
 #[must_use]
-pub fn mercury(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &mercury::L0[0], &mercury::L0[1], &mercury::L0[2]);
-    let l1 = calculate_var(t, &mercury::L1[0], &mercury::L1[1], &mercury::L1[2]);
-    let l2 = calculate_var(t, &mercury::L2[0], &mercury::L2[1], &mercury::L2[2]);
-    let l3 = calculate_var(t, &mercury::L3[0], &mercury::L3[1], &mercury::L3[2]);
-    let l4 = calculate_var(t, &mercury::L4[0], &mercury::L4[1], &mercury::L4[2]);
-    let l5 = calculate_var(t, &mercury::L5[0], &mercury::L5[1], &mercury::L5[2]);
-
-    let b0 = calculate_var(t, &mercury::B0[0], &mercury::B0[1], &mercury::B0[2]);
-    let b1 = calculate_var(t, &mercury::B1[0], &mercury::B1[1], &mercury::B1[2]);
-    let b2 = calculate_var(t, &mercury::B2[0], &mercury::B2[1], &mercury::B2[2]);
-    let b3 = calculate_var(t, &mercury::B3[0], &mercury::B3[1], &mercury::B3[2]);
-    let b4 = calculate_var(t, &mercury::B4[0], &mercury::B4[1], &mercury::B4[2]);
-    let b5 = calculate_var(t, &mercury::B5[0], &mercury::B5[1], &mercury::B5[2]);
-
-    let r0 = calculate_var(t, &mercury::R0[0], &mercury::R0[1], &mercury::R0[2]);
-    let r1 = calculate_var(t, &mercury::R1[0], &mercury::R1[1], &mercury::R1[2]);
-    let r2 = calculate_var(t, &mercury::R2[0], &mercury::R2[1], &mercury::R2[2]);
-    let r3 = calculate_var(t, &mercury::R3[0], &mercury::R3[1], &mercury::R3[2]);
-    let r4 = calculate_var(t, &mercury::R4[0], &mercury::R4[1], &mercury::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+pub fn mercury(jde: impl Into<JulianDay>) -> SphericalCoordinates {
+    spherical(calculate_body(jde.into(), &MERCURY))
+}
+
+/// Calculates VSOP87B solution for Mercury, along with its rates of change.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the planet Mercury, and the rates of change of its longitude, latitude
+/// and distance, by differentiating each term of the series. The parameter needed is the Julian Day
+/// (*JD*) for the given date. It returns a `SphericalState` structure, with the spherical
+/// coordinates of the planet, as returned by [`mercury()`], and their rates in radians per day and
+/// *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87b;
+///
+/// let state = vsop87b::mercury_state(2451545.0);
+/// let coordinates = state.coordinates();
+///
+/// assert!(coordinates.longitude() > 4.4293481042 && coordinates.longitude() < 4.4293481044);
+/// assert!(coordinates.latitude() > -0.0527573412 && coordinates.latitude() < -0.0527573410);
+/// assert!(coordinates.distance() > 0.4664711 && coordinates.distance() < 0.4664719);
+/// assert!(state.longitude_rate() > 0.047908841490 && state.longitude_rate() < 0.047908841491);
+/// ```
+#[cfg(feature = "mercury")]
+#[must_use]
+pub fn mercury_state(jde: impl Into<JulianDay>) -> SphericalState {
+    calculate_spherical_state(jde.into(), &MERCURY)
+}
+
+/// Calculates VSOP87B solution for Mercury, checking the Julian Day.
+///
+/// This function is equivalent to [`mercury()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Mercury (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// assert_eq!(vsop87b::mercury_checked(2451545.0)?, vsop87b::mercury(2451545.0));
+/// assert_eq!(
+///     vsop87b::mercury_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Mercury })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "mercury")]
+pub fn mercury_checked(jde: impl Into<JulianDay>) -> Result<SphericalCoordinates, Error> {
+    checked(Body::Mercury, jde)
+}
+
+/// Calculates VSOP87B solution for Mercury, along with its rates of change, checking the Julian
+/// Day.
+///
+/// This function is equivalent to [`mercury_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Mercury (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Error};
+///
+/// assert_eq!(vsop87b::mercury_state_checked(2451545.0)?, vsop87b::mercury_state(2451545.0));
+/// assert_eq!(vsop87b::mercury_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "mercury")]
+pub fn mercury_state_checked(jde: impl Into<JulianDay>) -> Result<SphericalState, Error> {
+    checked_state(Body::Mercury, jde)
 }
 
 /// Calculates VSOP87B solution for Venus.
@@ -107,9 +429,9 @@ pub fn mercury(jde: f64) -> SphericalCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Venus in the solar system using spherical coordinates. In this case, we
-/// calculate where Venus was in December 31st, 1899.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Venus in the
+/// solar system using spherical coordinates. In this case, we calculate where Venus was in December
+/// 31st, 1899.
 ///
 /// ```
 /// use vsop87::vsop87b;
@@ -120,45 +442,81 @@ pub fn mercury(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > -0.0591709805 && coordinates.latitude() < -0.0591709803);
 /// assert!(coordinates.distance() > 0.7274715 && coordinates.distance() < 0.7274723);
 /// ```
+#[cfg(feature = "venus")]
 #[must_use]
-pub fn venus(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &venus::L0[0], &venus::L0[1], &venus::L0[2]);
-    let l1 = calculate_var(t, &venus::L1[0], &venus::L1[1], &venus::L1[2]);
-    let l2 = calculate_var(t, &venus::L2[0], &venus::L2[1], &venus::L2[2]);
-    let l3 = calculate_var(t, &venus::L3[0], &venus::L3[1], &venus::L3[2]);
-    let l4 = calculate_var(t, &venus::L4[0], &venus::L4[1], &venus::L4[2]);
-    let l5 = calculate_var(t, &venus::L5[0], &venus::L5[1], &venus::L5[2]);
-
-    let b0 = calculate_var(t, &venus::B0[0], &venus::B0[1], &venus::B0[2]);
-    let b1 = calculate_var(t, &venus::B1[0], &venus::B1[1], &venus::B1[2]);
-    let b2 = calculate_var(t, &venus::B2[0], &venus::B2[1], &venus::B2[2]);
-    let b3 = calculate_var(t, &venus::B3[0], &venus::B3[1], &venus::B3[2]);
-    let b4 = calculate_var(t, &venus::B4[0], &venus::B4[1], &venus::B4[2]);
-    let b5 = calculate_var(t, &venus::B5[0], &venus::B5[1], &venus::B5[2]);
-
-    let r0 = calculate_var(t, &venus::R0[0], &venus::R0[1], &venus::R0[2]);
-    let r1 = calculate_var(t, &venus::R1[0], &venus::R1[1], &venus::R1[2]);
-    let r2 = calculate_var(t, &venus::R2[0], &venus::R2[1], &venus::R2[2]);
-    let r3 = calculate_var(t, &venus::R3[0], &venus::R3[1], &venus::R3[2]);
-    let r4 = calculate_var(t, &venus::R4[0], &venus::R4[1], &venus::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+pub fn venus(jde: impl Into<JulianDay>) -> SphericalCoordinates {
+    spherical(calculate_body(jde.into(), &VENUS))
+}
+
+/// Calculates VSOP87B solution for Venus, along with its rates of change.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the planet Venus, and the rates of change of its longitude, latitude
+/// and distance, by differentiating each term of the series. The parameter needed is the Julian Day
+/// (*JD*) for the given date. It returns a `SphericalState` structure, with the spherical
+/// coordinates of the planet, as returned by [`venus()`], and their rates in radians per day and
+/// *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87b;
+///
+/// let state = vsop87b::venus_state(2415020.0);
+/// let coordinates = state.coordinates();
+///
+/// assert!(coordinates.longitude() > 5.9993518123 && coordinates.longitude() < 5.9993518125);
+/// assert!(coordinates.latitude() > -0.0591709805 && coordinates.latitude() < -0.0591709803);
+/// assert!(coordinates.distance() > 0.7274715 && coordinates.distance() < 0.7274723);
+/// assert!(state.longitude_rate() > 0.027692656079 && state.longitude_rate() < 0.027692656080);
+/// ```
+#[cfg(feature = "venus")]
+#[must_use]
+pub fn venus_state(jde: impl Into<JulianDay>) -> SphericalState {
+    calculate_spherical_state(jde.into(), &VENUS)
+}
+
+/// Calculates VSOP87B solution for Venus, checking the Julian Day.
+///
+/// This function is equivalent to [`venus()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Venus (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// assert_eq!(vsop87b::venus_checked(2451545.0)?, vsop87b::venus(2451545.0));
+/// assert_eq!(
+///     vsop87b::venus_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Venus })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "venus")]
+pub fn venus_checked(jde: impl Into<JulianDay>) -> Result<SphericalCoordinates, Error> {
+    checked(Body::Venus, jde)
+}
+
+/// Calculates VSOP87B solution for Venus, along with its rates of change, checking the Julian Day.
+///
+/// This function is equivalent to [`venus_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Venus (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Error};
+///
+/// assert_eq!(vsop87b::venus_state_checked(2451545.0)?, vsop87b::venus_state(2451545.0));
+/// assert_eq!(vsop87b::venus_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "venus")]
+pub fn venus_state_checked(jde: impl Into<JulianDay>) -> Result<SphericalState, Error> {
+    checked_state(Body::Venus, jde)
 }
 
 /// Calculates VSOP87B solution for Earth.
@@ -171,9 +529,9 @@ pub fn venus(jde: f64) -> SphericalCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Earth in the solar system using spherical coordinates. In this case, we
-/// calculate where the Earth was in December 30th, 1799.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Earth in the
+/// solar system using spherical coordinates. In this case, we calculate where the Earth was in
+/// December 30th, 1799.
 ///
 /// ```
 /// use vsop87::vsop87b;
@@ -184,45 +542,81 @@ pub fn venus(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0004381094 && coordinates.latitude() < 0.0004381096);
 /// assert!(coordinates.distance() > 0.9832270 && coordinates.distance() < 0.9832278);
 /// ```
+#[cfg(feature = "earth")]
 #[must_use]
-pub fn earth(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &earth::L0[0], &earth::L0[1], &earth::L0[2]);
-    let l1 = calculate_var(t, &earth::L1[0], &earth::L1[1], &earth::L1[2]);
-    let l2 = calculate_var(t, &earth::L2[0], &earth::L2[1], &earth::L2[2]);
-    let l3 = calculate_var(t, &earth::L3[0], &earth::L3[1], &earth::L3[2]);
-    let l4 = calculate_var(t, &earth::L4[0], &earth::L4[1], &earth::L4[2]);
-    let l5 = calculate_var(t, &earth::L5[0], &earth::L5[1], &earth::L5[2]);
-
-    let b0 = calculate_var(t, &earth::B0[0], &earth::B0[1], &earth::B0[2]);
-    let b1 = calculate_var(t, &earth::B1[0], &earth::B1[1], &earth::B1[2]);
-    let b2 = calculate_var(t, &earth::B2[0], &earth::B2[1], &earth::B2[2]);
-    let b3 = calculate_var(t, &earth::B3[0], &earth::B3[1], &earth::B3[2]);
-    let b4 = calculate_var(t, &earth::B4[0], &earth::B4[1], &earth::B4[2]);
-    let b5 = calculate_var(t, &earth::B5[0], &earth::B5[1], &earth::B5[2]);
-
-    let r0 = calculate_var(t, &earth::R0[0], &earth::R0[1], &earth::R0[2]);
-    let r1 = calculate_var(t, &earth::R1[0], &earth::R1[1], &earth::R1[2]);
-    let r2 = calculate_var(t, &earth::R2[0], &earth::R2[1], &earth::R2[2]);
-    let r3 = calculate_var(t, &earth::R3[0], &earth::R3[1], &earth::R3[2]);
-    let r4 = calculate_var(t, &earth::R4[0], &earth::R4[1], &earth::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+pub fn earth(jde: impl Into<JulianDay>) -> SphericalCoordinates {
+    spherical(calculate_body(jde.into(), &EARTH))
+}
+
+/// Calculates VSOP87B solution for Earth, along with its rates of change.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the planet Earth, and the rates of change of its longitude, latitude
+/// and distance, by differentiating each term of the series. The parameter needed is the Julian Day
+/// (*JD*) for the given date. It returns a `SphericalState` structure, with the spherical
+/// coordinates of the planet, as returned by [`earth()`], and their rates in radians per day and
+/// *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87b;
+///
+/// let state = vsop87b::earth_state(2378495.0);
+/// let coordinates = state.coordinates();
+///
+/// assert!(coordinates.longitude() > 1.7750058557 && coordinates.longitude() < 1.7750058559);
+/// assert!(coordinates.latitude() > 0.0004381094 && coordinates.latitude() < 0.0004381096);
+/// assert!(coordinates.distance() > 0.9832270 && coordinates.distance() < 0.9832278);
+/// assert!(state.longitude_rate() > 0.017797118393 && state.longitude_rate() < 0.017797118394);
+/// ```
+#[cfg(feature = "earth")]
+#[must_use]
+pub fn earth_state(jde: impl Into<JulianDay>) -> SphericalState {
+    calculate_spherical_state(jde.into(), &EARTH)
+}
+
+/// Calculates VSOP87B solution for Earth, checking the Julian Day.
+///
+/// This function is equivalent to [`earth()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Earth (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// assert_eq!(vsop87b::earth_checked(2451545.0)?, vsop87b::earth(2451545.0));
+/// assert_eq!(
+///     vsop87b::earth_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Earth })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "earth")]
+pub fn earth_checked(jde: impl Into<JulianDay>) -> Result<SphericalCoordinates, Error> {
+    checked(Body::Earth, jde)
+}
+
+/// Calculates VSOP87B solution for Earth, along with its rates of change, checking the Julian Day.
+///
+/// This function is equivalent to [`earth_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Earth (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Error};
+///
+/// assert_eq!(vsop87b::earth_state_checked(2451545.0)?, vsop87b::earth_state(2451545.0));
+/// assert_eq!(vsop87b::earth_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "earth")]
+pub fn earth_state_checked(jde: impl Into<JulianDay>) -> Result<SphericalState, Error> {
+    checked_state(Body::Earth, jde)
 }
 
 /// Calculates VSOP87B solution for Mars.
@@ -235,9 +629,9 @@ pub fn earth(jde: f64) -> SphericalCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Mars in the solar system using spherical coordinates. In this case, we
-/// calculate where Mars was in December 29th, 1699.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Mars in the
+/// solar system using spherical coordinates. In this case, we calculate where Mars was in December
+/// 29th, 1699.
 ///
 /// ```
 /// use vsop87::vsop87b;
@@ -248,45 +642,81 @@ pub fn earth(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0280781216 && coordinates.latitude() < 0.0280781218);
 /// assert!(coordinates.distance() > 1.6584693 && coordinates.distance() < 1.6584701);
 /// ```
+#[cfg(feature = "mars")]
 #[must_use]
-pub fn mars(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &mars::L0[0], &mars::L0[1], &mars::L0[2]);
-    let l1 = calculate_var(t, &mars::L1[0], &mars::L1[1], &mars::L1[2]);
-    let l2 = calculate_var(t, &mars::L2[0], &mars::L2[1], &mars::L2[2]);
-    let l3 = calculate_var(t, &mars::L3[0], &mars::L3[1], &mars::L3[2]);
-    let l4 = calculate_var(t, &mars::L4[0], &mars::L4[1], &mars::L4[2]);
-    let l5 = calculate_var(t, &mars::L5[0], &mars::L5[1], &mars::L5[2]);
-
-    let b0 = calculate_var(t, &mars::B0[0], &mars::B0[1], &mars::B0[2]);
-    let b1 = calculate_var(t, &mars::B1[0], &mars::B1[1], &mars::B1[2]);
-    let b2 = calculate_var(t, &mars::B2[0], &mars::B2[1], &mars::B2[2]);
-    let b3 = calculate_var(t, &mars::B3[0], &mars::B3[1], &mars::B3[2]);
-    let b4 = calculate_var(t, &mars::B4[0], &mars::B4[1], &mars::B4[2]);
-    let b5 = calculate_var(t, &mars::B5[0], &mars::B5[1], &mars::B5[2]);
-
-    let r0 = calculate_var(t, &mars::R0[0], &mars::R0[1], &mars::R0[2]);
-    let r1 = calculate_var(t, &mars::R1[0], &mars::R1[1], &mars::R1[2]);
-    let r2 = calculate_var(t, &mars::R2[0], &mars::R2[1], &mars::R2[2]);
-    let r3 = calculate_var(t, &mars::R3[0], &mars::R3[1], &mars::R3[2]);
-    let r4 = calculate_var(t, &mars::R4[0], &mars::R4[1], &mars::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+pub fn mars(jde: impl Into<JulianDay>) -> SphericalCoordinates {
+    spherical(calculate_body(jde.into(), &MARS))
+}
+
+/// Calculates VSOP87B solution for Mars, along with its rates of change.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the planet Mars, and the rates of change of its longitude, latitude and
+/// distance, by differentiating each term of the series. The parameter needed is the Julian Day
+/// (*JD*) for the given date. It returns a `SphericalState` structure, with the spherical
+/// coordinates of the planet, as returned by [`mars()`], and their rates in radians per day and
+/// *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87b;
+///
+/// let state = vsop87b::mars_state(2341970.0);
+/// let coordinates = state.coordinates();
+///
+/// assert!(coordinates.longitude() > 2.9897807829 && coordinates.longitude() < 2.9897807831);
+/// assert!(coordinates.latitude() > 0.0280781216 && coordinates.latitude() < 0.0280781218);
+/// assert!(coordinates.distance() > 1.6584693 && coordinates.distance() < 1.6584701);
+/// assert!(state.longitude_rate() > 0.007688212799 && state.longitude_rate() < 0.007688212800);
+/// ```
+#[cfg(feature = "mars")]
+#[must_use]
+pub fn mars_state(jde: impl Into<JulianDay>) -> SphericalState {
+    calculate_spherical_state(jde.into(), &MARS)
+}
+
+/// Calculates VSOP87B solution for Mars, checking the Julian Day.
+///
+/// This function is equivalent to [`mars()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Mars (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// assert_eq!(vsop87b::mars_checked(2451545.0)?, vsop87b::mars(2451545.0));
+/// assert_eq!(
+///     vsop87b::mars_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Mars })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "mars")]
+pub fn mars_checked(jde: impl Into<JulianDay>) -> Result<SphericalCoordinates, Error> {
+    checked(Body::Mars, jde)
+}
+
+/// Calculates VSOP87B solution for Mars, along with its rates of change, checking the Julian Day.
+///
+/// This function is equivalent to [`mars_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Mars (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Error};
+///
+/// assert_eq!(vsop87b::mars_state_checked(2451545.0)?, vsop87b::mars_state(2451545.0));
+/// assert_eq!(vsop87b::mars_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "mars")]
+pub fn mars_state_checked(jde: impl Into<JulianDay>) -> Result<SphericalState, Error> {
+    checked_state(Body::Mars, jde)
 }
 
 /// Calculates VSOP87B solution for Jupiter.
@@ -299,9 +729,9 @@ pub fn mars(jde: f64) -> SphericalCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Jupiter in the solar system using spherical coordinates. In this case,
-/// we calculate where Jupiter was in December 29th, 1599.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Jupiter in the
+/// solar system using spherical coordinates. In this case, we calculate where Jupiter was in
+/// December 29th, 1599.
 ///
 /// ```
 /// use vsop87::vsop87b;
@@ -312,45 +742,82 @@ pub fn mars(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0145957281 && coordinates.latitude() < 0.0145957283);
 /// assert!(coordinates.distance() > 5.3439451 && coordinates.distance() < 5.3439459);
 /// ```
+#[cfg(feature = "jupiter")]
 #[must_use]
-pub fn jupiter(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &jupiter::L0[0], &jupiter::L0[1], &jupiter::L0[2]);
-    let l1 = calculate_var(t, &jupiter::L1[0], &jupiter::L1[1], &jupiter::L1[2]);
-    let l2 = calculate_var(t, &jupiter::L2[0], &jupiter::L2[1], &jupiter::L2[2]);
-    let l3 = calculate_var(t, &jupiter::L3[0], &jupiter::L3[1], &jupiter::L3[2]);
-    let l4 = calculate_var(t, &jupiter::L4[0], &jupiter::L4[1], &jupiter::L4[2]);
-    let l5 = calculate_var(t, &jupiter::L5[0], &jupiter::L5[1], &jupiter::L5[2]);
-
-    let b0 = calculate_var(t, &jupiter::B0[0], &jupiter::B0[1], &jupiter::B0[2]);
-    let b1 = calculate_var(t, &jupiter::B1[0], &jupiter::B1[1], &jupiter::B1[2]);
-    let b2 = calculate_var(t, &jupiter::B2[0], &jupiter::B2[1], &jupiter::B2[2]);
-    let b3 = calculate_var(t, &jupiter::B3[0], &jupiter::B3[1], &jupiter::B3[2]);
-    let b4 = calculate_var(t, &jupiter::B4[0], &jupiter::B4[1], &jupiter::B4[2]);
-    let b5 = calculate_var(t, &jupiter::B5[0], &jupiter::B5[1], &jupiter::B5[2]);
-
-    let r0 = calculate_var(t, &jupiter::R0[0], &jupiter::R0[1], &jupiter::R0[2]);
-    let r1 = calculate_var(t, &jupiter::R1[0], &jupiter::R1[1], &jupiter::R1[2]);
-    let r2 = calculate_var(t, &jupiter::R2[0], &jupiter::R2[1], &jupiter::R2[2]);
-    let r3 = calculate_var(t, &jupiter::R3[0], &jupiter::R3[1], &jupiter::R3[2]);
-    let r4 = calculate_var(t, &jupiter::R4[0], &jupiter::R4[1], &jupiter::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+pub fn jupiter(jde: impl Into<JulianDay>) -> SphericalCoordinates {
+    spherical(calculate_body(jde.into(), &JUPITER))
+}
+
+/// Calculates VSOP87B solution for Jupiter, along with its rates of change.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the planet Jupiter, and the rates of change of its longitude, latitude
+/// and distance, by differentiating each term of the series. The parameter needed is the Julian Day
+/// (*JD*) for the given date. It returns a `SphericalState` structure, with the spherical
+/// coordinates of the planet, as returned by [`jupiter()`], and their rates in radians per day and
+/// *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87b;
+///
+/// let state = vsop87b::jupiter_state(2305445.0);
+/// let coordinates = state.coordinates();
+///
+/// assert!(coordinates.longitude() > 2.4323346133 && coordinates.longitude() < 2.4323346135);
+/// assert!(coordinates.latitude() > 0.0145957281 && coordinates.latitude() < 0.0145957283);
+/// assert!(coordinates.distance() > 5.3439451 && coordinates.distance() < 5.3439459);
+/// assert!(state.longitude_rate() > 0.001372847867 && state.longitude_rate() < 0.001372847868);
+/// ```
+#[cfg(feature = "jupiter")]
+#[must_use]
+pub fn jupiter_state(jde: impl Into<JulianDay>) -> SphericalState {
+    calculate_spherical_state(jde.into(), &JUPITER)
+}
+
+/// Calculates VSOP87B solution for Jupiter, checking the Julian Day.
+///
+/// This function is equivalent to [`jupiter()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Jupiter (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// assert_eq!(vsop87b::jupiter_checked(2451545.0)?, vsop87b::jupiter(2451545.0));
+/// assert_eq!(
+///     vsop87b::jupiter_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "jupiter")]
+pub fn jupiter_checked(jde: impl Into<JulianDay>) -> Result<SphericalCoordinates, Error> {
+    checked(Body::Jupiter, jde)
+}
+
+/// Calculates VSOP87B solution for Jupiter, along with its rates of change, checking the Julian
+/// Day.
+///
+/// This function is equivalent to [`jupiter_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Jupiter (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Error};
+///
+/// assert_eq!(vsop87b::jupiter_state_checked(2451545.0)?, vsop87b::jupiter_state(2451545.0));
+/// assert_eq!(vsop87b::jupiter_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "jupiter")]
+pub fn jupiter_state_checked(jde: impl Into<JulianDay>) -> Result<SphericalState, Error> {
+    checked_state(Body::Jupiter, jde)
 }
 
 /// Calculates VSOP87B solution for Saturn.
@@ -363,9 +830,9 @@ pub fn jupiter(jde: f64) -> SphericalCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Saturn in the solar system using spherical coordinates. In this case, we
-/// calculate where Saturn was in December 19th, 1499.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Saturn in the
+/// solar system using spherical coordinates. In this case, we calculate where Saturn was in
+/// December 19th, 1499.
 ///
 /// ```
 /// use vsop87::vsop87b;
@@ -376,45 +843,81 @@ pub fn jupiter(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > -0.0369435534 && coordinates.latitude() < -0.0369435532);
 /// assert!(coordinates.distance() > 9.0669210 && coordinates.distance() < 9.0669218);
 /// ```
+#[cfg(feature = "saturn")]
 #[must_use]
-pub fn saturn(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &saturn::L0[0], &saturn::L0[1], &saturn::L0[2]);
-    let l1 = calculate_var(t, &saturn::L1[0], &saturn::L1[1], &saturn::L1[2]);
-    let l2 = calculate_var(t, &saturn::L2[0], &saturn::L2[1], &saturn::L2[2]);
-    let l3 = calculate_var(t, &saturn::L3[0], &saturn::L3[1], &saturn::L3[2]);
-    let l4 = calculate_var(t, &saturn::L4[0], &saturn::L4[1], &saturn::L4[2]);
-    let l5 = calculate_var(t, &saturn::L5[0], &saturn::L5[1], &saturn::L5[2]);
-
-    let b0 = calculate_var(t, &saturn::B0[0], &saturn::B0[1], &saturn::B0[2]);
-    let b1 = calculate_var(t, &saturn::B1[0], &saturn::B1[1], &saturn::B1[2]);
-    let b2 = calculate_var(t, &saturn::B2[0], &saturn::B2[1], &saturn::B2[2]);
-    let b3 = calculate_var(t, &saturn::B3[0], &saturn::B3[1], &saturn::B3[2]);
-    let b4 = calculate_var(t, &saturn::B4[0], &saturn::B4[1], &saturn::B4[2]);
-    let b5 = calculate_var(t, &saturn::B5[0], &saturn::B5[1], &saturn::B5[2]);
-
-    let r0 = calculate_var(t, &saturn::R0[0], &saturn::R0[1], &saturn::R0[2]);
-    let r1 = calculate_var(t, &saturn::R1[0], &saturn::R1[1], &saturn::R1[2]);
-    let r2 = calculate_var(t, &saturn::R2[0], &saturn::R2[1], &saturn::R2[2]);
-    let r3 = calculate_var(t, &saturn::R3[0], &saturn::R3[1], &saturn::R3[2]);
-    let r4 = calculate_var(t, &saturn::R4[0], &saturn::R4[1], &saturn::R4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t3 * t;
-    let t5 = t4 * t;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4 + l5 * t5) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3 + b4 * t4 + b5 * t5;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3 + r4 * t4;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+pub fn saturn(jde: impl Into<JulianDay>) -> SphericalCoordinates {
+    spherical(calculate_body(jde.into(), &SATURN))
+}
+
+/// Calculates VSOP87B solution for Saturn, along with its rates of change.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the planet Saturn, and the rates of change of its longitude, latitude
+/// and distance, by differentiating each term of the series. The parameter needed is the Julian Day
+/// (*JD*) for the given date. It returns a `SphericalState` structure, with the spherical
+/// coordinates of the planet, as returned by [`saturn()`], and their rates in radians per day and
+/// *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87b;
+///
+/// let state = vsop87b::saturn_state(2268920.0);
+/// let coordinates = state.coordinates();
+///
+/// assert!(coordinates.longitude() > 0.9812189104 && coordinates.longitude() < 0.9812189106);
+/// assert!(coordinates.latitude() > -0.0369435534 && coordinates.latitude() < -0.0369435532);
+/// assert!(coordinates.distance() > 9.0669210 && coordinates.distance() < 9.0669218);
+/// assert!(state.longitude_rate() > 0.000646398059 && state.longitude_rate() < 0.000646398060);
+/// ```
+#[cfg(feature = "saturn")]
+#[must_use]
+pub fn saturn_state(jde: impl Into<JulianDay>) -> SphericalState {
+    calculate_spherical_state(jde.into(), &SATURN)
+}
+
+/// Calculates VSOP87B solution for Saturn, checking the Julian Day.
+///
+/// This function is equivalent to [`saturn()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Saturn (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// assert_eq!(vsop87b::saturn_checked(2451545.0)?, vsop87b::saturn(2451545.0));
+/// assert_eq!(
+///     vsop87b::saturn_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Saturn })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "saturn")]
+pub fn saturn_checked(jde: impl Into<JulianDay>) -> Result<SphericalCoordinates, Error> {
+    checked(Body::Saturn, jde)
+}
+
+/// Calculates VSOP87B solution for Saturn, along with its rates of change, checking the Julian Day.
+///
+/// This function is equivalent to [`saturn_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Saturn (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Error};
+///
+/// assert_eq!(vsop87b::saturn_state_checked(2451545.0)?, vsop87b::saturn_state(2451545.0));
+/// assert_eq!(vsop87b::saturn_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "saturn")]
+pub fn saturn_state_checked(jde: impl Into<JulianDay>) -> Result<SphericalState, Error> {
+    checked_state(Body::Saturn, jde)
 }
 
 /// Calculates VSOP87B solution for Uranus.
@@ -427,9 +930,9 @@ pub fn saturn(jde: f64) -> SphericalCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Uranus in the solar system using spherical coordinates. In this case, we
-/// calculate where Uranus was in December 19th, 1399.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Uranus in the
+/// solar system using spherical coordinates. In this case, we calculate where Uranus was in
+/// December 19th, 1399.
 ///
 /// ```
 /// use vsop87::vsop87b;
@@ -440,40 +943,81 @@ pub fn saturn(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > -0.0033027750 && coordinates.latitude() < -0.0033027748);
 /// assert!(coordinates.distance() > 19.2694309 && coordinates.distance() < 19.2694317);
 /// ```
+#[cfg(feature = "uranus")]
 #[must_use]
-pub fn uranus(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &uranus::L0[0], &uranus::L0[1], &uranus::L0[2]);
-    let l1 = calculate_var(t, &uranus::L1[0], &uranus::L1[1], &uranus::L1[2]);
-    let l2 = calculate_var(t, &uranus::L2[0], &uranus::L2[1], &uranus::L2[2]);
-    let l3 = calculate_var(t, &uranus::L3[0], &uranus::L3[1], &uranus::L3[2]);
-    let l4 = calculate_var(t, &uranus::L4[0], &uranus::L4[1], &uranus::L4[2]);
-
-    let b0 = calculate_var(t, &uranus::B0[0], &uranus::B0[1], &uranus::B0[2]);
-    let b1 = calculate_var(t, &uranus::B1[0], &uranus::B1[1], &uranus::B1[2]);
-    let b2 = calculate_var(t, &uranus::B2[0], &uranus::B2[1], &uranus::B2[2]);
-    let b3 = calculate_var(t, &uranus::B3[0], &uranus::B3[1], &uranus::B3[2]);
-
-    let r0 = calculate_var(t, &uranus::R0[0], &uranus::R0[1], &uranus::R0[2]);
-    let r1 = calculate_var(t, &uranus::R1[0], &uranus::R1[1], &uranus::R1[2]);
-    let r2 = calculate_var(t, &uranus::R2[0], &uranus::R2[1], &uranus::R2[2]);
-    let r3 = calculate_var(t, &uranus::R3[0], &uranus::R3[1], &uranus::R3[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3 + l4 * t4) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
-    }
+pub fn uranus(jde: impl Into<JulianDay>) -> SphericalCoordinates {
+    spherical(calculate_body(jde.into(), &URANUS))
+}
+
+/// Calculates VSOP87B solution for Uranus, along with its rates of change.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the planet Uranus, and the rates of change of its longitude, latitude
+/// and distance, by differentiating each term of the series. The parameter needed is the Julian Day
+/// (*JD*) for the given date. It returns a `SphericalState` structure, with the spherical
+/// coordinates of the planet, as returned by [`uranus()`], and their rates in radians per day and
+/// *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87b;
+///
+/// let state = vsop87b::uranus_state(2232395.0);
+/// let coordinates = state.coordinates();
+///
+/// assert!(coordinates.longitude() > 4.6715450661 && coordinates.longitude() < 4.6715450663);
+/// assert!(coordinates.latitude() > -0.0033027750 && coordinates.latitude() < -0.0033027748);
+/// assert!(coordinates.distance() > 19.2694309 && coordinates.distance() < 19.2694317);
+/// assert!(state.longitude_rate() > 0.000203025341 && state.longitude_rate() < 0.000203025342);
+/// ```
+#[cfg(feature = "uranus")]
+#[must_use]
+pub fn uranus_state(jde: impl Into<JulianDay>) -> SphericalState {
+    calculate_spherical_state(jde.into(), &URANUS)
+}
+
+/// Calculates VSOP87B solution for Uranus, checking the Julian Day.
+///
+/// This function is equivalent to [`uranus()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Uranus (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// assert_eq!(vsop87b::uranus_checked(2451545.0)?, vsop87b::uranus(2451545.0));
+/// assert_eq!(
+///     vsop87b::uranus_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Uranus })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "uranus")]
+pub fn uranus_checked(jde: impl Into<JulianDay>) -> Result<SphericalCoordinates, Error> {
+    checked(Body::Uranus, jde)
+}
+
+/// Calculates VSOP87B solution for Uranus, along with its rates of change, checking the Julian Day.
+///
+/// This function is equivalent to [`uranus_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Uranus (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Error};
+///
+/// assert_eq!(vsop87b::uranus_state_checked(2451545.0)?, vsop87b::uranus_state(2451545.0));
+/// assert_eq!(vsop87b::uranus_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "uranus")]
+pub fn uranus_state_checked(jde: impl Into<JulianDay>) -> Result<SphericalState, Error> {
+    checked_state(Body::Uranus, jde)
 }
 
 /// Calculates VSOP87B solution for Neptune.
@@ -486,9 +1030,9 @@ pub fn uranus(jde: f64) -> SphericalCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Neptuine in the solar system using spherical coordinates. In this case,
-/// we calculate where Neptune was in December 19th, 1299.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Neptuine in the
+/// solar system using spherical coordinates. In this case, we calculate where Neptune was in
+/// December 19th, 1299.
 ///
 /// ```
 /// use vsop87::vsop87b;
@@ -499,36 +1043,583 @@ pub fn uranus(jde: f64) -> SphericalCoordinates {
 /// assert!(coordinates.latitude() > 0.0306777429 && coordinates.latitude() < 0.0306777431);
 /// assert!(coordinates.distance() > 30.3109111 && coordinates.distance() < 30.3109119);
 /// ```
+#[cfg(feature = "neptune")]
 #[must_use]
-pub fn neptune(jde: f64) -> SphericalCoordinates {
-    let t = calculate_t(jde);
-
-    let l0 = calculate_var(t, &neptune::L0[0], &neptune::L0[1], &neptune::L0[2]);
-    let l1 = calculate_var(t, &neptune::L1[0], &neptune::L1[1], &neptune::L1[2]);
-    let l2 = calculate_var(t, &neptune::L2[0], &neptune::L2[1], &neptune::L2[2]);
-    let l3 = calculate_var(t, &neptune::L3[0], &neptune::L3[1], &neptune::L3[2]);
-
-    let b0 = calculate_var(t, &neptune::B0[0], &neptune::B0[1], &neptune::B0[2]);
-    let b1 = calculate_var(t, &neptune::B1[0], &neptune::B1[1], &neptune::B1[2]);
-    let b2 = calculate_var(t, &neptune::B2[0], &neptune::B2[1], &neptune::B2[2]);
-    let b3 = calculate_var(t, &neptune::B3[0], &neptune::B3[1], &neptune::B3[2]);
-
-    let r0 = calculate_var(t, &neptune::R0[0], &neptune::R0[1], &neptune::R0[2]);
-    let r1 = calculate_var(t, &neptune::R1[0], &neptune::R1[1], &neptune::R1[2]);
-    let r2 = calculate_var(t, &neptune::R2[0], &neptune::R2[1], &neptune::R2[2]);
-    let r3 = calculate_var(t, &neptune::R3[0], &neptune::R3[1], &neptune::R3[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-
-    let l = (l0 + l1 * t + l2 * t2 + l3 * t3) % (2_f64 * PI);
-    let b = b0 + b1 * t + b2 * t2 + b3 * t3;
-    let r = r0 + r1 * t + r2 * t2 + r3 * t3;
-
-    SphericalCoordinates {
-        lon: if l > 0_f64 { l } else { 2_f64 * PI + l },
-        lat: b,
-        dist: r,
+pub fn neptune(jde: impl Into<JulianDay>) -> SphericalCoordinates {
+    spherical(calculate_body(jde.into(), &NEPTUNE))
+}
+
+/// Calculates VSOP87B solution for Neptune, along with its rates of change.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the planet Neptune, and the rates of change of its longitude, latitude
+/// and distance, by differentiating each term of the series. The parameter needed is the Julian Day
+/// (*JD*) for the given date. It returns a `SphericalState` structure, with the spherical
+/// coordinates of the planet, as returned by [`neptune()`], and their rates in radians per day and
+/// *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87b;
+///
+/// let state = vsop87b::neptune_state(2195870.0);
+/// let coordinates = state.coordinates();
+///
+/// assert!(coordinates.longitude() > 3.7635416327 && coordinates.longitude() < 3.7635416329);
+/// assert!(coordinates.latitude() > 0.0306777429 && coordinates.latitude() < 0.0306777431);
+/// assert!(coordinates.distance() > 30.3109111 && coordinates.distance() < 30.3109119);
+/// assert!(state.longitude_rate() > 0.000102537050 && state.longitude_rate() < 0.000102537051);
+/// ```
+#[cfg(feature = "neptune")]
+#[must_use]
+pub fn neptune_state(jde: impl Into<JulianDay>) -> SphericalState {
+    calculate_spherical_state(jde.into(), &NEPTUNE)
+}
+
+/// Calculates VSOP87B solution for Neptune, checking the Julian Day.
+///
+/// This function is equivalent to [`neptune()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Neptune (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// assert_eq!(vsop87b::neptune_checked(2451545.0)?, vsop87b::neptune(2451545.0));
+/// assert_eq!(
+///     vsop87b::neptune_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Neptune })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "neptune")]
+pub fn neptune_checked(jde: impl Into<JulianDay>) -> Result<SphericalCoordinates, Error> {
+    checked(Body::Neptune, jde)
+}
+
+/// Calculates VSOP87B solution for Neptune, along with its rates of change, checking the Julian
+/// Day.
+///
+/// This function is equivalent to [`neptune_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Neptune (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Error};
+///
+/// assert_eq!(vsop87b::neptune_state_checked(2451545.0)?, vsop87b::neptune_state(2451545.0));
+/// assert_eq!(vsop87b::neptune_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "neptune")]
+pub fn neptune_state_checked(jde: impl Into<JulianDay>) -> Result<SphericalState, Error> {
+    checked_state(Body::Neptune, jde)
+}
+
+/// Calculates VSOP87B solution for all its bodies at the same Julian Day.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for all the bodies available in VSOP87B, at the given Julian Day (*JD*). It
+/// returns the spherical coordinates of each body, in the order of [`BODIES`]. It is equivalent to
+/// calling the function of each body, but the time is only calculated once.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87b, Body};
+///
+/// let bodies = vsop87b::all_bodies(2451545.0);
+///
+/// assert_eq!(vsop87b::BODIES[4], Body::Jupiter);
+/// let jupiter = vsop87b::jupiter(2451545.0);
+/// assert_eq!(bodies[4], jupiter);
+/// # }
+/// ```
+#[must_use]
+pub fn all_bodies(jde: impl Into<JulianDay>) -> [SphericalCoordinates; BODIES.len()] {
+    calculate_all(jde.into(), &ALL_BODIES).map(spherical)
+}
+
+/// Calculates VSOP87B solution for all its bodies at the same Julian Day, in parallel.
+///
+/// This function is equivalent to [`all_bodies()`], but each body is calculated in parallel, using
+/// the global [`rayon`] thread pool. It is only available with the `rayon` feature.
+#[cfg(feature = "rayon")]
+#[must_use]
+pub fn all_bodies_parallel(jde: impl Into<JulianDay>) -> [SphericalCoordinates; BODIES.len()] {
+    calculate_all_parallel(jde.into(), &ALL_BODIES).map(spherical)
+}
+
+/// Calculates VSOP87B solution for the given body, with the given precision.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the given body, skipping the terms of the series that are not needed
+/// for the given `Precision`. The parameters needed are the body, the Julian Day (*JD*) for the
+/// given date and the precision. It returns the spherical coordinates, along with the amplitude
+/// thresholds used for each of them, or an error if the body is not available in VSOP87B.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "mercury")]
+/// # {
+/// use vsop87::{vsop87b, Body, Precision};
+///
+/// let coordinates = vsop87b::mercury(2451545.0);
+/// let truncated =
+///     vsop87b::truncated(Body::Mercury, 2451545.0, Precision::Arcseconds(1.0)).unwrap();
+///
+/// assert!((truncated.value.longitude() - coordinates.longitude()).abs() < 0.000005);
+/// assert!((truncated.value.latitude() - coordinates.latitude()).abs() < 0.000005);
+/// assert!((truncated.value.distance() - coordinates.distance()).abs() < 0.000005);
+/// assert!(truncated.thresholds.longitude() > 0.0);
+/// # }
+/// ```
+pub fn truncated(
+    body: Body,
+    jde: impl Into<JulianDay>,
+    precision: Precision,
+) -> Result<Truncated<SphericalCoordinates>, Error> {
+    let ([l, b, r], [l_threshold, b_threshold, r_threshold]) = calculate_truncated(
+        jde.into(),
+        body,
+        body_series(body)?,
+        [Variable::Angle, Variable::Angle, Variable::Distance],
+        precision,
+    );
+
+    Ok(Truncated {
+        value: spherical([l, b, r]),
+        thresholds: SphericalCoordinates {
+            lon: l_threshold,
+            lat: b_threshold,
+            dist: r_threshold,
+        },
+    })
+}
+
+/// Calculates VSOP87B solution for the given body at many Julian Days.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the given body at each of the given Julian Days (*JD*), writing the
+/// spherical coordinates in the output slice, in the same order. It is equivalent to calling the
+/// function of the body for each Julian Day, but much faster for big batches, since each term of
+/// the series is calculated for many Julian Days at the same time.
+///
+/// It returns an error if the body is not available in VSOP87B, or if the output does not have the
+/// same length as the Julian Days.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87b, Body, SphericalCoordinates};
+///
+/// // Calculate the position of Jupiter every 30 days.
+/// let jdes = [2451545.0, 2451575.0, 2451605.0];
+/// let mut output = [SphericalCoordinates::default(); 3];
+/// vsop87b::batch(Body::Jupiter, &jdes, &mut output).unwrap();
+///
+/// for (&jde, coordinates) in jdes.iter().zip(&output) {
+///     let expected = vsop87b::jupiter(jde);
+///     assert!((coordinates.longitude() - expected.longitude()).abs() < 0.000000001);
+///     assert!((coordinates.latitude() - expected.latitude()).abs() < 0.000000001);
+///     assert!((coordinates.distance() - expected.distance()).abs() < 0.000000001);
+/// }
+/// # }
+/// ```
+pub fn batch(body: Body, jdes: &[f64], output: &mut [SphericalCoordinates]) -> Result<(), Error> {
+    calculate_batch(jdes, body_series(body)?, output, spherical)
+}
+
+/// Calculates VSOP87B solution for the given body at many two-part Julian Days.
+///
+/// This function is equivalent to [`batch()`], but it takes [`JulianDay`]s instead of `f64` values,
+/// so that the time variable of each of them is calculated without losing precision. See the
+/// [precision of Julian Days](JulianDay#precision).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87b, Body, JulianDay, SphericalCoordinates};
+///
+/// // Calculate the spherical coordinates of Jupiter every 6 hours.
+/// let jdes = [0.0, 0.25, 0.5, 0.75].map(|fraction| JulianDay::from_parts(2451545.0, fraction));
+/// let mut output = [SphericalCoordinates::default(); 4];
+/// vsop87b::batch_julian_days(Body::Jupiter, &jdes, &mut output)?;
+///
+/// let mut expected = [SphericalCoordinates::default(); 4];
+/// vsop87b::batch(Body::Jupiter, &[2451545.0, 2451545.25, 2451545.5, 2451545.75], &mut expected)?;
+/// assert_eq!(output, expected);
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+pub fn batch_julian_days(
+    body: Body,
+    jdes: &[JulianDay],
+    output: &mut [SphericalCoordinates],
+) -> Result<(), Error> {
+    calculate_batch(jdes, body_series(body)?, output, spherical)
+}
+
+/// Calculates VSOP87B solution for the given body, checking the Julian Day.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the given body at the given Julian Day (*JD*), in the same way as the
+/// function of the body.
+///
+/// The functions of the bodies also have checked variants of their own, such as
+/// `vsop87b::saturn_checked()`, which call this function with the body as a parameter.
+///
+/// It returns an error if the body is not available in VSOP87B, if the Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// let checked = vsop87b::checked(Body::Jupiter, 2451545.0).unwrap();
+/// assert_eq!(checked, vsop87b::jupiter(2451545.0));
+///
+/// assert_eq!(
+///     vsop87b::checked(Body::Jupiter, 0.0),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// assert_eq!(vsop87b::checked(Body::Jupiter, f64::NAN), Err(Error::NonFinite));
+/// # }
+/// ```
+pub fn checked(body: Body, jde: impl Into<JulianDay>) -> Result<SphericalCoordinates, Error> {
+    let jde = jde.into();
+
+    let series = body_series(body)?;
+    check_validity(body, jde)?;
+
+    Ok(spherical(calculate_body(jde, series)))
+}
+
+/// Calculates VSOP87B solution and its rates for the given body, checking the Julian Day.
+///
+/// This function calculates the VSOP87B solution (heliocentric ecliptic spherical coordinates for
+/// the equinox J2000.0) for the given body at the given Julian Day (*JD*), along with its rates, in
+/// the same way as the state function of the body.
+///
+/// It returns an error if the body is not available in VSOP87B, if the Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// let checked = vsop87b::checked_state(Body::Jupiter, 2451545.0).unwrap();
+/// assert_eq!(checked, vsop87b::jupiter_state(2451545.0));
+///
+/// assert!(vsop87b::checked_state(Body::Jupiter, f64::INFINITY).is_err());
+/// # }
+/// ```
+pub fn checked_state(body: Body, jde: impl Into<JulianDay>) -> Result<SphericalState, Error> {
+    let jde = jde.into();
+
+    let series = body_series(body)?;
+    check_validity(body, jde)?;
+
+    Ok(calculate_spherical_state(jde, series))
+}
+
+/// Calculates VSOP87B solution for the given body, tagged with its frame and its center.
+///
+/// This function is equivalent to [`checked()`], but the coordinates are tagged as
+/// [`EclipticJ2000`] and [`Heliocentric`], so that they cannot be mixed with coordinates in other
+/// frames or relative to other centers. See the [`typed`](crate::typed) module for more
+/// information.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{typed::Position, vsop87b, Body};
+///
+/// let jupiter = vsop87b::typed(Body::Jupiter, 2451545.0)?;
+/// assert_eq!(jupiter.coordinates(), vsop87b::jupiter(2451545.0));
+///
+/// let rectangular = Position::from(jupiter);
+/// assert!((rectangular.distance() - jupiter.coordinates().distance()).abs() < 1e-12);
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+pub fn typed(
+    body: Body,
+    jde: impl Into<JulianDay>,
+) -> Result<Spherical<EclipticJ2000, Heliocentric>, Error> {
+    checked(body, jde).map(Spherical::new)
+}
+
+/// Calculates VSOP87B solution for all its bodies, checking the Julian Day.
+///
+/// This function is equivalent to [`all_bodies()`], but it checks that the Julian Day is valid for
+/// all the bodies.
+///
+/// It returns an error if the Julian Day is not finite, or if it is outside of the validity span of
+/// the solution for any of the bodies (see [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// let bodies = vsop87b::all_bodies_checked(2451545.0).unwrap();
+/// assert_eq!(bodies, vsop87b::all_bodies(2451545.0));
+///
+/// // Valid for the inner planets, but not for Jupiter.
+/// assert_eq!(
+///     vsop87b::all_bodies_checked(1500000.0),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// # }
+/// ```
+pub fn all_bodies_checked(
+    jde: impl Into<JulianDay>,
+) -> Result<[SphericalCoordinates; BODIES.len()], Error> {
+    let jde = jde.into();
+
+    for &body in BODIES {
+        check_validity(body, jde)?;
+    }
+
+    Ok(all_bodies(jde))
+}
+
+/// Calculates VSOP87B solution for all its bodies in parallel, checking the Julian Day.
+///
+/// This function is equivalent to [`all_bodies_parallel()`], but it checks that the Julian Day is
+/// valid for all the bodies.
+///
+/// It returns an error if the Julian Day is not finite, or if it is outside of the validity span of
+/// the solution for any of the bodies (see [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87b, Error};
+///
+/// let bodies = vsop87b::all_bodies_parallel_checked(2451545.0)?;
+/// assert_eq!(bodies, vsop87b::all_bodies_parallel(2451545.0));
+///
+/// assert_eq!(vsop87b::all_bodies_parallel_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+#[cfg(feature = "rayon")]
+pub fn all_bodies_parallel_checked(
+    jde: impl Into<JulianDay>,
+) -> Result<[SphericalCoordinates; BODIES.len()], Error> {
+    let jde = jde.into();
+
+    for &body in BODIES {
+        check_validity(body, jde)?;
+    }
+
+    Ok(all_bodies_parallel(jde))
+}
+
+/// Calculates VSOP87B solution with the given precision, checking the Julian Day.
+///
+/// This function is equivalent to [`truncated()`], but it checks that the Julian Day is valid for
+/// the body.
+///
+/// It returns an error if the body is not available in VSOP87B, if the Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// # use vsop87::Precision;
+/// let checked = vsop87b::truncated_checked(Body::Jupiter, 2451545.0, Precision::Arcseconds(1.0));
+/// assert_eq!(checked, vsop87b::truncated(Body::Jupiter, 2451545.0, Precision::Arcseconds(1.0)));
+///
+/// assert_eq!(
+///     vsop87b::truncated_checked(Body::Jupiter, 0.0, Precision::Full),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// # }
+/// ```
+pub fn truncated_checked(
+    body: Body,
+    jde: impl Into<JulianDay>,
+    precision: Precision,
+) -> Result<Truncated<SphericalCoordinates>, Error> {
+    let jde = jde.into();
+
+    ensure_supported(body)?;
+    check_validity(body, jde)?;
+
+    truncated(body, jde, precision)
+}
+
+/// Calculates VSOP87B solution at many Julian Days, checking the Julian Days.
+///
+/// This function is equivalent to [`batch()`], but it checks that all the Julian Days are valid for
+/// the body before calculating any of them.
+///
+/// It returns an error if the body is not available in VSOP87B, if the Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87b, Body, Error};
+///
+/// # use vsop87::SphericalCoordinates;
+/// let mut output = [SphericalCoordinates::default(); 2];
+/// vsop87b::batch_checked(Body::Jupiter, &[2451545.0, 2451546.0], &mut output).unwrap();
+///
+/// assert_eq!(
+///     vsop87b::batch_checked(Body::Jupiter, &[2451545.0, f64::NAN], &mut output),
+///     Err(Error::NonFinite)
+/// );
+/// # }
+/// ```
+pub fn batch_checked(
+    body: Body,
+    jdes: &[f64],
+    output: &mut [SphericalCoordinates],
+) -> Result<(), Error> {
+    ensure_supported(body)?;
+    for &jde in jdes {
+        check_validity(body, jde.into())?;
+    }
+
+    batch(body, jdes, output)
+}
+
+/// Calculates VSOP87B solution at many two-part Julian Days, checking the Julian Days.
+///
+/// This function is equivalent to [`batch_julian_days()`], but it checks that all the Julian Days
+/// are valid for the body before calculating any of them.
+///
+/// It returns an error if the body is not available in VSOP87B, if a Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87b, Body, Error, JulianDay, SphericalCoordinates};
+///
+/// let jdes = [JulianDay::from_parts(2451545.0, 0.25), JulianDay::new(2451546.0)];
+/// let mut output = [SphericalCoordinates::default(); 2];
+/// vsop87b::batch_julian_days_checked(Body::Jupiter, &jdes, &mut output)?;
+///
+/// let jdes = [JulianDay::new(2451545.0), JulianDay::new(0.0)];
+/// assert_eq!(
+///     vsop87b::batch_julian_days_checked(Body::Jupiter, &jdes, &mut output),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+pub fn batch_julian_days_checked(
+    body: Body,
+    jdes: &[JulianDay],
+    output: &mut [SphericalCoordinates],
+) -> Result<(), Error> {
+    ensure_supported(body)?;
+    for &jde in jdes {
+        check_validity(body, jde)?;
+    }
+
+    batch_julian_days(body, jdes, output)
+}
+
+/// Checks that the given body is available in VSOP87B.
+fn ensure_supported(body: Body) -> Result<(), Error> {
+    body_series(body).map(|_| ())
+}
+
+/// Gets the series of the given body.
+fn body_series(body: Body) -> Result<&'static [&'static [Series]; 3], Error> {
+    match body {
+        #[cfg(feature = "mercury")]
+        Body::Mercury => Ok(&MERCURY),
+        #[cfg(feature = "venus")]
+        Body::Venus => Ok(&VENUS),
+        #[cfg(feature = "earth")]
+        Body::Earth => Ok(&EARTH),
+        #[cfg(feature = "mars")]
+        Body::Mars => Ok(&MARS),
+        #[cfg(feature = "jupiter")]
+        Body::Jupiter => Ok(&JUPITER),
+        #[cfg(feature = "saturn")]
+        Body::Saturn => Ok(&SATURN),
+        #[cfg(feature = "uranus")]
+        Body::Uranus => Ok(&URANUS),
+        #[cfg(feature = "neptune")]
+        Body::Neptune => Ok(&NEPTUNE),
+        _ => Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87B,
+            body,
+        }),
+    }
+}
+
+/// *VSOP87B* solution, as an [`Ephemeris`].
+///
+/// Its positions are the heliocentric ecliptic spherical coordinates for the equinox J2000.0, in
+/// [`Coordinates::Spherical`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct VSOP87B;
+
+impl Ephemeris for VSOP87B {
+    fn position(&self, body: Body, jde: f64) -> Result<Coordinates, Error> {
+        Solution::VSOP87B.position(body, jde)
+    }
+
+    fn position_at(&self, body: Body, jde: JulianDay) -> Result<Coordinates, Error> {
+        Solution::VSOP87B.position_at(body, jde)
+    }
+
+    fn frame(&self) -> Frame {
+        Solution::VSOP87B.frame()
+    }
+
+    fn center(&self) -> Center {
+        Solution::VSOP87B.center()
+    }
+
+    fn bodies(&self) -> &[Body] {
+        Solution::VSOP87B.bodies()
+    }
+
+    fn validity(&self, body: Body) -> Option<Validity> {
+        Ephemeris::validity(&Solution::VSOP87B, body)
     }
 }
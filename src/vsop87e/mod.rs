@@ -8,11 +8,13 @@
 //!
 //! # Example
 //!
-//! Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-//! position of the Sun in the solar system using rectangular coordinates. In this case, we
-//! calculate where the Sun was in January 1st, 2000.
+//! Given a date in [*JD*](crate::JulianDay), we can get the position of the Sun in the solar system
+//! using rectangular coordinates. In this case, we calculate where the Sun was in January 1st,
+//! 2000.
 //!
 //! ```
+//! # #[cfg(feature = "sun")]
+//! # {
 //! use vsop87::vsop87e;
 //!
 //! let coordinates = vsop87e::sun(2451545.0);
@@ -20,18 +22,341 @@
 //! assert!(coordinates.x > -0.0071415280 && coordinates.x < -0.0071415278);
 //! assert!(coordinates.y > -0.0027881716 && coordinates.y < -0.0027881714);
 //! assert!(coordinates.z > 0.0002041 && coordinates.z < 0.0002081);
+//! # }
+//! ```
 
+#[cfg(feature = "earth")]
 mod earth;
+#[cfg(feature = "jupiter")]
 mod jupiter;
+#[cfg(feature = "mars")]
 mod mars;
+#[cfg(feature = "mercury")]
 mod mercury;
+#[cfg(feature = "neptune")]
 mod neptune;
+#[cfg(feature = "saturn")]
 mod saturn;
+#[cfg(feature = "sun")]
 mod sun;
+#[cfg(feature = "uranus")]
 mod uranus;
+#[cfg(feature = "venus")]
 mod venus;
 
-use super::{calculate_t, calculate_var, RectangularCoordinates};
+#[cfg(feature = "rayon")]
+use super::calculate_all_parallel;
+#[cfg(any(
+    feature = "sun",
+    feature = "mercury",
+    feature = "venus",
+    feature = "earth",
+    feature = "mars",
+    feature = "jupiter",
+    feature = "saturn",
+    feature = "uranus",
+    feature = "neptune"
+))]
+use super::series;
+use super::{
+    calculate_all, calculate_batch, calculate_body, calculate_state,
+    ephemeris::check_validity,
+    precision::{calculate_truncated, Variable},
+    rectangular,
+    typed::{Barycentric, EclipticJ2000, Position, State},
+    Body, Center, Coordinates, Ephemeris, Error, Frame, JulianDay, Precision,
+    RectangularCoordinates, Series, Solution, StateVector, Truncated, Validity,
+};
+
+/// Series of the X, Y and Z coordinates of the Sun.
+#[cfg(feature = "sun")]
+static SUN: [&[Series]; 3] = [
+    &[
+        series(&sun::X0),
+        series(&sun::X1),
+        series(&sun::X2),
+        series(&sun::X3),
+        series(&sun::X4),
+        series(&sun::X5),
+    ],
+    &[
+        series(&sun::Y0),
+        series(&sun::Y1),
+        series(&sun::Y2),
+        series(&sun::Y3),
+        series(&sun::Y4),
+        series(&sun::Y5),
+    ],
+    &[
+        series(&sun::Z0),
+        series(&sun::Z1),
+        series(&sun::Z2),
+        series(&sun::Z3),
+        series(&sun::Z4),
+    ],
+];
+
+/// Series of the X, Y and Z coordinates of Mercury.
+#[cfg(feature = "mercury")]
+static MERCURY: [&[Series]; 3] = [
+    &[
+        series(&mercury::X0),
+        series(&mercury::X1),
+        series(&mercury::X2),
+        series(&mercury::X3),
+        series(&mercury::X4),
+        series(&mercury::X5),
+    ],
+    &[
+        series(&mercury::Y0),
+        series(&mercury::Y1),
+        series(&mercury::Y2),
+        series(&mercury::Y3),
+        series(&mercury::Y4),
+        series(&mercury::Y5),
+    ],
+    &[
+        series(&mercury::Z0),
+        series(&mercury::Z1),
+        series(&mercury::Z2),
+        series(&mercury::Z3),
+        series(&mercury::Z4),
+    ],
+];
+
+/// Series of the X, Y and Z coordinates of Venus.
+#[cfg(feature = "venus")]
+static VENUS: [&[Series]; 3] = [
+    &[
+        series(&venus::X0),
+        series(&venus::X1),
+        series(&venus::X2),
+        series(&venus::X3),
+        series(&venus::X4),
+        series(&venus::X5),
+    ],
+    &[
+        series(&venus::Y0),
+        series(&venus::Y1),
+        series(&venus::Y2),
+        series(&venus::Y3),
+        series(&venus::Y4),
+        series(&venus::Y5),
+    ],
+    &[
+        series(&venus::Z0),
+        series(&venus::Z1),
+        series(&venus::Z2),
+        series(&venus::Z3),
+        series(&venus::Z4),
+    ],
+];
+
+/// Series of the X, Y and Z coordinates of Earth.
+#[cfg(feature = "earth")]
+static EARTH: [&[Series]; 3] = [
+    &[
+        series(&earth::X0),
+        series(&earth::X1),
+        series(&earth::X2),
+        series(&earth::X3),
+        series(&earth::X4),
+        series(&earth::X5),
+    ],
+    &[
+        series(&earth::Y0),
+        series(&earth::Y1),
+        series(&earth::Y2),
+        series(&earth::Y3),
+        series(&earth::Y4),
+        series(&earth::Y5),
+    ],
+    &[
+        series(&earth::Z0),
+        series(&earth::Z1),
+        series(&earth::Z2),
+        series(&earth::Z3),
+        series(&earth::Z4),
+    ],
+];
+
+/// Series of the X, Y and Z coordinates of Mars.
+#[cfg(feature = "mars")]
+static MARS: [&[Series]; 3] = [
+    &[
+        series(&mars::X0),
+        series(&mars::X1),
+        series(&mars::X2),
+        series(&mars::X3),
+        series(&mars::X4),
+        series(&mars::X5),
+    ],
+    &[
+        series(&mars::Y0),
+        series(&mars::Y1),
+        series(&mars::Y2),
+        series(&mars::Y3),
+        series(&mars::Y4),
+        series(&mars::Y5),
+    ],
+    &[
+        series(&mars::Z0),
+        series(&mars::Z1),
+        series(&mars::Z2),
+        series(&mars::Z3),
+        series(&mars::Z4),
+    ],
+];
+
+/// Series of the X, Y and Z coordinates of Jupiter.
+#[cfg(feature = "jupiter")]
+static JUPITER: [&[Series]; 3] = [
+    &[
+        series(&jupiter::X0),
+        series(&jupiter::X1),
+        series(&jupiter::X2),
+        series(&jupiter::X3),
+        series(&jupiter::X4),
+        series(&jupiter::X5),
+    ],
+    &[
+        series(&jupiter::Y0),
+        series(&jupiter::Y1),
+        series(&jupiter::Y2),
+        series(&jupiter::Y3),
+        series(&jupiter::Y4),
+        series(&jupiter::Y5),
+    ],
+    &[
+        series(&jupiter::Z0),
+        series(&jupiter::Z1),
+        series(&jupiter::Z2),
+        series(&jupiter::Z3),
+        series(&jupiter::Z4),
+    ],
+];
+
+/// Series of the X, Y and Z coordinates of Saturn.
+#[cfg(feature = "saturn")]
+static SATURN: [&[Series]; 3] = [
+    &[
+        series(&saturn::X0),
+        series(&saturn::X1),
+        series(&saturn::X2),
+        series(&saturn::X3),
+        series(&saturn::X4),
+        series(&saturn::X5),
+    ],
+    &[
+        series(&saturn::Y0),
+        series(&saturn::Y1),
+        series(&saturn::Y2),
+        series(&saturn::Y3),
+        series(&saturn::Y4),
+        series(&saturn::Y5),
+    ],
+    &[
+        series(&saturn::Z0),
+        series(&saturn::Z1),
+        series(&saturn::Z2),
+        series(&saturn::Z3),
+        series(&saturn::Z4),
+    ],
+];
+
+/// Series of the X, Y and Z coordinates of Uranus.
+#[cfg(feature = "uranus")]
+static URANUS: [&[Series]; 3] = [
+    &[
+        series(&uranus::X0),
+        series(&uranus::X1),
+        series(&uranus::X2),
+        series(&uranus::X3),
+        series(&uranus::X4),
+    ],
+    &[
+        series(&uranus::Y0),
+        series(&uranus::Y1),
+        series(&uranus::Y2),
+        series(&uranus::Y3),
+        series(&uranus::Y4),
+    ],
+    &[
+        series(&uranus::Z0),
+        series(&uranus::Z1),
+        series(&uranus::Z2),
+    ],
+];
+
+/// Series of the X, Y and Z coordinates of Neptune.
+#[cfg(feature = "neptune")]
+static NEPTUNE: [&[Series]; 3] = [
+    &[
+        series(&neptune::X0),
+        series(&neptune::X1),
+        series(&neptune::X2),
+        series(&neptune::X3),
+        series(&neptune::X4),
+    ],
+    &[
+        series(&neptune::Y0),
+        series(&neptune::Y1),
+        series(&neptune::Y2),
+        series(&neptune::Y3),
+        series(&neptune::Y4),
+    ],
+    &[
+        series(&neptune::Z0),
+        series(&neptune::Z1),
+        series(&neptune::Z2),
+    ],
+];
+
+/// Bodies available in VSOP87E, in the order used by [`all_bodies()`].
+///
+/// Only the bodies enabled with their cargo features are included.
+pub const BODIES: &[Body] = &[
+    #[cfg(feature = "sun")]
+    Body::Sun,
+    #[cfg(feature = "mercury")]
+    Body::Mercury,
+    #[cfg(feature = "venus")]
+    Body::Venus,
+    #[cfg(feature = "earth")]
+    Body::Earth,
+    #[cfg(feature = "mars")]
+    Body::Mars,
+    #[cfg(feature = "jupiter")]
+    Body::Jupiter,
+    #[cfg(feature = "saturn")]
+    Body::Saturn,
+    #[cfg(feature = "uranus")]
+    Body::Uranus,
+    #[cfg(feature = "neptune")]
+    Body::Neptune,
+];
+
+/// Series of all the bodies available in VSOP87E, in the same order as [`BODIES`].
+static ALL_BODIES: [&[&[Series]; 3]; BODIES.len()] = [
+    #[cfg(feature = "sun")]
+    &SUN,
+    #[cfg(feature = "mercury")]
+    &MERCURY,
+    #[cfg(feature = "venus")]
+    &VENUS,
+    #[cfg(feature = "earth")]
+    &EARTH,
+    #[cfg(feature = "mars")]
+    &MARS,
+    #[cfg(feature = "jupiter")]
+    &JUPITER,
+    #[cfg(feature = "saturn")]
+    &SATURN,
+    #[cfg(feature = "uranus")]
+    &URANUS,
+    #[cfg(feature = "neptune")]
+    &NEPTUNE,
+];
 
 /// Calculates VSOP87E solution for the Sun.
 ///
@@ -43,9 +368,9 @@ use super::{calculate_t, calculate_var, RectangularCoordinates};
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the Sun in the solar system using rectangular coordinates. In this case, we
-/// calculate where the Sun was in January 1st, 2000.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the Sun in the solar system
+/// using rectangular coordinates. In this case, we calculate where the Sun was in January 1st,
+/// 2000.
 ///
 /// ```
 /// use vsop87::vsop87e;
@@ -56,41 +381,79 @@ use super::{calculate_t, calculate_var, RectangularCoordinates};
 /// assert!(coordinates.y > -0.0027881716 && coordinates.y < -0.0027881714);
 /// assert!(coordinates.z > 0.0002041 && coordinates.z < 0.0002081);
 /// ```
+#[cfg(feature = "sun")]
+#[must_use]
+pub fn sun(jde: impl Into<JulianDay>) -> RectangularCoordinates {
+    rectangular(calculate_body(jde.into(), &SUN))
+}
+
+/// Calculates VSOP87E solution for the Sun, along with its velocity.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the Sun, and its velocity, by differentiating each term of the series.
+/// The parameter needed is the Julian Day (*JD*) for the given date. It returns a `StateVector`
+/// structure, with the position of the Sun in *AU*, as returned by [`sun()`], and its velocity in
+/// *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87e;
+///
+/// let state = vsop87e::sun_state(2451545.0);
+///
+/// assert!(state.position.x > -0.0071415280 && state.position.x < -0.0071415278);
+/// assert!(state.position.y > -0.0027881716 && state.position.y < -0.0027881714);
+/// assert!(state.position.z > 0.0002041 && state.position.z < 0.0002081);
+/// assert!(state.velocity.x > 0.000005377401 && state.velocity.x < 0.000005377402);
+/// ```
+#[cfg(feature = "sun")]
 #[must_use]
-pub fn sun(jde: f64) -> RectangularCoordinates {
-    let t = calculate_t(jde);
-
-    let x0 = calculate_var(t, &sun::X0[0], &sun::X0[1], &sun::X0[2]);
-    let x1 = calculate_var(t, &sun::X1[0], &sun::X1[1], &sun::X1[2]);
-    let x2 = calculate_var(t, &sun::X2[0], &sun::X2[1], &sun::X2[2]);
-    let x3 = calculate_var(t, &sun::X3[0], &sun::X3[1], &sun::X3[2]);
-    let x4 = calculate_var(t, &sun::X4[0], &sun::X4[1], &sun::X4[2]);
-    let x5 = calculate_var(t, &sun::X5[0], &sun::X5[1], &sun::X5[2]);
-
-    let y0 = calculate_var(t, &sun::Y0[0], &sun::Y0[1], &sun::Y0[2]);
-    let y1 = calculate_var(t, &sun::Y1[0], &sun::Y1[1], &sun::Y1[2]);
-    let y2 = calculate_var(t, &sun::Y2[0], &sun::Y2[1], &sun::Y2[2]);
-    let y3 = calculate_var(t, &sun::Y3[0], &sun::Y3[1], &sun::Y3[2]);
-    let y4 = calculate_var(t, &sun::Y4[0], &sun::Y4[1], &sun::Y4[2]);
-    let y5 = calculate_var(t, &sun::Y5[0], &sun::Y5[1], &sun::Y5[2]);
-
-    let z0 = calculate_var(t, &sun::Z0[0], &sun::Z0[1], &sun::Z0[2]);
-    let z1 = calculate_var(t, &sun::Z1[0], &sun::Z1[1], &sun::Z1[2]);
-    let z2 = calculate_var(t, &sun::Z2[0], &sun::Z2[1], &sun::Z2[2]);
-    let z3 = calculate_var(t, &sun::Z3[0], &sun::Z3[1], &sun::Z3[2]);
-    let z4 = calculate_var(t, &sun::Z4[0], &sun::Z4[1], &sun::Z4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
-
-    RectangularCoordinates { x, y, z }
+pub fn sun_state(jde: impl Into<JulianDay>) -> StateVector {
+    calculate_state(jde.into(), &SUN)
+}
+
+/// Calculates VSOP87E solution for the Sun, checking the Julian Day.
+///
+/// This function is equivalent to [`sun()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for the Sun (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// assert_eq!(vsop87e::sun_checked(2451545.0)?, vsop87e::sun(2451545.0));
+/// assert_eq!(
+///     vsop87e::sun_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Sun })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "sun")]
+pub fn sun_checked(jde: impl Into<JulianDay>) -> Result<RectangularCoordinates, Error> {
+    checked(Body::Sun, jde)
+}
+
+/// Calculates VSOP87E solution for the Sun, along with its velocity, checking the Julian Day.
+///
+/// This function is equivalent to [`sun_state()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for the Sun (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Error};
+///
+/// assert_eq!(vsop87e::sun_state_checked(2451545.0)?, vsop87e::sun_state(2451545.0));
+/// assert_eq!(vsop87e::sun_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "sun")]
+pub fn sun_state_checked(jde: impl Into<JulianDay>) -> Result<StateVector, Error> {
+    checked_state(Body::Sun, jde)
 }
 
 /// Calculates VSOP87E solution for Mercury.
@@ -103,9 +466,9 @@ pub fn sun(jde: f64) -> RectangularCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Mercury in the solar system using rectangular coordinates. In this case,
-/// we calculate where Mercury was in December 31st, 1899.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Mercury in the
+/// solar system using rectangular coordinates. In this case, we calculate where Mercury was in
+/// December 31st, 1899.
 ///
 /// ```
 /// use vsop87::vsop87e;
@@ -116,41 +479,79 @@ pub fn sun(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -0.1438666202 && coordinates.y < -0.1438666200);
 /// assert!(coordinates.z > 0.0235142 && coordinates.z < 0.0235182);
 /// ```
+#[cfg(feature = "mercury")]
+#[must_use]
+pub fn mercury(jde: impl Into<JulianDay>) -> RectangularCoordinates {
+    rectangular(calculate_body(jde.into(), &MERCURY))
+}
+
+/// Calculates VSOP87E solution for Mercury, along with its velocity.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the planet Mercury, and its velocity, by differentiating each term of
+/// the series. The parameter needed is the Julian Day (*JD*) for the given date. It returns a
+/// `StateVector` structure, with the position of the planet in *AU*, as returned by [`mercury()`],
+/// and its velocity in *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87e;
+///
+/// let state = vsop87e::mercury_state(2415020.0);
+///
+/// assert!(state.position.x > -0.3865370328 && state.position.x < -0.3865370326);
+/// assert!(state.position.y > -0.1438666202 && state.position.y < -0.1438666200);
+/// assert!(state.position.z > 0.0235142 && state.position.z < 0.0235182);
+/// assert!(state.velocity.x > 0.004294182375 && state.velocity.x < 0.004294182376);
+/// ```
+#[cfg(feature = "mercury")]
 #[must_use]
-pub fn mercury(jde: f64) -> RectangularCoordinates {
-    let t = calculate_t(jde);
-
-    let x0 = calculate_var(t, &mercury::X0[0], &mercury::X0[1], &mercury::X0[2]);
-    let x1 = calculate_var(t, &mercury::X1[0], &mercury::X1[1], &mercury::X1[2]);
-    let x2 = calculate_var(t, &mercury::X2[0], &mercury::X2[1], &mercury::X2[2]);
-    let x3 = calculate_var(t, &mercury::X3[0], &mercury::X3[1], &mercury::X3[2]);
-    let x4 = calculate_var(t, &mercury::X4[0], &mercury::X4[1], &mercury::X4[2]);
-    let x5 = calculate_var(t, &mercury::X5[0], &mercury::X5[1], &mercury::X5[2]);
-
-    let y0 = calculate_var(t, &mercury::Y0[0], &mercury::Y0[1], &mercury::Y0[2]);
-    let y1 = calculate_var(t, &mercury::Y1[0], &mercury::Y1[1], &mercury::Y1[2]);
-    let y2 = calculate_var(t, &mercury::Y2[0], &mercury::Y2[1], &mercury::Y2[2]);
-    let y3 = calculate_var(t, &mercury::Y3[0], &mercury::Y3[1], &mercury::Y3[2]);
-    let y4 = calculate_var(t, &mercury::Y4[0], &mercury::Y4[1], &mercury::Y4[2]);
-    let y5 = calculate_var(t, &mercury::Y5[0], &mercury::Y5[1], &mercury::Y5[2]);
-
-    let z0 = calculate_var(t, &mercury::Z0[0], &mercury::Z0[1], &mercury::Z0[2]);
-    let z1 = calculate_var(t, &mercury::Z1[0], &mercury::Z1[1], &mercury::Z1[2]);
-    let z2 = calculate_var(t, &mercury::Z2[0], &mercury::Z2[1], &mercury::Z2[2]);
-    let z3 = calculate_var(t, &mercury::Z3[0], &mercury::Z3[1], &mercury::Z3[2]);
-    let z4 = calculate_var(t, &mercury::Z4[0], &mercury::Z4[1], &mercury::Z4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
-
-    RectangularCoordinates { x, y, z }
+pub fn mercury_state(jde: impl Into<JulianDay>) -> StateVector {
+    calculate_state(jde.into(), &MERCURY)
+}
+
+/// Calculates VSOP87E solution for Mercury, checking the Julian Day.
+///
+/// This function is equivalent to [`mercury()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Mercury (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// assert_eq!(vsop87e::mercury_checked(2451545.0)?, vsop87e::mercury(2451545.0));
+/// assert_eq!(
+///     vsop87e::mercury_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Mercury })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "mercury")]
+pub fn mercury_checked(jde: impl Into<JulianDay>) -> Result<RectangularCoordinates, Error> {
+    checked(Body::Mercury, jde)
+}
+
+/// Calculates VSOP87E solution for Mercury, along with its velocity, checking the Julian Day.
+///
+/// This function is equivalent to [`mercury_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Mercury (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Error};
+///
+/// assert_eq!(vsop87e::mercury_state_checked(2451545.0)?, vsop87e::mercury_state(2451545.0));
+/// assert_eq!(vsop87e::mercury_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "mercury")]
+pub fn mercury_state_checked(jde: impl Into<JulianDay>) -> Result<StateVector, Error> {
+    checked_state(Body::Mercury, jde)
 }
 
 /// Calculates VSOP87E solution for Venus.
@@ -163,9 +564,9 @@ pub fn mercury(jde: f64) -> RectangularCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Venus in the solar system using rectangular coordinates. In this case,
-/// we calculate where Venus was in December 30th, 1799.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Venus in the
+/// solar system using rectangular coordinates. In this case, we calculate where Venus was in
+/// December 30th, 1799.
 ///
 /// ```
 /// use vsop87::vsop87e;
@@ -176,41 +577,79 @@ pub fn mercury(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > 0.3900421674 && coordinates.y < 0.3900421676);
 /// assert!(coordinates.z > 0.0397542 && coordinates.z < 0.0397582);
 /// ```
+#[cfg(feature = "venus")]
+#[must_use]
+pub fn venus(jde: impl Into<JulianDay>) -> RectangularCoordinates {
+    rectangular(calculate_body(jde.into(), &VENUS))
+}
+
+/// Calculates VSOP87E solution for Venus, along with its velocity.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the planet Venus, and its velocity, by differentiating each term of the
+/// series. The parameter needed is the Julian Day (*JD*) for the given date. It returns a
+/// `StateVector` structure, with the position of the planet in *AU*, as returned by [`venus()`],
+/// and its velocity in *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87e;
+///
+/// let state = vsop87e::venus_state(2378495.0);
+///
+/// assert!(state.position.x > -0.5948645228 && state.position.x < -0.5948645226);
+/// assert!(state.position.y > 0.3900421674 && state.position.y < 0.3900421676);
+/// assert!(state.position.z > 0.0397542 && state.position.z < 0.0397582);
+/// assert!(state.velocity.x > -0.011232802206 && state.velocity.x < -0.011232802205);
+/// ```
+#[cfg(feature = "venus")]
 #[must_use]
-pub fn venus(jde: f64) -> RectangularCoordinates {
-    let t = calculate_t(jde);
-
-    let x0 = calculate_var(t, &venus::X0[0], &venus::X0[1], &venus::X0[2]);
-    let x1 = calculate_var(t, &venus::X1[0], &venus::X1[1], &venus::X1[2]);
-    let x2 = calculate_var(t, &venus::X2[0], &venus::X2[1], &venus::X2[2]);
-    let x3 = calculate_var(t, &venus::X3[0], &venus::X3[1], &venus::X3[2]);
-    let x4 = calculate_var(t, &venus::X4[0], &venus::X4[1], &venus::X4[2]);
-    let x5 = calculate_var(t, &venus::X5[0], &venus::X5[1], &venus::X5[2]);
-
-    let y0 = calculate_var(t, &venus::Y0[0], &venus::Y0[1], &venus::Y0[2]);
-    let y1 = calculate_var(t, &venus::Y1[0], &venus::Y1[1], &venus::Y1[2]);
-    let y2 = calculate_var(t, &venus::Y2[0], &venus::Y2[1], &venus::Y2[2]);
-    let y3 = calculate_var(t, &venus::Y3[0], &venus::Y3[1], &venus::Y3[2]);
-    let y4 = calculate_var(t, &venus::Y4[0], &venus::Y4[1], &venus::Y4[2]);
-    let y5 = calculate_var(t, &venus::Y5[0], &venus::Y5[1], &venus::Y5[2]);
-
-    let z0 = calculate_var(t, &venus::Z0[0], &venus::Z0[1], &venus::Z0[2]);
-    let z1 = calculate_var(t, &venus::Z1[0], &venus::Z1[1], &venus::Z1[2]);
-    let z2 = calculate_var(t, &venus::Z2[0], &venus::Z2[1], &venus::Z2[2]);
-    let z3 = calculate_var(t, &venus::Z3[0], &venus::Z3[1], &venus::Z3[2]);
-    let z4 = calculate_var(t, &venus::Z4[0], &venus::Z4[1], &venus::Z4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
-
-    RectangularCoordinates { x, y, z }
+pub fn venus_state(jde: impl Into<JulianDay>) -> StateVector {
+    calculate_state(jde.into(), &VENUS)
+}
+
+/// Calculates VSOP87E solution for Venus, checking the Julian Day.
+///
+/// This function is equivalent to [`venus()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Venus (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// assert_eq!(vsop87e::venus_checked(2451545.0)?, vsop87e::venus(2451545.0));
+/// assert_eq!(
+///     vsop87e::venus_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Venus })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "venus")]
+pub fn venus_checked(jde: impl Into<JulianDay>) -> Result<RectangularCoordinates, Error> {
+    checked(Body::Venus, jde)
+}
+
+/// Calculates VSOP87E solution for Venus, along with its velocity, checking the Julian Day.
+///
+/// This function is equivalent to [`venus_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Venus (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Error};
+///
+/// assert_eq!(vsop87e::venus_state_checked(2451545.0)?, vsop87e::venus_state(2451545.0));
+/// assert_eq!(vsop87e::venus_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "venus")]
+pub fn venus_state_checked(jde: impl Into<JulianDay>) -> Result<StateVector, Error> {
+    checked_state(Body::Venus, jde)
 }
 
 /// Calculates VSOP87E solution for Earth.
@@ -223,9 +662,9 @@ pub fn venus(jde: f64) -> RectangularCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Earth in the solar system using rectangular coordinates. In this case,
-/// we calculate where the Earth was in December 29th, 1699.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Earth in the
+/// solar system using rectangular coordinates. In this case, we calculate where the Earth was in
+/// December 29th, 1699.
 ///
 /// ```
 /// use vsop87::vsop87e;
@@ -236,41 +675,79 @@ pub fn venus(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > 0.9651943804 && coordinates.y < 0.9651943806);
 /// assert!(coordinates.z > 0.0007705 && coordinates.z < 0.0007745);
 /// ```
+#[cfg(feature = "earth")]
+#[must_use]
+pub fn earth(jde: impl Into<JulianDay>) -> RectangularCoordinates {
+    rectangular(calculate_body(jde.into(), &EARTH))
+}
+
+/// Calculates VSOP87E solution for Earth, along with its velocity.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the planet Earth, and its velocity, by differentiating each term of the
+/// series. The parameter needed is the Julian Day (*JD*) for the given date. It returns a
+/// `StateVector` structure, with the position of the planet in *AU*, as returned by [`earth()`],
+/// and its velocity in *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87e;
+///
+/// let state = vsop87e::earth_state(2341970.0);
+///
+/// assert!(state.position.x > -0.2155959338 && state.position.x < -0.2155959336);
+/// assert!(state.position.y > 0.9651943804 && state.position.y < 0.9651943806);
+/// assert!(state.position.z > 0.0007705 && state.position.z < 0.0007745);
+/// assert!(state.velocity.x > -0.017094769181 && state.velocity.x < -0.017094769180);
+/// ```
+#[cfg(feature = "earth")]
 #[must_use]
-pub fn earth(jde: f64) -> RectangularCoordinates {
-    let t = calculate_t(jde);
-
-    let x0 = calculate_var(t, &earth::X0[0], &earth::X0[1], &earth::X0[2]);
-    let x1 = calculate_var(t, &earth::X1[0], &earth::X1[1], &earth::X1[2]);
-    let x2 = calculate_var(t, &earth::X2[0], &earth::X2[1], &earth::X2[2]);
-    let x3 = calculate_var(t, &earth::X3[0], &earth::X3[1], &earth::X3[2]);
-    let x4 = calculate_var(t, &earth::X4[0], &earth::X4[1], &earth::X4[2]);
-    let x5 = calculate_var(t, &earth::X5[0], &earth::X5[1], &earth::X5[2]);
-
-    let y0 = calculate_var(t, &earth::Y0[0], &earth::Y0[1], &earth::Y0[2]);
-    let y1 = calculate_var(t, &earth::Y1[0], &earth::Y1[1], &earth::Y1[2]);
-    let y2 = calculate_var(t, &earth::Y2[0], &earth::Y2[1], &earth::Y2[2]);
-    let y3 = calculate_var(t, &earth::Y3[0], &earth::Y3[1], &earth::Y3[2]);
-    let y4 = calculate_var(t, &earth::Y4[0], &earth::Y4[1], &earth::Y4[2]);
-    let y5 = calculate_var(t, &earth::Y5[0], &earth::Y5[1], &earth::Y5[2]);
-
-    let z0 = calculate_var(t, &earth::Z0[0], &earth::Z0[1], &earth::Z0[2]);
-    let z1 = calculate_var(t, &earth::Z1[0], &earth::Z1[1], &earth::Z1[2]);
-    let z2 = calculate_var(t, &earth::Z2[0], &earth::Z2[1], &earth::Z2[2]);
-    let z3 = calculate_var(t, &earth::Z3[0], &earth::Z3[1], &earth::Z3[2]);
-    let z4 = calculate_var(t, &earth::Z4[0], &earth::Z4[1], &earth::Z4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
-
-    RectangularCoordinates { x, y, z }
+pub fn earth_state(jde: impl Into<JulianDay>) -> StateVector {
+    calculate_state(jde.into(), &EARTH)
+}
+
+/// Calculates VSOP87E solution for Earth, checking the Julian Day.
+///
+/// This function is equivalent to [`earth()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Earth (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// assert_eq!(vsop87e::earth_checked(2451545.0)?, vsop87e::earth(2451545.0));
+/// assert_eq!(
+///     vsop87e::earth_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Earth })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "earth")]
+pub fn earth_checked(jde: impl Into<JulianDay>) -> Result<RectangularCoordinates, Error> {
+    checked(Body::Earth, jde)
+}
+
+/// Calculates VSOP87E solution for Earth, along with its velocity, checking the Julian Day.
+///
+/// This function is equivalent to [`earth_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Earth (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Error};
+///
+/// assert_eq!(vsop87e::earth_state_checked(2451545.0)?, vsop87e::earth_state(2451545.0));
+/// assert_eq!(vsop87e::earth_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "earth")]
+pub fn earth_state_checked(jde: impl Into<JulianDay>) -> Result<StateVector, Error> {
+    checked_state(Body::Earth, jde)
 }
 
 /// Calculates VSOP87E solution for Mars.
@@ -283,9 +760,9 @@ pub fn earth(jde: f64) -> RectangularCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Mars in the solar system using rectangular coordinates. In this case, we
-/// calculate where Mercury was in December 29th, 1599.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Mars in the
+/// solar system using rectangular coordinates. In this case, we calculate where Mercury was in
+/// December 29th, 1599.
 ///
 /// ```
 /// use vsop87::vsop87e;
@@ -296,41 +773,79 @@ pub fn earth(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > 1.4065798250 && coordinates.y < 1.4065798252);
 /// assert!(coordinates.z > 0.0502476 && coordinates.z < 0.0502516);
 /// ```
+#[cfg(feature = "mars")]
 #[must_use]
-pub fn mars(jde: f64) -> RectangularCoordinates {
-    let t = calculate_t(jde);
-
-    let x0 = calculate_var(t, &mars::X0[0], &mars::X0[1], &mars::X0[2]);
-    let x1 = calculate_var(t, &mars::X1[0], &mars::X1[1], &mars::X1[2]);
-    let x2 = calculate_var(t, &mars::X2[0], &mars::X2[1], &mars::X2[2]);
-    let x3 = calculate_var(t, &mars::X3[0], &mars::X3[1], &mars::X3[2]);
-    let x4 = calculate_var(t, &mars::X4[0], &mars::X4[1], &mars::X4[2]);
-    let x5 = calculate_var(t, &mars::X5[0], &mars::X5[1], &mars::X5[2]);
-
-    let y0 = calculate_var(t, &mars::Y0[0], &mars::Y0[1], &mars::Y0[2]);
-    let y1 = calculate_var(t, &mars::Y1[0], &mars::Y1[1], &mars::Y1[2]);
-    let y2 = calculate_var(t, &mars::Y2[0], &mars::Y2[1], &mars::Y2[2]);
-    let y3 = calculate_var(t, &mars::Y3[0], &mars::Y3[1], &mars::Y3[2]);
-    let y4 = calculate_var(t, &mars::Y4[0], &mars::Y4[1], &mars::Y4[2]);
-    let y5 = calculate_var(t, &mars::Y5[0], &mars::Y5[1], &mars::Y5[2]);
-
-    let z0 = calculate_var(t, &mars::Z0[0], &mars::Z0[1], &mars::Z0[2]);
-    let z1 = calculate_var(t, &mars::Z1[0], &mars::Z1[1], &mars::Z1[2]);
-    let z2 = calculate_var(t, &mars::Z2[0], &mars::Z2[1], &mars::Z2[2]);
-    let z3 = calculate_var(t, &mars::Z3[0], &mars::Z3[1], &mars::Z3[2]);
-    let z4 = calculate_var(t, &mars::Z4[0], &mars::Z4[1], &mars::Z4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
-
-    RectangularCoordinates { x, y, z }
+pub fn mars(jde: impl Into<JulianDay>) -> RectangularCoordinates {
+    rectangular(calculate_body(jde.into(), &MARS))
+}
+
+/// Calculates VSOP87E solution for Mars, along with its velocity.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the planet Mars, and its velocity, by differentiating each term of the
+/// series. The parameter needed is the Julian Day (*JD*) for the given date. It returns a
+/// `StateVector` structure, with the position of the planet in *AU*, as returned by [`mars()`], and
+/// its velocity in *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87e;
+///
+/// let state = vsop87e::mars_state(2305445.0);
+///
+/// assert!(state.position.x > -0.8237565239 && state.position.x < -0.8237565237);
+/// assert!(state.position.y > 1.4065798250 && state.position.y < 1.4065798252);
+/// assert!(state.position.z > 0.0502476 && state.position.z < 0.0502516);
+/// assert!(state.velocity.x > -0.011485391849 && state.velocity.x < -0.011485391848);
+/// ```
+#[cfg(feature = "mars")]
+#[must_use]
+pub fn mars_state(jde: impl Into<JulianDay>) -> StateVector {
+    calculate_state(jde.into(), &MARS)
+}
+
+/// Calculates VSOP87E solution for Mars, checking the Julian Day.
+///
+/// This function is equivalent to [`mars()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Mars (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// assert_eq!(vsop87e::mars_checked(2451545.0)?, vsop87e::mars(2451545.0));
+/// assert_eq!(
+///     vsop87e::mars_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Mars })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "mars")]
+pub fn mars_checked(jde: impl Into<JulianDay>) -> Result<RectangularCoordinates, Error> {
+    checked(Body::Mars, jde)
+}
+
+/// Calculates VSOP87E solution for Mars, along with its velocity, checking the Julian Day.
+///
+/// This function is equivalent to [`mars_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Mars (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Error};
+///
+/// assert_eq!(vsop87e::mars_state_checked(2451545.0)?, vsop87e::mars_state(2451545.0));
+/// assert_eq!(vsop87e::mars_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "mars")]
+pub fn mars_state_checked(jde: impl Into<JulianDay>) -> Result<StateVector, Error> {
+    checked_state(Body::Mars, jde)
 }
 
 /// Calculates VSOP87E solution for Jupiter.
@@ -343,9 +858,9 @@ pub fn mars(jde: f64) -> RectangularCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Jupiter in the solar system using rectangular coordinates. In this case,
-/// we calculate where Jupiter was in December 19th, 1499.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Jupiter in the
+/// solar system using rectangular coordinates. In this case, we calculate where Jupiter was in
+/// December 19th, 1499.
 ///
 /// ```
 /// use vsop87::vsop87e;
@@ -356,41 +871,79 @@ pub fn mars(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -1.9854861384 && coordinates.y < -1.9854861382);
 /// assert!(coordinates.z > -0.0959289 && coordinates.z < -0.0959249);
 /// ```
+#[cfg(feature = "jupiter")]
 #[must_use]
-pub fn jupiter(jde: f64) -> RectangularCoordinates {
-    let t = calculate_t(jde);
-
-    let x0 = calculate_var(t, &jupiter::X0[0], &jupiter::X0[1], &jupiter::X0[2]);
-    let x1 = calculate_var(t, &jupiter::X1[0], &jupiter::X1[1], &jupiter::X1[2]);
-    let x2 = calculate_var(t, &jupiter::X2[0], &jupiter::X2[1], &jupiter::X2[2]);
-    let x3 = calculate_var(t, &jupiter::X3[0], &jupiter::X3[1], &jupiter::X3[2]);
-    let x4 = calculate_var(t, &jupiter::X4[0], &jupiter::X4[1], &jupiter::X4[2]);
-    let x5 = calculate_var(t, &jupiter::X5[0], &jupiter::X5[1], &jupiter::X5[2]);
-
-    let y0 = calculate_var(t, &jupiter::Y0[0], &jupiter::Y0[1], &jupiter::Y0[2]);
-    let y1 = calculate_var(t, &jupiter::Y1[0], &jupiter::Y1[1], &jupiter::Y1[2]);
-    let y2 = calculate_var(t, &jupiter::Y2[0], &jupiter::Y2[1], &jupiter::Y2[2]);
-    let y3 = calculate_var(t, &jupiter::Y3[0], &jupiter::Y3[1], &jupiter::Y3[2]);
-    let y4 = calculate_var(t, &jupiter::Y4[0], &jupiter::Y4[1], &jupiter::Y4[2]);
-    let y5 = calculate_var(t, &jupiter::Y5[0], &jupiter::Y5[1], &jupiter::Y5[2]);
-
-    let z0 = calculate_var(t, &jupiter::Z0[0], &jupiter::Z0[1], &jupiter::Z0[2]);
-    let z1 = calculate_var(t, &jupiter::Z1[0], &jupiter::Z1[1], &jupiter::Z1[2]);
-    let z2 = calculate_var(t, &jupiter::Z2[0], &jupiter::Z2[1], &jupiter::Z2[2]);
-    let z3 = calculate_var(t, &jupiter::Z3[0], &jupiter::Z3[1], &jupiter::Z3[2]);
-    let z4 = calculate_var(t, &jupiter::Z4[0], &jupiter::Z4[1], &jupiter::Z4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
-
-    RectangularCoordinates { x, y, z }
+pub fn jupiter(jde: impl Into<JulianDay>) -> RectangularCoordinates {
+    rectangular(calculate_body(jde.into(), &JUPITER))
+}
+
+/// Calculates VSOP87E solution for Jupiter, along with its velocity.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the planet Jupiter, and its velocity, by differentiating each term of
+/// the series. The parameter needed is the Julian Day (*JD*) for the given date. It returns a
+/// `StateVector` structure, with the position of the planet in *AU*, as returned by [`jupiter()`],
+/// and its velocity in *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87e;
+///
+/// let state = vsop87e::jupiter_state(2268920.0);
+///
+/// assert!(state.position.x > 4.5819830418 && state.position.x < 4.5819830420);
+/// assert!(state.position.y > -1.9854861384 && state.position.y < -1.9854861382);
+/// assert!(state.position.z > -0.0959289 && state.position.z < -0.0959249);
+/// assert!(state.velocity.x > 0.002917208917 && state.velocity.x < 0.002917208918);
+/// ```
+#[cfg(feature = "jupiter")]
+#[must_use]
+pub fn jupiter_state(jde: impl Into<JulianDay>) -> StateVector {
+    calculate_state(jde.into(), &JUPITER)
+}
+
+/// Calculates VSOP87E solution for Jupiter, checking the Julian Day.
+///
+/// This function is equivalent to [`jupiter()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Jupiter (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// assert_eq!(vsop87e::jupiter_checked(2451545.0)?, vsop87e::jupiter(2451545.0));
+/// assert_eq!(
+///     vsop87e::jupiter_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "jupiter")]
+pub fn jupiter_checked(jde: impl Into<JulianDay>) -> Result<RectangularCoordinates, Error> {
+    checked(Body::Jupiter, jde)
+}
+
+/// Calculates VSOP87E solution for Jupiter, along with its velocity, checking the Julian Day.
+///
+/// This function is equivalent to [`jupiter_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Jupiter (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Error};
+///
+/// assert_eq!(vsop87e::jupiter_state_checked(2451545.0)?, vsop87e::jupiter_state(2451545.0));
+/// assert_eq!(vsop87e::jupiter_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "jupiter")]
+pub fn jupiter_state_checked(jde: impl Into<JulianDay>) -> Result<StateVector, Error> {
+    checked_state(Body::Jupiter, jde)
 }
 
 /// Calculates VSOP87E solution for Saturn.
@@ -403,9 +956,9 @@ pub fn jupiter(jde: f64) -> RectangularCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Saturn in the solar system using rectangular coordinates. In this case,
-/// we calculate where Saturn was in December 19th, 1399.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Saturn in the
+/// solar system using rectangular coordinates. In this case, we calculate where Saturn was in
+/// December 19th, 1399.
 ///
 /// ```
 /// use vsop87::vsop87e;
@@ -416,41 +969,79 @@ pub fn jupiter(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -10.0240954526 && coordinates.y < -10.0240954524);
 /// assert!(coordinates.z > 0.1345888 && coordinates.z < 0.1345928);
 /// ```
+#[cfg(feature = "saturn")]
 #[must_use]
-pub fn saturn(jde: f64) -> RectangularCoordinates {
-    let t = calculate_t(jde);
-
-    let x0 = calculate_var(t, &saturn::X0[0], &saturn::X0[1], &saturn::X0[2]);
-    let x1 = calculate_var(t, &saturn::X1[0], &saturn::X1[1], &saturn::X1[2]);
-    let x2 = calculate_var(t, &saturn::X2[0], &saturn::X2[1], &saturn::X2[2]);
-    let x3 = calculate_var(t, &saturn::X3[0], &saturn::X3[1], &saturn::X3[2]);
-    let x4 = calculate_var(t, &saturn::X4[0], &saturn::X4[1], &saturn::X4[2]);
-    let x5 = calculate_var(t, &saturn::X5[0], &saturn::X5[1], &saturn::X5[2]);
-
-    let y0 = calculate_var(t, &saturn::Y0[0], &saturn::Y0[1], &saturn::Y0[2]);
-    let y1 = calculate_var(t, &saturn::Y1[0], &saturn::Y1[1], &saturn::Y1[2]);
-    let y2 = calculate_var(t, &saturn::Y2[0], &saturn::Y2[1], &saturn::Y2[2]);
-    let y3 = calculate_var(t, &saturn::Y3[0], &saturn::Y3[1], &saturn::Y3[2]);
-    let y4 = calculate_var(t, &saturn::Y4[0], &saturn::Y4[1], &saturn::Y4[2]);
-    let y5 = calculate_var(t, &saturn::Y5[0], &saturn::Y5[1], &saturn::Y5[2]);
-
-    let z0 = calculate_var(t, &saturn::Z0[0], &saturn::Z0[1], &saturn::Z0[2]);
-    let z1 = calculate_var(t, &saturn::Z1[0], &saturn::Z1[1], &saturn::Z1[2]);
-    let z2 = calculate_var(t, &saturn::Z2[0], &saturn::Z2[1], &saturn::Z2[2]);
-    let z3 = calculate_var(t, &saturn::Z3[0], &saturn::Z3[1], &saturn::Z3[2]);
-    let z4 = calculate_var(t, &saturn::Z4[0], &saturn::Z4[1], &saturn::Z4[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-    let t5 = t2 * t3;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4 + x5 * t5;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4 + y5 * t5;
-    let z = z0 + z1 * t + z2 * t2 + z3 * t3 + z4 * t4;
-
-    RectangularCoordinates { x, y, z }
+pub fn saturn(jde: impl Into<JulianDay>) -> RectangularCoordinates {
+    rectangular(calculate_body(jde.into(), &SATURN))
+}
+
+/// Calculates VSOP87E solution for Saturn, along with its velocity.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the planet Saturn, and its velocity, by differentiating each term of
+/// the series. The parameter needed is the Julian Day (*JD*) for the given date. It returns a
+/// `StateVector` structure, with the position of the planet in *AU*, as returned by [`saturn()`],
+/// and its velocity in *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87e;
+///
+/// let state = vsop87e::saturn_state(2232395.0);
+///
+/// assert!(state.position.x > 1.2645936161 && state.position.x < 1.2645936163);
+/// assert!(state.position.y > -10.0240954526 && state.position.y < -10.0240954524);
+/// assert!(state.position.z > 0.1345888 && state.position.z < 0.1345928);
+/// assert!(state.velocity.x > 0.005202266912 && state.velocity.x < 0.005202266913);
+/// ```
+#[cfg(feature = "saturn")]
+#[must_use]
+pub fn saturn_state(jde: impl Into<JulianDay>) -> StateVector {
+    calculate_state(jde.into(), &SATURN)
+}
+
+/// Calculates VSOP87E solution for Saturn, checking the Julian Day.
+///
+/// This function is equivalent to [`saturn()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Saturn (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// assert_eq!(vsop87e::saturn_checked(2451545.0)?, vsop87e::saturn(2451545.0));
+/// assert_eq!(
+///     vsop87e::saturn_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Saturn })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "saturn")]
+pub fn saturn_checked(jde: impl Into<JulianDay>) -> Result<RectangularCoordinates, Error> {
+    checked(Body::Saturn, jde)
+}
+
+/// Calculates VSOP87E solution for Saturn, along with its velocity, checking the Julian Day.
+///
+/// This function is equivalent to [`saturn_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Saturn (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Error};
+///
+/// assert_eq!(vsop87e::saturn_state_checked(2451545.0)?, vsop87e::saturn_state(2451545.0));
+/// assert_eq!(vsop87e::saturn_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "saturn")]
+pub fn saturn_state_checked(jde: impl Into<JulianDay>) -> Result<StateVector, Error> {
+    checked_state(Body::Saturn, jde)
 }
 
 /// Calculates VSOP87E solution for Uranus.
@@ -463,9 +1054,9 @@ pub fn saturn(jde: f64) -> RectangularCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Uranus in the solar system using rectangular coordinates. In this case,
-/// we calculate where Neptune was in December 19th, 1299.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Uranus in the
+/// solar system using rectangular coordinates. In this case, we calculate where Neptune was in
+/// December 19th, 1299.
 ///
 /// ```
 /// use vsop87::vsop87e;
@@ -476,36 +1067,79 @@ pub fn saturn(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -5.1666300881 && coordinates.y < -5.1666300879);
 /// assert!(coordinates.z > 0.2124594 && coordinates.z < 0.2124634);
 /// ```
+#[cfg(feature = "uranus")]
 #[must_use]
-pub fn uranus(jde: f64) -> RectangularCoordinates {
-    let t = calculate_t(jde);
-
-    let x0 = calculate_var(t, &uranus::X0[0], &uranus::X0[1], &uranus::X0[2]);
-    let x1 = calculate_var(t, &uranus::X1[0], &uranus::X1[1], &uranus::X1[2]);
-    let x2 = calculate_var(t, &uranus::X2[0], &uranus::X2[1], &uranus::X2[2]);
-    let x3 = calculate_var(t, &uranus::X3[0], &uranus::X3[1], &uranus::X3[2]);
-    let x4 = calculate_var(t, &uranus::X4[0], &uranus::X4[1], &uranus::X4[2]);
-
-    let y0 = calculate_var(t, &uranus::Y0[0], &uranus::Y0[1], &uranus::Y0[2]);
-    let y1 = calculate_var(t, &uranus::Y1[0], &uranus::Y1[1], &uranus::Y1[2]);
-    let y2 = calculate_var(t, &uranus::Y2[0], &uranus::Y2[1], &uranus::Y2[2]);
-    let y3 = calculate_var(t, &uranus::Y3[0], &uranus::Y3[1], &uranus::Y3[2]);
-    let y4 = calculate_var(t, &uranus::Y4[0], &uranus::Y4[1], &uranus::Y4[2]);
-
-    let z0 = calculate_var(t, &uranus::Z0[0], &uranus::Z0[1], &uranus::Z0[2]);
-    let z1 = calculate_var(t, &uranus::Z1[0], &uranus::Z1[1], &uranus::Z1[2]);
-    let z2 = calculate_var(t, &uranus::Z2[0], &uranus::Z2[1], &uranus::Z2[2]);
+pub fn uranus(jde: impl Into<JulianDay>) -> RectangularCoordinates {
+    rectangular(calculate_body(jde.into(), &URANUS))
+}
 
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
+/// Calculates VSOP87E solution for Uranus, along with its velocity.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the planet Uranus, and its velocity, by differentiating each term of
+/// the series. The parameter needed is the Julian Day (*JD*) for the given date. It returns a
+/// `StateVector` structure, with the position of the planet in *AU*, as returned by [`uranus()`],
+/// and its velocity in *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87e;
+///
+/// let state = vsop87e::uranus_state(2195870.0);
+///
+/// assert!(state.position.x > -17.6538791198 && state.position.x < -17.6538791196);
+/// assert!(state.position.y > -5.1666300881 && state.position.y < -5.1666300879);
+/// assert!(state.position.z > 0.2124594 && state.position.z < 0.2124634);
+/// assert!(state.velocity.x > 0.001073954048 && state.velocity.x < 0.001073954049);
+/// ```
+#[cfg(feature = "uranus")]
+#[must_use]
+pub fn uranus_state(jde: impl Into<JulianDay>) -> StateVector {
+    calculate_state(jde.into(), &URANUS)
+}
 
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4;
-    let z = z0 + z1 * t + z2 * t2;
+/// Calculates VSOP87E solution for Uranus, checking the Julian Day.
+///
+/// This function is equivalent to [`uranus()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Uranus (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// assert_eq!(vsop87e::uranus_checked(2451545.0)?, vsop87e::uranus(2451545.0));
+/// assert_eq!(
+///     vsop87e::uranus_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Uranus })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "uranus")]
+pub fn uranus_checked(jde: impl Into<JulianDay>) -> Result<RectangularCoordinates, Error> {
+    checked(Body::Uranus, jde)
+}
 
-    RectangularCoordinates { x, y, z }
+/// Calculates VSOP87E solution for Uranus, along with its velocity, checking the Julian Day.
+///
+/// This function is equivalent to [`uranus_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Uranus (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Error};
+///
+/// assert_eq!(vsop87e::uranus_state_checked(2451545.0)?, vsop87e::uranus_state(2451545.0));
+/// assert_eq!(vsop87e::uranus_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "uranus")]
+pub fn uranus_state_checked(jde: impl Into<JulianDay>) -> Result<StateVector, Error> {
+    checked_state(Body::Uranus, jde)
 }
 
 /// Calculates VSOP87E solution for Neptune.
@@ -518,9 +1152,9 @@ pub fn uranus(jde: f64) -> RectangularCoordinates {
 ///
 /// # Example
 ///
-/// Given a date in [*JD*](http://aa.usno.navy.mil/data/docs/JulianDate.php), we can get the
-/// position of the planet Neptune in the solar system using rectangular coordinates. In this case,
-/// we calculate where Neptune was in December 19th, 1199.
+/// Given a date in [*JD*](crate::JulianDay), we can get the position of the planet Neptune in the
+/// solar system using rectangular coordinates. In this case, we calculate where Neptune was in
+/// December 19th, 1199.
 ///
 /// ```
 /// use vsop87::vsop87e;
@@ -531,34 +1165,606 @@ pub fn uranus(jde: f64) -> RectangularCoordinates {
 /// assert!(coordinates.y > -2.0298541973 && coordinates.y < -2.0298541971);
 /// assert!(coordinates.z > -0.6440972 && coordinates.z < -0.6440932);
 /// ```
+#[cfg(feature = "neptune")]
+#[must_use]
+pub fn neptune(jde: impl Into<JulianDay>) -> RectangularCoordinates {
+    rectangular(calculate_body(jde.into(), &NEPTUNE))
+}
+
+/// Calculates VSOP87E solution for Neptune, along with its velocity.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the planet Neptune, and its velocity, by differentiating each term of
+/// the series. The parameter needed is the Julian Day (*JD*) for the given date. It returns a
+/// `StateVector` structure, with the position of the planet in *AU*, as returned by [`neptune()`],
+/// and its velocity in *AU* per day.
+///
+/// # Example
+///
+/// ```
+/// use vsop87::vsop87e;
+///
+/// let state = vsop87e::neptune_state(2159345.0);
+///
+/// assert!(state.position.x > 29.8297729718 && state.position.x < 29.8297729720);
+/// assert!(state.position.y > -2.0298541973 && state.position.y < -2.0298541971);
+/// assert!(state.position.z > -0.6440972 && state.position.z < -0.6440932);
+/// assert!(state.velocity.x > 0.000191695963 && state.velocity.x < 0.000191695964);
+/// ```
+#[cfg(feature = "neptune")]
+#[must_use]
+pub fn neptune_state(jde: impl Into<JulianDay>) -> StateVector {
+    calculate_state(jde.into(), &NEPTUNE)
+}
+
+/// Calculates VSOP87E solution for Neptune, checking the Julian Day.
+///
+/// This function is equivalent to [`neptune()`], but it returns an error if the Julian Day is not
+/// finite, or if it is outside of the validity span of the solution for Neptune (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// assert_eq!(vsop87e::neptune_checked(2451545.0)?, vsop87e::neptune(2451545.0));
+/// assert_eq!(
+///     vsop87e::neptune_checked(0.0),
+///     Err(Error::OutOfRange { body: Body::Neptune })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "neptune")]
+pub fn neptune_checked(jde: impl Into<JulianDay>) -> Result<RectangularCoordinates, Error> {
+    checked(Body::Neptune, jde)
+}
+
+/// Calculates VSOP87E solution for Neptune, along with its velocity, checking the Julian Day.
+///
+/// This function is equivalent to [`neptune_state()`], but it returns an error if the Julian Day is
+/// not finite, or if it is outside of the validity span of the solution for Neptune (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Error};
+///
+/// assert_eq!(vsop87e::neptune_state_checked(2451545.0)?, vsop87e::neptune_state(2451545.0));
+/// assert_eq!(vsop87e::neptune_state_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "neptune")]
+pub fn neptune_state_checked(jde: impl Into<JulianDay>) -> Result<StateVector, Error> {
+    checked_state(Body::Neptune, jde)
+}
+
+/// Calculates VSOP87E solution for all its bodies at the same Julian Day.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for all the bodies available in VSOP87E, at the given Julian Day (*JD*). It
+/// returns the rectangular coordinates of each body, in the order of [`BODIES`]. It is equivalent
+/// to calling the function of each body, but the time is only calculated once.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87e, Body};
+///
+/// let bodies = vsop87e::all_bodies(2451545.0);
+///
+/// assert_eq!(vsop87e::BODIES[5], Body::Jupiter);
+/// let jupiter = vsop87e::jupiter(2451545.0);
+/// assert_eq!(bodies[5], jupiter);
+/// # }
+/// ```
 #[must_use]
-pub fn neptune(jde: f64) -> RectangularCoordinates {
-    let t = calculate_t(jde);
-
-    let x0 = calculate_var(t, &neptune::X0[0], &neptune::X0[1], &neptune::X0[2]);
-    let x1 = calculate_var(t, &neptune::X1[0], &neptune::X1[1], &neptune::X1[2]);
-    let x2 = calculate_var(t, &neptune::X2[0], &neptune::X2[1], &neptune::X2[2]);
-    let x3 = calculate_var(t, &neptune::X3[0], &neptune::X3[1], &neptune::X3[2]);
-    let x4 = calculate_var(t, &neptune::X4[0], &neptune::X4[1], &neptune::X4[2]);
-
-    let y0 = calculate_var(t, &neptune::Y0[0], &neptune::Y0[1], &neptune::Y0[2]);
-    let y1 = calculate_var(t, &neptune::Y1[0], &neptune::Y1[1], &neptune::Y1[2]);
-    let y2 = calculate_var(t, &neptune::Y2[0], &neptune::Y2[1], &neptune::Y2[2]);
-    let y3 = calculate_var(t, &neptune::Y3[0], &neptune::Y3[1], &neptune::Y3[2]);
-    let y4 = calculate_var(t, &neptune::Y4[0], &neptune::Y4[1], &neptune::Y4[2]);
-
-    let z0 = calculate_var(t, &neptune::Z0[0], &neptune::Z0[1], &neptune::Z0[2]);
-    let z1 = calculate_var(t, &neptune::Z1[0], &neptune::Z1[1], &neptune::Z1[2]);
-    let z2 = calculate_var(t, &neptune::Z2[0], &neptune::Z2[1], &neptune::Z2[2]);
-
-    // We calculate the `t` potencies beforehand for easy re-use.
-    let t2 = t * t;
-    let t3 = t2 * t;
-    let t4 = t2 * t2;
-
-    let x = x0 + x1 * t + x2 * t2 + x3 * t3 + x4 * t4;
-    let y = y0 + y1 * t + y2 * t2 + y3 * t3 + y4 * t4;
-    let z = z0 + z1 * t + z2 * t2;
-
-    RectangularCoordinates { x, y, z }
+pub fn all_bodies(jde: impl Into<JulianDay>) -> [RectangularCoordinates; BODIES.len()] {
+    calculate_all(jde.into(), &ALL_BODIES).map(rectangular)
+}
+
+/// Calculates VSOP87E solution for all its bodies at the same Julian Day, in parallel.
+///
+/// This function is equivalent to [`all_bodies()`], but each body is calculated in parallel, using
+/// the global [`rayon`] thread pool. It is only available with the `rayon` feature.
+#[cfg(feature = "rayon")]
+#[must_use]
+pub fn all_bodies_parallel(jde: impl Into<JulianDay>) -> [RectangularCoordinates; BODIES.len()] {
+    calculate_all_parallel(jde.into(), &ALL_BODIES).map(rectangular)
+}
+
+/// Calculates VSOP87E solution for the given body, with the given precision.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the given body, skipping the terms of the series that are not needed
+/// for the given `Precision`. The parameters needed are the body, the Julian Day (*JD*) for the
+/// given date and the precision. It returns the rectangular coordinates, along with the amplitude
+/// thresholds used for each of them, or an error if the body is not available in VSOP87E.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "sun")]
+/// # {
+/// use vsop87::{vsop87e, Body, Precision};
+///
+/// let coordinates = vsop87e::sun(2451545.0);
+/// let truncated =
+///     vsop87e::truncated(Body::Sun, 2451545.0, Precision::Arcseconds(1.0)).unwrap();
+///
+/// assert!((truncated.value.x - coordinates.x).abs() < 0.000001);
+/// assert!((truncated.value.y - coordinates.y).abs() < 0.000001);
+/// assert!((truncated.value.z - coordinates.z).abs() < 0.000001);
+/// assert!(truncated.thresholds.x > 0.0);
+/// # }
+/// ```
+pub fn truncated(
+    body: Body,
+    jde: impl Into<JulianDay>,
+    precision: Precision,
+) -> Result<Truncated<RectangularCoordinates>, Error> {
+    let ([x, y, z], [x_threshold, y_threshold, z_threshold]) = calculate_truncated(
+        jde.into(),
+        body,
+        body_series(body)?,
+        [Variable::Distance; 3],
+        precision,
+    );
+
+    Ok(Truncated {
+        value: rectangular([x, y, z]),
+        thresholds: RectangularCoordinates {
+            x: x_threshold,
+            y: y_threshold,
+            z: z_threshold,
+        },
+    })
+}
+
+/// Calculates VSOP87E solution for the given body at many Julian Days.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the given body at each of the given Julian Days (*JD*), writing the
+/// rectangular coordinates in the output slice, in the same order. It is equivalent to calling the
+/// function of the body for each Julian Day, but much faster for big batches, since each term of
+/// the series is calculated for many Julian Days at the same time.
+///
+/// It returns an error if the body is not available in VSOP87E, or if the output does not have the
+/// same length as the Julian Days.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87e, Body, RectangularCoordinates};
+///
+/// // Calculate the position of Jupiter every 30 days.
+/// let jdes = [2451545.0, 2451575.0, 2451605.0];
+/// let mut output = [RectangularCoordinates::default(); 3];
+/// vsop87e::batch(Body::Jupiter, &jdes, &mut output).unwrap();
+///
+/// for (&jde, coordinates) in jdes.iter().zip(&output) {
+///     let expected = vsop87e::jupiter(jde);
+///     assert!((coordinates.x - expected.x).abs() < 0.000000001);
+///     assert!((coordinates.y - expected.y).abs() < 0.000000001);
+///     assert!((coordinates.z - expected.z).abs() < 0.000000001);
+/// }
+/// # }
+/// ```
+pub fn batch(body: Body, jdes: &[f64], output: &mut [RectangularCoordinates]) -> Result<(), Error> {
+    calculate_batch(jdes, body_series(body)?, output, rectangular)
+}
+
+/// Calculates VSOP87E solution for the given body at many two-part Julian Days.
+///
+/// This function is equivalent to [`batch()`], but it takes [`JulianDay`]s instead of `f64` values,
+/// so that the time variable of each of them is calculated without losing precision. See the
+/// [precision of Julian Days](JulianDay#precision).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87e, Body, JulianDay, RectangularCoordinates};
+///
+/// // Calculate the rectangular coordinates of Jupiter every 6 hours.
+/// let jdes = [0.0, 0.25, 0.5, 0.75].map(|fraction| JulianDay::from_parts(2451545.0, fraction));
+/// let mut output = [RectangularCoordinates::default(); 4];
+/// vsop87e::batch_julian_days(Body::Jupiter, &jdes, &mut output)?;
+///
+/// let mut expected = [RectangularCoordinates::default(); 4];
+/// vsop87e::batch(Body::Jupiter, &[2451545.0, 2451545.25, 2451545.5, 2451545.75], &mut expected)?;
+/// assert_eq!(output, expected);
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+pub fn batch_julian_days(
+    body: Body,
+    jdes: &[JulianDay],
+    output: &mut [RectangularCoordinates],
+) -> Result<(), Error> {
+    calculate_batch(jdes, body_series(body)?, output, rectangular)
+}
+
+/// Calculates VSOP87E solution for the given body, checking the Julian Day.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the given body at the given Julian Day (*JD*), in the same way as the
+/// function of the body.
+///
+/// The functions of the bodies also have checked variants of their own, such as
+/// `vsop87e::saturn_checked()`, which call this function with the body as a parameter.
+///
+/// It returns an error if the body is not available in VSOP87E, if the Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// let checked = vsop87e::checked(Body::Jupiter, 2451545.0).unwrap();
+/// assert_eq!(checked, vsop87e::jupiter(2451545.0));
+///
+/// assert_eq!(
+///     vsop87e::checked(Body::Jupiter, 0.0),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// assert_eq!(vsop87e::checked(Body::Jupiter, f64::NAN), Err(Error::NonFinite));
+/// # }
+/// ```
+pub fn checked(body: Body, jde: impl Into<JulianDay>) -> Result<RectangularCoordinates, Error> {
+    let jde = jde.into();
+
+    let series = body_series(body)?;
+    check_validity(body, jde)?;
+
+    Ok(rectangular(calculate_body(jde, series)))
+}
+
+/// Calculates VSOP87E solution and its rates for the given body, checking the Julian Day.
+///
+/// This function calculates the VSOP87E solution (barycentric ecliptic rectangular coordinates for
+/// the equinox J2000.0) for the given body at the given Julian Day (*JD*), along with its rates, in
+/// the same way as the state function of the body.
+///
+/// It returns an error if the body is not available in VSOP87E, if the Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// let checked = vsop87e::checked_state(Body::Jupiter, 2451545.0).unwrap();
+/// assert_eq!(checked, vsop87e::jupiter_state(2451545.0));
+///
+/// assert!(vsop87e::checked_state(Body::Jupiter, f64::INFINITY).is_err());
+/// # }
+/// ```
+pub fn checked_state(body: Body, jde: impl Into<JulianDay>) -> Result<StateVector, Error> {
+    let jde = jde.into();
+
+    let series = body_series(body)?;
+    check_validity(body, jde)?;
+
+    Ok(calculate_state(jde, series))
+}
+
+/// Calculates VSOP87E solution for the given body, tagged with its frame and its center.
+///
+/// This function is equivalent to [`checked()`], but the coordinates are tagged as
+/// [`EclipticJ2000`] and [`Barycentric`], so that they cannot be mixed with coordinates in other
+/// frames or relative to other centers. See the [`typed`](crate::typed) module for more
+/// information.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87e, Body};
+///
+/// let jupiter = vsop87e::typed(Body::Jupiter, 2451545.0)?;
+/// assert_eq!(jupiter.coordinates(), vsop87e::jupiter(2451545.0));
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+pub fn typed(
+    body: Body,
+    jde: impl Into<JulianDay>,
+) -> Result<Position<EclipticJ2000, Barycentric>, Error> {
+    checked(body, jde).map(Position::new)
+}
+
+/// Calculates VSOP87E solution and its rates for the given body, tagged with its frame and its
+/// center.
+///
+/// This function is equivalent to [`checked_state()`], but the position and the velocity are
+/// tagged as [`EclipticJ2000`] and [`Barycentric`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87e, Body};
+///
+/// let jupiter = vsop87e::typed_state(Body::Jupiter, 2451545.0)?;
+/// assert_eq!(jupiter.state_vector(), vsop87e::jupiter_state(2451545.0));
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+pub fn typed_state(
+    body: Body,
+    jde: impl Into<JulianDay>,
+) -> Result<State<EclipticJ2000, Barycentric>, Error> {
+    checked_state(body, jde).map(State::new)
+}
+
+/// Calculates VSOP87E solution for all its bodies, checking the Julian Day.
+///
+/// This function is equivalent to [`all_bodies()`], but it checks that the Julian Day is valid for
+/// all the bodies.
+///
+/// It returns an error if the Julian Day is not finite, or if it is outside of the validity span of
+/// the solution for any of the bodies (see [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "sun")]
+/// # {
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// let bodies = vsop87e::all_bodies_checked(2451545.0).unwrap();
+/// assert_eq!(bodies, vsop87e::all_bodies(2451545.0));
+///
+/// // Valid for the planets, but not for the Sun.
+/// assert_eq!(
+///     vsop87e::all_bodies_checked(1500000.0),
+///     Err(Error::OutOfRange { body: Body::Sun })
+/// );
+/// # }
+/// ```
+pub fn all_bodies_checked(
+    jde: impl Into<JulianDay>,
+) -> Result<[RectangularCoordinates; BODIES.len()], Error> {
+    let jde = jde.into();
+
+    for &body in BODIES {
+        check_validity(body, jde)?;
+    }
+
+    Ok(all_bodies(jde))
+}
+
+/// Calculates VSOP87E solution for all its bodies in parallel, checking the Julian Day.
+///
+/// This function is equivalent to [`all_bodies_parallel()`], but it checks that the Julian Day is
+/// valid for all the bodies.
+///
+/// It returns an error if the Julian Day is not finite, or if it is outside of the validity span of
+/// the solution for any of the bodies (see [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// use vsop87::{vsop87e, Error};
+///
+/// let bodies = vsop87e::all_bodies_parallel_checked(2451545.0)?;
+/// assert_eq!(bodies, vsop87e::all_bodies_parallel(2451545.0));
+///
+/// assert_eq!(vsop87e::all_bodies_parallel_checked(f64::NAN), Err(Error::NonFinite));
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+#[cfg(feature = "rayon")]
+pub fn all_bodies_parallel_checked(
+    jde: impl Into<JulianDay>,
+) -> Result<[RectangularCoordinates; BODIES.len()], Error> {
+    let jde = jde.into();
+
+    for &body in BODIES {
+        check_validity(body, jde)?;
+    }
+
+    Ok(all_bodies_parallel(jde))
+}
+
+/// Calculates VSOP87E solution with the given precision, checking the Julian Day.
+///
+/// This function is equivalent to [`truncated()`], but it checks that the Julian Day is valid for
+/// the body.
+///
+/// It returns an error if the body is not available in VSOP87E, if the Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// # use vsop87::Precision;
+/// let checked = vsop87e::truncated_checked(Body::Jupiter, 2451545.0, Precision::Arcseconds(1.0));
+/// assert_eq!(checked, vsop87e::truncated(Body::Jupiter, 2451545.0, Precision::Arcseconds(1.0)));
+///
+/// assert_eq!(
+///     vsop87e::truncated_checked(Body::Jupiter, 0.0, Precision::Full),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// # }
+/// ```
+pub fn truncated_checked(
+    body: Body,
+    jde: impl Into<JulianDay>,
+    precision: Precision,
+) -> Result<Truncated<RectangularCoordinates>, Error> {
+    let jde = jde.into();
+
+    ensure_supported(body)?;
+    check_validity(body, jde)?;
+
+    truncated(body, jde, precision)
+}
+
+/// Calculates VSOP87E solution at many Julian Days, checking the Julian Days.
+///
+/// This function is equivalent to [`batch()`], but it checks that all the Julian Days are valid for
+/// the body before calculating any of them.
+///
+/// It returns an error if the body is not available in VSOP87E, if the Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87e, Body, Error};
+///
+/// # use vsop87::RectangularCoordinates;
+/// let mut output = [RectangularCoordinates::default(); 2];
+/// vsop87e::batch_checked(Body::Jupiter, &[2451545.0, 2451546.0], &mut output).unwrap();
+///
+/// assert_eq!(
+///     vsop87e::batch_checked(Body::Jupiter, &[2451545.0, f64::NAN], &mut output),
+///     Err(Error::NonFinite)
+/// );
+/// # }
+/// ```
+pub fn batch_checked(
+    body: Body,
+    jdes: &[f64],
+    output: &mut [RectangularCoordinates],
+) -> Result<(), Error> {
+    ensure_supported(body)?;
+    for &jde in jdes {
+        check_validity(body, jde.into())?;
+    }
+
+    batch(body, jdes, output)
+}
+
+/// Calculates VSOP87E solution at many two-part Julian Days, checking the Julian Days.
+///
+/// This function is equivalent to [`batch_julian_days()`], but it checks that all the Julian Days
+/// are valid for the body before calculating any of them.
+///
+/// It returns an error if the body is not available in VSOP87E, if a Julian Day is not finite, or
+/// if it is outside of the validity span of the solution for the body (see
+/// [`VALIDITY`](crate::VALIDITY)).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "jupiter")]
+/// # {
+/// use vsop87::{vsop87e, Body, Error, JulianDay, RectangularCoordinates};
+///
+/// let jdes = [JulianDay::from_parts(2451545.0, 0.25), JulianDay::new(2451546.0)];
+/// let mut output = [RectangularCoordinates::default(); 2];
+/// vsop87e::batch_julian_days_checked(Body::Jupiter, &jdes, &mut output)?;
+///
+/// let jdes = [JulianDay::new(2451545.0), JulianDay::new(0.0)];
+/// assert_eq!(
+///     vsop87e::batch_julian_days_checked(Body::Jupiter, &jdes, &mut output),
+///     Err(Error::OutOfRange { body: Body::Jupiter })
+/// );
+/// # }
+/// # Ok::<(), vsop87::Error>(())
+/// ```
+pub fn batch_julian_days_checked(
+    body: Body,
+    jdes: &[JulianDay],
+    output: &mut [RectangularCoordinates],
+) -> Result<(), Error> {
+    ensure_supported(body)?;
+    for &jde in jdes {
+        check_validity(body, jde)?;
+    }
+
+    batch_julian_days(body, jdes, output)
+}
+
+/// Gets the series of the given body.
+// Bodies can be disabled with features, so the wildcard can match more than one body.
+#[allow(clippy::match_wildcard_for_single_variants)]
+/// Checks that the given body is available in VSOP87E.
+fn ensure_supported(body: Body) -> Result<(), Error> {
+    body_series(body).map(|_| ())
+}
+
+fn body_series(body: Body) -> Result<&'static [&'static [Series]; 3], Error> {
+    match body {
+        #[cfg(feature = "sun")]
+        Body::Sun => Ok(&SUN),
+        #[cfg(feature = "mercury")]
+        Body::Mercury => Ok(&MERCURY),
+        #[cfg(feature = "venus")]
+        Body::Venus => Ok(&VENUS),
+        #[cfg(feature = "earth")]
+        Body::Earth => Ok(&EARTH),
+        #[cfg(feature = "mars")]
+        Body::Mars => Ok(&MARS),
+        #[cfg(feature = "jupiter")]
+        Body::Jupiter => Ok(&JUPITER),
+        #[cfg(feature = "saturn")]
+        Body::Saturn => Ok(&SATURN),
+        #[cfg(feature = "uranus")]
+        Body::Uranus => Ok(&URANUS),
+        #[cfg(feature = "neptune")]
+        Body::Neptune => Ok(&NEPTUNE),
+        _ => Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87E,
+            body,
+        }),
+    }
+}
+
+/// *VSOP87E* solution, as an [`Ephemeris`].
+///
+/// Its positions are the barycentric ecliptic rectangular coordinates for the equinox J2000.0, in
+/// [`Coordinates::Rectangular`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct VSOP87E;
+
+impl Ephemeris for VSOP87E {
+    fn position(&self, body: Body, jde: f64) -> Result<Coordinates, Error> {
+        Solution::VSOP87E.position(body, jde)
+    }
+
+    fn position_at(&self, body: Body, jde: JulianDay) -> Result<Coordinates, Error> {
+        Solution::VSOP87E.position_at(body, jde)
+    }
+
+    fn frame(&self) -> Frame {
+        Solution::VSOP87E.frame()
+    }
+
+    fn center(&self) -> Center {
+        Solution::VSOP87E.center()
+    }
+
+    fn bodies(&self) -> &[Body] {
+        Solution::VSOP87E.bodies()
+    }
+
+    fn validity(&self, body: Body) -> Option<Validity> {
+        Ephemeris::validity(&Solution::VSOP87E, body)
+    }
 }
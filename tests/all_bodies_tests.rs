@@ -0,0 +1,67 @@
+use vsop87::*;
+
+#[test]
+fn it_all_bodies() {
+    let jde = 2415020.0;
+
+    for (solution, bodies) in [
+        (Solution::VSOP87, vsop87::BODIES),
+        (Solution::VSOP87A, vsop87a::BODIES),
+        (Solution::VSOP87B, vsop87b::BODIES),
+        (Solution::VSOP87C, vsop87c::BODIES),
+        (Solution::VSOP87D, vsop87d::BODIES),
+        (Solution::VSOP87E, vsop87e::BODIES),
+    ] {
+        assert_eq!(solution.bodies(), bodies);
+    }
+
+    for (body, elements) in vsop87::BODIES.iter().zip(vsop87::all_bodies(jde)) {
+        assert_eq!(
+            calculate(Solution::VSOP87, *body, jde),
+            Ok(Coordinates::Elements(elements))
+        );
+    }
+    for (body, coordinates) in vsop87a::BODIES.iter().zip(vsop87a::all_bodies(jde)) {
+        assert_eq!(
+            calculate(Solution::VSOP87A, *body, jde),
+            Ok(Coordinates::Rectangular(coordinates))
+        );
+    }
+    for (body, coordinates) in vsop87b::BODIES.iter().zip(vsop87b::all_bodies(jde)) {
+        assert_eq!(
+            calculate(Solution::VSOP87B, *body, jde),
+            Ok(Coordinates::Spherical(coordinates))
+        );
+    }
+    for (body, coordinates) in vsop87c::BODIES.iter().zip(vsop87c::all_bodies(jde)) {
+        assert_eq!(
+            calculate(Solution::VSOP87C, *body, jde),
+            Ok(Coordinates::Rectangular(coordinates))
+        );
+    }
+    for (body, coordinates) in vsop87d::BODIES.iter().zip(vsop87d::all_bodies(jde)) {
+        assert_eq!(
+            calculate(Solution::VSOP87D, *body, jde),
+            Ok(Coordinates::Spherical(coordinates))
+        );
+    }
+    for (body, coordinates) in vsop87e::BODIES.iter().zip(vsop87e::all_bodies(jde)) {
+        assert_eq!(
+            calculate(Solution::VSOP87E, *body, jde),
+            Ok(Coordinates::Rectangular(coordinates))
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn it_all_bodies_parallel() {
+    let jde = 2451545.0;
+
+    assert_eq!(vsop87::all_bodies_parallel(jde), vsop87::all_bodies(jde));
+    assert_eq!(vsop87a::all_bodies_parallel(jde), vsop87a::all_bodies(jde));
+    assert_eq!(vsop87b::all_bodies_parallel(jde), vsop87b::all_bodies(jde));
+    assert_eq!(vsop87c::all_bodies_parallel(jde), vsop87c::all_bodies(jde));
+    assert_eq!(vsop87d::all_bodies_parallel(jde), vsop87d::all_bodies(jde));
+    assert_eq!(vsop87e::all_bodies_parallel(jde), vsop87e::all_bodies(jde));
+}
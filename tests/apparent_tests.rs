@@ -0,0 +1,161 @@
+mod common;
+
+use common::{ARCSECOND, SPEED_OF_LIGHT};
+use vsop87::*;
+
+/// Calculates the angle between two directions, in radians.
+fn separation(a: SphericalCoordinates, b: SphericalCoordinates) -> f64 {
+    let (a, b) = (
+        RectangularCoordinates::from(a),
+        RectangularCoordinates::from(b),
+    );
+    let cross = (
+        a.y * b.z - a.z * b.y,
+        a.z * b.x - a.x * b.z,
+        a.x * b.y - a.y * b.x,
+    );
+    let sine = (cross.0.powi(2) + cross.1.powi(2) + cross.2.powi(2)).sqrt();
+
+    sine.atan2(a.x * b.x + a.y * b.y + a.z * b.z)
+}
+
+#[test]
+fn it_meeus_examples() {
+    // Example 33.a of Meeus, Astronomical Algorithms: Venus in 1992 December 20.0 TD, and example
+    // 25.b: the Sun in 1992 October 13.0 TD, as hours, minutes and seconds, and degrees, minutes
+    // and seconds.
+    let examples = [
+        (
+            Body::Venus,
+            2448976.5,
+            (21_f64, 4.0, 41.454),
+            (-18_f64, -53.0, -16.84),
+        ),
+        (
+            Body::Sun,
+            2448908.5,
+            (13.0, 13.0, 30.749),
+            (-7.0, -47.0, -1.74),
+        ),
+    ];
+
+    for (body, tt, (h, m, s), (d, am, asec)) in examples {
+        let expected_ra = ((h + m / 60.0 + s / 3_600.0) * 15.0).to_radians();
+        let expected_dec = (d + am / 60.0 + asec / 3_600.0).to_radians();
+
+        for nutation in [Nutation::Iau1980, Nutation::Iau2000B] {
+            let apparent = geocentric_place(body, tt, nutation)
+                .unwrap()
+                .apparent()
+                .equatorial();
+
+            assert!((apparent.longitude() - expected_ra).abs() < 0.2 * ARCSECOND);
+            assert!((apparent.latitude() - expected_dec).abs() < 0.2 * ARCSECOND);
+        }
+    }
+}
+
+#[test]
+fn it_apparent_longitude_of_the_sun() {
+    // The apparent longitude of the Sun is its geometric longitude of the date, in the FK5 frame,
+    // plus the nutation in longitude and the aberration, of −20.4898″ / R. The equinox of the FK5
+    // frame and the origin of the ICRS differ by 0.09″.
+    for tt in [2415020.0, 2448908.5, 2460000.5, 2469807.5] {
+        let earth = vsop87c::earth(tt);
+        let sun = RectangularCoordinates {
+            x: -earth.x,
+            y: -earth.y,
+            z: -earth.z,
+        };
+        let geometric = SphericalCoordinates::from(
+            Nutation::Iau1980.ecliptic(tt) * Rotation::vsop87_of_date_to_fk5(tt) * sun,
+        );
+        let expected = geometric.longitude() - 20.489_8 * ARCSECOND / geometric.distance();
+
+        let apparent = geocentric_place(Body::Sun, tt, Nutation::Iau1980)
+            .unwrap()
+            .apparent()
+            .ecliptic();
+        assert!((apparent.longitude() - expected).abs() < 0.15 * ARCSECOND);
+        assert!((apparent.latitude() - geometric.latitude()).abs() < 0.05 * ARCSECOND);
+    }
+}
+
+#[test]
+fn it_light_time() {
+    let tt = 2460000.5;
+
+    for body in [Body::Sun, Body::Mercury, Body::Mars, Body::Neptune] {
+        let place = geocentric_place(body, tt, Nutation::Iau2000B).unwrap();
+        let astrometric = place.astrometric().equatorial();
+        let apparent = place.apparent().equatorial();
+
+        // The light travels the distance of the astrometric and apparent places.
+        assert!((place.light_time() * SPEED_OF_LIGHT - astrometric.distance()).abs() < 1e-9);
+        assert!((apparent.distance() - astrometric.distance()).abs() < 1e-12);
+
+        // The astrometric place is the geometric place of the body at the time of emission.
+        let earth = vsop87e::earth(tt);
+        let emitted = vsop87e::checked(body, tt - place.light_time()).unwrap();
+        let expected = SphericalCoordinates::from(RectangularCoordinates {
+            x: emitted.x - earth.x,
+            y: emitted.y - earth.y,
+            z: emitted.z - earth.z,
+        });
+        let ecliptic = place.astrometric().ecliptic();
+        assert!((ecliptic.longitude() - expected.longitude()).abs() < 1e-6 * ARCSECOND);
+        assert!((ecliptic.latitude() - expected.latitude()).abs() < 1e-6 * ARCSECOND);
+    }
+
+    // The Sun is 8.3 light-minutes away.
+    let sun = geocentric_place(Body::Sun, tt, Nutation::Iau2000B).unwrap();
+    assert!((sun.light_time() * 1_440.0 - 8.3).abs() < 0.1);
+}
+
+#[test]
+fn it_frames() {
+    let tt = 2451545.0;
+
+    for body in [Body::Mercury, Body::Venus, Body::Jupiter, Body::Saturn] {
+        let place = geocentric_place(body, tt, Nutation::Iau2000B).unwrap();
+
+        // The equatorial and ecliptic coordinates of the geometric place are the same position.
+        let geometric = place.geometric();
+        let rotated = geometric.ecliptic().to_icrs();
+        assert!(separation(rotated, geometric.equatorial()) < 1e-6 * ARCSECOND);
+
+        // Aberration displaces the planets by up to 20.5″ plus the light-time correction, and
+        // precession and nutation from J2000.0 are below 20″.
+        let displacement = separation(
+            place.astrometric().equatorial(),
+            place.apparent().equatorial(),
+        );
+        assert!(displacement < 60.0 * ARCSECOND);
+
+        let true_equator = place.apparent().equatorial();
+        let from_ecliptic = SphericalCoordinates::from(
+            Rotation::ecliptic_to_equatorial(Nutation::Iau2000B.true_obliquity(tt))
+                * RectangularCoordinates::from(place.apparent().ecliptic()),
+        );
+        assert!(separation(true_equator, from_ecliptic) < 1e-6 * ARCSECOND);
+    }
+}
+
+#[test]
+fn it_errors() {
+    assert_eq!(
+        geocentric_place(Body::Earth, 2451545.0, Nutation::Iau2000B),
+        Err(Error::ObserverBody)
+    );
+    assert_eq!(
+        geocentric_place(Body::EarthMoon, 2451545.0, Nutation::Iau2000B),
+        Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87E,
+            body: Body::EarthMoon,
+        })
+    );
+    assert_eq!(
+        geocentric_place(Body::Mars, f64::NAN, Nutation::Iau2000B),
+        Err(Error::NonFinite)
+    );
+}
@@ -0,0 +1,109 @@
+use vsop87::*;
+
+/// Julian Days every 10 days and a half, with a length that is not a multiple of the batch size
+/// or of the vector size.
+fn jdes() -> Vec<f64> {
+    (0..203).map(|i| 2305447.5 + f64::from(i) * 10.5).collect()
+}
+
+#[test]
+fn it_batch_vsop87() {
+    let jdes = jdes();
+    let mut output = vec![VSOP87Elements::default(); jdes.len()];
+
+    for (body, function) in [
+        (Body::Mercury, vsop87::mercury as fn(f64) -> VSOP87Elements),
+        (Body::EarthMoon, vsop87::earth_moon),
+        (Body::Neptune, vsop87::neptune),
+    ] {
+        vsop87::batch(body, &jdes, &mut output).unwrap();
+
+        for (&jde, elements) in jdes.iter().zip(&output) {
+            let expected = function(jde);
+            assert!((elements.a - expected.a).abs() < 1e-12);
+            assert!((elements.l - expected.l).abs() < 1e-10);
+            assert!((elements.k - expected.k).abs() < 1e-12);
+            assert!((elements.h - expected.h).abs() < 1e-12);
+            assert!((elements.q - expected.q).abs() < 1e-12);
+            assert!((elements.p - expected.p).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn it_batch_rectangular() {
+    let jdes = jdes();
+    let mut output = vec![RectangularCoordinates::default(); jdes.len()];
+
+    for (batch, body, function) in [
+        (
+            vsop87a::batch as fn(Body, &[f64], &mut [RectangularCoordinates]) -> Result<(), Error>,
+            Body::Venus,
+            vsop87a::venus as fn(f64) -> RectangularCoordinates,
+        ),
+        (vsop87a::batch, Body::EarthMoon, vsop87a::earth_moon),
+        (vsop87c::batch, Body::Jupiter, vsop87c::jupiter),
+        (vsop87e::batch, Body::Sun, vsop87e::sun),
+        (vsop87e::batch, Body::Uranus, vsop87e::uranus),
+    ] {
+        batch(body, &jdes, &mut output).unwrap();
+
+        for (&jde, coordinates) in jdes.iter().zip(&output) {
+            let expected = function(jde);
+            assert!((coordinates.x - expected.x).abs() < 1e-12);
+            assert!((coordinates.y - expected.y).abs() < 1e-12);
+            assert!((coordinates.z - expected.z).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn it_batch_spherical() {
+    let jdes = jdes();
+    let mut output = vec![SphericalCoordinates::default(); jdes.len()];
+
+    for (batch, body, function) in [
+        (
+            vsop87b::batch as fn(Body, &[f64], &mut [SphericalCoordinates]) -> Result<(), Error>,
+            Body::Earth,
+            vsop87b::earth as fn(f64) -> SphericalCoordinates,
+        ),
+        (vsop87d::batch, Body::Mars, vsop87d::mars),
+        (vsop87d::batch, Body::Saturn, vsop87d::saturn),
+    ] {
+        batch(body, &jdes, &mut output).unwrap();
+
+        for (&jde, coordinates) in jdes.iter().zip(&output) {
+            let expected = function(jde);
+            assert!((coordinates.longitude() - expected.longitude()).abs() < 1e-10);
+            assert!((coordinates.latitude() - expected.latitude()).abs() < 1e-12);
+            assert!((coordinates.distance() - expected.distance()).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn it_batch_empty() {
+    let mut output: [RectangularCoordinates; 0] = [];
+    assert_eq!(vsop87a::batch(Body::Earth, &[], &mut output), Ok(()));
+}
+
+#[test]
+fn it_batch_errors() {
+    let jdes = [2451545.0, 2451546.0];
+
+    let mut output = [RectangularCoordinates::default(); 3];
+    assert_eq!(
+        vsop87a::batch(Body::Earth, &jdes, &mut output),
+        Err(Error::OutputLength)
+    );
+
+    let mut output = [SphericalCoordinates::default(); 2];
+    assert_eq!(
+        vsop87d::batch(Body::EarthMoon, &jdes, &mut output),
+        Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87D,
+            body: Body::EarthMoon,
+        })
+    );
+}
@@ -0,0 +1,39 @@
+//! Constants and helpers shared by the integration tests.
+
+// Each test crate only uses some of the helpers.
+#![allow(dead_code)]
+
+use vsop87::{JulianDay, RectangularCoordinates, Rotation};
+
+/// One arcsecond, in radians.
+pub const ARCSECOND: f64 = std::f64::consts::PI / 648_000.0;
+
+/// Speed of light, in AU per day.
+pub const SPEED_OF_LIGHT: f64 = 299_792_458.0 * 86_400.0 / 149_597_870_700.0;
+
+/// Gets the Julian Day of the start of the given Julian year.
+pub fn year(year: i32) -> JulianDay {
+    JulianDay::new(2451545.0 + f64::from(year - 2000) * 365.25)
+}
+
+/// Calculates the distance between two rectangular coordinates.
+pub fn distance(a: RectangularCoordinates, b: RectangularCoordinates) -> f64 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)).sqrt()
+}
+
+/// Calculates the angle between two rectangular coordinates, in radians.
+pub fn angle(a: RectangularCoordinates, b: RectangularCoordinates) -> f64 {
+    let rotation = (a.y * b.z - a.z * b.y).hypot(a.z * b.x - a.x * b.z);
+    rotation
+        .hypot(a.x * b.y - a.y * b.x)
+        .atan2(a.x * b.x + a.y * b.y + a.z * b.z)
+}
+
+/// Checks that the matrix of a rotation matches the expected one.
+pub fn assert_matrix(rotation: Rotation, expected: [[f64; 3]; 3], tolerance: f64) {
+    for (row, expected_row) in rotation.matrix().iter().zip(expected) {
+        for (value, expected) in row.iter().zip(expected_row) {
+            assert!((value - expected).abs() < tolerance, "{value} {expected}");
+        }
+    }
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use common::year;
+use vsop87::*;
+
+/// Values of *ΔT* and their standard errors, in seconds, from Morrison and Stephenson (2004) and
+/// from observations.
+const OBSERVED: [(i32, f64, f64); 31] = [
+    (-500, 17_190.0, 430.0),
+    (-400, 15_530.0, 390.0),
+    (-300, 14_080.0, 360.0),
+    (-200, 12_790.0, 330.0),
+    (-100, 11_640.0, 290.0),
+    (0, 10_580.0, 260.0),
+    (100, 9_600.0, 240.0),
+    (200, 8_640.0, 210.0),
+    (300, 7_680.0, 180.0),
+    (400, 6_700.0, 160.0),
+    (500, 5_710.0, 140.0),
+    (600, 4_740.0, 120.0),
+    (700, 3_810.0, 100.0),
+    (800, 2_960.0, 80.0),
+    (900, 2_200.0, 70.0),
+    (1000, 1_570.0, 55.0),
+    (1100, 1_090.0, 40.0),
+    (1200, 740.0, 30.0),
+    (1300, 490.0, 20.0),
+    (1400, 320.0, 20.0),
+    (1500, 200.0, 20.0),
+    (1600, 120.0, 20.0),
+    (1700, 9.0, 5.0),
+    (1800, 14.0, 1.0),
+    (1900, -3.0, 1.0),
+    (1950, 29.0, 0.1),
+    (1960, 33.1, 0.1),
+    (1970, 40.2, 0.1),
+    (1980, 50.5, 0.1),
+    (1990, 56.9, 0.1),
+    (2000, 63.8, 0.1),
+];
+
+#[test]
+fn it_observed_values() {
+    for (y, delta_t, uncertainty) in OBSERVED {
+        let jd = year(y);
+        assert!((DeltaT::uncertainty(jd) - uncertainty).abs() < 1e-2);
+
+        for model in [DeltaT::EspenakMeeus, DeltaT::MorrisonStephenson] {
+            let error = (model.seconds(jd) - delta_t).abs();
+            assert!(error < uncertainty.max(1.0), "{model:?} {y}: {error}");
+        }
+    }
+
+    // The spline goes through the tabulated values.
+    for (y, delta_t, _) in &OBSERVED[..22] {
+        assert!((DeltaT::MorrisonStephenson.seconds(year(*y)) - delta_t).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn it_continuity() {
+    for boundary in [
+        -500, 500, 1600, 1700, 1800, 1860, 1900, 1920, 1941, 1961, 1986, 2005, 2050, 2150,
+    ] {
+        let jd = year(boundary);
+        for model in [DeltaT::EspenakMeeus, DeltaT::MorrisonStephenson] {
+            let before = model.seconds(jd - 1e-3);
+            let after = model.seconds(jd + 1e-3);
+            assert!((before - after).abs() < 15.0, "{model:?} {boundary}");
+        }
+
+        let before = DeltaT::uncertainty(jd - 1e-3);
+        let after = DeltaT::uncertainty(jd + 1e-3);
+        assert!((before - after).abs() < 1.0, "{boundary}");
+    }
+}
+
+#[test]
+fn it_uncertainty() {
+    assert!((DeltaT::uncertainty(year(-1700)) - 1_000.0).abs() < 20.0);
+    assert!(DeltaT::uncertainty(year(-3000)) > DeltaT::uncertainty(year(-2000)));
+    assert!(DeltaT::uncertainty(year(2100)) > DeltaT::uncertainty(year(2050)));
+    assert!(DeltaT::uncertainty(year(2050)) > DeltaT::uncertainty(year(2020)));
+}
+
+#[test]
+fn it_convert() {
+    for model in [DeltaT::EspenakMeeus, DeltaT::MorrisonStephenson] {
+        for y in [-3000, -585, 0, 1054, 1650, 1912, 2024, 2500] {
+            let ut = year(y);
+            let tt = model.tt(ut);
+            assert!(((tt - ut) * 86400.0 - model.seconds(ut)).abs() < 1e-6);
+            assert!((model.ut(tt) - ut).abs() * 86400.0 < 1e-6);
+        }
+    }
+
+    assert_eq!(DeltaT::default(), DeltaT::EspenakMeeus);
+}
+
+#[test]
+#[cfg(all(feature = "vsop87b", feature = "venus"))]
+fn it_historical_positions() {
+    // Supernova of July 4th, 1054, in the proleptic Julian calendar.
+    let ut = JulianDay::from_julian_calendar(1054, 7, 4).unwrap();
+    let tt = DeltaT::MorrisonStephenson.tt(ut);
+    assert!(((tt - ut) * 86400.0 - 1_300.0).abs() < 100.0);
+
+    let venus = vsop87b::venus(tt);
+    assert!(venus.distance() > 0.7);
+    assert!(calculate_checked(Solution::VSOP87B, Body::Venus, tt).is_ok());
+}
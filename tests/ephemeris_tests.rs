@@ -0,0 +1,111 @@
+use vsop87::*;
+
+/// Ephemeris that always places the bodies in the same position.
+struct StaticEphemeris;
+
+impl Ephemeris for StaticEphemeris {
+    fn position(&self, body: Body, _jde: f64) -> Result<Coordinates, Error> {
+        if self.supports(body) {
+            Ok(Coordinates::Rectangular(RectangularCoordinates {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+            }))
+        } else {
+            Err(Error::UnknownBody)
+        }
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::EclipticJ2000
+    }
+
+    fn center(&self) -> Center {
+        Center::Heliocentric
+    }
+
+    fn bodies(&self) -> &[Body] {
+        &[Body::Earth]
+    }
+}
+
+fn earth_position<E: Ephemeris>(ephemeris: &E, jde: f64) -> Coordinates {
+    ephemeris.position(Body::Earth, jde).unwrap()
+}
+
+#[test]
+fn it_generic() {
+    let jde = 2341970.0;
+
+    assert_eq!(
+        earth_position(&vsop87a::VSOP87A, jde),
+        Coordinates::Rectangular(vsop87a::earth(jde))
+    );
+    assert_eq!(
+        earth_position(&vsop87b::VSOP87B, jde),
+        Coordinates::Spherical(vsop87b::earth(jde))
+    );
+    assert_eq!(
+        earth_position(&vsop87c::VSOP87C, jde),
+        Coordinates::Rectangular(vsop87c::earth(jde))
+    );
+    assert_eq!(
+        earth_position(&vsop87d::VSOP87D, jde),
+        Coordinates::Spherical(vsop87d::earth(jde))
+    );
+    assert_eq!(
+        earth_position(&vsop87e::VSOP87E, jde),
+        Coordinates::Rectangular(vsop87e::earth(jde))
+    );
+    assert_eq!(
+        VSOP87.position(Body::EarthMoon, jde),
+        Ok(Coordinates::Elements(vsop87::earth_moon(jde)))
+    );
+    assert!(earth_position(&StaticEphemeris, jde)
+        .rectangular()
+        .is_some());
+}
+
+#[test]
+fn it_dyn() {
+    let ephemerides: Vec<(Box<dyn Ephemeris>, Solution)> = vec![
+        (Box::new(VSOP87), Solution::VSOP87),
+        (Box::new(vsop87a::VSOP87A), Solution::VSOP87A),
+        (Box::new(vsop87b::VSOP87B), Solution::VSOP87B),
+        (Box::new(vsop87c::VSOP87C), Solution::VSOP87C),
+        (Box::new(vsop87d::VSOP87D), Solution::VSOP87D),
+        (Box::new(vsop87e::VSOP87E), Solution::VSOP87E),
+    ];
+
+    for (ephemeris, solution) in &ephemerides {
+        assert_eq!(ephemeris.bodies(), solution.bodies());
+        assert_eq!(ephemeris.frame(), solution.frame());
+        assert_eq!(ephemeris.center(), solution.center());
+        for body in Body::ALL {
+            assert_eq!(ephemeris.supports(body), solution.supports(body));
+            assert_eq!(
+                ephemeris.position(body, 2451545.0),
+                solution.calculate(body, 2451545.0)
+            );
+        }
+    }
+
+    assert_eq!(ephemerides[3].0.frame(), Frame::EclipticOfDate);
+    assert_eq!(ephemerides[4].0.frame(), Frame::EclipticOfDate);
+    assert_eq!(ephemerides[5].0.center(), Center::Barycentric);
+}
+
+#[test]
+fn it_validity() {
+    let validity = vsop87d::VSOP87D.validity(Body::Saturn).unwrap();
+    assert!(validity.contains(2451545.0));
+    assert!(validity.contains(2122820.0));
+    assert!(!validity.contains(1000000.0));
+
+    let validity = vsop87a::VSOP87A.validity(Body::Neptune).unwrap();
+    assert!(validity.start < 2451545.0 - 6000.0 * 365.0);
+    assert!(validity.end > 2451545.0 + 6000.0 * 365.0);
+
+    assert_eq!(vsop87b::VSOP87B.validity(Body::EarthMoon), None);
+    assert_eq!(StaticEphemeris.validity(Body::Earth), None);
+}
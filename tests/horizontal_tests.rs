@@ -0,0 +1,198 @@
+use vsop87::*;
+
+/// One arcminute, in radians.
+const ARCMINUTE: f64 = std::f64::consts::PI / 10_800.0;
+
+/// Creates the equatorial coordinates of a direction from its right ascension and declination.
+fn equatorial(right_ascension: f64, declination: f64) -> SphericalCoordinates {
+    SphericalCoordinates::from(RectangularCoordinates {
+        x: declination.cos() * right_ascension.cos(),
+        y: declination.cos() * right_ascension.sin(),
+        z: declination.sin(),
+    })
+}
+
+#[test]
+fn it_sofa_examples() {
+    // Test cases of hd2ae and hd2pa of the SOFA library.
+    let horizontal =
+        HorizontalCoordinates::from_equatorial(equatorial(0.0, 1.2), 0.3, 1.1, Refraction::None);
+    assert!((horizontal.azimuth() - 5.916_889_243_730_066).abs() < 1e-13);
+    assert!((horizontal.altitude() - 0.447_218_630_499_049).abs() < 1e-13);
+    assert!((horizontal.hour_angle() - 1.1).abs() < 1e-13);
+
+    assert!((horizontal.parallactic_angle() - 1.906_227_428_001_996).abs() < 1e-13);
+}
+
+#[test]
+fn it_cardinal_points() {
+    let latitude = 0.8;
+
+    // A body on the meridian, south of the zenith, has an azimuth of 180°, an altitude of
+    // 90° − φ + δ and a parallactic angle of zero.
+    let horizontal = HorizontalCoordinates::from_equatorial(
+        equatorial(2.0, 0.1),
+        latitude,
+        2.0,
+        Refraction::None,
+    );
+    assert!((horizontal.azimuth() - std::f64::consts::PI).abs() < 1e-12);
+    assert!((horizontal.altitude() - (std::f64::consts::FRAC_PI_2 - latitude + 0.1)).abs() < 1e-12);
+    assert!(horizontal.parallactic_angle().abs() < 1e-12);
+
+    // The celestial pole is to the north, at the altitude of the latitude.
+    let horizontal = HorizontalCoordinates::from_equatorial(
+        equatorial(0.0, std::f64::consts::FRAC_PI_2),
+        latitude,
+        1.0,
+        Refraction::None,
+    );
+    assert!(horizontal.azimuth() < 1e-9 || horizontal.azimuth() > std::f64::consts::TAU - 1e-9);
+    assert!((horizontal.altitude() - latitude).abs() < 1e-12);
+
+    // A body on the equator, six hours east of the meridian, rises to the east.
+    let horizontal = HorizontalCoordinates::from_equatorial(
+        equatorial(1.0 + std::f64::consts::FRAC_PI_2, 0.0),
+        latitude,
+        1.0,
+        Refraction::None,
+    );
+    assert!((horizontal.azimuth() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    assert!(horizontal.altitude().abs() < 1e-12);
+    assert!(horizontal.parallactic_angle() < 0.0);
+}
+
+#[test]
+fn it_refraction() {
+    for altitude in [-0.5_f64, 0.0, 1.0, 5.0, 15.0, 45.0, 89.0] {
+        let altitude = altitude.to_radians();
+        let bennett = Refraction::Bennett.refraction(altitude);
+        let saemundsson = Refraction::Saemundsson.refraction(altitude);
+
+        // Both formulas are consistent, and the refraction decreases with the altitude.
+        assert!((bennett - saemundsson).abs() < 0.1 * ARCMINUTE);
+        assert!(bennett > 0.0);
+        assert!(bennett < Refraction::Bennett.refraction(altitude - 0.001));
+
+        // The atmospheric model is the formula of Sæmundsson for 1010 hPa and 10 °C.
+        let standard = Refraction::Atmospheric {
+            pressure: 1_010.0,
+            temperature: 10.0,
+        };
+        assert!((standard.refraction(altitude) - saemundsson).abs() < 1e-15);
+        let thin = Refraction::Atmospheric {
+            pressure: 505.0,
+            temperature: 10.0,
+        };
+        assert!((thin.refraction(altitude) - saemundsson / 2.0).abs() < 1e-15);
+    }
+
+    // A body on the apparent horizon is 34′ below the geometric horizon.
+    let apparent = HorizontalCoordinates::from_equatorial(
+        equatorial(0.0, 0.0),
+        0.0,
+        std::f64::consts::FRAC_PI_2 + 34.0 * ARCMINUTE,
+        Refraction::Bennett,
+    );
+    assert!(apparent.altitude().abs() < 0.5 * ARCMINUTE);
+    assert!((apparent.geometric_altitude() + 34.0 * ARCMINUTE).abs() < 1e-12);
+    assert!((apparent.refraction() - 34.0 * ARCMINUTE).abs() < 0.5 * ARCMINUTE);
+
+    // There is no refraction at the zenith, and it is never negative.
+    for refraction in [Refraction::Bennett, Refraction::Saemundsson] {
+        assert!(refraction.refraction(std::f64::consts::FRAC_PI_2).abs() < 1e-12 * ARCMINUTE);
+        assert!(refraction.refraction(89.999_f64.to_radians()) > 0.0);
+        assert!(refraction.refraction(89.999_f64.to_radians()) < 0.001 * ARCMINUTE);
+    }
+
+    // Below 1° under the horizon, the refraction decreases to zero at 2° under it, without jumps.
+    for refraction in [Refraction::Bennett, Refraction::Saemundsson] {
+        let at_minimum = refraction.refraction(-1_f64.to_radians());
+        assert!((at_minimum - 39.0 * ARCMINUTE).abs() < 0.5 * ARCMINUTE);
+        let step = 1e-6_f64.to_radians();
+        assert!(
+            (refraction.refraction(-1_f64.to_radians() - step) - at_minimum).abs()
+                < 1e-5 * at_minimum
+        );
+        let halfway = refraction.refraction(-1.5_f64.to_radians());
+        assert!((halfway - at_minimum / 2.0).abs() < 1e-12);
+        assert!(refraction.refraction(-1.999_f64.to_radians()) < 0.001 * at_minimum);
+        assert_eq!(refraction.refraction(-2_f64.to_radians()), 0.0);
+        assert_eq!(refraction.refraction(-2.1_f64.to_radians()), 0.0);
+    }
+
+    // No refraction is applied by the `None` model.
+    assert_eq!(Refraction::None.refraction(0.1), 0.0);
+    assert_eq!(Refraction::default(), Refraction::Saemundsson);
+}
+
+#[test]
+fn it_airmass() {
+    let airmass = |altitude: f64| {
+        HorizontalCoordinates::from_equatorial(
+            equatorial(0.0, altitude.to_radians()),
+            std::f64::consts::FRAC_PI_2,
+            0.0,
+            Refraction::None,
+        )
+        .airmass()
+    };
+
+    assert!((airmass(90.0).unwrap() - 1.0).abs() < 1e-3);
+    assert!((airmass(30.0).unwrap() - 2.0).abs() < 0.01);
+    assert!((airmass(0.0).unwrap() - 38.0).abs() < 1.0);
+    assert_eq!(airmass(-2.0), None);
+}
+
+#[test]
+fn it_horizontal_coordinates() {
+    let observer = Observer::new(0.7, -1.3, 200.0);
+    let tt = 2459000.5;
+    let ut1 = tt - 69.0 / 86_400.0;
+
+    for body in [Body::Sun, Body::Mars, Body::Jupiter] {
+        let place = topocentric_place(body, observer, ut1, tt, Nutation::Iau2000B).unwrap();
+        let horizontal = horizontal_coordinates(
+            body,
+            observer,
+            ut1,
+            tt,
+            Nutation::Iau2000B,
+            Refraction::None,
+        )
+        .unwrap();
+
+        // The hour angle is the local sidereal time minus the right ascension.
+        let equatorial = place.apparent().equatorial();
+        let local_sidereal_time = observer.local_sidereal_time(ut1, tt, Nutation::Iau2000B);
+        let hour_angle =
+            (local_sidereal_time - equatorial.longitude()).rem_euclid(std::f64::consts::TAU);
+        assert!((horizontal.hour_angle() - hour_angle).abs() < 1e-12);
+        assert_eq!(horizontal.distance(), equatorial.distance());
+
+        // The zenith distance is the angle to the zenith, at the declination of the latitude
+        // and the right ascension of the local sidereal time.
+        let zenith = RectangularCoordinates::from(self::equatorial(
+            local_sidereal_time,
+            observer.latitude(),
+        ));
+        let direction = RectangularCoordinates::from(self::equatorial(
+            equatorial.longitude(),
+            equatorial.latitude(),
+        ));
+        let cosine = zenith.x * direction.x + zenith.y * direction.y + zenith.z * direction.z;
+        assert!((horizontal.altitude().sin() - cosine).abs() < 1e-12);
+    }
+
+    assert_eq!(
+        horizontal_coordinates(
+            Body::Earth,
+            observer,
+            ut1,
+            tt,
+            Nutation::Iau2000B,
+            Refraction::None
+        ),
+        Err(Error::ObserverBody)
+    );
+}
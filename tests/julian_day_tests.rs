@@ -0,0 +1,304 @@
+use vsop87::*;
+
+/// Dates from the examples of chapter 7 of *Astronomical Algorithms*, by Jean Meeus, with their
+/// Julian Day.
+const MEEUS_DATES: [(Calendar, i32, u8, u8, u8, u8, f64); 14] = [
+    (Calendar::Gregorian, 2000, 1, 1, 12, 0, 2451545.0),
+    (Calendar::Gregorian, 1999, 1, 1, 0, 0, 2451179.5),
+    (Calendar::Gregorian, 1987, 1, 27, 0, 0, 2446822.5),
+    (Calendar::Gregorian, 1987, 6, 19, 12, 0, 2446966.0),
+    (Calendar::Gregorian, 1988, 1, 27, 0, 0, 2447187.5),
+    (Calendar::Gregorian, 1988, 6, 19, 12, 0, 2447332.0),
+    (Calendar::Gregorian, 1900, 1, 1, 0, 0, 2415020.5),
+    (Calendar::Gregorian, 1600, 1, 1, 0, 0, 2305447.5),
+    (Calendar::Gregorian, 1600, 12, 31, 0, 0, 2305812.5),
+    (Calendar::Julian, 837, 4, 10, 7, 12, 2026871.8),
+    (Calendar::Julian, -123, 12, 31, 0, 0, 1676496.5),
+    (Calendar::Julian, -122, 1, 1, 0, 0, 1676497.5),
+    (Calendar::Julian, -1000, 7, 12, 12, 0, 1356001.0),
+    (Calendar::Julian, -4712, 1, 1, 12, 0, 0.0),
+];
+
+#[test]
+fn it_calendar_to_julian_day() {
+    for (calendar, year, month, day, hour, minute, jd) in MEEUS_DATES {
+        let date = CalendarDate::new(calendar, year, month, day)
+            .unwrap()
+            .with_time(hour, minute, 0.0)
+            .unwrap();
+        assert!((JulianDay::from(date).value() - jd).abs() < 1e-9);
+
+        let back = JulianDay::new(jd).to_calendar(calendar).unwrap();
+        assert_eq!((back.year(), back.month(), back.day()), (year, month, day));
+        assert_eq!((back.hour(), back.minute()), (hour, minute));
+        assert!(back.second() < 1e-3 || back.second() > 59.999);
+    }
+
+    // The Julian Day 0 in the proleptic Gregorian calendar.
+    assert_eq!(
+        JulianDay::from_gregorian(-4713, 11, 24).map(|jd| jd + 0.5),
+        Ok(JulianDay::new(0.0))
+    );
+    assert_eq!(
+        JulianDay::from_gregorian(1582, 10, 15),
+        JulianDay::from_julian_calendar(1582, 10, 5)
+    );
+}
+
+#[test]
+fn it_calendar_round_trip() {
+    let mut jd = -1_000_000.25;
+    while jd < 5_000_000.0 {
+        for calendar in [Calendar::Gregorian, Calendar::Julian] {
+            let date = JulianDay::new(jd).to_calendar(calendar).unwrap();
+            assert_eq!(date.calendar(), calendar);
+            assert!((JulianDay::from(date).value() - jd).abs() < 1e-6);
+        }
+        jd += 1_234.567;
+    }
+}
+
+#[test]
+fn it_calendar_validation() {
+    assert!(CalendarDate::gregorian(2000, 2, 29).is_ok());
+    assert!(CalendarDate::gregorian(-400, 2, 29).is_ok());
+    assert_eq!(
+        CalendarDate::gregorian(1900, 2, 29),
+        Err(Error::InvalidDate)
+    );
+    assert!(CalendarDate::julian(1900, 2, 29).is_ok());
+    assert!(CalendarDate::julian(-1, 2, 29).is_err());
+    assert!(CalendarDate::julian(-4, 2, 29).is_ok());
+    assert_eq!(
+        CalendarDate::gregorian(2000, 13, 1),
+        Err(Error::InvalidDate)
+    );
+    assert_eq!(
+        CalendarDate::gregorian(2000, 4, 31),
+        Err(Error::InvalidDate)
+    );
+    assert_eq!(CalendarDate::gregorian(2000, 1, 0), Err(Error::InvalidDate));
+
+    let date = CalendarDate::gregorian(2000, 1, 1).unwrap();
+    assert!(date.with_time(23, 59, 59.999).is_ok());
+    assert_eq!(date.with_time(24, 0, 0.0), Err(Error::InvalidDate));
+    assert_eq!(date.with_time(12, 60, 0.0), Err(Error::InvalidDate));
+    assert_eq!(date.with_time(12, 0, 60.0), Err(Error::InvalidDate));
+    assert_eq!(date.with_time(12, 0, f64::NAN), Err(Error::InvalidDate));
+
+    assert_eq!(
+        JulianDay::new(f64::NAN).to_gregorian(),
+        Err(Error::NonFinite)
+    );
+    assert_eq!(JulianDay::new(1e20).to_gregorian(), Err(Error::InvalidDate));
+    for day in [1e300, -1e300] {
+        for calendar in [Calendar::Gregorian, Calendar::Julian] {
+            assert_eq!(
+                JulianDay::new(day).to_calendar(calendar),
+                Err(Error::InvalidDate)
+            );
+        }
+    }
+}
+
+#[test]
+fn it_parse() {
+    for (s, jd) in [
+        ("2000-01-01T12:00:00", 2451545.0),
+        ("2000-01-01T12:00:00Z", 2451545.0),
+        ("2000-01-01 12:00", 2451545.0),
+        ("2000-01-01T14:00+02:00", 2451545.0),
+        ("2000-01-01T09:30:00-0230", 2451545.0),
+        ("1999-12-31T23:00-13", 2451545.0),
+        ("2000-01-01", 2451544.5),
+        ("-4713-11-24T12:00:00", 0.0),
+        ("+12000-01-01", 6103969.5),
+    ] {
+        assert!((s.parse::<JulianDay>().unwrap().value() - jd).abs() < 1e-9);
+    }
+
+    let jd: JulianDay = "2000-01-01T12:00:00.5".parse().unwrap();
+    assert!((jd - JulianDay::J2000 - 0.5 / 86400.0).abs() < 1e-9);
+
+    let date: CalendarDate = "-0500-03-01T06:30:15.25".parse().unwrap();
+    assert_eq!((date.year(), date.month(), date.day()), (-500, 3, 1));
+    assert_eq!((date.hour(), date.minute(), date.second()), (6, 30, 15.25));
+    assert_eq!(date.calendar(), Calendar::Gregorian);
+    assert_eq!(
+        "2000-01-01T12:00+01:00".parse::<CalendarDate>(),
+        Err(Error::InvalidDateFormat)
+    );
+
+    for invalid in [
+        "",
+        "2000",
+        "2000-01",
+        "200-01-01",
+        "2000-1-01",
+        "2000-01-01-01",
+        "2000/01/01",
+        "2000-01-01T12",
+        "2000-01-01T12:00:00:00",
+        "2000-01-01T1:00",
+        "2000-01-01T12:00:0",
+        "2000-01-01T12:00:00.",
+        "2000-01-01T12:00:00.5e3",
+        "2000-01-01T12:00+1",
+        "2000-01-01T12:00+01:0",
+    ] {
+        assert_eq!(invalid.parse::<JulianDay>(), Err(Error::InvalidDateFormat));
+    }
+    assert_eq!("2000-02-30".parse::<JulianDay>(), Err(Error::InvalidDate));
+    assert_eq!(
+        "2000-01-01T25:00".parse::<JulianDay>(),
+        Err(Error::InvalidDate)
+    );
+    assert_eq!(
+        "2000-01-01T12:00+24:00".parse::<JulianDay>(),
+        Err(Error::InvalidDate)
+    );
+}
+
+#[test]
+fn it_display() {
+    let date = CalendarDate::gregorian(2024, 4, 8)
+        .unwrap()
+        .with_time(18, 17, 20.0)
+        .unwrap();
+    assert_eq!(date.to_string(), "2024-04-08T18:17:20");
+    assert_eq!(
+        date.with_time(6, 5, 4.5).unwrap().to_string(),
+        "2024-04-08T06:05:04.500000"
+    );
+    assert_eq!(
+        CalendarDate::julian(-44, 3, 15).unwrap().to_string(),
+        "-0044-03-15T00:00:00"
+    );
+
+    let date = JulianDay::new(2451545.25).to_gregorian().unwrap();
+    assert_eq!(date.to_string().parse::<CalendarDate>(), Ok(date));
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn it_system_time() {
+    use std::time::{Duration, SystemTime};
+
+    assert_eq!(
+        JulianDay::from(SystemTime::UNIX_EPOCH),
+        JulianDay::new(2440587.5)
+    );
+    assert_eq!(
+        JulianDay::from(SystemTime::UNIX_EPOCH + Duration::from_secs(946_728_000)),
+        JulianDay::J2000
+    );
+    assert_eq!(
+        JulianDay::from(SystemTime::UNIX_EPOCH - Duration::from_secs(43_200)),
+        JulianDay::new(2440587.0)
+    );
+
+    let now = JulianDay::now();
+    assert!(now > JulianDay::from_gregorian(2020, 1, 1).unwrap());
+    assert!(now.to_gregorian().unwrap().year() < 3000);
+}
+
+#[test]
+fn it_time_variable() {
+    let jd = JulianDay::new(2415020.0);
+
+    assert_eq!(jd.days_since_j2000(), -36525.0);
+    assert_eq!(jd.julian_centuries(), -1.0);
+    assert_eq!(jd.julian_millennia(), -0.1);
+    assert_eq!(f64::from(jd), 2415020.0);
+    assert_eq!(jd + 36525.0, JulianDay::J2000);
+    assert_eq!(JulianDay::J2000 - 36525.0, jd);
+    assert_eq!(JulianDay::J2000 - jd, 36525.0);
+}
+
+#[test]
+#[cfg(all(feature = "all_solutions", feature = "all_bodies"))]
+fn it_accepted_by_solutions() {
+    let jd = JulianDay::from_gregorian(1899, 12, 31).unwrap() + 0.5;
+    assert_eq!(jd, JulianDay::new(2415020.0));
+
+    assert_eq!(vsop87::mercury(jd), vsop87::mercury(2415020.0));
+    assert_eq!(vsop87a::earth(jd), vsop87a::earth(2415020.0));
+    assert_eq!(vsop87b::mars_state(jd), vsop87b::mars_state(2415020.0));
+    assert_eq!(vsop87d::all_bodies(jd), vsop87d::all_bodies(2415020.0));
+    assert_eq!(
+        vsop87e::checked(Body::Sun, jd),
+        vsop87e::checked(Body::Sun, 2415020.0)
+    );
+    assert_eq!(
+        calculate(Solution::VSOP87C, Body::Venus, jd),
+        calculate(Solution::VSOP87C, Body::Venus, 2415020.0)
+    );
+    assert_eq!(
+        Solution::VSOP87C.position(Body::Venus, jd.into()),
+        calculate(Solution::VSOP87C, Body::Venus, jd)
+    );
+    assert!(Validity::vsop87(Body::Saturn).contains(jd));
+}
+
+#[test]
+fn it_two_parts() {
+    let jd = JulianDay::from_parts(2460000.5, 0.25);
+
+    assert_eq!(jd.parts(), (2460000.5, 0.25));
+    assert_eq!(jd.value(), 2460000.75);
+    assert_eq!(jd, JulianDay::new(2460000.75));
+    assert_eq!(jd, JulianDay::from_parts(2460000.0, 0.75));
+    assert_eq!(JulianDay::from((2460000.5, 0.25)), jd);
+    assert!(jd < jd + 1e-9);
+    assert!(!JulianDay::from_parts(2460000.5, f64::NAN).is_finite());
+
+    // The time variable is calculated without cancellation.
+    let tiny = 1e-9;
+    assert_eq!(
+        JulianDay::from_parts(2451545.0, tiny).julian_millennia(),
+        tiny / 365250.0
+    );
+
+    let date = JulianDay::from_parts(2460000.5, 0.75 + 0.001 / 86400.0)
+        .to_gregorian()
+        .unwrap();
+    assert_eq!((date.hour(), date.minute()), (18, 0));
+    assert!((date.second() - 0.001).abs() < 1e-9);
+
+    let date = CalendarDate::gregorian(2023, 2, 25)
+        .unwrap()
+        .with_time(18, 0, 1e-6)
+        .unwrap();
+    let (day, fraction) = JulianDay::from(date).parts();
+    assert_eq!(day, 2460000.5);
+    assert!((fraction * 86400.0 - 64800.000001).abs() < 1e-9);
+}
+
+#[test]
+#[cfg(all(feature = "vsop87a", feature = "earth"))]
+fn it_two_parts_velocity() {
+    // Differentiating positions over one second needs the full precision of the date.
+    let jd = JulianDay::from_parts(2460000.5, 0.123456789);
+    let dt = 1.0 / 86400.0;
+
+    let before = vsop87a::earth(jd);
+    let after = vsop87a::earth(jd + dt);
+    let velocity = vsop87a::earth_state(jd).velocity;
+
+    assert!(((after.x - before.x) / dt - velocity.x).abs() < 1e-6 * velocity.x.abs());
+    assert!(((after.y - before.y) / dt - velocity.y).abs() < 1e-6 * velocity.y.abs());
+
+    let jdes = [jd, jd + dt];
+    let mut output = [RectangularCoordinates::default(); 2];
+    vsop87a::batch_julian_days(Body::Earth, &jdes, &mut output).unwrap();
+    assert!(((output[1].x - output[0].x) / dt - velocity.x).abs() < 1e-6 * velocity.x.abs());
+
+    assert_eq!(
+        Solution::VSOP87A.position_at(Body::Earth, jd + dt),
+        Ok(Coordinates::Rectangular(after))
+    );
+    assert_eq!(
+        vsop87a::VSOP87A.position_at(Body::Earth, jd),
+        Ok(Coordinates::Rectangular(before))
+    );
+}
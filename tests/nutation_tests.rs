@@ -0,0 +1,142 @@
+mod common;
+
+use common::{angle, assert_matrix, year, ARCSECOND};
+use vsop87::*;
+
+/// Julian Day used in the tests of the SOFA library.
+fn sofa_date() -> JulianDay {
+    JulianDay::from_parts(2400000.5, 53736.0)
+}
+
+#[test]
+fn it_nutation_angles() {
+    // The bundled IAU 1980 series is truncated to terms of 0.0003" or more.
+    let (longitude, obliquity) = Nutation::Iau1980.angles(sofa_date());
+    assert!((longitude + 0.000_009_643_658_353_226_564).abs() < 0.001 * ARCSECOND);
+    assert!((obliquity - 0.000_040_600_510_068_797_13).abs() < 0.001 * ARCSECOND);
+
+    let (longitude, obliquity) = Nutation::Iau2000B.angles(sofa_date());
+    assert!((longitude + 0.000_009_632_552_291_148_363).abs() < 1e-18);
+    assert!((obliquity - 0.000_040_631_971_066_211_59).abs() < 1e-18);
+
+    // Example 22.a of Meeus, Astronomical Algorithms: 1987 April 10, 0h TD. The IAU 2000B model
+    // differs from the IAU 1980 model by 0.007".
+    for model in [Nutation::Iau1980, Nutation::Iau2000B] {
+        let (longitude, obliquity) = model.angles(2446895.5);
+        assert!((longitude / ARCSECOND + 3.788).abs() < 0.01, "{model:?}");
+        assert!((obliquity / ARCSECOND - 9.443).abs() < 0.01, "{model:?}");
+    }
+
+    assert_eq!(Nutation::default(), Nutation::Iau2000B);
+}
+
+#[test]
+fn it_obliquity() {
+    let tt = JulianDay::from_parts(2400000.5, 54388.0);
+    assert!((Nutation::Iau1980.mean_obliquity(tt) - 0.409_075_134_764_381_6).abs() < 1e-15);
+    assert!((Nutation::Iau2000B.mean_obliquity(tt) - 0.409_074_922_938_725_8).abs() < 1e-15);
+
+    for model in [Nutation::Iau1980, Nutation::Iau2000B] {
+        let (_, obliquity) = model.angles(tt);
+        let true_obliquity = model.true_obliquity(tt);
+        assert!((true_obliquity - model.mean_obliquity(tt) - obliquity).abs() < 1e-15);
+        assert_eq!(
+            model.mean_obliquity(tt),
+            model.precession().mean_obliquity(tt)
+        );
+    }
+
+    // Example 22.a of Meeus, Astronomical Algorithms: 23°26'27.407" and 23°26'36.850".
+    let mean = Nutation::Iau1980.mean_obliquity(2446895.5) / ARCSECOND;
+    assert!((mean - (23.0 * 3600.0 + 26.0 * 60.0 + 27.407)).abs() < 0.001);
+    let true_obliquity = Nutation::Iau1980.true_obliquity(2446895.5) / ARCSECOND;
+    assert!((true_obliquity - (23.0 * 3600.0 + 26.0 * 60.0 + 36.850)).abs() < 0.001);
+}
+
+#[test]
+fn it_nutation_matrix() {
+    // Nutation matrices of the SOFA test suite.
+    let expected = [
+        [
+            0.999_999_999_953_499_9,
+            0.000_008_847_935_789_636_432,
+            0.000_003_835_906_502_164_019,
+        ],
+        [
+            -0.000_008_847_780_042_583_436,
+            0.999_999_999_136_657,
+            -0.000_040_600_527_027_271_31,
+        ],
+        [
+            -0.000_003_836_265_729_708_479,
+            0.000_040_600_493_086_126_39,
+            0.999_999_999_168_441_5,
+        ],
+    ];
+    assert_matrix(
+        Nutation::Iau1980.equatorial(sofa_date()),
+        expected,
+        0.001 * ARCSECOND,
+    );
+
+    // SOFA uses the IAU 1980 mean obliquity with the IAU 2000B nutation, that has no effect at
+    // this level.
+    let expected = [
+        [
+            0.999_999_999_953_607,
+            0.000_008_837_746_144_871_248,
+            0.000_003_831_488_838_252_203,
+        ],
+        [
+            -0.000_008_837_590_456_632_305,
+            0.999_999_999_135_469_3,
+            -0.000_040_631_987_985_595_92,
+        ],
+        [
+            -0.000_003_831_847_930_134_941,
+            0.000_040_631_954_122_581_68,
+            0.999_999_999_167_180_6,
+        ],
+    ];
+    assert_matrix(Nutation::Iau2000B.equatorial(sofa_date()), expected, 1e-11);
+
+    // The nutation of the ecliptic, seen from the true equator, is the nutation of the equator.
+    for model in [Nutation::Iau1980, Nutation::Iau2000B] {
+        let tt = year(2050);
+        let equatorial = model.equatorial(tt) * model.precession().ecliptic_to_equatorial(tt);
+        let ecliptic = model.ecliptic_to_true_equatorial(tt);
+        assert!((equatorial.inverse() * ecliptic).angle() < 1e-15);
+        assert!((model.ecliptic(tt).angle() - model.angles(tt).0.abs()).abs() < 1e-15);
+    }
+}
+
+#[test]
+fn it_true_equator_of_date() {
+    for y in [1800, 1900, 2000, 2100, 2200] {
+        let tt = year(y);
+
+        for (body, rectangular, spherical) in [
+            (vsop87a::venus(tt), vsop87c::venus(tt), vsop87d::venus(tt)),
+            (vsop87a::mars(tt), vsop87c::mars(tt), vsop87d::mars(tt)),
+            (
+                vsop87a::saturn(tt),
+                vsop87c::saturn(tt),
+                vsop87d::saturn(tt),
+            ),
+        ] {
+            // The same position, from the J2000.0 frame with the IAU 1976 precession.
+            let expected = body
+                .to_fk5()
+                .rotate(Precession::Iau1976.equatorial(JulianDay::J2000, tt))
+                .rotate(Nutation::Iau1980.equatorial(tt));
+
+            let rectangular = rectangular.to_true_equatorial(tt, Nutation::Iau1980);
+            assert!(angle(rectangular, expected) < 0.02 * ARCSECOND, "{y}");
+
+            // The series of VSOP87C and VSOP87D agree to about 0.01".
+            let spherical = spherical.to_true_equatorial(tt, Nutation::Iau1980);
+            let spherical = RectangularCoordinates::from(spherical);
+            assert!(angle(spherical, rectangular) < 0.02 * ARCSECOND, "{y}");
+        }
+    }
+}
@@ -0,0 +1,217 @@
+mod common;
+
+use common::{ARCSECOND, SPEED_OF_LIGHT};
+use vsop87::*;
+
+/// Calculates the difference between two vectors.
+fn difference(a: RectangularCoordinates, b: RectangularCoordinates) -> RectangularCoordinates {
+    RectangularCoordinates {
+        x: a.x - b.x,
+        y: a.y - b.y,
+        z: a.z - b.z,
+    }
+}
+
+/// Calculates the length of a vector.
+fn length(a: RectangularCoordinates) -> f64 {
+    (a.x * a.x + a.y * a.y + a.z * a.z).sqrt()
+}
+
+/// Calculates the unit vector of the given spherical coordinates.
+fn direction(coordinates: SphericalCoordinates) -> RectangularCoordinates {
+    let RectangularCoordinates { x, y, z } = coordinates.into();
+    let length = coordinates.distance();
+
+    RectangularCoordinates {
+        x: x / length,
+        y: y / length,
+        z: z / length,
+    }
+}
+
+#[test]
+fn it_terrestrial_coordinates() {
+    // Test cases of the SOFA library.
+    let observer = Observer::new(-0.5, 3.1, 2_500.0);
+    let expected = [
+        (
+            Ellipsoid::Wgs84,
+            [
+                -5_599_000.557_704_995,
+                233_011.672_234_792,
+                -3_040_909.470_698_336,
+            ],
+        ),
+        (
+            Ellipsoid::Grs80,
+            [
+                -5_599_000.557_726_098,
+                233_011.672_235_670_3,
+                -3_040_909.470_609_548,
+            ],
+        ),
+    ];
+    for (ellipsoid, [x, y, z]) in expected {
+        let coordinates = observer.with_ellipsoid(ellipsoid).terrestrial_coordinates();
+
+        assert!((coordinates.x - x).abs() < 1e-7);
+        assert!((coordinates.y - y).abs() < 1e-7);
+        assert!((coordinates.z - z).abs() < 1e-7);
+    }
+    assert_eq!(observer.ellipsoid(), Ellipsoid::Wgs84);
+
+    // Example 11.a of Meeus, Astronomical Algorithms: ρ sin φ' and ρ cos φ' of Palomar.
+    let palomar = Observer::new(
+        (33.0 + 21.0 / 60.0 + 22.0 / 3_600.0_f64).to_radians(),
+        0.0,
+        1_706.0,
+    );
+    let coordinates = palomar.terrestrial_coordinates();
+    let radius = Ellipsoid::Wgs84.equatorial_radius();
+    assert!((coordinates.z / radius - 0.546_861).abs() < 2e-6);
+    assert!((coordinates.x / radius - 0.836_339).abs() < 2e-6);
+}
+
+#[test]
+fn it_observer_state() {
+    let observer = Observer::new(0.8, -1.2, 350.0);
+    let tt = 2460000.5;
+    let ut1 = tt - 69.0 / 86_400.0;
+
+    for nutation in [Nutation::Iau1980, Nutation::Iau2000B] {
+        let state = observer.state(ut1, tt, nutation).state_vector();
+        let terrestrial = observer.terrestrial_coordinates();
+        let radius = length(terrestrial) / 149_597_870_700.0;
+
+        // The rotation keeps the distance, and the velocity is perpendicular to the position.
+        assert!((length(state.position) - radius).abs() < 1e-18);
+        let speed =
+            7.292_115e-5 * 86_400.0 * terrestrial.x.hypot(terrestrial.y) / 149_597_870_700.0;
+        assert!((length(state.velocity) - speed).abs() / speed < 1e-6);
+        let projection = state.position.x * state.velocity.x
+            + state.position.y * state.velocity.y
+            + state.position.z * state.velocity.z;
+        assert!(projection.abs() < 1e-6 * radius * speed);
+
+        // After a sidereal day, the observer is back to the same place.
+        let sidereal_day = 1.0 / 1.002_737_811_911_354_6;
+        let next = observer
+            .state(ut1 + sidereal_day, tt + sidereal_day, nutation)
+            .state_vector();
+        assert!(length(difference(next.position, state.position)) < 1e-6 * radius);
+    }
+}
+
+#[test]
+fn it_parallax() {
+    let observer = Observer::new(-0.6, 2.5, 100.0);
+    let tt = 2455000.5;
+    let ut1 = tt - 66.0 / 86_400.0;
+
+    for body in [Body::Sun, Body::Venus, Body::Mars] {
+        let geocentric = geocentric_place(body, tt, Nutation::Iau2000B).unwrap();
+        let topocentric = topocentric_place(body, observer, ut1, tt, Nutation::Iau2000B).unwrap();
+
+        // The astrometric topocentric position is the geocentric one minus the position of the
+        // observer, except for the motion of the body during the 21 ms that the light takes to
+        // cross the radius of the Earth.
+        let position = observer
+            .state(ut1, tt, Nutation::Iau2000B)
+            .position()
+            .coordinates();
+        let expected = SphericalCoordinates::from(difference(
+            geocentric.astrometric().equatorial().into(),
+            position,
+        ));
+        let astrometric = topocentric.astrometric().equatorial();
+        let separation = length(difference(direction(astrometric), direction(expected)));
+        assert!(separation < 0.01 * ARCSECOND);
+        assert!((astrometric.distance() - expected.distance()).abs() < 1e-7);
+
+        // The parallax is up to 8.8″ at 1 AU.
+        let parallax = length(difference(
+            direction(topocentric.apparent().equatorial()),
+            direction(geocentric.apparent().equatorial()),
+        ));
+        assert!(parallax < 8.8 * ARCSECOND / astrometric.distance() + 0.4 * ARCSECOND);
+    }
+}
+
+#[test]
+fn it_diurnal_aberration() {
+    let tt = 2451545.0;
+    let ut1 = tt - 64.0 / 86_400.0;
+
+    for observer in [
+        Observer::new(0.0, 0.0, 0.0),
+        Observer::new(0.0, 1.5, 0.0),
+        Observer::new(1.0, -2.0, 4_000.0),
+    ] {
+        for body in [Body::Jupiter, Body::Saturn, Body::Neptune] {
+            let geocentric = geocentric_place(body, tt, Nutation::Iau2000B).unwrap();
+            let topocentric =
+                topocentric_place(body, observer, ut1, tt, Nutation::Iau2000B).unwrap();
+
+            // The apparent places of the observer and of the center of the Earth differ from
+            // the astrometric places, rotated into the true equator, by the diurnal aberration,
+            // v × p / c.
+            let rotation = Nutation::Iau2000B.equatorial(tt)
+                * Precession::Iau2006.equatorial(JulianDay::J2000, tt)
+                * Rotation::FRAME_BIAS.inverse();
+            let shift = |apparent: Place, astrometric: Place| {
+                difference(
+                    direction(apparent.equatorial()),
+                    rotation * direction(astrometric.equatorial()),
+                )
+            };
+            let diurnal = length(difference(
+                shift(topocentric.apparent(), topocentric.astrometric()),
+                shift(geocentric.apparent(), geocentric.astrometric()),
+            ));
+
+            let velocity = observer
+                .state(ut1, tt, Nutation::Iau2000B)
+                .velocity()
+                .coordinates();
+            let p = direction(topocentric.astrometric().equatorial());
+            let cross = RectangularCoordinates {
+                x: velocity.y * p.z - velocity.z * p.y,
+                y: velocity.z * p.x - velocity.x * p.z,
+                z: velocity.x * p.y - velocity.y * p.x,
+            };
+            let expected = length(cross) / SPEED_OF_LIGHT;
+
+            assert!(expected < 0.33 * ARCSECOND);
+            assert!((diurnal - expected).abs() < 0.005 * ARCSECOND);
+        }
+    }
+}
+
+#[test]
+fn it_errors() {
+    let observer = Observer::new(0.7, 0.1, 50.0);
+
+    assert_eq!(
+        topocentric_place(
+            Body::Earth,
+            observer,
+            2451545.0,
+            2451545.0,
+            Nutation::Iau2000B
+        ),
+        Err(Error::ObserverBody)
+    );
+    assert_eq!(
+        topocentric_place(
+            Body::EarthMoon,
+            observer,
+            2451545.0,
+            2451545.0,
+            Nutation::Iau2000B
+        ),
+        Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87E,
+            body: Body::EarthMoon,
+        })
+    );
+}
@@ -0,0 +1,156 @@
+mod common;
+
+use common::{angle, assert_matrix, year, ARCSECOND};
+use vsop87::*;
+
+/// Julian Day used in the tests of the SOFA library.
+fn sofa_date() -> JulianDay {
+    JulianDay::from_parts(2400000.5, 50123.9999)
+}
+
+#[test]
+fn it_equatorial_precession() {
+    // Precession matrix of the SOFA test suite for the IAU 1976 model.
+    let precession = Precession::Iau1976.equatorial(JulianDay::J2000, sofa_date());
+    let expected = [
+        [
+            0.999_999_550_432_835_1,
+            0.000_869_663_220_948_096_1,
+            0.000_377_915_347_495_988_8,
+        ],
+        [
+            -0.000_869_663_220_948_511_2,
+            0.999_999_621_842_856_1,
+            -0.000_000_164_328_477_611_188_6,
+        ],
+        [
+            -0.000_377_915_347_495_033_7,
+            -0.000_000_164_330_674_614_736_7,
+            0.999_999_928_589_979,
+        ],
+    ];
+    assert_matrix(precession, expected, 1e-15);
+
+    // Matrix of the SOFA test suite for the IAU 2006 model, that includes the frame bias. The
+    // angles of Fukushima and Williams used by SOFA agree to 20 microarcseconds.
+    let precession = Precession::Iau2006.equatorial(JulianDay::J2000, sofa_date())
+        * Rotation::FRAME_BIAS.inverse();
+    let expected = [
+        [
+            0.999_999_550_517_600_7,
+            0.000_869_540_461_734_820_8,
+            0.000_377_973_520_186_558_9,
+        ],
+        [
+            -0.000_869_540_472_377_203_1,
+            0.999_999_621_949_602_7,
+            -0.000_000_136_175_249_708_027,
+        ],
+        [
+            -0.000_377_973_495_703_408_9,
+            -0.000_000_192_488_084_789_445_7,
+            0.999_999_928_567_997_2,
+        ],
+    ];
+    assert_matrix(precession, expected, 1e-10);
+
+    // Example 21.b of Meeus, Astronomical Algorithms: θ Persei in 2028 November 13.19 TD.
+    let (ra, dec) = (41.054_063_f64.to_radians(), 49.227_75_f64.to_radians());
+    let star = RectangularCoordinates {
+        x: dec.cos() * ra.cos(),
+        y: dec.cos() * ra.sin(),
+        z: dec.sin(),
+    };
+    let precession = Precession::Iau1976.equatorial(JulianDay::J2000, 2462088.69);
+    let star = SphericalCoordinates::from(star.rotate(precession));
+    assert!((star.longitude().to_degrees() - 41.547_214).abs() < 1e-6);
+    assert!((star.latitude().to_degrees() - 49.348_483).abs() < 1e-6);
+}
+
+#[test]
+fn it_between_epochs() {
+    for model in [Precession::Iau1976, Precession::Iau2006] {
+        let (b1950, j2050) = (JulianDay::new(2433282.4235), year(2050));
+
+        // Precessing through an intermediate epoch gives the same result.
+        let direct = model.equatorial(b1950, j2050);
+        let indirect =
+            model.equatorial(JulianDay::J2000, j2050) * model.equatorial(b1950, JulianDay::J2000);
+        assert!((direct.inverse() * indirect).angle() < 1e-15);
+        assert!((model.ecliptic(j2050, b1950) * model.ecliptic(b1950, j2050)).angle() < 1e-15);
+        assert!(model.equatorial(j2050, j2050).angle() < 1e-15);
+
+        // The general precession in longitude is about 50.3" per year.
+        let precession = model.ecliptic(JulianDay::J2000, j2050).angle();
+        assert!((precession / ARCSECOND / 50.0 - 50.29).abs() < 0.02);
+    }
+}
+
+#[test]
+fn it_ecliptic_and_equatorial_frames() {
+    for y in [1800, 1950, 2100, 2300] {
+        let tt = year(y);
+
+        // The precession of the equator is the precession of the ecliptic, seen from the
+        // equator through the obliquities of both dates.
+        let model = Precession::Iau2006;
+        let ecliptic = model.ecliptic_to_equatorial(tt).inverse()
+            * model.equatorial(JulianDay::J2000, tt)
+            * model.ecliptic_to_equatorial(JulianDay::J2000);
+        let difference = ecliptic.inverse() * model.ecliptic(JulianDay::J2000, tt);
+        assert!(difference.angle() < 1e-5 * ARCSECOND, "{y}");
+
+        assert!((model.mean_obliquity(JulianDay::J2000) - Rotation::OBLIQUITY_J2000).abs() < 1e-15);
+        let difference = Precession::Iau1976.mean_obliquity(tt) - model.mean_obliquity(tt);
+        assert!(difference.abs() < 0.15 * ARCSECOND);
+    }
+}
+
+#[test]
+fn it_reproduces_vsop87c() {
+    for y in [1800, 1900, 1950, 2000, 2050, 2100, 2200] {
+        let tt = year(y);
+        let centuries = f64::from(y - 2000).abs() / 100.0;
+
+        for (body, expected) in [
+            (vsop87a::mercury(tt), vsop87c::mercury(tt)),
+            (vsop87a::earth(tt), vsop87c::earth(tt)),
+            (vsop87a::mars(tt), vsop87c::mars(tt)),
+            (vsop87a::jupiter(tt), vsop87c::jupiter(tt)),
+            (vsop87a::neptune(tt), vsop87c::neptune(tt)),
+        ] {
+            let iau_1976 = body.rotate(Precession::Iau1976.ecliptic(JulianDay::J2000, tt));
+            assert!(angle(iau_1976, expected) < 0.02 * ARCSECOND, "{y}");
+
+            let iau_2006 = body.rotate(Precession::Iau2006.ecliptic(JulianDay::J2000, tt));
+            let tolerance = 0.02 + 0.31 * centuries;
+            assert!(angle(iau_2006, expected) < tolerance * ARCSECOND, "{y}");
+        }
+    }
+
+    // Bodies without a VSOP87C series, and velocities, can be precessed too.
+    let tt = year(2100);
+    let precession = Precession::Iau1976.ecliptic(JulianDay::J2000, tt);
+    let (earth, sun) = (vsop87e::earth(tt), vsop87e::sun(tt));
+    let heliocentric = RectangularCoordinates {
+        x: earth.x - sun.x,
+        y: earth.y - sun.y,
+        z: earth.z - sun.z,
+    };
+    let expected = vsop87c::earth_state(tt);
+    assert!(angle(heliocentric.rotate(precession), expected.position) < 0.01 * ARCSECOND);
+
+    // The velocity of VSOP87C is relative to the moving equinox of the date, so it differs from
+    // the precessed velocity by the rotation of the frame: the general precession in longitude,
+    // 5029.0966″ + 2.22226″ T per Julian century, turns the position about the pole of the
+    // ecliptic by about 6.4e-7 AU per day. The motion of the ecliptic itself, of 47″ per century,
+    // adds a component along the pole of less than 1e-8 AU per day.
+    let velocity = vsop87a::earth_state(tt).velocity.rotate(precession);
+    let centuries = (tt - JulianDay::J2000) / 36_525.0;
+    let rate = (5_029.096_6 + 2.222_26 * centuries) * ARCSECOND / 36_525.0;
+    let (x, y) = (-rate * expected.position.y, rate * expected.position.x);
+    assert!(x.hypot(y) > 6e-7);
+    assert!((expected.velocity.x - velocity.x - x).abs() < 1e-3 * x.hypot(y));
+    assert!((expected.velocity.y - velocity.y - y).abs() < 1e-3 * x.hypot(y));
+    assert!((expected.velocity.z - velocity.z).abs() < 1e-8);
+}
@@ -0,0 +1,109 @@
+use vsop87::*;
+
+#[test]
+fn it_full_precision() {
+    let jde = 2415020.0;
+
+    let truncated = vsop87a::truncated(Body::Mars, jde, Precision::Full).unwrap();
+    let coordinates = vsop87a::mars(jde);
+    assert!((truncated.value.x - coordinates.x).abs() < 1e-12);
+    assert!((truncated.value.y - coordinates.y).abs() < 1e-12);
+    assert!((truncated.value.z - coordinates.z).abs() < 1e-12);
+    assert_eq!(truncated.thresholds.x, 0.0);
+    assert_eq!(truncated.thresholds.y, 0.0);
+    assert_eq!(truncated.thresholds.z, 0.0);
+
+    let truncated = vsop87d::truncated(Body::Jupiter, jde, Precision::default()).unwrap();
+    let coordinates = vsop87d::jupiter(jde);
+    assert!((truncated.value.longitude() - coordinates.longitude()).abs() < 1e-10);
+    assert!((truncated.value.latitude() - coordinates.latitude()).abs() < 1e-12);
+    assert!((truncated.value.distance() - coordinates.distance()).abs() < 1e-12);
+
+    let truncated = vsop87::truncated(Body::EarthMoon, jde, Precision::Full).unwrap();
+    let elements = vsop87::earth_moon(jde);
+    assert!((truncated.value.a - elements.a).abs() < 1e-12);
+    assert!((truncated.value.l - elements.l).abs() < 1e-10);
+    assert!((truncated.value.p - elements.p).abs() < 1e-12);
+}
+
+#[test]
+fn it_amplitude_thresholds() {
+    let truncated = vsop87e::truncated(Body::Sun, 2451545.0, Precision::Amplitude(1e-6)).unwrap();
+    assert_eq!(truncated.thresholds.x, 1e-6);
+    assert_eq!(truncated.thresholds.y, 1e-6);
+    assert_eq!(truncated.thresholds.z, 1e-6);
+
+    let coordinates = vsop87e::sun(2451545.0);
+    assert!((truncated.value.x - coordinates.x).abs() < 1e-4);
+    assert!((truncated.value.y - coordinates.y).abs() < 1e-4);
+    assert!((truncated.value.z - coordinates.z).abs() < 1e-4);
+
+    // A threshold below the smallest amplitude keeps all the terms.
+    let truncated =
+        vsop87a::truncated(Body::Mars, 2415020.0, Precision::Amplitude(1e-300)).unwrap();
+    let coordinates = vsop87a::mars(2415020.0);
+    assert!((truncated.value.x - coordinates.x).abs() < 1e-12);
+    assert!((truncated.value.y - coordinates.y).abs() < 1e-12);
+    assert!((truncated.value.z - coordinates.z).abs() < 1e-12);
+}
+
+#[test]
+fn it_arcseconds_accuracy() {
+    let arcsecond = (1.0_f64 / 3600.0).to_radians();
+
+    for i in 0..40 {
+        let jde = 2305447.5 + i as f64 * 7305.25;
+        for (body, full) in [
+            (Body::Mercury, vsop87b::mercury(jde)),
+            (Body::Earth, vsop87b::earth(jde)),
+            (Body::Saturn, vsop87b::saturn(jde)),
+            (Body::Neptune, vsop87b::neptune(jde)),
+        ] {
+            let truncated = vsop87b::truncated(body, jde, Precision::Arcseconds(1.0)).unwrap();
+            assert!(truncated.thresholds.longitude() > 0.0);
+
+            let mut lon_error = (truncated.value.longitude() - full.longitude()).abs();
+            if lon_error > std::f64::consts::PI {
+                lon_error = 2.0 * std::f64::consts::PI - lon_error;
+            }
+            assert!(lon_error < arcsecond);
+            assert!((truncated.value.latitude() - full.latitude()).abs() < arcsecond);
+            assert!(
+                (truncated.value.distance() - full.distance()).abs() < arcsecond * full.distance()
+            );
+        }
+    }
+}
+
+#[test]
+fn it_astronomical_units_accuracy() {
+    for i in 0..40 {
+        let jde = 2305447.5 + i as f64 * 7305.25;
+        let full = vsop87c::uranus(jde);
+        let truncated =
+            vsop87c::truncated(Body::Uranus, jde, Precision::AstronomicalUnits(1e-5)).unwrap();
+
+        assert!((truncated.value.x - full.x).abs() < 1e-5);
+        assert!((truncated.value.y - full.y).abs() < 1e-5);
+        assert!((truncated.value.z - full.z).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn it_truncated_unsupported_body() {
+    assert_eq!(
+        vsop87::truncated(Body::Earth, 2451545.0, Precision::Full),
+        Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87,
+            body: Body::Earth,
+        })
+    );
+    assert_eq!(
+        vsop87b::truncated(Body::Sun, 2451545.0, Precision::Full),
+        Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87B,
+            body: Body::Sun,
+        })
+    );
+    assert!(vsop87e::truncated(Body::EarthMoon, 2451545.0, Precision::Full).is_err());
+}
@@ -0,0 +1,190 @@
+use vsop87::*;
+
+/// Calculates the horizontal coordinates of a body, without refraction.
+fn position(body: Body, observer: Observer, ut1: JulianDay) -> HorizontalCoordinates {
+    horizontal_coordinates(
+        body,
+        observer,
+        ut1,
+        DeltaT::EspenakMeeus.tt(ut1),
+        Nutation::Iau2000B,
+        Refraction::None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn it_events() {
+    let observer = Observer::new(0.9, 0.1, 50.0);
+    let date = JulianDay::from_gregorian(2024, 3, 20).unwrap();
+
+    for body in [Body::Sun, Body::Venus, Body::Mars, Body::Jupiter] {
+        for horizon in [Horizon::Standard, Horizon::Sun, Horizon::Custom(0.1)] {
+            let events =
+                rise_transit_set(body, observer, date, DeltaT::EspenakMeeus, horizon).unwrap();
+            assert_eq!(events.visibility(), Visibility::CrossesHorizon);
+
+            // The body is on the horizon when it rises and sets, and on the meridian at the
+            // transits.
+            for event in [events.rise(), events.set()] {
+                let event = event.unwrap();
+                assert!(event >= date && event <= date + 1.0);
+                let altitude = position(body, observer, event).geometric_altitude();
+                assert!((altitude - horizon.altitude()).abs() < 1e-6);
+            }
+            let upper = events.upper_transit().unwrap();
+            let hour_angle = position(body, observer, upper).hour_angle();
+            assert!(hour_angle.min(std::f64::consts::TAU - hour_angle) < 1e-6);
+            let lower = events.lower_transit().unwrap();
+            let hour_angle = position(body, observer, lower).hour_angle();
+            assert!((hour_angle - std::f64::consts::PI).abs() < 1e-6);
+            assert!((upper - lower).abs() < 0.51);
+        }
+    }
+
+    // At the equinox, the Sun rises in the morning and sets in the evening, 12 hours apart
+    // with the refraction and its semidiameter, and it transits at noon, with the equation of
+    // time.
+    let sun = rise_transit_set(
+        Body::Sun,
+        observer,
+        date,
+        DeltaT::EspenakMeeus,
+        Horizon::Sun,
+    )
+    .unwrap();
+    let hours = |event: Option<JulianDay>| (event.unwrap() - date) * 24.0;
+    let noon = 12.0 - observer.longitude().to_degrees() / 15.0;
+    assert!((hours(sun.upper_transit()) - noon - 0.125).abs() < 0.02);
+    assert!((hours(sun.set()) - hours(sun.rise()) - 12.2).abs() < 0.1);
+    assert!(sun.rise() < sun.upper_transit() && sun.upper_transit() < sun.set());
+
+    // The Sun rises before and sets after its center crosses the standard horizon.
+    let center = rise_transit_set(
+        Body::Sun,
+        observer,
+        date,
+        DeltaT::EspenakMeeus,
+        Horizon::Standard,
+    )
+    .unwrap();
+    assert!(sun.rise() < center.rise() && sun.set() > center.set());
+    assert_eq!(sun.upper_transit(), center.upper_transit());
+}
+
+#[test]
+fn it_circumpolar() {
+    let north = Observer::new(80_f64.to_radians(), 20_f64.to_radians(), 0.0);
+    let south = Observer::new(-80_f64.to_radians(), 20_f64.to_radians(), 0.0);
+    let summer = JulianDay::from_gregorian(2024, 6, 21).unwrap();
+    let winter = JulianDay::from_gregorian(2024, 12, 21).unwrap();
+
+    for (observer, date, visibility) in [
+        (north, summer, Visibility::Circumpolar),
+        (north, winter, Visibility::NeverRises),
+        (south, summer, Visibility::NeverRises),
+        (south, winter, Visibility::Circumpolar),
+    ] {
+        let sun = rise_transit_set(
+            Body::Sun,
+            observer,
+            date,
+            DeltaT::EspenakMeeus,
+            Horizon::Sun,
+        )
+        .unwrap();
+        assert_eq!(sun.visibility(), visibility);
+        assert_eq!(sun.rise(), None);
+        assert_eq!(sun.set(), None);
+
+        // The Sun still crosses the meridian.
+        assert!(sun.upper_transit().is_some());
+        assert!(sun.lower_transit().is_some());
+    }
+
+    // The first sunrise after the polar night.
+    let observer = Observer::new(70_f64.to_radians(), 0.0, 0.0);
+    let mut date = JulianDay::from_gregorian(2024, 1, 10).unwrap();
+    loop {
+        let sun = rise_transit_set(
+            Body::Sun,
+            observer,
+            date,
+            DeltaT::EspenakMeeus,
+            Horizon::Sun,
+        )
+        .unwrap();
+        if sun.visibility() == Visibility::CrossesHorizon {
+            assert!(sun.rise().unwrap() < sun.upper_transit().unwrap());
+            assert!(sun.set().unwrap() > sun.upper_transit().unwrap());
+            break;
+        }
+        assert_eq!(sun.visibility(), Visibility::NeverRises);
+        date = date + 1.0;
+    }
+    assert!(date > JulianDay::from_gregorian(2024, 1, 12).unwrap());
+    assert!(date < JulianDay::from_gregorian(2024, 1, 20).unwrap());
+}
+
+#[test]
+fn it_polar_circle() {
+    // Near the polar circle at the summer solstice, the Sun sets for less than an hour around
+    // its lower transit, at local midnight.
+    let date = JulianDay::from_gregorian(2024, 6, 21).unwrap();
+    for (latitude, minutes) in [(65.60, 50.4), (65.66, 37.1), (65.70, 24.5)] {
+        let observer = Observer::new(f64::to_radians(latitude), -7.5_f64.to_radians(), 0.0);
+        let sun = rise_transit_set(
+            Body::Sun,
+            observer,
+            date,
+            DeltaT::EspenakMeeus,
+            Horizon::Sun,
+        )
+        .unwrap();
+        assert_eq!(sun.visibility(), Visibility::CrossesHorizon);
+
+        let (set, rise) = (sun.set().unwrap(), sun.rise().unwrap());
+        assert!(set < sun.lower_transit().unwrap() && sun.lower_transit().unwrap() < rise);
+        assert!(((rise - set) * 1_440.0 - minutes).abs() < 0.5);
+    }
+
+    // A bit further north, it stays above the horizon.
+    let observer = Observer::new(65.8_f64.to_radians(), -7.5_f64.to_radians(), 0.0);
+    let sun = rise_transit_set(
+        Body::Sun,
+        observer,
+        date,
+        DeltaT::EspenakMeeus,
+        Horizon::Sun,
+    )
+    .unwrap();
+    assert_eq!(sun.visibility(), Visibility::Circumpolar);
+    assert_eq!(sun.rise(), None);
+    assert_eq!(sun.set(), None);
+}
+
+#[test]
+fn it_errors() {
+    let observer = Observer::new(0.9, 0.1, 50.0);
+
+    assert_eq!(
+        rise_transit_set(
+            Body::Earth,
+            observer,
+            2460000.5,
+            DeltaT::EspenakMeeus,
+            Horizon::Standard
+        ),
+        Err(Error::ObserverBody)
+    );
+    assert_eq!(
+        rise_transit_set(
+            Body::Mars,
+            observer,
+            f64::NAN,
+            DeltaT::EspenakMeeus,
+            Horizon::Standard
+        ),
+        Err(Error::NonFinite)
+    );
+}
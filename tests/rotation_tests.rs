@@ -0,0 +1,136 @@
+mod common;
+
+use common::{distance, ARCSECOND};
+use vsop87::*;
+
+#[test]
+fn it_orthogonal() {
+    for rotation in [
+        Rotation::IDENTITY,
+        Rotation::VSOP87_TO_FK5,
+        Rotation::FRAME_BIAS,
+        Rotation::VSOP87_TO_ICRS,
+        Rotation::about_x(1.0) * Rotation::about_y(-2.0) * Rotation::about_z(3.0),
+    ] {
+        let product = (rotation * rotation.inverse()).matrix();
+        for (i, row) in product.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((value - expected).abs() < 1e-12, "{rotation:?}");
+            }
+        }
+        assert!((rotation * rotation.inverse()).angle() < 1e-12);
+    }
+
+    assert_eq!(Rotation::default(), Rotation::IDENTITY);
+    assert!((Rotation::about_z(0.5).angle() - 0.5).abs() < 1e-15);
+    assert!((Rotation::about_y(-3.0).angle() - 3.0).abs() < 1e-15);
+}
+
+#[test]
+fn it_frame_bias() {
+    // Bias matrix of the SOFA test suite, from the ICRS to the mean equator and equinox J2000.0.
+    let expected = [
+        [
+            0.999_999_999_999_994_2,
+            -0.000_000_070_782_797_441_991_97,
+            0.000_000_080_562_171_469_761_34,
+        ],
+        [
+            0.000_000_070_782_794_778_573_37,
+            0.999_999_999_999_996_9,
+            0.000_000_033_060_414_542_221_37,
+        ],
+        [
+            -0.000_000_080_562_173_809_869_72,
+            -0.000_000_033_060_408_839_805_53,
+            0.999_999_999_999_996_2,
+        ],
+    ];
+    let matrix = Rotation::FRAME_BIAS.inverse().matrix();
+    for (row, expected_row) in matrix.iter().zip(expected) {
+        for (value, expected) in row.iter().zip(expected_row) {
+            assert!((value - expected).abs() < 1e-18);
+        }
+    }
+
+    // The bias is built from the offsets of the equinox and the pole.
+    let obliquity = 84_381.448 * ARCSECOND;
+    let bias = Rotation::about_x(0.006_819_2 * ARCSECOND)
+        * Rotation::about_y(-0.041_775 * obliquity.sin() * ARCSECOND)
+        * Rotation::about_z(-0.0146 * ARCSECOND);
+    assert!((bias * Rotation::FRAME_BIAS).angle() < 1e-16);
+
+    let icrs = Rotation::FRAME_BIAS * Rotation::ecliptic_to_equatorial(Rotation::OBLIQUITY_J2000);
+    assert!((icrs.inverse() * Rotation::VSOP87_TO_ICRS).angle() < 1e-15);
+}
+
+#[test]
+fn it_vsop87_to_fk5() {
+    // Example 26.a of Meeus, Astronomical Algorithms: the geocentric position of the Sun in the
+    // FK5 frame, in 1992 October 13.0 TD, that agrees to 0.5".
+    let earth = vsop87a::earth(2448908.5);
+    let sun = RectangularCoordinates {
+        x: -earth.x,
+        y: -earth.y,
+        z: -earth.z,
+    }
+    .to_fk5();
+    let expected = RectangularCoordinates {
+        x: -0.937_395_90,
+        y: -0.313_167_93,
+        z: -0.135_779_24,
+    };
+    assert!(distance(sun, expected) < 0.5 * ARCSECOND);
+
+    // The ICRS differs from the FK5 frame by less than 0.1".
+    for jde in [2415020.0, 2451545.0, 2469807.5] {
+        let mars = vsop87a::mars(jde);
+        let difference = distance(mars.to_fk5(), mars.to_icrs());
+        assert!(difference / distance(mars, RectangularCoordinates::default()) < 0.1 * ARCSECOND);
+    }
+}
+
+#[test]
+fn it_spherical_coordinates() {
+    for jde in [2415020.0, 2451545.0, 2469807.5] {
+        let rectangular = vsop87a::jupiter(jde);
+        let spherical = vsop87b::jupiter(jde);
+
+        // VSOP87A and VSOP87B give the same position in different coordinates.
+        assert!(distance(RectangularCoordinates::from(spherical), rectangular) < 1e-6);
+        let converted = SphericalCoordinates::from(rectangular);
+        assert!((converted.longitude() - spherical.longitude()).abs() < 1e-6);
+        assert!((converted.latitude() - spherical.latitude()).abs() < 1e-6);
+        assert!((converted.distance() - spherical.distance()).abs() < 1e-6);
+
+        // Rotating both kinds of coordinates gives the same result.
+        for rotation in [Rotation::VSOP87_TO_FK5, Rotation::VSOP87_TO_ICRS] {
+            let rotated = RectangularCoordinates::from(spherical.rotate(rotation));
+            assert!(distance(rotated, rectangular.rotate(rotation)) < 1e-6);
+        }
+        assert_eq!(
+            spherical.to_fk5(),
+            spherical.rotate(Rotation::VSOP87_TO_FK5)
+        );
+        assert_eq!(
+            spherical.to_icrs(),
+            spherical.rotate(Rotation::VSOP87_TO_ICRS)
+        );
+
+        let equatorial = spherical.to_fk5();
+        assert!((0.0..std::f64::consts::TAU).contains(&equatorial.longitude()));
+        assert!((equatorial.distance() - spherical.distance()).abs() < 1e-11);
+    }
+}
+
+#[test]
+fn it_state_vector() {
+    let state = vsop87e::earth_state(2451545.0);
+    let rotated = state.to_fk5();
+
+    assert_eq!(rotated.position, state.position.to_fk5());
+    assert_eq!(rotated.velocity, state.velocity.to_fk5());
+    assert_eq!(state.to_icrs(), state.rotate(Rotation::VSOP87_TO_ICRS));
+    assert_eq!(state.rotate(Rotation::IDENTITY), state);
+}
@@ -0,0 +1,87 @@
+use vsop87::*;
+
+/// Julian Day used in the tests of the SOFA library.
+fn sofa_date() -> JulianDay {
+    JulianDay::from_parts(2400000.5, 53736.0)
+}
+
+/// Calculates the distance between two angles, in radians.
+fn angular_distance(a: f64, b: f64) -> f64 {
+    let difference = (a - b).rem_euclid(std::f64::consts::TAU);
+    difference.min(std::f64::consts::TAU - difference)
+}
+
+#[test]
+fn it_earth_rotation_angle() {
+    let era = earth_rotation_angle(JulianDay::from_parts(2400000.5, 54388.0));
+    assert!((era - 0.402_283_724_002_815_8).abs() < 1e-12);
+
+    // One sidereal day later, the Earth has rotated a full turn.
+    let ut1 = JulianDay::J2000 + 0.25;
+    let next = ut1 + 1.0 / 1.002_737_811_911_354_6;
+    assert!((earth_rotation_angle(next) - earth_rotation_angle(ut1)).abs() < 1e-9);
+
+    // The precision is kept far from J2000.0.
+    let ut1 = JulianDay::from_parts(2816787.5, 0.123_456_789);
+    let value = earth_rotation_angle(ut1);
+    assert!((0.0..std::f64::consts::TAU).contains(&value));
+    assert!((earth_rotation_angle(ut1.value()) - value).abs() < 1e-7);
+}
+
+#[test]
+fn it_mean_sidereal_time() {
+    let gmst = SiderealTime::Iau1982.greenwich_mean(sofa_date(), sofa_date());
+    assert!((gmst - 1.754_174_981_860_675).abs() < 1e-12);
+
+    let gmst = SiderealTime::Iau2006.greenwich_mean(sofa_date(), sofa_date());
+    assert!((gmst - 1.754_174_971_870_091).abs() < 1e-12);
+
+    // Example 12.a of Meeus, Astronomical Algorithms: 13h 10m 46.3668s.
+    let ut1 = JulianDay::new(2446895.5);
+    let gmst = SiderealTime::Iau1982.greenwich_mean(ut1, ut1);
+    assert!((gmst.to_degrees() / 15.0 - (13.0 + 10.0 / 60.0 + 46.3668 / 3600.0)).abs() < 1e-7);
+
+    // The IAU 2006 model corrects the precession rate by 0.3" per century.
+    let difference = SiderealTime::Iau2006.greenwich_mean(ut1, ut1) - gmst;
+    assert!(difference.abs() < 0.06 / 206_264.806);
+}
+
+#[test]
+fn it_apparent_sidereal_time() {
+    let gast = SiderealTime::Iau1982.greenwich_apparent(sofa_date(), sofa_date());
+    assert!((gast - 1.754_166_136_020_645).abs() < 2e-9);
+
+    let gast = SiderealTime::Iau2006.greenwich_apparent(sofa_date(), sofa_date());
+    assert!((gast - 1.754_166_137_675_019).abs() < 5e-9);
+
+    // Example 12.a of Meeus, Astronomical Algorithms: 13h 10m 46.1351s.
+    let ut1 = JulianDay::new(2446895.5);
+    let gast = SiderealTime::Iau1982.greenwich_apparent(ut1, ut1);
+    assert!((gast.to_degrees() / 15.0 - (13.0 + 10.0 / 60.0 + 46.1351 / 3600.0)).abs() < 1e-7);
+
+    let equation = SiderealTime::Iau1982.equation_of_the_equinoxes(ut1);
+    assert!((equation.to_degrees() * 240.0 + 0.2317).abs() < 1e-4);
+}
+
+#[test]
+fn it_local_sidereal_time() {
+    let ut1: JulianDay = "2024-04-08T18:17:20".parse().unwrap();
+    let tt = ut1 + 69.184 / 86400.0;
+
+    for model in [SiderealTime::Iau1982, SiderealTime::Iau2006] {
+        let gmst = model.greenwich_mean(ut1, tt);
+        let gast = model.greenwich_apparent(ut1, tt);
+
+        for longitude in [-3.0, -1.0, 0.0, 0.5, 3.0] {
+            let lmst = model.local_mean(ut1, tt, longitude);
+            let last = model.local_apparent(ut1, tt, longitude);
+            assert!((0.0..std::f64::consts::TAU).contains(&lmst));
+            assert!((0.0..std::f64::consts::TAU).contains(&last));
+
+            assert!(angular_distance(lmst, gmst + longitude) < 1e-12);
+            assert!(angular_distance(last, gast + longitude) < 1e-12);
+        }
+    }
+
+    assert_eq!(SiderealTime::default(), SiderealTime::Iau2006);
+}
@@ -0,0 +1,102 @@
+use vsop87::*;
+
+#[test]
+fn it_parse_body() {
+    for body in Body::ALL {
+        assert_eq!(body.to_string().parse::<Body>(), Ok(body));
+        assert_eq!(body.name().to_uppercase().parse::<Body>(), Ok(body));
+    }
+
+    assert_eq!(" neptune ".parse::<Body>(), Ok(Body::Neptune));
+    assert_eq!("earth_moon".parse::<Body>(), Ok(Body::EarthMoon));
+    assert_eq!("EMB".parse::<Body>(), Ok(Body::EarthMoon));
+    assert_eq!("Pluto".parse::<Body>(), Err(Error::UnknownBody));
+    assert_eq!("".parse::<Body>(), Err(Error::UnknownBody));
+}
+
+#[test]
+fn it_parse_solution() {
+    for solution in Solution::ALL {
+        assert_eq!(solution.to_string().parse::<Solution>(), Ok(solution));
+    }
+
+    assert_eq!("vsop87e".parse::<Solution>(), Ok(Solution::VSOP87E));
+    assert_eq!("VSOP87F".parse::<Solution>(), Err(Error::UnknownSolution));
+}
+
+#[test]
+fn it_supported_bodies() {
+    assert!(Solution::VSOP87E.supports(Body::Sun));
+    assert!(Solution::VSOP87.supports(Body::EarthMoon));
+    assert!(Solution::VSOP87A.supports(Body::EarthMoon));
+    assert!(!Solution::VSOP87.supports(Body::Earth));
+
+    for solution in [
+        Solution::VSOP87,
+        Solution::VSOP87A,
+        Solution::VSOP87B,
+        Solution::VSOP87C,
+        Solution::VSOP87D,
+    ] {
+        assert!(!solution.supports(Body::Sun));
+    }
+
+    for solution in [
+        Solution::VSOP87B,
+        Solution::VSOP87C,
+        Solution::VSOP87D,
+        Solution::VSOP87E,
+    ] {
+        assert!(!solution.supports(Body::EarthMoon));
+    }
+}
+
+#[test]
+fn it_calculate() {
+    let jde = 2415020.0;
+
+    for solution in Solution::ALL {
+        for body in Body::ALL {
+            let result = calculate(solution, body, jde);
+            if solution.supports(body) {
+                let coordinates = result.unwrap();
+                match solution {
+                    Solution::VSOP87 => assert!(coordinates.elements().is_some()),
+                    Solution::VSOP87A | Solution::VSOP87C | Solution::VSOP87E => {
+                        assert!(coordinates.rectangular().is_some())
+                    }
+                    Solution::VSOP87B | Solution::VSOP87D => {
+                        assert!(coordinates.spherical().is_some())
+                    }
+                }
+            } else {
+                assert_eq!(result, Err(Error::UnsupportedBody { solution, body }));
+            }
+        }
+    }
+
+    assert_eq!(
+        calculate(Solution::VSOP87, Body::Saturn, jde),
+        Ok(Coordinates::Elements(vsop87::saturn(jde)))
+    );
+    assert_eq!(
+        calculate(Solution::VSOP87A, Body::EarthMoon, jde),
+        Ok(Coordinates::Rectangular(vsop87a::earth_moon(jde)))
+    );
+    assert_eq!(
+        Solution::VSOP87B.calculate(Body::Uranus, jde),
+        Ok(Coordinates::Spherical(vsop87b::uranus(jde)))
+    );
+    assert_eq!(
+        calculate(Solution::VSOP87C, Body::Mercury, jde),
+        Ok(Coordinates::Rectangular(vsop87c::mercury(jde)))
+    );
+    assert_eq!(
+        calculate(Solution::VSOP87D, Body::Earth, jde),
+        Ok(Coordinates::Spherical(vsop87d::earth(jde)))
+    );
+    assert_eq!(
+        calculate(Solution::VSOP87E, Body::Sun, jde),
+        Ok(Coordinates::Rectangular(vsop87e::sun(jde)))
+    );
+}
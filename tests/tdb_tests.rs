@@ -0,0 +1,94 @@
+use vsop87::*;
+
+/// Julian Day in *TT* of January 1st, 1977 at 00:00:00 *TAI*.
+const COORDINATE_EPOCH: f64 = 2443144.5003725;
+
+/// Short series of *TDB − TT* given in the USNO Circular 179, with an accuracy of about 10
+/// microseconds between 1600 and 2200.
+fn circular_179(tt: JulianDay) -> f64 {
+    let t = tt.julian_centuries();
+
+    0.001657 * (628.3076 * t + 6.2401).sin()
+        + 0.000022 * (575.3385 * t + 4.2970).sin()
+        + 0.000014 * (1256.6152 * t + 6.1969).sin()
+        + 0.000005 * (606.9777 * t + 4.0212).sin()
+        + 0.000005 * (52.9691 * t + 0.4444).sin()
+        + 0.000002 * (21.3299 * t + 5.5431).sin()
+        + 0.000010 * t * (628.3076 * t + 4.2490).sin()
+}
+
+#[test]
+fn it_tdb_minus_tt_abridged() {
+    // Geocentric part of the SOFA test case of `iauDtdb`. The terms of the full series missing
+    // from the abridged one add up to 0.31 microseconds at this date.
+    let tdb_minus_tt = tdb_minus_tt_abridged(JulianDay::from_parts(2448939.5, 0.123));
+    assert!((tdb_minus_tt + 0.001_279_984_4).abs() < 4e-7);
+
+    for day in (0..219_000).step_by(73) {
+        let tt = JulianDay::new(2305447.5 + f64::from(day));
+        let error = (vsop87::tdb_minus_tt_abridged(tt) - circular_179(tt)).abs();
+        assert!(error < 1e-5, "{tt:?}: {error}");
+    }
+}
+
+#[test]
+fn it_tdb_minus_tt_truncated() {
+    for day in (0..219_000).step_by(97) {
+        let tt = JulianDay::new(2305447.5 + f64::from(day));
+        let full = tdb_minus_tt_abridged(tt);
+
+        assert!((tdb_minus_tt_truncated(tt, 0.0) - full).abs() < 1e-12);
+        assert!((tdb_minus_tt_truncated(tt, 1e-6) - full).abs() < 5e-6);
+        assert!((tdb_minus_tt_truncated(tt, 1e-5) - full).abs() < 2.5e-5);
+        assert!((tdb_minus_tt_truncated(tt, 1.0) - full).abs() < 2e-3);
+    }
+}
+
+#[test]
+fn it_convert() {
+    let time_scales = TimeScales::new();
+
+    let tdb = time_scales.tdb(JulianDay::J2000, TimeScale::Tt);
+    assert!(
+        ((tdb - JulianDay::J2000) * 86400.0 - tdb_minus_tt_abridged(JulianDay::J2000)).abs() < 1e-9
+    );
+    let tt = time_scales.convert(tdb, TimeScale::Tdb, TimeScale::Tt);
+    assert!((tt - JulianDay::J2000).abs() * 86400.0 < 1e-9);
+
+    // The coordinate time scales are synchronized with TT at the epoch.
+    let epoch = JulianDay::from_parts(2443144.5, 0.0003725);
+    assert!((epoch.value() - COORDINATE_EPOCH).abs() < 1e-9);
+    let tcg = time_scales.convert(epoch, TimeScale::Tt, TimeScale::Tcg);
+    assert!((tcg - epoch).abs() * 86400.0 < 1e-9);
+    let tdb = time_scales.tdb(epoch, TimeScale::Tt);
+    let tcb = time_scales.convert(epoch, TimeScale::Tt, TimeScale::Tcb);
+    assert!(((tcb - tdb) * 86400.0 - 6.55e-5).abs() < 1e-9);
+
+    // At J2000.0, TCG and TCB are ahead by 0.51 and 11.25 seconds.
+    let tcg = time_scales.convert(JulianDay::J2000, TimeScale::Tt, TimeScale::Tcg);
+    assert!(((tcg - JulianDay::J2000) * 86400.0 - 0.505_8).abs() < 1e-4);
+    let tcb = time_scales.convert(JulianDay::J2000, TimeScale::Tt, TimeScale::Tcb);
+    assert!(((tcb - JulianDay::J2000) * 86400.0 - 11.253_6).abs() < 1e-4);
+
+    let utc: JulianDay = "2024-04-08T18:17:20Z".parse().unwrap();
+    let tcb = time_scales.convert(utc, TimeScale::Utc, TimeScale::Tcb);
+    let back = time_scales.convert(tcb, TimeScale::Tcb, TimeScale::Utc);
+    assert!((back - utc).abs() * 86400.0 < 1e-6);
+    assert!(((tcb - utc) * 86400.0 - 69.184 - 23.13).abs() < 0.01);
+}
+
+#[test]
+fn it_barycentric_positions() {
+    let utc: JulianDay = "2024-04-08T18:17:20Z".parse().unwrap();
+    let tdb = TimeScales::new().tdb(utc, TimeScale::Utc);
+
+    let earth = vsop87e::earth(tdb);
+    let approximation = vsop87e::earth(TimeScales::new().tt(utc, TimeScale::Utc));
+
+    // The Earth moves about 60 meters in 2 milliseconds.
+    let distance = ((earth.x - approximation.x).powi(2)
+        + (earth.y - approximation.y).powi(2)
+        + (earth.z - approximation.z).powi(2))
+    .sqrt();
+    assert!(distance * 149_597_870_700.0 < 70.0);
+}
@@ -0,0 +1,185 @@
+use vsop87::*;
+
+/// Leap second file in the format published by the IERS.
+#[cfg(not(feature = "no_std"))]
+const LEAP_SECOND_DAT: &str = "\
+#  Value of TAI-UTC in second valid beetween the initial value until
+#  the epoch given on the next line. The last line reads that NO
+#  leap second was introduced since the corresponding date
+#
+#  File expires on 28 December 2025
+#
+#    MJD        Date        TAI-UTC (s)
+#           day month year
+#    ---    --------------   ------
+#
+    41317.0    1  1 1972       10
+    41499.0    1  7 1972       11
+    41683.0    1  1 1973       12
+    42048.0    1  1 1974       13
+    42413.0    1  1 1975       14
+    42778.0    1  1 1976       15
+    43144.0    1  1 1977       16
+    43509.0    1  1 1978       17
+    43874.0    1  1 1979       18
+    44239.0    1  1 1980       19
+    44786.0    1  7 1981       20
+    45151.0    1  7 1982       21
+    45516.0    1  7 1983       22
+    46247.0    1  7 1985       23
+    47161.0    1  1 1988       24
+    47892.0    1  1 1990       25
+    48257.0    1  1 1991       26
+    48804.0    1  7 1992       27
+    49169.0    1  7 1993       28
+    49534.0    1  7 1994       29
+    50083.0    1  1 1996       30
+    50630.0    1  7 1997       31
+    51179.0    1  1 1999       32
+    53736.0    1  1 2006       33
+    54832.0    1  1 2009       34
+    56109.0    1  7 2012       35
+    57204.0    1  7 2015       36
+    57754.0    1  1 2017       37
+";
+
+fn utc(s: &str) -> JulianDay {
+    s.parse().unwrap()
+}
+
+#[test]
+fn it_tai_minus_utc() {
+    let table = LeapSeconds::bundled();
+
+    assert_eq!(table.tai_minus_utc(utc("1972-01-01")), 10.0);
+    assert!((table.tai_minus_utc(utc("1971-12-31T23:59:59")) - 9.892_242).abs() < 1e-6);
+    assert_eq!(table.tai_minus_utc(utc("1999-01-01")), 32.0);
+    assert_eq!(table.tai_minus_utc(utc("2016-12-31T23:59:59")), 36.0);
+    assert_eq!(table.tai_minus_utc(utc("2017-01-01")), 37.0);
+    assert_eq!(table.tai_minus_utc(utc("2024-04-08")), 37.0);
+    assert_eq!(table.last_leap_second(), utc("2017-01-01"));
+
+    // Drifting differences before 1972.
+    let drift = table.tai_minus_utc(JulianDay::from_mjd(39000.0));
+    assert!((drift - (3.74013 + 239.0 * 0.001296)).abs() < 1e-9);
+    assert_eq!(table.tai_minus_utc(utc("1959-12-31")), 0.0);
+}
+
+#[test]
+fn it_convert() {
+    let time_scales = TimeScales::new();
+
+    let tt = time_scales.tt(utc("2024-04-08T18:17:20Z"), TimeScale::Utc);
+    assert!((tt - utc("2024-04-08T18:18:29.184")).abs() * 86400.0 < 1e-6);
+    let tt = time_scales.tt(utc("2000-01-01T11:58:55.816"), TimeScale::Utc);
+    assert!((tt - JulianDay::J2000).abs() * 86400.0 < 1e-6);
+
+    let tai = time_scales.convert(JulianDay::J2000, TimeScale::Tt, TimeScale::Tai);
+    assert!(((JulianDay::J2000 - tai) * 86400.0 - 32.184).abs() < 1e-6);
+
+    // Leap seconds.
+    let before = time_scales.convert(utc("2016-12-31T23:59:59"), TimeScale::Utc, TimeScale::Tai);
+    let after = time_scales.convert(utc("2017-01-01T00:00:00"), TimeScale::Utc, TimeScale::Tai);
+    assert!(((after - before) * 86400.0 - 2.0).abs() < 1e-6);
+    assert!((before - utc("2017-01-01T00:00:35")).abs() * 86400.0 < 1e-6);
+    assert!((after - utc("2017-01-01T00:00:37")).abs() * 86400.0 < 1e-6);
+    let leap = time_scales.convert(utc("2017-01-01T00:00:36"), TimeScale::Tai, TimeScale::Utc);
+    assert!((leap - utc("2017-01-01T00:00:00")).abs() * 86400.0 < 1e-6);
+
+    assert_eq!(
+        time_scales.convert(utc("2017-01-01"), TimeScale::Ut1, TimeScale::Ut1),
+        utc("2017-01-01")
+    );
+}
+
+#[test]
+fn it_convert_round_trip() {
+    let time_scales = TimeScales::new().with_dut1(0.3341);
+    assert_eq!(time_scales.dut1(), 0.3341);
+    assert_eq!(time_scales.leap_seconds(), &LeapSeconds::bundled());
+
+    for date in [
+        "1962-06-01T00:00:00",
+        "1972-06-30T23:59:00",
+        "1985-07-01T00:00:10",
+        "2016-12-31T23:00:00",
+        "2024-04-08T18:17:20",
+        "2100-01-01T00:00:00",
+    ] {
+        let jd = utc(date);
+        for from in TimeScale::ALL {
+            for to in TimeScale::ALL {
+                let converted = time_scales.convert(jd, from, to);
+                let back = time_scales.convert(converted, to, from);
+                assert!((back - jd).abs() * 86400.0 < 1e-6);
+            }
+        }
+    }
+
+    let ut1 = time_scales.convert(utc("2024-04-08T18:17:20Z"), TimeScale::Utc, TimeScale::Ut1);
+    assert!(((ut1 - utc("2024-04-08T18:17:20Z")) * 86400.0 - 0.3341).abs() < 1e-6);
+    let tt = time_scales.tt(ut1, TimeScale::Ut1);
+    assert!(((tt - ut1) * 86400.0 - 68.8499).abs() < 1e-6);
+}
+
+#[test]
+fn it_time_scale_names() {
+    for scale in TimeScale::ALL {
+        assert_eq!(scale.to_string().parse(), Ok(scale));
+    }
+    assert_eq!(" tt ".parse::<TimeScale>(), Ok(TimeScale::Tt));
+    assert_eq!("TDT".parse::<TimeScale>(), Err(Error::UnknownTimeScale));
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn it_leap_second_file() {
+    let table: LeapSeconds = LEAP_SECOND_DAT.parse().unwrap();
+    assert_eq!(table, LeapSeconds::bundled());
+
+    // A future leap second.
+    let updated: LeapSeconds = format!("{LEAP_SECOND_DAT}    61041.0    1  1 2026       38\n")
+        .parse()
+        .unwrap();
+    assert_eq!(updated.last_leap_second(), utc("2026-01-01"));
+    assert_eq!(updated.tai_minus_utc(utc("2025-12-31T23:59:59")), 37.0);
+    assert_eq!(updated.tai_minus_utc(utc("2026-06-01")), 38.0);
+
+    let time_scales = TimeScales::new().with_leap_seconds(updated);
+    let tt = time_scales.tt(utc("2026-06-01"), TimeScale::Utc);
+    assert!(((tt - utc("2026-06-01")) * 86400.0 - 70.184).abs() < 1e-6);
+
+    let path = std::env::temp_dir().join(format!("vsop87_leap_seconds_{}.dat", std::process::id()));
+    std::fs::write(&path, LEAP_SECOND_DAT).unwrap();
+    let loaded = LeapSeconds::from_file(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap(), LeapSeconds::bundled());
+
+    let error = LeapSeconds::from_file(path).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn it_leap_second_file_errors() {
+    for invalid in [
+        "",
+        "# Only comments\n",
+        "41317.0 1 1 1972\n",
+        "41317.0 1 1 1972 10 extra\n",
+        "41317.0 1 1 1972 ten\n",
+        "41317.0 1 13 1972 10\n",
+        // The Modified Julian Day does not match the date.
+        "41318.0 1 1 1972 10\n",
+        // The dates are not in increasing order.
+        "41499.0 1 7 1972 11\n41317.0 1 1 1972 10\n",
+    ] {
+        assert_eq!(
+            invalid.parse::<LeapSeconds>(),
+            Err(Error::InvalidLeapSeconds)
+        );
+    }
+
+    let error = LeapSeconds::from_file(file!()).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,147 @@
+use vsop87::*;
+
+#[test]
+fn it_twilights() {
+    let observer = Observer::new(0.8, -0.4, 120.0);
+    let date = JulianDay::from_gregorian(2024, 3, 20).unwrap();
+    let sun = rise_transit_set(
+        Body::Sun,
+        observer,
+        date,
+        DeltaT::EspenakMeeus,
+        Horizon::Sun,
+    )
+    .unwrap();
+
+    // The twilights begin one after the other before sunrise, and end in the opposite order
+    // after sunset.
+    let mut begin = sun.rise().unwrap();
+    let mut end = sun.set().unwrap();
+    for kind in [Twilight::Civil, Twilight::Nautical, Twilight::Astronomical] {
+        let times = twilight(observer, date, DeltaT::EspenakMeeus, kind).unwrap();
+        assert_eq!(times.visibility(), Visibility::CrossesHorizon);
+        assert!(times.begin().unwrap() < begin);
+        assert!(times.end().unwrap() > end);
+        begin = times.begin().unwrap();
+        end = times.end().unwrap();
+
+        // The center of the Sun is at the depression of the twilight.
+        for event in [times.begin().unwrap(), times.end().unwrap()] {
+            let altitude = horizontal_coordinates(
+                Body::Sun,
+                observer,
+                event,
+                DeltaT::EspenakMeeus.tt(event),
+                Nutation::Iau2000B,
+                Refraction::None,
+            )
+            .unwrap()
+            .geometric_altitude();
+            assert!((altitude + kind.depression()).abs() < 1e-6);
+        }
+
+        let custom = twilight(
+            observer,
+            date,
+            DeltaT::EspenakMeeus,
+            Twilight::Custom(kind.depression()),
+        )
+        .unwrap();
+        assert_eq!(custom, times);
+    }
+    assert!(begin > date);
+    assert!(end < date + 1.0);
+}
+
+#[test]
+fn it_short_night() {
+    // Near 48.5° of latitude at the summer solstice, the astronomical night lasts less than an
+    // hour, around local midnight, and it disappears a bit further north.
+    let date = JulianDay::from_gregorian(2024, 6, 21).unwrap();
+    let times = |latitude: f64| {
+        let observer = Observer::new(latitude.to_radians(), -7.5_f64.to_radians(), 0.0);
+        twilight(observer, date, DeltaT::EspenakMeeus, Twilight::Astronomical).unwrap()
+    };
+
+    let night = times(48.5);
+    assert_eq!(night.visibility(), Visibility::CrossesHorizon);
+    let (end, begin) = (night.end().unwrap(), night.begin().unwrap());
+    assert!(end < begin);
+    assert!(((begin - end) * 1_440.0 - 27.1).abs() < 0.5);
+
+    let white = times(48.6);
+    assert_eq!(white.visibility(), Visibility::Circumpolar);
+    assert_eq!(white.begin(), None);
+    assert_eq!(white.end(), None);
+}
+
+#[test]
+fn it_custom_depressions() {
+    // On the equator at the equinox, the Sun goes down vertically by 15° per hour: the civil
+    // twilight lasts 24 minutes, and the evening golden and blue hours 40 and 8 minutes.
+    let observer = Observer::new(0.0, 0.0, 0.0);
+    let date = JulianDay::from_gregorian(2024, 3, 20).unwrap();
+    let end = |degrees: f64| {
+        twilight(
+            observer,
+            date,
+            DeltaT::EspenakMeeus,
+            Twilight::Custom(degrees.to_radians()),
+        )
+        .unwrap()
+        .end()
+        .unwrap()
+    };
+
+    let minutes = |start: JulianDay, end: JulianDay| (end - start) * 1_440.0;
+    assert!((minutes(end(0.0), end(6.0)) - 24.0).abs() < 0.1);
+    assert!((minutes(end(-6.0), end(4.0)) - 40.0).abs() < 0.1);
+    assert!((minutes(end(4.0), end(6.0)) - 8.0).abs() < 0.1);
+}
+
+#[test]
+fn it_polar_twilights() {
+    let observer = Observer::new(80_f64.to_radians(), 15_f64.to_radians(), 0.0);
+
+    // In the polar summer, the Sun stays above the horizon.
+    let summer = JulianDay::from_gregorian(2024, 6, 21).unwrap();
+    for kind in [Twilight::Civil, Twilight::Nautical, Twilight::Astronomical] {
+        let times = twilight(observer, summer, DeltaT::EspenakMeeus, kind).unwrap();
+        assert_eq!(times.visibility(), Visibility::Circumpolar);
+        assert_eq!(times.begin(), None);
+        assert_eq!(times.end(), None);
+    }
+
+    // In the polar winter, the Sun culminates 13.4° below the horizon: there is only an
+    // astronomical twilight at noon.
+    let winter = JulianDay::from_gregorian(2024, 12, 21).unwrap();
+    for (kind, visibility) in [
+        (Twilight::Civil, Visibility::NeverRises),
+        (Twilight::Nautical, Visibility::NeverRises),
+        (Twilight::Astronomical, Visibility::CrossesHorizon),
+    ] {
+        let times = twilight(observer, winter, DeltaT::EspenakMeeus, kind).unwrap();
+        assert_eq!(times.visibility(), visibility);
+        assert_eq!(
+            times.begin().is_some(),
+            visibility == Visibility::CrossesHorizon
+        );
+        assert_eq!(
+            times.end().is_some(),
+            visibility == Visibility::CrossesHorizon
+        );
+    }
+}
+
+#[test]
+fn it_errors() {
+    assert_eq!(
+        twilight(
+            Observer::new(0.8, -0.4, 120.0),
+            f64::NAN,
+            DeltaT::EspenakMeeus,
+            Twilight::Civil
+        ),
+        Err(Error::NonFinite)
+    );
+}
@@ -0,0 +1,150 @@
+mod common;
+
+use common::{distance, ARCSECOND};
+use vsop87::{
+    typed::{Barycentric, EclipticJ2000, EclipticOfDate, Heliocentric, Position, Spherical},
+    *,
+};
+
+#[test]
+fn it_typed_functions() {
+    let jde = 2451545.0;
+
+    assert_eq!(
+        vsop87a::typed(Body::Mars, jde).unwrap().coordinates(),
+        vsop87a::mars(jde)
+    );
+    assert_eq!(
+        vsop87b::typed(Body::Mars, jde).unwrap().coordinates(),
+        vsop87b::mars(jde)
+    );
+    assert_eq!(
+        vsop87c::typed(Body::Mars, jde).unwrap().coordinates(),
+        vsop87c::mars(jde)
+    );
+    assert_eq!(
+        vsop87d::typed(Body::Mars, jde).unwrap().coordinates(),
+        vsop87d::mars(jde)
+    );
+    assert_eq!(
+        vsop87e::typed(Body::Mars, jde).unwrap().coordinates(),
+        vsop87e::mars(jde)
+    );
+    assert_eq!(
+        vsop87e::typed_state(Body::Mars, jde)
+            .unwrap()
+            .state_vector(),
+        vsop87e::mars_state(jde)
+    );
+
+    assert_eq!(
+        vsop87a::typed(Body::Sun, jde),
+        Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87A,
+            body: Body::Sun,
+        })
+    );
+    assert_eq!(
+        vsop87c::typed_state(Body::Mars, f64::NAN),
+        Err(Error::NonFinite)
+    );
+}
+
+#[test]
+fn it_centers() {
+    let jde = 2460000.5;
+
+    // The heliocentric positions of VSOP87A and VSOP87E agree to their precision.
+    let sun = vsop87e::typed_state(Body::Sun, jde).unwrap();
+    for body in [Body::Earth, Body::Mars, Body::Jupiter] {
+        let heliocentric = vsop87e::typed_state(body, jde).unwrap().heliocentric(sun);
+        let expected = vsop87a::typed_state(body, jde).unwrap();
+
+        assert!((heliocentric.position() - expected.position()).length() < 1e-6);
+        assert!((heliocentric.velocity() - expected.velocity()).speed() < 1e-8);
+    }
+
+    // The geocentric position of the Sun is opposite to the heliocentric position of the Earth.
+    let earth: Position<EclipticJ2000, Heliocentric> = vsop87a::typed(Body::Earth, jde).unwrap();
+    let origin = Position::<EclipticJ2000, Heliocentric>::new(RectangularCoordinates::default());
+    let sun = origin.geocentric(earth);
+    assert_eq!(sun.coordinates(), (-(earth - origin)).coordinates());
+    assert!((sun.distance() - earth.distance()).abs() < 1e-15);
+
+    // Moving along the velocity for a short time approximates the next position.
+    let state = vsop87a::typed_state(Body::Earth, jde).unwrap();
+    let next = vsop87a::typed(Body::Earth, jde + 0.01).unwrap();
+    let predicted = state.position() + state.velocity() * 0.01;
+    assert!((next - predicted).length() < 1e-7);
+    assert!((predicted - state.velocity() * 0.01 - state.position()).length() < 1e-15);
+}
+
+#[test]
+fn it_frames() {
+    let tt = 2469807.5;
+
+    // Precessing VSOP87A into the ecliptic of the date reproduces VSOP87C.
+    let mars = vsop87a::typed(Body::Mars, tt).unwrap();
+    let of_date = mars.to_ecliptic_of_date(tt, Precession::Iau1976);
+    let expected: Position<EclipticOfDate, Heliocentric> = vsop87c::typed(Body::Mars, tt).unwrap();
+    assert!((of_date - expected).length() / expected.distance() < 0.02 * ARCSECOND);
+    let back = of_date.to_ecliptic_j2000(tt, Precession::Iau1976);
+    assert!((back - mars).length() < 1e-14);
+
+    // The mean equator of the date is reached in the same way from every frame.
+    let from_fk5 = mars
+        .to_fk5()
+        .to_mean_equator_of_date(tt, Precession::Iau1976);
+    let from_ecliptic = expected.to_mean_equator_of_date(tt, Precession::Iau1976);
+    assert!((from_fk5 - from_ecliptic).length() / mars.distance() < 0.02 * ARCSECOND);
+    let from_icrs = mars
+        .to_icrs()
+        .to_mean_equator_of_date(tt, Precession::Iau2006);
+    let via_fk5 = from_fk5.to_fk5(tt, Precession::Iau1976);
+    assert!((via_fk5 - mars.to_fk5()).length() < 1e-14);
+    assert!((from_icrs.to_icrs(tt, Precession::Iau2006) - mars.to_icrs()).length() < 1e-14);
+
+    // The true equator of the date is reached from the ecliptic or from the mean equator.
+    let true_equator = expected.to_true_equator_of_date(tt, Nutation::Iau1980);
+    let through_mean = from_ecliptic.to_true_equator_of_date(tt, Nutation::Iau1980);
+    assert!(distance(true_equator.coordinates(), through_mean.coordinates()) < 1e-14);
+    let mean = true_equator.to_mean_equator_of_date(tt, Nutation::Iau1980);
+    assert!((mean - from_ecliptic).length() < 1e-14);
+
+    // The rotations of the states, displacements and velocities are undone by the inverse ones,
+    // to the 12 decimals of the rotation into FK5.
+    let state = vsop87a::typed_state(Body::Mars, tt).unwrap();
+    let fk5 = state.to_fk5();
+    assert_eq!(fk5.position(), state.position().to_fk5());
+    assert_eq!(fk5.velocity(), state.velocity().to_fk5());
+    assert!((fk5.to_ecliptic_j2000().position() - state.position()).length() < 1e-11);
+    let icrs = state.to_icrs();
+    assert!((icrs.velocity().to_ecliptic_j2000() - state.velocity()).speed() < 1e-16);
+    assert!((icrs.to_ecliptic_j2000().velocity() - state.velocity()).speed() < 1e-16);
+
+    let displacement = mars.displacement();
+    assert_eq!(displacement.to_icrs(), mars.to_icrs().displacement());
+    assert!((displacement.to_fk5().to_ecliptic_j2000() - displacement).length() < 1e-11);
+    assert!((mars.to_icrs().to_ecliptic_j2000() - mars).length() < 1e-14);
+    assert!((mars.to_fk5().to_ecliptic_j2000() - mars).length() < 1e-11);
+}
+
+#[test]
+fn it_spherical() {
+    let jde = 2451545.0;
+
+    let spherical: Spherical<EclipticJ2000, Heliocentric> =
+        vsop87b::typed(Body::Jupiter, jde).unwrap();
+    let rectangular = vsop87a::typed(Body::Jupiter, jde).unwrap();
+    assert!((Position::from(spherical) - rectangular).length() < 1e-6);
+
+    let converted = Spherical::from(rectangular).coordinates();
+    assert!((converted.longitude() - spherical.coordinates().longitude()).abs() < 1e-6);
+    assert!((converted.latitude() - spherical.coordinates().latitude()).abs() < 1e-6);
+
+    let barycentric: Spherical<EclipticJ2000, Barycentric> =
+        vsop87e::typed(Body::Jupiter, jde).unwrap().into();
+    assert!(
+        (barycentric.coordinates().distance() - spherical.coordinates().distance()).abs() < 0.01
+    );
+}
@@ -0,0 +1,188 @@
+use vsop87::*;
+
+#[test]
+fn it_validity_table() {
+    for (body, validity) in VALIDITY {
+        assert_eq!(Validity::vsop87(body), validity);
+        assert!(validity.contains(2451545.0));
+    }
+
+    assert_eq!(
+        Validity::vsop87(Body::Mercury),
+        Validity::around_j2000(4000.0)
+    );
+    assert_eq!(
+        Validity::vsop87(Body::EarthMoon),
+        Validity::around_j2000(4000.0)
+    );
+    assert_eq!(Validity::vsop87(Body::Sun), Validity::around_j2000(2000.0));
+    assert_eq!(
+        Validity::vsop87(Body::Saturn),
+        Validity::around_j2000(2000.0)
+    );
+    assert_eq!(
+        Validity::vsop87(Body::Uranus),
+        Validity::around_j2000(6000.0)
+    );
+
+    for body in Body::ALL {
+        assert!(VALIDITY
+            .iter()
+            .any(|(validity_body, _)| *validity_body == body));
+    }
+}
+
+#[test]
+fn it_checked() {
+    let jde = 2415020.0;
+
+    assert_eq!(vsop87::checked(Body::Mars, jde), Ok(vsop87::mars(jde)));
+    assert_eq!(vsop87a::checked(Body::Earth, jde), Ok(vsop87a::earth(jde)));
+    assert_eq!(
+        vsop87b::checked(Body::Saturn, jde),
+        Ok(vsop87b::saturn(jde))
+    );
+    assert_eq!(vsop87c::checked(Body::Venus, jde), Ok(vsop87c::venus(jde)));
+    assert_eq!(
+        vsop87d::checked(Body::Neptune, jde),
+        Ok(vsop87d::neptune(jde))
+    );
+    assert_eq!(vsop87e::checked(Body::Sun, jde), Ok(vsop87e::sun(jde)));
+
+    assert_eq!(
+        vsop87::checked_state(Body::Uranus, jde),
+        Ok(vsop87::uranus_state(jde))
+    );
+    assert_eq!(
+        vsop87d::checked_state(Body::Mercury, jde),
+        Ok(vsop87d::mercury_state(jde))
+    );
+    assert_eq!(
+        vsop87e::checked_state(Body::Jupiter, jde),
+        Ok(vsop87e::jupiter_state(jde))
+    );
+}
+
+#[test]
+fn it_checked_errors() {
+    // Saturn is only valid for 2,000 years around J2000.0.
+    assert_eq!(
+        vsop87b::checked(Body::Saturn, 0.0),
+        Err(Error::OutOfRange { body: Body::Saturn })
+    );
+    assert_eq!(
+        vsop87b::checked(Body::Saturn, 1_700_000.0),
+        Err(Error::OutOfRange { body: Body::Saturn })
+    );
+    assert!(vsop87b::checked(Body::Mars, 1_700_000.0).is_ok());
+    assert!(vsop87b::checked(Body::Neptune, 0.0).is_err());
+    assert!(vsop87b::checked(Body::Neptune, 300_000.0).is_ok());
+
+    for jde in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(vsop87a::checked(Body::Earth, jde), Err(Error::NonFinite));
+        assert_eq!(
+            vsop87c::checked_state(Body::Earth, jde),
+            Err(Error::NonFinite)
+        );
+        assert_eq!(
+            calculate_checked(Solution::VSOP87, Body::Venus, jde),
+            Err(Error::NonFinite)
+        );
+    }
+
+    // Unsupported bodies are reported before invalid Julian Days.
+    assert_eq!(
+        vsop87d::checked(Body::Sun, f64::NAN),
+        Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87D,
+            body: Body::Sun,
+        })
+    );
+    assert_eq!(
+        calculate_checked(Solution::VSOP87, Body::Earth, 0.0),
+        Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87,
+            body: Body::Earth,
+        })
+    );
+}
+
+#[test]
+fn it_checked_batch_and_all_bodies() {
+    let jdes = [2451545.0, 2451910.5, 2452275.5];
+    let mut output = [RectangularCoordinates::default(); 3];
+    let mut expected = [RectangularCoordinates::default(); 3];
+
+    vsop87e::batch_checked(Body::Sun, &jdes, &mut output).unwrap();
+    vsop87e::batch(Body::Sun, &jdes, &mut expected).unwrap();
+    assert_eq!(output, expected);
+
+    assert_eq!(
+        vsop87e::batch_checked(Body::Sun, &[2451545.0, 1_000_000.0, 2451545.0], &mut output),
+        Err(Error::OutOfRange { body: Body::Sun })
+    );
+
+    let julian_days = jdes.map(JulianDay::new);
+    vsop87e::batch_julian_days_checked(Body::Sun, &julian_days, &mut output).unwrap();
+    assert_eq!(output, expected);
+    assert_eq!(
+        vsop87e::batch_julian_days_checked(Body::EarthMoon, &julian_days, &mut output),
+        Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87E,
+            body: Body::EarthMoon
+        })
+    );
+
+    assert_eq!(
+        vsop87d::all_bodies_checked(2451545.0),
+        Ok(vsop87d::all_bodies(2451545.0))
+    );
+    // Valid for the inner planets, but not for Jupiter.
+    assert_eq!(
+        vsop87d::all_bodies_checked(1_500_000.0),
+        Err(Error::OutOfRange {
+            body: Body::Jupiter
+        })
+    );
+    #[cfg(feature = "rayon")]
+    assert_eq!(
+        vsop87d::all_bodies_parallel_checked(1_500_000.0),
+        Err(Error::OutOfRange {
+            body: Body::Jupiter
+        })
+    );
+
+    assert_eq!(
+        vsop87::truncated_checked(Body::Neptune, 2451545.0, Precision::Arcseconds(1.0)),
+        vsop87::truncated(Body::Neptune, 2451545.0, Precision::Arcseconds(1.0))
+    );
+    assert_eq!(
+        vsop87::truncated_checked(Body::Neptune, f64::NAN, Precision::Full),
+        Err(Error::NonFinite)
+    );
+}
+
+#[test]
+fn it_position_checked() {
+    assert_eq!(
+        vsop87c::VSOP87C.position_checked(Body::Uranus, 2451545.0),
+        vsop87c::VSOP87C.position(Body::Uranus, 2451545.0)
+    );
+    assert_eq!(
+        Solution::VSOP87A.position_checked(Body::Jupiter, 0.0),
+        Err(Error::OutOfRange {
+            body: Body::Jupiter
+        })
+    );
+    assert_eq!(
+        Solution::VSOP87A.position_checked(Body::Sun, 2451545.0),
+        Err(Error::UnsupportedBody {
+            solution: Solution::VSOP87A,
+            body: Body::Sun,
+        })
+    );
+    assert_eq!(
+        Solution::VSOP87E.calculate_checked(Body::Sun, f64::NAN),
+        Err(Error::NonFinite)
+    );
+}
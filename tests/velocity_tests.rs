@@ -0,0 +1,267 @@
+use vsop87::*;
+
+const EPOCHS: [f64; 4] = [2451545.0, 2415020.0, 2268920.0, 2634200.5];
+
+/// Checks that the state vector matches the position function, and that the velocity matches the
+/// central finite difference of the position.
+fn check_state(position: fn(f64) -> RectangularCoordinates, state: fn(f64) -> StateVector) {
+    const H: f64 = 0.01;
+
+    for jde in EPOCHS {
+        let expected = position(jde);
+        let state = state(jde);
+
+        assert!((state.position.x - expected.x).abs() < 1e-12);
+        assert!((state.position.y - expected.y).abs() < 1e-12);
+        assert!((state.position.z - expected.z).abs() < 1e-12);
+
+        let before = position(jde - H);
+        let after = position(jde + H);
+
+        assert!((state.velocity.x - (after.x - before.x) / (2.0 * H)).abs() < 1e-8);
+        assert!((state.velocity.y - (after.y - before.y) / (2.0 * H)).abs() < 1e-8);
+        assert!((state.velocity.z - (after.z - before.z) / (2.0 * H)).abs() < 1e-8);
+    }
+}
+
+/// Checks that the spherical state matches the position function, and that the rates match the
+/// central finite difference of the coordinates.
+fn check_spherical_state(
+    position: fn(f64) -> SphericalCoordinates,
+    state: fn(f64) -> SphericalState,
+) {
+    const H: f64 = 0.01;
+
+    for jde in EPOCHS {
+        let expected = position(jde);
+        let state = state(jde);
+        let coordinates = state.coordinates();
+
+        // The longitude grows a lot in a millennium, so the rounding error is a bit bigger.
+        assert!((coordinates.longitude() - expected.longitude()).abs() < 1e-10);
+        assert!((coordinates.latitude() - expected.latitude()).abs() < 1e-12);
+        assert!((coordinates.distance() - expected.distance()).abs() < 1e-12);
+
+        let before = position(jde - H);
+        let after = position(jde + H);
+
+        let mut lon_diff = after.longitude() - before.longitude();
+        if lon_diff > std::f64::consts::PI {
+            lon_diff -= 2.0 * std::f64::consts::PI;
+        } else if lon_diff < -std::f64::consts::PI {
+            lon_diff += 2.0 * std::f64::consts::PI;
+        }
+
+        assert!((state.longitude_rate() - lon_diff / (2.0 * H)).abs() < 1e-8);
+        assert!(
+            (state.latitude_rate() - (after.latitude() - before.latitude()) / (2.0 * H)).abs()
+                < 1e-8
+        );
+        assert!(
+            (state.distance_rate() - (after.distance() - before.distance()) / (2.0 * H)).abs()
+                < 1e-8
+        );
+    }
+}
+
+#[test]
+fn it_vsop87a_state() {
+    check_state(vsop87a::mercury, vsop87a::mercury_state);
+    check_state(vsop87a::venus, vsop87a::venus_state);
+    check_state(vsop87a::earth, vsop87a::earth_state);
+    check_state(vsop87a::earth_moon, vsop87a::earth_moon_state);
+    check_state(vsop87a::mars, vsop87a::mars_state);
+    check_state(vsop87a::jupiter, vsop87a::jupiter_state);
+    check_state(vsop87a::saturn, vsop87a::saturn_state);
+    check_state(vsop87a::uranus, vsop87a::uranus_state);
+    check_state(vsop87a::neptune, vsop87a::neptune_state);
+}
+
+#[test]
+fn it_vsop87c_state() {
+    check_state(vsop87c::mercury, vsop87c::mercury_state);
+    check_state(vsop87c::venus, vsop87c::venus_state);
+    check_state(vsop87c::earth, vsop87c::earth_state);
+    check_state(vsop87c::mars, vsop87c::mars_state);
+    check_state(vsop87c::jupiter, vsop87c::jupiter_state);
+    check_state(vsop87c::saturn, vsop87c::saturn_state);
+    check_state(vsop87c::uranus, vsop87c::uranus_state);
+    check_state(vsop87c::neptune, vsop87c::neptune_state);
+}
+
+#[test]
+fn it_vsop87b_state() {
+    check_spherical_state(vsop87b::mercury, vsop87b::mercury_state);
+    check_spherical_state(vsop87b::venus, vsop87b::venus_state);
+    check_spherical_state(vsop87b::earth, vsop87b::earth_state);
+    check_spherical_state(vsop87b::mars, vsop87b::mars_state);
+    check_spherical_state(vsop87b::jupiter, vsop87b::jupiter_state);
+    check_spherical_state(vsop87b::saturn, vsop87b::saturn_state);
+    check_spherical_state(vsop87b::uranus, vsop87b::uranus_state);
+    check_spherical_state(vsop87b::neptune, vsop87b::neptune_state);
+}
+
+#[test]
+fn it_vsop87d_state() {
+    check_spherical_state(vsop87d::mercury, vsop87d::mercury_state);
+    check_spherical_state(vsop87d::venus, vsop87d::venus_state);
+    check_spherical_state(vsop87d::earth, vsop87d::earth_state);
+    check_spherical_state(vsop87d::mars, vsop87d::mars_state);
+    check_spherical_state(vsop87d::jupiter, vsop87d::jupiter_state);
+    check_spherical_state(vsop87d::saturn, vsop87d::saturn_state);
+    check_spherical_state(vsop87d::uranus, vsop87d::uranus_state);
+    check_spherical_state(vsop87d::neptune, vsop87d::neptune_state);
+}
+
+#[test]
+fn it_vsop87e_state() {
+    check_state(vsop87e::sun, vsop87e::sun_state);
+    check_state(vsop87e::mercury, vsop87e::mercury_state);
+    check_state(vsop87e::venus, vsop87e::venus_state);
+    check_state(vsop87e::earth, vsop87e::earth_state);
+    check_state(vsop87e::mars, vsop87e::mars_state);
+    check_state(vsop87e::jupiter, vsop87e::jupiter_state);
+    check_state(vsop87e::saturn, vsop87e::saturn_state);
+    check_state(vsop87e::uranus, vsop87e::uranus_state);
+    check_state(vsop87e::neptune, vsop87e::neptune_state);
+}
+
+#[test]
+fn it_earth_orbital_speed() {
+    let state = vsop87a::earth_state(2451545.0);
+    let speed = (state.velocity.x * state.velocity.x
+        + state.velocity.y * state.velocity.y
+        + state.velocity.z * state.velocity.z)
+        .sqrt();
+
+    // Around 30.3 km/s, near the perihelion.
+    assert!(speed > 0.0174 && speed < 0.0176);
+}
+
+#[test]
+fn it_earth_radial_velocity() {
+    // The Earth is near the perihelion in early January, and the distance grows afterwards.
+    let state = vsop87b::earth_state(2451545.0 + 10.0);
+    assert!(state.distance_rate() > 0.0);
+
+    let state = vsop87b::earth_state(2451545.0 - 10.0);
+    assert!(state.distance_rate() < 0.0);
+
+    // A bit more than one degree per day.
+    assert!(state.longitude_rate() > 0.0175 && state.longitude_rate() < 0.0180);
+}
+
+/// Checks that the elements state matches the elements function, and that the rates match the
+/// central finite difference of the elements.
+fn check_elements_state(
+    elements: fn(f64) -> VSOP87Elements,
+    state: fn(f64) -> VSOP87ElementsState,
+) {
+    const H: f64 = 0.01;
+
+    for jde in EPOCHS {
+        let expected = elements(jde);
+        let state = state(jde);
+
+        assert!((state.elements.a - expected.a).abs() < 1e-12);
+        assert!((state.elements.l - expected.l).abs() < 1e-10);
+        assert!((state.elements.k - expected.k).abs() < 1e-12);
+        assert!((state.elements.h - expected.h).abs() < 1e-12);
+        assert!((state.elements.q - expected.q).abs() < 1e-12);
+        assert!((state.elements.p - expected.p).abs() < 1e-12);
+
+        let before = elements(jde - H);
+        let after = elements(jde + H);
+
+        let mut l_diff = after.l - before.l;
+        if l_diff < 0.0 {
+            l_diff += 2.0 * std::f64::consts::PI;
+        }
+
+        assert!((state.rates.a - (after.a - before.a) / (2.0 * H)).abs() < 1e-8);
+        assert!((state.rates.l - l_diff / (2.0 * H)).abs() < 1e-8);
+        assert!((state.rates.k - (after.k - before.k) / (2.0 * H)).abs() < 1e-8);
+        assert!((state.rates.h - (after.h - before.h) / (2.0 * H)).abs() < 1e-8);
+        assert!((state.rates.q - (after.q - before.q) / (2.0 * H)).abs() < 1e-8);
+        assert!((state.rates.p - (after.p - before.p) / (2.0 * H)).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn it_vsop87_state() {
+    check_elements_state(vsop87::mercury, vsop87::mercury_state);
+    check_elements_state(vsop87::venus, vsop87::venus_state);
+    check_elements_state(vsop87::earth_moon, vsop87::earth_moon_state);
+    check_elements_state(vsop87::mars, vsop87::mars_state);
+    check_elements_state(vsop87::jupiter, vsop87::jupiter_state);
+    check_elements_state(vsop87::saturn, vsop87::saturn_state);
+    check_elements_state(vsop87::uranus, vsop87::uranus_state);
+    check_elements_state(vsop87::neptune, vsop87::neptune_state);
+}
+
+#[test]
+fn it_precession() {
+    /// Radians per day to arcseconds per Julian century.
+    const FACTOR: f64 = 36525.0 * 180.0 * 3600.0 / std::f64::consts::PI;
+
+    // Average over a century, to remove most of the periodic perturbations.
+    let (apsidal, nodal, mean_motion) = (0..1000)
+        .map(|i| KeplerianRates::from(vsop87::mercury_state(2451545.0 + 36.525 * f64::from(i))))
+        .fold((0.0, 0.0, 0.0), |(apsidal, nodal, mean_motion), rates| {
+            (
+                apsidal + rates.apsidal_precession().unwrap() / 1000.0,
+                nodal + rates.nodal_precession().unwrap() / 1000.0,
+                mean_motion + rates.mean_anomaly_rate() / 1000.0,
+            )
+        });
+
+    // Around 575"/century for the perihelion, and -450"/century for the node.
+    assert!(apsidal * FACTOR > 525.0 && apsidal * FACTOR < 625.0);
+    assert!(nodal * FACTOR > -475.0 && nodal * FACTOR < -425.0);
+    // Around 4.09 degrees per day.
+    assert!(mean_motion.to_degrees() > 4.09 && mean_motion.to_degrees() < 4.095);
+
+    let rates = KeplerianRates::from(vsop87::earth_moon_state(2451545.0));
+    assert!(rates.semimajor_axis_rate().abs() < 1e-6);
+    assert!(rates.eccentricity_rate().abs() < 1e-6);
+    assert!(rates.inclination_rate().abs() < 1e-6);
+}
+
+#[test]
+fn it_singular_rates() {
+    // A circular orbit in the plane of the ecliptic, with its elements growing from zero.
+    let rates = KeplerianRates::from(VSOP87ElementsState {
+        elements: VSOP87Elements {
+            a: 1.0,
+            l: 0.0,
+            k: 0.0,
+            h: 0.0,
+            q: 0.0,
+            p: 0.0,
+        },
+        rates: VSOP87ElementsRates {
+            a: 0.0,
+            l: 0.017,
+            k: -3e-8,
+            h: 4e-8,
+            q: 5e-9,
+            p: -1.2e-8,
+        },
+    });
+
+    assert!((rates.eccentricity_rate() - 5e-8).abs() < 1e-20);
+    assert!((rates.inclination_rate() - 2.6e-8).abs() < 1e-20);
+    assert_eq!(rates.apsidal_precession(), None);
+    assert_eq!(rates.nodal_precession(), None);
+    assert!((rates.mean_anomaly_rate() - 0.017).abs() < 1e-15);
+
+    // Every rate is defined for the planets.
+    for state in [
+        vsop87::mercury_state(2451545.0),
+        vsop87::neptune_state(2451545.0),
+    ] {
+        let rates = KeplerianRates::from(state);
+        assert!(rates.apsidal_precession().unwrap().is_finite());
+        assert!(rates.nodal_precession().unwrap().is_finite());
+    }
+}